@@ -0,0 +1,94 @@
+//! Benchmarks for the sequential and the parallel evaluation of the
+//! residual Helmholtz energy contributions.
+//! Both evaluations are done for every number of contributions,
+//! independent of `MIN_PARALLEL_CONTRIBUTIONS`, so that the benchmark
+//! shows from which number of contributions on the parallel
+//! evaluation in `EquationOfState::evaluate_residual` pays off.
+use criterion::{criterion_group, criterion_main, Criterion};
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::{
+    parameter::{IdentifierOption, Parameter},
+    DensityInitialization, Derivative, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual,
+    State, StateHD,
+};
+use ndarray::arr1;
+use num_dual::DualNum;
+use quantity::si::*;
+use rayon::prelude::*;
+use std::sync::Arc;
+
+/// Residual Helmholtz energy with all contributions evaluated sequentially.
+fn a_res_sequential<D: DualNum<f64>, E: EquationOfState>(inp: (&Arc<E>, &StateHD<D>)) -> D
+where
+    (dyn HelmholtzEnergy + 'static): HelmholtzEnergyDual<D>,
+{
+    inp.0
+        .residual()
+        .iter()
+        .map(|c| c.helmholtz_energy(inp.1))
+        .sum()
+}
+
+/// Residual Helmholtz energy with all contributions evaluated in parallel.
+fn a_res_parallel<D: DualNum<f64>, E: EquationOfState>(inp: (&Arc<E>, &StateHD<D>)) -> D
+where
+    (dyn HelmholtzEnergy + 'static): HelmholtzEnergyDual<D>,
+{
+    inp.0
+        .residual()
+        .par_iter()
+        .map(|c| c.helmholtz_energy(inp.1))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .sum()
+}
+
+/// Benchmark for the sequential and parallel evaluation for different dual number types.
+fn bench_parallel<E: EquationOfState>(c: &mut Criterion, group_name: &str, state: State<E>) {
+    let mut group = c.benchmark_group(group_name);
+    let state_f64 = state.derive0();
+    let state_dual3 = state.derive3(Derivative::DV);
+    group.bench_function("a_f64_sequential", |b| {
+        b.iter(|| a_res_sequential((&state.eos, &state_f64)))
+    });
+    group.bench_function("a_f64_parallel", |b| {
+        b.iter(|| a_res_parallel((&state.eos, &state_f64)))
+    });
+    group.bench_function("a_dual3_sequential", |b| {
+        b.iter(|| a_res_sequential((&state.eos, &state_dual3)))
+    });
+    group.bench_function("a_dual3_parallel", |b| {
+        b.iter(|| a_res_parallel((&state.eos, &state_dual3)))
+    });
+}
+
+/// Benchmark for the PC-SAFT equation of state with three to five contributions.
+fn pcsaft(c: &mut Criterion) {
+    let t = 300.0 * KELVIN;
+    let p = BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    for (component, file, contributions) in [
+        ("hexane", "gross2001", 3),
+        ("acetone", "gross2006", 4),
+        ("water_4C_polar", "rehner2020", 5),
+    ] {
+        let parameters = PcSaftParameters::from_json(
+            vec![component],
+            format!("./parameters/pcsaft/{file}.json"),
+            None,
+            IdentifierOption::Name,
+        )
+        .unwrap();
+        let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+        assert_eq!(eos.residual().len(), contributions);
+        let state = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid).unwrap();
+        bench_parallel(
+            c,
+            &format!("parallel_contributions_{component}_{contributions}"),
+            state,
+        );
+    }
+}
+
+criterion_group!(bench, pcsaft);
+criterion_main!(bench);
@@ -2,7 +2,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::{
     parameter::{IdentifierOption, Parameter},
-    Contributions, DensityInitialization, EquationOfState, PhaseEquilibrium, State,
+    Contributions, DensityInitialization, EquationOfState, Phase, PhaseEquilibrium, State,
 };
 use ndarray::{Array, Array1};
 use quantity::si::*;
@@ -21,6 +21,20 @@ fn npt<E: EquationOfState>(
     State::new_npt(eos, t, p, n, rho0).unwrap();
 }
 
+/// Evaluate logarithmic fugacity coefficients via the NPT constructor
+fn ln_phi_state<E: EquationOfState>(
+    (eos, t, p, n, phase): (&Arc<E>, SINumber, SINumber, &SIArray1, Phase),
+) {
+    State::new_npt(eos, t, p, n, phase).unwrap().ln_phi();
+}
+
+/// Evaluate logarithmic fugacity coefficients without creating a state
+fn ln_phi_eos<E: EquationOfState>(
+    (eos, t, p, n, phase): (&Arc<E>, SINumber, SINumber, &SIArray1, Phase),
+) {
+    eos.ln_phi(t, p, Some(n), phase).unwrap();
+}
+
 /// Evaluate critical point constructor
 fn critical_point<E: EquationOfState>((eos, n): (&Arc<E>, Option<&SIArray1>)) {
     State::critical_point(eos, n, None, Default::default()).unwrap();
@@ -108,6 +122,28 @@ fn bench_states<E: EquationOfState>(c: &mut Criterion, group_name: &str, eos: &A
             ))
         })
     });
+    group.bench_function("ln_phi_state_liquid", |b| {
+        b.iter(|| {
+            ln_phi_state((
+                eos,
+                vle.liquid().temperature,
+                vle.liquid().pressure(Contributions::Total) * 1.01,
+                &n,
+                Phase::Liquid,
+            ))
+        })
+    });
+    group.bench_function("ln_phi_eos_liquid", |b| {
+        b.iter(|| {
+            ln_phi_eos((
+                eos,
+                vle.liquid().temperature,
+                vle.liquid().pressure(Contributions::Total) * 1.01,
+                &n,
+                Phase::Liquid,
+            ))
+        })
+    });
     group.bench_function("critical_point", |b| {
         b.iter(|| critical_point((eos, Some(&n))))
     });
@@ -0,0 +1,83 @@
+//! Benchmarks for the evaluation of the residual Helmholtz energy of
+//! SAFT-VRQ Mie with and without the temperature cache of the state.
+//! The effective and hard-sphere diameters of SAFT-VRQ Mie require a
+//! numerical integration of the potential. With the cache, they are
+//! only evaluated once per evaluation of the Helmholtz energy instead
+//! of once per Helmholtz energy contribution.
+use criterion::{criterion_group, criterion_main, Criterion};
+use feos::saftvrqmie::{SaftVRQMie, SaftVRQMieParameters};
+use feos_core::{
+    parameter::{IdentifierOption, Parameter},
+    DensityInitialization, Derivative, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual,
+    State, StateHD,
+};
+use ndarray::arr1;
+use num_dual::DualNum;
+use quantity::si::*;
+use std::sync::Arc;
+
+/// Residual Helmholtz energy without the temperature cache.
+fn a_res_uncached<D: DualNum<f64>, E: EquationOfState>(inp: (&Arc<E>, &StateHD<D>)) -> D
+where
+    (dyn HelmholtzEnergy + 'static): HelmholtzEnergyDual<D>,
+{
+    inp.0
+        .residual()
+        .iter()
+        .map(|c| c.helmholtz_energy(inp.1))
+        .sum()
+}
+
+/// Benchmark for the evaluation with and without cache for different dual number types.
+fn bench_cache<E: EquationOfState>(c: &mut Criterion, group_name: &str, state: State<E>) {
+    let mut group = c.benchmark_group(group_name);
+    let state_f64 = state.derive0();
+    let state_dual = state.derive1(Derivative::DT);
+    let state_dual3 = state.derive3(Derivative::DT);
+    group.bench_function("a_f64_uncached", |b| {
+        b.iter(|| a_res_uncached((&state.eos, &state_f64)))
+    });
+    group.bench_function("a_f64_cached", |b| {
+        b.iter(|| state.eos.evaluate_residual(&state_f64))
+    });
+    group.bench_function("a_dual_uncached", |b| {
+        b.iter(|| a_res_uncached((&state.eos, &state_dual)))
+    });
+    group.bench_function("a_dual_cached", |b| {
+        b.iter(|| state.eos.evaluate_residual(&state_dual))
+    });
+    group.bench_function("a_dual3_uncached", |b| {
+        b.iter(|| a_res_uncached((&state.eos, &state_dual3)))
+    });
+    group.bench_function("a_dual3_cached", |b| {
+        b.iter(|| state.eos.evaluate_residual(&state_dual3))
+    });
+}
+
+/// Benchmark for SAFT-VRQ Mie for a pure component and a binary mixture.
+fn saftvrqmie(c: &mut Criterion) {
+    let t = 50.0 * KELVIN;
+    let p = BAR;
+    for components in [vec!["hydrogen"], vec!["hydrogen", "neon"]] {
+        let binary =
+            (components.len() > 1).then(|| "./parameters/saftvrqmie/aasen2020_binary.json");
+        let parameters = SaftVRQMieParameters::from_json(
+            components.clone(),
+            "./parameters/saftvrqmie/hammer2023.json",
+            binary,
+            IdentifierOption::Name,
+        )
+        .unwrap();
+        let eos = Arc::new(SaftVRQMie::new(Arc::new(parameters)));
+        let moles = arr1(&vec![1.0; components.len()]) * MOL;
+        let state = State::new_npt(&eos, t, p, &moles, DensityInitialization::Vapor).unwrap();
+        bench_cache(
+            c,
+            &format!("temperature_cache_{}", components.join("_")),
+            state,
+        );
+    }
+}
+
+criterion_group!(bench, saftvrqmie);
+criterion_main!(bench);
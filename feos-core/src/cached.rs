@@ -0,0 +1,214 @@
+//! Wrapper around an equation of state that memoizes recently evaluated states.
+use crate::equation_of_state::EquationOfState;
+use crate::errors::{EosError, EosResult};
+use crate::phase_equilibria::{PhaseDiagram, SolverOptions};
+use crate::state::State;
+use crate::EosUnit;
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Number of bits of the mantissa that are discarded when rounding
+/// the state variables for the cache key (relative precision ~1e-12).
+const ROUNDING_BITS: u32 = 12;
+
+struct StateCache<E> {
+    states: VecDeque<(Vec<u64>, Arc<State<E>>)>,
+    hit: u64,
+    miss: u64,
+}
+
+/// Equation of state wrapper that memoizes the most recently used states.
+///
+/// States are identified by their temperature, volume and amount of substance
+/// of each component, rounded to a relative precision of approximately $10^{-12}$.
+/// Because a [State] caches all partial derivatives that have been evaluated,
+/// querying the same property of a cached state again does not require a
+/// re-evaluation of the equation of state.
+///
+/// The cache is bounded: if more than `capacity` different states are requested,
+/// the least recently used state is discarded. The wrapper can be shared between
+/// threads.
+///
+/// Additionally, the pure component critical points are calculated only once per
+/// wrapper and reused by [CachedEquationOfState::acentric_factors],
+/// [CachedEquationOfState::phase_diagram_pure] and
+/// [CachedEquationOfState::critical_locus_binary]. The corresponding functions
+/// of [State] and [PhaseDiagram] that are called with the equation of state
+/// directly do not use the cache. Because the wrapper holds its own reference to the equation of state,
+/// a subset of the equation of state requires a new wrapper and, therefore, never
+/// uses the critical points of the full system.
+pub struct CachedEquationOfState<E> {
+    eos: Arc<E>,
+    capacity: usize,
+    cache: Mutex<StateCache<E>>,
+    critical_points: Mutex<Option<Vec<State<E>>>>,
+}
+
+impl<E: EquationOfState> CachedEquationOfState<E> {
+    /// Create a new wrapper that stores at most `capacity` states.
+    pub fn new(eos: &Arc<E>, capacity: usize) -> Self {
+        Self {
+            eos: eos.clone(),
+            capacity,
+            cache: Mutex::new(StateCache {
+                states: VecDeque::with_capacity(capacity),
+                hit: 0,
+                miss: 0,
+            }),
+            critical_points: Mutex::new(None),
+        }
+    }
+
+    /// Return the underlying equation of state.
+    pub fn eos(&self) -> &Arc<E> {
+        &self.eos
+    }
+
+    /// Return the state for the given temperature, volume and moles.
+    ///
+    /// If the same state has been requested recently, the cached state is returned,
+    /// otherwise a new state is created and stored in the cache.
+    pub fn state(
+        &self,
+        temperature: SINumber,
+        volume: SINumber,
+        moles: &SIArray1,
+    ) -> EosResult<Arc<State<E>>> {
+        let key = cache_key(
+            temperature.to_reduced(SIUnit::reference_temperature())?,
+            volume.to_reduced(SIUnit::reference_volume())?,
+            moles.to_reduced(SIUnit::reference_moles())?.iter(),
+        );
+
+        {
+            let mut cache = self.cache.lock().unwrap();
+            if let Some(i) = cache.states.iter().position(|(k, _)| *k == key) {
+                cache.hit += 1;
+                let entry = cache.states.remove(i).unwrap();
+                let state = entry.1.clone();
+                cache.states.push_back(entry);
+                return Ok(state);
+            }
+            cache.miss += 1;
+        }
+
+        // the state is created without holding the lock; if another thread
+        // stored the same state in the meantime, it is not stored again
+        let state = Arc::new(State::new_nvt(&self.eos, temperature, volume, moles)?);
+        let mut cache = self.cache.lock().unwrap();
+        if self.capacity > 0 && !cache.states.iter().any(|(k, _)| *k == key) {
+            if cache.states.len() == self.capacity {
+                cache.states.pop_front();
+            }
+            cache.states.push_back((key, state.clone()));
+        }
+        Ok(state)
+    }
+
+    /// Return the critical points of all pure components.
+    ///
+    /// The critical points are calculated with [State::critical_point_pure] on the
+    /// first call and returned from the cache afterwards. The `options` are only
+    /// used for the first successful calculation; errors are not cached.
+    pub fn critical_point_pure(&self, options: SolverOptions) -> EosResult<Vec<State<E>>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if let Some(cp) = self.critical_points.lock().unwrap().as_ref() {
+            return Ok(cp.clone());
+        }
+        // the lock is not held during the calculation; if another thread stored
+        // critical points in the meantime, those are returned instead
+        let cp = State::critical_point_pure(&self.eos, None, options)?;
+        Ok(self
+            .critical_points
+            .lock()
+            .unwrap()
+            .get_or_insert(cp)
+            .clone())
+    }
+
+    /// Calculate the acentric factors of all components using the cached
+    /// critical points.
+    ///
+    /// See [State::acentric_factors].
+    pub fn acentric_factors(&self, options: SolverOptions) -> EosResult<Array1<f64>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let critical_points = self.critical_point_pure(options)?;
+        State::acentric_factors_from_critical_points(&critical_points, options)
+    }
+
+    /// Calculate the phase diagram of a pure component using the cached
+    /// critical point.
+    ///
+    /// See [PhaseDiagram::pure].
+    pub fn phase_diagram_pure(
+        &self,
+        min_temperature: SINumber,
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<PhaseDiagram<E, 2>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if self.eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(self.eos.components(), 1));
+        }
+        let critical_point = self.critical_point_pure(options)?.remove(0);
+        PhaseDiagram::pure_from_critical_point(
+            &self.eos,
+            min_temperature,
+            npoints,
+            critical_point,
+            options,
+        )
+    }
+
+    /// Trace the critical line of a binary system using the cached critical
+    /// points of the pure components.
+    ///
+    /// See [State::critical_locus_binary].
+    pub fn critical_locus_binary(
+        &self,
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<Vec<State<E>>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if self.eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(self.eos.components(), 2));
+        }
+        let critical_points = self.critical_point_pure(options)?;
+        State::critical_locus_binary_from_critical_points(
+            &self.eos,
+            &critical_points,
+            npoints,
+            options,
+        )
+    }
+
+    /// Return the number of cache hits and misses.
+    pub fn statistics(&self) -> (u64, u64) {
+        let cache = self.cache.lock().unwrap();
+        (cache.hit, cache.miss)
+    }
+
+    /// Remove all states and the critical points from the cache.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().states.clear();
+        *self.critical_points.lock().unwrap() = None;
+    }
+}
+
+fn cache_key<'a, I: Iterator<Item = &'a f64>>(temperature: f64, volume: f64, moles: I) -> Vec<u64> {
+    [temperature, volume]
+        .iter()
+        .chain(moles)
+        .map(|x| (x.to_bits() + (1 << (ROUNDING_BITS - 1))) >> ROUNDING_BITS)
+        .collect()
+}
@@ -1,26 +1,36 @@
-//! Implementation of the Peng-Robinson equation of state.
+//! Implementation of the Peng-Robinson and the Soave-Redlich-Kwong equations of state.
 //!
 //! This module acts as a reference on how a simple equation
 //! of state - with a single contribution to the Helmholtz energy - can be implemented.
 //! The implementation closely follows the form of the equations given in
 //! [this wikipedia article](https://en.wikipedia.org/wiki/Cubic_equations_of_state#Peng%E2%80%93Robinson_equation_of_state).
+//!
+//! Both equations of state use the same parameters ([PengRobinsonParameters])
+//! and only differ in the constants of the generalized cubic equation
+//! $$p=\frac{RT}{v-b}-\frac{a(T)}{(v+\delta_1b)(v+\delta_2b)}$$
+//! and of the generalized Soave alpha function.
 use crate::equation_of_state::{
     EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution,
 };
+use crate::errors::{EosError, EosResult};
 use crate::joback::{Joback, JobackRecord};
 use crate::parameter::{Identifier, Parameter, ParameterError, PureRecord};
 use crate::si::{GRAM, MOL};
 use crate::state::StateHD;
-use crate::MolarWeight;
+use crate::{EosUnit, MolarWeight};
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
-use quantity::si::SIArray1;
+use quantity::si::{SIArray1, SINumber, SIUnit};
 use serde::{Deserialize, Serialize};
-use std::f64::consts::SQRT_2;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::f64::consts::{PI, SQRT_2};
 use std::fmt;
 use std::sync::Arc;
 
 const KB_A3: f64 = 13806490.0;
+/// Conversion factor from m³/mol to Å³ per molecule.
+const M3_MOL_A3: f64 = 1e30 / 6.02214076e23;
 
 /// Peng-Robinson parameters for a single substance.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -31,6 +41,22 @@ pub struct PengRobinsonRecord {
     pc: f64,
     /// acentric factor
     acentric_factor: f64,
+    /// coefficients of the Mathias-Copeman alpha function
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    mathias_copeman: Option<[f64; 3]>,
+    /// volume translation in m³/mol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    volume_translation: Option<f64>,
+    /// attraction parameter in Pa m⁶/mol²
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    a: Option<f64>,
+    /// covolume in m³/mol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    b: Option<f64>,
 }
 
 impl PengRobinsonRecord {
@@ -40,31 +66,164 @@ impl PengRobinsonRecord {
             tc,
             pc,
             acentric_factor,
+            mathias_copeman: None,
+            volume_translation: None,
+            a: None,
+            b: None,
         }
     }
+
+    /// Create a new pure substance record from the attraction parameter `a`
+    /// in units of Pa m⁶/mol², the covolume `b` in units of m³/mol and the
+    /// coefficient `c1` of the Soave alpha function
+    /// $\sqrt{\alpha}=1+c_1\left(1-\sqrt{T/T_c}\right)$, e.g., for the
+    /// physical contribution of the CPA equation of state.
+    ///
+    /// The critical pressure is calculated from the covolume of the Peng-Robinson
+    /// equation of state. The acentric factor is not used.
+    pub fn from_attraction_and_covolume(tc: f64, a: f64, b: f64, c1: f64) -> Self {
+        let pc = PENG_ROBINSON.omega_b * tc * KB_A3 / (b * M3_MOL_A3);
+        let mut record = Self::new(tc, pc, 0.0).with_mathias_copeman([c1, 0.0, 0.0]);
+        record.a = Some(a);
+        record.b = Some(b);
+        record
+    }
+
+    /// Use the Mathias-Copeman alpha function with the given coefficients
+    /// instead of the generalized Soave alpha function.
+    ///
+    /// Above the critical temperature, only the first coefficient is used.
+    pub fn with_mathias_copeman(mut self, coefficients: [f64; 3]) -> Self {
+        self.mathias_copeman = Some(coefficients);
+        self
+    }
+
+    /// Use a constant (Peneloux) volume translation in units of m³/mol.
+    ///
+    /// The molar volume of the equation of state is shifted by $-c$,
+    /// which leaves vapor pressures unchanged.
+    pub fn with_volume_translation(mut self, c: f64) -> Self {
+        self.volume_translation = Some(c);
+        self
+    }
+
+    /// Covolume of the Peng-Robinson equation of state in m³/mol.
+    fn covolume(&self) -> f64 {
+        self.b
+            .unwrap_or(PENG_ROBINSON.omega_b * self.tc * KB_A3 / self.pc / M3_MOL_A3)
+    }
 }
 
 impl std::fmt::Display for PengRobinsonRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "PengRobinsonRecord(tc={} K", self.tc)?;
         write!(f, ", pc={} Pa", self.pc)?;
-        write!(f, ", acentric factor={}", self.acentric_factor)
+        write!(f, ", acentric factor={}", self.acentric_factor)?;
+        if let Some(c) = self.mathias_copeman {
+            write!(f, ", mathias_copeman={:?}", c)?;
+        }
+        if let Some(c) = self.volume_translation {
+            write!(f, ", volume_translation={} m³/mol", c)?;
+        }
+        if let Some(a) = self.a {
+            write!(f, ", a={} Pa m⁶/mol²", a)?;
+        }
+        if let Some(b) = self.b {
+            write!(f, ", b={} m³/mol", b)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Binary interaction parameters of the Peng-Robinson equation of state.
+///
+/// The binary interaction parameter can depend linearly on temperature:
+/// $k_{ij}(T)=k_{ij}+k_{ij,T}T$
+///
+/// For compatibility, a binary record can also be read from a single number.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(from = "PengRobinsonBinaryRecordInput")]
+pub struct PengRobinsonBinaryRecord {
+    /// binary interaction parameter
+    pub k_ij: f64,
+    /// temperature dependence of the binary interaction parameter in 1/K
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k_ij_t: Option<f64>,
+}
+
+impl PengRobinsonBinaryRecord {
+    pub fn new(k_ij: f64, k_ij_t: Option<f64>) -> Self {
+        Self { k_ij, k_ij_t }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PengRobinsonBinaryRecordInput {
+    Constant(f64),
+    Record {
+        k_ij: f64,
+        #[serde(default)]
+        k_ij_t: Option<f64>,
+    },
+}
+
+impl From<PengRobinsonBinaryRecordInput> for PengRobinsonBinaryRecord {
+    fn from(input: PengRobinsonBinaryRecordInput) -> Self {
+        match input {
+            PengRobinsonBinaryRecordInput::Constant(k_ij) => k_ij.into(),
+            PengRobinsonBinaryRecordInput::Record { k_ij, k_ij_t } => Self { k_ij, k_ij_t },
+        }
+    }
+}
+
+impl From<f64> for PengRobinsonBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self { k_ij, k_ij_t: None }
+    }
+}
+
+impl TryFrom<PengRobinsonBinaryRecord> for f64 {
+    type Error = ParameterError;
+
+    fn try_from(binary_record: PengRobinsonBinaryRecord) -> Result<Self, Self::Error> {
+        match binary_record.k_ij_t {
+            None => Ok(binary_record.k_ij),
+            Some(_) => Err(ParameterError::IncompatibleParameters(String::from(
+                "a temperature dependent binary interaction parameter can not be converted to a single number.",
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for PengRobinsonBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PengRobinsonBinaryRecord(k_ij={}", self.k_ij)?;
+        if let Some(k_ij_t) = self.k_ij_t {
+            write!(f, ", k_ij_t={} 1/K", k_ij_t)?;
+        }
+        write!(f, ")")
     }
 }
 
 /// Peng-Robinson parameters for one ore more substances.
+///
+/// The same parameters are used for the Soave-Redlich-Kwong equation of state.
 pub struct PengRobinsonParameters {
     /// Critical temperature in Kelvin
     tc: Array1<f64>,
-    a: Array1<f64>,
-    b: Array1<f64>,
+    /// Volume translation in units of Å³ per molecule
+    c: Array1<f64>,
     /// Binary interaction parameter
     k_ij: Array2<f64>,
-    kappa: Array1<f64>,
+    /// Temperature dependence of the binary interaction parameter in 1/K
+    k_ij_t: Array2<f64>,
     /// Molar weight in units of g/mol
     molarweight: Array1<f64>,
     /// List of pure component records
     pure_records: Vec<PureRecord<PengRobinsonRecord, JobackRecord>>,
+    /// Matrix of binary records
+    binary_records: Array2<PengRobinsonBinaryRecord>,
     /// List of ideal gas Joback records
     joback_records: Option<Vec<JobackRecord>>,
 }
@@ -74,7 +233,11 @@ impl std::fmt::Display for PengRobinsonParameters {
         self.pure_records
             .iter()
             .try_for_each(|pr| writeln!(f, "{}", pr))?;
-        writeln!(f, "\nk_ij:\n{}", self.k_ij)
+        writeln!(f, "\nk_ij:\n{}", self.k_ij)?;
+        if self.k_ij_t.iter().any(|&k| k != 0.0) {
+            writeln!(f, "\nk_ij_t:\n{}", self.k_ij_t)?;
+        }
+        Ok(())
     }
 }
 
@@ -100,6 +263,10 @@ impl PengRobinsonParameters {
                     tc: tc[i],
                     pc: pc[i],
                     acentric_factor: acentric_factor[i],
+                    mathias_copeman: None,
+                    volume_translation: None,
+                    a: None,
+                    b: None,
                 };
                 let id = Identifier::default();
                 PureRecord::new(id, molarweight[i], record, None)
@@ -107,7 +274,77 @@ impl PengRobinsonParameters {
             .collect();
         Ok(PengRobinsonParameters::from_records(
             records,
-            Array2::zeros([pc.len(); 2]),
+            Array2::default([pc.len(); 2]),
+        ))
+    }
+
+    /// Return a copy of the parameters with the given volume translations
+    /// in units of m³/mol for all components.
+    ///
+    /// The volume translation of every component has to be smaller than its
+    /// covolume in the Peng-Robinson equation of state, otherwise the translated
+    /// volume of a dense liquid is not positive.
+    pub fn with_volume_translation(&self, c: &[f64]) -> Result<Self, ParameterError> {
+        if c.len() != self.pure_records.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "expected {} volume translations, got {}.",
+                self.pure_records.len(),
+                c.len()
+            )));
+        }
+        for (i, (pr, &c)) in self.pure_records.iter().zip(c).enumerate() {
+            let b = pr.model_record.covolume();
+            if c >= b {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "the volume translation of component {} ({} m³/mol) has to be smaller than its covolume ({} m³/mol).",
+                    i, c, b
+                )));
+            }
+        }
+        let pure_records = self
+            .pure_records
+            .iter()
+            .zip(c)
+            .map(|(pr, &c)| {
+                let mut pr = pr.clone();
+                pr.model_record = pr.model_record.with_volume_translation(c);
+                pr
+            })
+            .collect();
+        Ok(Self::from_records(
+            pure_records,
+            self.binary_records.clone(),
+        ))
+    }
+
+    /// Return a copy of the parameters with the given coefficients of the
+    /// Mathias-Copeman alpha function for all components.
+    ///
+    /// Components without coefficients use the generalized Soave alpha function.
+    pub fn with_mathias_copeman(
+        &self,
+        coefficients: &[Option<[f64; 3]>],
+    ) -> Result<Self, ParameterError> {
+        if coefficients.len() != self.pure_records.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "expected {} sets of Mathias-Copeman coefficients, got {}.",
+                self.pure_records.len(),
+                coefficients.len()
+            )));
+        }
+        let pure_records = self
+            .pure_records
+            .iter()
+            .zip(coefficients)
+            .map(|(pr, &c)| {
+                let mut pr = pr.clone();
+                pr.model_record.mathias_copeman = c;
+                pr
+            })
+            .collect();
+        Ok(Self::from_records(
+            pure_records,
+            self.binary_records.clone(),
         ))
     }
 }
@@ -115,29 +352,21 @@ impl PengRobinsonParameters {
 impl Parameter for PengRobinsonParameters {
     type Pure = PengRobinsonRecord;
     type IdealGas = JobackRecord;
-    type Binary = f64;
+    type Binary = PengRobinsonBinaryRecord;
 
     /// Creates parameters from pure component records.
     fn from_records(
         pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
         binary_records: Array2<Self::Binary>,
     ) -> Self {
-        let n = pure_records.len();
-
-        let mut tc = Array1::zeros(n);
-        let mut a = Array1::zeros(n);
-        let mut b = Array1::zeros(n);
-        let mut molarweight = Array1::zeros(n);
-        let mut kappa = Array1::zeros(n);
-
-        for (i, record) in pure_records.iter().enumerate() {
-            molarweight[i] = record.molarweight;
-            let r = &record.model_record;
-            tc[i] = r.tc;
-            a[i] = 0.45724 * r.tc.powi(2) * KB_A3 / r.pc;
-            b[i] = 0.07780 * r.tc * KB_A3 / r.pc;
-            kappa[i] = 0.37464 + (1.54226 - 0.26992 * r.acentric_factor) * r.acentric_factor;
-        }
+        let tc = pure_records.iter().map(|r| r.model_record.tc).collect();
+        let c = pure_records
+            .iter()
+            .map(|r| r.model_record.volume_translation.unwrap_or(0.0) * M3_MOL_A3)
+            .collect();
+        let molarweight = pure_records.iter().map(|r| r.molarweight).collect();
+        let k_ij = binary_records.mapv(|br| br.k_ij);
+        let k_ij_t = binary_records.mapv(|br| br.k_ij_t.unwrap_or(0.0));
 
         let joback_records = pure_records
             .iter()
@@ -146,12 +375,12 @@ impl Parameter for PengRobinsonParameters {
 
         Self {
             tc,
-            a,
-            b,
-            k_ij: binary_records,
-            kappa,
+            c,
+            k_ij,
+            k_ij_t,
             molarweight,
             pure_records,
+            binary_records,
             joback_records,
         }
     }
@@ -160,53 +389,222 @@ impl Parameter for PengRobinsonParameters {
         &self,
     ) -> (
         &[PureRecord<PengRobinsonRecord, JobackRecord>],
-        &Array2<f64>,
+        &Array2<PengRobinsonBinaryRecord>,
     ) {
-        (&self.pure_records, &self.k_ij)
+        (&self.pure_records, &self.binary_records)
     }
 }
 
-struct PengRobinsonContribution {
+/// Constants of a cubic equation of state.
+#[derive(Clone, Copy)]
+struct CubicConstants {
+    /// Name of the equation of state
+    name: &'static str,
+    /// Prefactor of the attraction parameter $a_i=\Omega_a\frac{R^2T_{c,i}^2}{p_{c,i}}$
+    omega_a: f64,
+    /// Prefactor of the covolume $b_i=\Omega_b\frac{RT_{c,i}}{p_{c,i}}$
+    omega_b: f64,
+    /// Parameters $\delta_1$ and $\delta_2$ of the attractive term
+    delta: [f64; 2],
+    /// Coefficients of the generalized Soave alpha function as polynomial of the acentric factor
+    kappa: [f64; 3],
+}
+
+const PENG_ROBINSON: CubicConstants = CubicConstants {
+    name: "Peng Robinson",
+    omega_a: 0.45724,
+    omega_b: 0.07780,
+    delta: [1.0 + SQRT_2, 1.0 - SQRT_2],
+    kappa: [0.37464, 1.54226, -0.26992],
+};
+
+const SOAVE_REDLICH_KWONG: CubicConstants = CubicConstants {
+    name: "Soave Redlich Kwong",
+    omega_a: 0.42748,
+    omega_b: 0.08664,
+    delta: [1.0, 0.0],
+    kappa: [0.480, 1.574, -0.176],
+};
+
+/// Pure component parameters of a specific cubic equation of state.
+struct CubicParameters {
+    constants: CubicConstants,
     parameters: Arc<PengRobinsonParameters>,
+    a: Array1<f64>,
+    b: Array1<f64>,
+    /// Coefficients of the alpha function
+    kappa: Array2<f64>,
 }
 
-impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for PengRobinsonContribution {
-    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
-        // temperature dependent a parameter
-        let p = &self.parameters;
-        let x = &state.molefracs;
-        let ak = (&p.tc.mapv(|tc| (D::one() - (state.temperature / tc).sqrt())) * &p.kappa + 1.0)
-            .mapv(|x| x.powi(2))
-            * &p.a;
+impl CubicParameters {
+    fn new(parameters: &Arc<PengRobinsonParameters>, constants: CubicConstants) -> Self {
+        let n = parameters.pure_records.len();
+        let mut a = Array1::zeros(n);
+        let mut b = Array1::zeros(n);
+        let mut kappa = Array2::zeros((n, 3));
+        let [k0, k1, k2] = constants.kappa;
+        for (i, record) in parameters.pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            a[i] = r.a.map_or_else(
+                || constants.omega_a * r.tc.powi(2) * KB_A3 / r.pc,
+                |a| a * M3_MOL_A3.powi(2) / KB_A3,
+            );
+            b[i] = r.b.map_or_else(
+                || constants.omega_b * r.tc * KB_A3 / r.pc,
+                |b| b * M3_MOL_A3,
+            );
+            match r.mathias_copeman {
+                Some(c) => kappa.row_mut(i).assign(&Array1::from(c.to_vec())),
+                None => kappa[(i, 0)] = k0 + (k1 + k2 * r.acentric_factor) * r.acentric_factor,
+            }
+        }
+        Self {
+            constants,
+            parameters: parameters.clone(),
+            a,
+            b,
+            kappa,
+        }
+    }
 
-        // Mixing rules
+    /// Temperature dependent a parameter of the mixture.
+    fn a_mix<D: DualNum<f64>>(&self, temperature: D, molefracs: &Array1<D>) -> D {
+        let tc = &self.parameters.tc;
+        let ak = Array1::from_shape_fn(tc.len(), |i| {
+            let s = D::one() - (temperature / tc[i]).sqrt();
+            let k = self.kappa.row(i);
+            // Mathias-Copeman: the higher order terms are only used below the critical temperature
+            let sqrt_alpha = if temperature.re() < tc[i] {
+                s * (s * (s * k[2] + k[1]) + k[0]) + 1.0
+            } else {
+                s * k[0] + 1.0
+            };
+            sqrt_alpha.powi(2) * self.a[i]
+        });
+
+        // Mixing rules with temperature dependent binary interaction parameters
+        let x = molefracs;
+        let k_ij = &self.parameters.k_ij;
+        let k_ij_t = &self.parameters.k_ij_t;
         let mut ak_mix = D::zero();
         for i in 0..ak.len() {
             for j in 0..ak.len() {
-                ak_mix += (ak[i] * ak[j]).sqrt() * (x[i] * x[j] * (1.0 - p.k_ij[(i, j)]));
+                let one_minus_k_ij = -temperature * k_ij_t[(i, j)] + (1.0 - k_ij[(i, j)]);
+                ak_mix += (ak[i] * ak[j]).sqrt() * (x[i] * x[j] * one_minus_k_ij);
             }
         }
-        let b = (x * &p.b).sum();
+        ak_mix
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        let b = (moles * &self.b).sum() / moles.sum();
+        // volume translations that exceed the covolume are not physical
+        // (see [PengRobinsonParameters::with_volume_translation])
+        let c = ((moles * &self.parameters.c).sum() / moles.sum()).min(b);
+        1.0 / (b / 0.9 - c)
+    }
+
+    fn molar_volume_roots(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Vec<SINumber>> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let p = pressure.to_reduced(SIUnit::reference_pressure())?;
+        let a = self.a_mix(t, molefracs);
+        let b = (molefracs * &self.b).sum();
+        let c = (molefracs * &self.parameters.c).sum();
+
+        // p(v-b)(v²+ubv+wb²) - T(v²+ubv+wb²) + a(v-b) = 0
+        let [d1, d2] = self.constants.delta;
+        let (u, w) = (d1 + d2, d1 * d2);
+        let roots = real_polynomial_roots([
+            p,
+            p * b * (u - 1.0) - t,
+            a + p * b * b * (w - u) - t * u * b,
+            -p * w * b.powi(3) - t * w * b * b - a * b,
+        ]);
+        let volumes: Vec<_> = roots
+            .into_iter()
+            .filter(|v| v.is_finite() && *v > b)
+            .map(|v| (v - c) * SIUnit::reference_volume() / SIUnit::reference_moles())
+            .collect();
+        if volumes.is_empty() {
+            return Err(EosError::NoValidVolumeRoot(format!(
+                "T = {}, p = {}",
+                temperature, pressure
+            )));
+        }
+        Ok(volumes)
+    }
 
-        // Helmholtz energy
+    /// Liquid and vapor densities from the smallest and the largest root of
+    /// the cubic equation, used to initialize the density iteration.
+    fn initial_densities(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+    ) -> Option<[SINumber; 2]> {
+        let molefracs = (moles / moles.sum()).into_value().ok()?;
+        let roots = self
+            .molar_volume_roots(temperature, pressure, &molefracs)
+            .ok()?;
+        Some([roots.first()?.powi(-1), roots.last()?.powi(-1)])
+    }
+}
+
+struct CubicContribution {
+    parameters: Arc<CubicParameters>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for CubicContribution {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        // temperature dependent a parameter
+        let p = &self.parameters;
+        let ak_mix = p.a_mix(state.temperature, &state.molefracs);
+        let b = (&state.molefracs * &p.b).sum();
+        let c = (&state.molefracs * &p.parameters.c).sum();
+        let [d1, d2] = p.constants.delta;
+
+        // Helmholtz energy evaluated at the translated volume
         let n = state.moles.sum();
-        let v = state.volume;
-        n * ((v / (v - b * n)).ln()
-            - ak_mix / (b * SQRT_2 * 2.0 * state.temperature)
-                * ((v + b * n * (1.0 + SQRT_2)) / (v + b * n * (1.0 - SQRT_2))).ln())
+        let v = state.volume + n * c;
+        n * ((state.volume / (v - b * n)).ln()
+            - ak_mix / (b * (d1 - d2) * state.temperature)
+                * ((v + b * n * d1) / (v + b * n * d2)).ln())
     }
 }
 
-impl fmt::Display for PengRobinsonContribution {
+impl fmt::Display for CubicContribution {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Peng Robinson")
+        write!(f, "{}", self.parameters.constants.name)
     }
 }
 
+/// Ideal gas contribution and residual contributions of a cubic equation of state.
+fn cubic_contributions(
+    parameters: &Arc<PengRobinsonParameters>,
+    constants: CubicConstants,
+) -> (Arc<CubicParameters>, Joback, Vec<Box<dyn HelmholtzEnergy>>) {
+    let ideal_gas = parameters.joback_records.as_ref().map_or_else(
+        || Joback::default(parameters.tc.len()),
+        |j| Joback::new(j.clone()),
+    );
+    let cubic_parameters = Arc::new(CubicParameters::new(parameters, constants));
+    let contributions: Vec<Box<dyn HelmholtzEnergy>> = vec![Box::new(CubicContribution {
+        parameters: cubic_parameters.clone(),
+    })];
+    (cubic_parameters, ideal_gas, contributions)
+}
+
 /// A simple version of the Peng-Robinson equation of state.
 pub struct PengRobinson {
     /// Parameters
     parameters: Arc<PengRobinsonParameters>,
+    /// Model specific parameters
+    cubic_parameters: Arc<CubicParameters>,
     /// Ideal gas contributions to the Helmholtz energy
     ideal_gas: Joback,
     /// Non-ideal contributions to the Helmholtz energy
@@ -216,25 +614,123 @@ pub struct PengRobinson {
 impl PengRobinson {
     /// Create a new equation of state from a set of parameters.
     pub fn new(parameters: Arc<PengRobinsonParameters>) -> Self {
-        let ideal_gas = parameters.joback_records.as_ref().map_or_else(
-            || Joback::default(parameters.tc.len()),
-            |j| Joback::new(j.clone()),
-        );
-        let contributions: Vec<Box<dyn HelmholtzEnergy>> =
-            vec![Box::new(PengRobinsonContribution {
-                parameters: parameters.clone(),
-            })];
+        let (cubic_parameters, ideal_gas, contributions) =
+            cubic_contributions(&parameters, PENG_ROBINSON);
         Self {
             parameters,
+            cubic_parameters,
             ideal_gas,
             contributions,
         }
     }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<PengRobinsonParameters> {
+        &self.parameters
+    }
+
+    /// Calculate the molar volumes that solve the cubic equation for the given
+    /// temperature, pressure and composition in ascending order.
+    ///
+    /// Only real roots that are larger than the covolume of the mixture are
+    /// physical. If no such root exists, e.g., for very low temperatures or
+    /// unphysical binary interaction parameters, [EosError::NoValidVolumeRoot]
+    /// is returned.
+    ///
+    /// If volume translations are provided, the roots are shifted accordingly.
+    pub fn molar_volume_roots(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Vec<SINumber>> {
+        self.cubic_parameters
+            .molar_volume_roots(temperature, pressure, molefracs)
+    }
+}
+
+/// The Soave-Redlich-Kwong equation of state.
+///
+/// Uses the same parameters and mixing rules as [PengRobinson].
+pub struct Srk {
+    /// Parameters
+    parameters: Arc<PengRobinsonParameters>,
+    /// Model specific parameters
+    cubic_parameters: Arc<CubicParameters>,
+    /// Ideal gas contributions to the Helmholtz energy
+    ideal_gas: Joback,
+    /// Non-ideal contributions to the Helmholtz energy
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+}
+
+impl Srk {
+    /// Create a new equation of state from a set of parameters.
+    pub fn new(parameters: Arc<PengRobinsonParameters>) -> Self {
+        let (cubic_parameters, ideal_gas, contributions) =
+            cubic_contributions(&parameters, SOAVE_REDLICH_KWONG);
+        Self {
+            parameters,
+            cubic_parameters,
+            ideal_gas,
+            contributions,
+        }
+    }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<PengRobinsonParameters> {
+        &self.parameters
+    }
+
+    /// Calculate the molar volumes that solve the cubic equation for the given
+    /// temperature, pressure and composition in ascending order.
+    ///
+    /// See [PengRobinson::molar_volume_roots].
+    pub fn molar_volume_roots(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<Vec<SINumber>> {
+        self.cubic_parameters
+            .molar_volume_roots(temperature, pressure, molefracs)
+    }
+}
+
+/// Real roots of the polynomial $c_0x^3+c_1x^2+c_2x+c_3$ in ascending order.
+fn real_polynomial_roots(c: [f64; 4]) -> Vec<f64> {
+    let mut roots = if c[0] == 0.0 {
+        // quadratic equation
+        let d = c[2] * c[2] - 4.0 * c[1] * c[3];
+        if d < 0.0 {
+            vec![]
+        } else {
+            vec![
+                (-c[2] - d.sqrt()) / (2.0 * c[1]),
+                (-c[2] + d.sqrt()) / (2.0 * c[1]),
+            ]
+        }
+    } else {
+        // cubic equation (Cardano/trigonometric solution)
+        let (a2, a1, a0) = (c[1] / c[0], c[2] / c[0], c[3] / c[0]);
+        let q = (3.0 * a1 - a2 * a2) / 9.0;
+        let r = (9.0 * a2 * a1 - 27.0 * a0 - 2.0 * a2.powi(3)) / 54.0;
+        let d = q.powi(3) + r * r;
+        if d >= 0.0 {
+            vec![(r + d.sqrt()).cbrt() + (r - d.sqrt()).cbrt() - a2 / 3.0]
+        } else {
+            let theta = (r / (-q.powi(3)).sqrt()).acos();
+            (0..3)
+                .map(|k| 2.0 * (-q).sqrt() * ((theta + 2.0 * PI * k as f64) / 3.0).cos() - a2 / 3.0)
+                .collect()
+        }
+    };
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    roots
 }
 
 impl EquationOfState for PengRobinson {
     fn components(&self) -> usize {
-        self.parameters.b.len()
+        self.parameters.tc.len()
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
@@ -242,8 +738,17 @@ impl EquationOfState for PengRobinson {
     }
 
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        let b = (moles * &self.parameters.b).sum() / moles.sum();
-        0.9 / b
+        self.cubic_parameters.compute_max_density(moles)
+    }
+
+    fn initial_densities(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+    ) -> Option<[SINumber; 2]> {
+        self.cubic_parameters
+            .initial_densities(temperature, pressure, moles)
     }
 
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
@@ -261,14 +766,53 @@ impl MolarWeight for PengRobinson {
     }
 }
 
+impl EquationOfState for Srk {
+    fn components(&self) -> usize {
+        self.parameters.tc.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.cubic_parameters.compute_max_density(moles)
+    }
+
+    fn initial_densities(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+    ) -> Option<[SINumber; 2]> {
+        self.cubic_parameters
+            .initial_densities(temperature, pressure, moles)
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl MolarWeight for Srk {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::phase_equilibria::SolverOptions;
-    use crate::state::State;
+    use crate::phase_equilibria::{PhaseEquilibrium, SolverOptions};
+    use crate::state::{DensityInitialization, State};
     use crate::Contributions;
     use crate::{EosResult, Verbosity};
     use approx::*;
+    use ndarray::arr1;
     use quantity::si::*;
     use std::sync::Arc;
 
@@ -316,7 +860,8 @@ mod tests {
         let propane = mixture[0].clone();
         let tc = propane.model_record.tc;
         let pc = propane.model_record.pc;
-        let parameters = PengRobinsonParameters::from_records(vec![propane], Array2::zeros((1, 1)));
+        let parameters =
+            PengRobinsonParameters::from_records(vec![propane], Array2::default((1, 1)));
         let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
         let options = SolverOptions::new().verbosity(Verbosity::Iter);
         let cp = State::critical_point(&pr, None, None, options)?;
@@ -329,4 +874,254 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn soave_redlich_kwong() -> EosResult<()> {
+        let propane = pure_record_vec()[0].clone();
+        let tc = propane.model_record.tc;
+        let pc = propane.model_record.pc;
+        let parameters =
+            PengRobinsonParameters::from_records(vec![propane], Array2::default((1, 1)));
+        let srk = Arc::new(Srk::new(Arc::new(parameters)));
+        let cp = State::critical_point(&srk, None, None, SolverOptions::default())?;
+        assert_relative_eq!(cp.temperature, tc * KELVIN, max_relative = 1e-4);
+        assert_relative_eq!(
+            cp.pressure(Contributions::Total),
+            pc * PASCAL,
+            max_relative = 1e-4
+        );
+
+        // the volume roots solve the Soave-Redlich-Kwong equation
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let roots = srk.molar_volume_roots(t, p, &arr1(&[1.0]))?;
+        assert_eq!(roots.len(), 3);
+        for &v in &roots {
+            let state = State::new_nvt(&srk, t, v * MOL, &(arr1(&[1.0]) * MOL))?;
+            assert_relative_eq!(state.pressure(Contributions::Total), p, max_relative = 1e-8);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mathias_copeman_water() -> EosResult<()> {
+        let record = PengRobinsonRecord::new(647.096, 22.064e6, 0.3443);
+        let soave = PengRobinsonParameters::from_records(
+            vec![PureRecord::new(
+                Identifier::default(),
+                18.015,
+                record.clone(),
+                None,
+            )],
+            Array2::default((1, 1)),
+        );
+        let mathias_copeman = PengRobinsonParameters::from_records(
+            vec![PureRecord::new(
+                Identifier::default(),
+                18.015,
+                record.with_mathias_copeman([0.9209, -0.3509, 0.3635]),
+                None,
+            )],
+            Array2::default((1, 1)),
+        );
+        let soave = Arc::new(PengRobinson::new(Arc::new(soave)));
+        let mathias_copeman = Arc::new(PengRobinson::new(Arc::new(mathias_copeman)));
+
+        // vapor pressures of water from IAPWS-95
+        for (t, p_ref) in [(300.0, 3536.8), (400.0, 245770.0), (500.0, 2639200.0)] {
+            let t = t * KELVIN;
+            let p_ref = p_ref * PASCAL;
+            let p_soave = PhaseEquilibrium::vapor_pressure(&soave, t)[0].unwrap();
+            let p_mc = PhaseEquilibrium::vapor_pressure(&mathias_copeman, t)[0].unwrap();
+            let dev_soave = ((p_soave - p_ref) / p_ref).into_value()?.abs();
+            let dev_mc = ((p_mc - p_ref) / p_ref).into_value()?.abs();
+            assert!(dev_mc < 5e-3);
+            assert!(dev_mc < dev_soave);
+        }
+
+        // above the critical temperature, only the first coefficient is used
+        let t = 700.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let n = arr1(&[1.0]) * MOL;
+        let record = PengRobinsonRecord::new(647.096, 22.064e6, 0.3443);
+        let first_coefficient = PengRobinsonParameters::from_records(
+            vec![PureRecord::new(
+                Identifier::default(),
+                18.015,
+                record.with_mathias_copeman([0.9209, 0.0, 0.0]),
+                None,
+            )],
+            Array2::default((1, 1)),
+        );
+        let first_coefficient = Arc::new(PengRobinson::new(Arc::new(first_coefficient)));
+        assert_relative_eq!(
+            State::new_nvt(&mathias_copeman, t, v, &n)?.pressure(Contributions::Total),
+            State::new_nvt(&first_coefficient, t, v, &n)?.pressure(Contributions::Total),
+            max_relative = 1e-14
+        );
+
+        // the coefficients can also be set for existing parameters
+        let parameters = soave.parameters();
+        let from_parameters =
+            parameters.with_mathias_copeman(&[Some([0.9209, -0.3509, 0.3635])])?;
+        let from_parameters = Arc::new(PengRobinson::new(Arc::new(from_parameters)));
+        assert_relative_eq!(
+            State::new_nvt(&mathias_copeman, t, v, &n)?.pressure(Contributions::Total),
+            State::new_nvt(&from_parameters, t, v, &n)?.pressure(Contributions::Total),
+            max_relative = 1e-14
+        );
+        let unchanged = parameters.with_mathias_copeman(&[None])?;
+        let unchanged = Arc::new(PengRobinson::new(Arc::new(unchanged)));
+        assert_relative_eq!(
+            State::new_nvt(&soave, t, v, &n)?.pressure(Contributions::Total),
+            State::new_nvt(&unchanged, t, v, &n)?.pressure(Contributions::Total),
+            max_relative = 1e-14
+        );
+        assert!(parameters.with_mathias_copeman(&[None, None]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn volume_translation_co2() -> EosResult<()> {
+        let record = PengRobinsonRecord::new(304.13, 7.3773e6, 0.22394);
+        let parameters = PengRobinsonParameters::from_records(
+            vec![PureRecord::new(
+                Identifier::default(),
+                44.0098,
+                record,
+                None,
+            )],
+            Array2::default((1, 1)),
+        );
+        let translated = parameters.with_volume_translation(&[-1.2e-6])?;
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let translated = Arc::new(PengRobinson::new(Arc::new(translated)));
+
+        // saturated liquid densities of CO2 from Span and Wagner (1996)
+        for (t, rho_ref) in [(230.0, 1129.0), (250.0, 1045.97)] {
+            let t = t * KELVIN;
+            let rho_ref = rho_ref * KILOGRAM / METER.powi(3);
+            let vle = PhaseEquilibrium::pure(&pr, t, None, Default::default())?;
+            let vle_translated = PhaseEquilibrium::pure(&translated, t, None, Default::default())?;
+            assert_relative_eq!(
+                vle.vapor().pressure(Contributions::Total),
+                vle_translated.vapor().pressure(Contributions::Total),
+                max_relative = 1e-8
+            );
+            let dev = ((vle.liquid().mass_density() - rho_ref) / rho_ref)
+                .into_value()?
+                .abs();
+            let dev_translated = ((vle_translated.liquid().mass_density() - rho_ref) / rho_ref)
+                .into_value()?
+                .abs();
+            assert!(dev_translated < 1e-2);
+            assert!(dev_translated < dev);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn volume_translation_larger_than_covolume() -> EosResult<()> {
+        let record = PengRobinsonRecord::new(304.13, 7.3773e6, 0.22394);
+        let b = 0.07780 * RGAS.to_reduced(JOULE / KELVIN / MOL)? * 304.13 / 7.3773e6;
+        let parameters = PengRobinsonParameters::new_pure(PureRecord::new(
+            Identifier::default(),
+            44.0098,
+            record.clone(),
+            None,
+        ));
+        assert!(parameters.with_volume_translation(&[0.99 * b]).is_ok());
+        assert!(parameters.with_volume_translation(&[1.01 * b]).is_err());
+
+        // records with larger volume translations still give a finite maximum density
+        let parameters = PengRobinsonParameters::new_pure(PureRecord::new(
+            Identifier::default(),
+            44.0098,
+            record.with_volume_translation(2.0 * b),
+            None,
+        ));
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let max_density = pr.max_density(None)?.to_reduced(MOL / METER.powi(3))?;
+        assert!(max_density.is_finite() && max_density > 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn temperature_dependent_k_ij() -> EosResult<()> {
+        let records = pure_record_vec();
+        let binary = |k_ij: f64, k_ij_t: Option<f64>| {
+            let k = PengRobinsonBinaryRecord::new(k_ij, k_ij_t);
+            let parameters = PengRobinsonParameters::from_records(
+                records.clone(),
+                Array2::from_shape_fn((2, 2), |(i, j)| if i == j { Default::default() } else { k }),
+            );
+            Arc::new(PengRobinson::new(Arc::new(parameters)))
+        };
+        let t = 300.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let n = arr1(&[0.3, 0.7]) * MOL;
+
+        // k_ij(T) evaluated at a fixed temperature
+        let linear = State::new_nvt(&binary(0.01, Some(1e-4)), t, v, &n)?;
+        let constant = State::new_nvt(&binary(0.04, None), t, v, &n)?;
+        assert_relative_eq!(
+            linear.pressure(Contributions::Total),
+            constant.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+
+        // the temperature dependence enters the entropy
+        let h = 1e-4 * KELVIN;
+        let a = |t| {
+            State::new_nvt(&binary(0.01, Some(1e-4)), t, v, &n)
+                .map(|s| s.helmholtz_energy(Contributions::ResidualNvt))
+        };
+        let s_num = -(a(t + h)? - a(t - h)?) / (2.0 * h);
+        assert_relative_eq!(
+            linear.entropy(Contributions::ResidualNvt),
+            s_num,
+            max_relative = 1e-6
+        );
+
+        // binary records can still be read from a single number
+        let record: PengRobinsonBinaryRecord = serde_json::from_str("0.04").unwrap();
+        assert_eq!(record.k_ij, 0.04);
+        assert!(record.k_ij_t.is_none());
+        let record: PengRobinsonBinaryRecord =
+            serde_json::from_str(r#"{"k_ij": 0.01, "k_ij_t": 1e-4}"#).unwrap();
+        assert_eq!(record.k_ij_t, Some(1e-4));
+        assert!(f64::try_from(record).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn molar_volume_roots() -> EosResult<()> {
+        let propane = pure_record_vec()[0].clone();
+        let parameters =
+            PengRobinsonParameters::from_records(vec![propane], Array2::default((1, 1)));
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let x = arr1(&[1.0]);
+
+        // liquid, unstable and vapor root below the vapor pressure
+        let t = 300.0 * KELVIN;
+        let p = BAR;
+        let roots = pr.molar_volume_roots(t, p, &x)?;
+        assert_eq!(roots.len(), 3);
+        for &v in &roots {
+            let state = State::new_nvt(&pr, t, v * MOL, &(arr1(&[1.0]) * MOL))?;
+            assert_relative_eq!(state.pressure(Contributions::Total), p, max_relative = 1e-8);
+        }
+
+        // the outer roots are used to initialize the density iteration
+        let moles = arr1(&[1.0]) * MOL;
+        let liquid = State::new_npt(&pr, t, p, &moles, DensityInitialization::Liquid)?;
+        let vapor = State::new_npt(&pr, t, p, &moles, DensityInitialization::Vapor)?;
+        assert_relative_eq!(liquid.density, roots[0].powi(-1), max_relative = 1e-10);
+        assert_relative_eq!(vapor.density, roots[2].powi(-1), max_relative = 1e-10);
+
+        // no physical root for negative pressures at high temperatures
+        let roots = pr.molar_volume_roots(1000.0 * KELVIN, -100.0 * BAR, &x);
+        assert!(matches!(roots, Err(EosError::NoValidVolumeRoot(_))));
+        Ok(())
+    }
 }
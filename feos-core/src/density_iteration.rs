@@ -29,7 +29,7 @@ pub fn density_iteration<E: EquationOfState>(
     let mut iterations = 0;
     'iteration: for k in 0..maxiter {
         iterations += 1;
-        let (mut p, mut dp_drho) = State::new_nvt(eos, temperature, n / rho, moles)?.p_dpdrho();
+        let (mut p, mut dp_drho) = p_dpdrho(&State::new_nvt(eos, temperature, n / rho, moles)?)?;
 
         // attempt to correct for poor initial density rho_init
         if dp_drho.is_sign_negative() && k == 0 {
@@ -38,7 +38,7 @@ pub fn density_iteration<E: EquationOfState>(
             } else {
                 (1.1 * initial_density).min(maxdensity)?
             };
-            let p_ = State::new_nvt(eos, temperature, n / rho, moles)?.p_dpdrho();
+            let p_ = p_dpdrho(&State::new_nvt(eos, temperature, n / rho, moles)?)?;
             p = p_.0;
             dp_drho = p_.1;
         }
@@ -144,6 +144,18 @@ pub fn density_iteration<E: EquationOfState>(
     }
 }
 
+/// Pressure and its derivative with respect to density of the state.
+///
+/// If the pressure is NaN, the error of the failing Helmholtz energy
+/// contribution (e.g. [EosError::AssociationNotConverged]) is returned.
+fn p_dpdrho<E: EquationOfState>(state: &State<E>) -> EosResult<(SINumber, SINumber)> {
+    let (p, dp_drho) = state.p_dpdrho();
+    if p.to_reduced(SIUnit::reference_pressure())?.is_nan() {
+        state.try_residual_helmholtz_energy()?;
+    }
+    Ok((p, dp_drho))
+}
+
 fn pressure_spinodal<E: EquationOfState>(
     eos: &Arc<E>,
     temperature: SINumber,
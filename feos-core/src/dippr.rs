@@ -0,0 +1,270 @@
+//! Implementation of the ideal gas heat capacity (de Broglie wavelength)
+//! based on the DIPPR-107 (Aly-Lee) correlation.
+
+use crate::{
+    EosResult, EosUnit, EquationOfState, HelmholtzEnergy, IdealGasContribution,
+    IdealGasContributionDual,
+};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::si::{SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Coefficients of the DIPPR-107 correlation
+/// $$c_p^\mathrm{ig}=A+B\left(\frac{C/T}{\sinh(C/T)}\right)^2+D\left(\frac{E/T}{\cosh(E/T)}\right)^2$$
+/// for a pure component.
+///
+/// As in the DIPPR database, $A$, $B$ and $D$ are given in units of J/(kmol K)
+/// and $C$ and $E$ in units of K.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Dippr107Record {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+}
+
+impl Dippr107Record {
+    /// Creates a new `Dippr107Record`.
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64) -> Self {
+        Self { a, b, c, d, e }
+    }
+
+    /// Heat capacity in units of J/(kmol K).
+    fn c_p<D: DualNum<f64>>(&self, temperature: D) -> D {
+        let c = temperature.recip() * self.c;
+        let e = temperature.recip() * self.e;
+        (c / c.sinh()).powi(2) * self.b + (e / e.cosh()).powi(2) * self.d + self.a
+    }
+
+    /// Integral of the heat capacity $\int c_p^\mathrm{ig}\,\mathrm{d}T$ up to an
+    /// arbitrary constant in units of J/kmol.
+    fn enthalpy<D: DualNum<f64>>(&self, temperature: D) -> D {
+        let c = temperature.recip() * self.c;
+        let e = temperature.recip() * self.e;
+        temperature * self.a + c.tanh().recip() * (self.b * self.c) - e.tanh() * (self.d * self.e)
+    }
+
+    /// Integral $\int\frac{c_p^\mathrm{ig}}{T}\,\mathrm{d}T$ up to an arbitrary
+    /// constant in units of J/(kmol K).
+    fn entropy<D: DualNum<f64>>(&self, temperature: D) -> D {
+        let c = temperature.recip() * self.c;
+        let e = temperature.recip() * self.e;
+        temperature.ln() * self.a + (c / c.tanh() - c.sinh().ln()) * self.b
+            - (e * e.tanh() - e.cosh().ln()) * self.d
+    }
+}
+
+impl fmt::Display for Dippr107Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Dippr107Record(a={}, b={}, c={}, d={}, e={})",
+            self.a, self.b, self.c, self.d, self.e
+        )
+    }
+}
+
+/// The ideal gas contribution based on the DIPPR-107 correlation.
+///
+/// The integrals of the heat capacity are mapped onto the de Broglie wavelength
+/// of each component via
+/// $$\ln\frac{\Lambda_i^3}{\AA^3}=\frac{h_i(T)-Ts_i(T)}{RT}+\ln\frac{k_\mathrm{B}T}{p_0\AA^3}$$
+/// with $h_i(T)=\int_{T_0}^Tc_{p,i}^\mathrm{ig}\,\mathrm{d}T$ and
+/// $s_i(T)=\int_{T_0}^T\frac{c_{p,i}^\mathrm{ig}}{T}\,\mathrm{d}T$. As for
+/// [Joback](crate::joback::Joback), the ideal gas enthalpy and entropy are
+/// therefore zero at $T_0=298.15\,\mathrm{K}$ and $p_0=1\,\mathrm{bar}$.
+/// The residual Helmholtz energy of an equation of state can be combined
+/// with the correlation using [WithIdealGas](crate::ideal_gas::WithIdealGas).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dippr107 {
+    pub records: Vec<Dippr107Record>,
+}
+
+impl Dippr107 {
+    /// Creates a new DIPPR-107 contribution.
+    pub fn new(records: Vec<Dippr107Record>) -> Self {
+        Self { records }
+    }
+
+    /// Directly calculates the ideal gas heat capacity from the DIPPR-107 correlation.
+    pub fn c_p(&self, temperature: SINumber, molefracs: &Array1<f64>) -> EosResult<SINumber> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let mut c_p = 0.0;
+        for (r, &x) in self.records.iter().zip(molefracs.iter()) {
+            c_p += x * r.c_p(t);
+        }
+        Ok(c_p / RGAS_KMOL * SIUnit::gas_constant())
+    }
+}
+
+impl fmt::Display for Dippr107 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ideal gas (DIPPR-107)")
+    }
+}
+
+const KB: f64 = 1.380649e-23;
+/// Gas constant in units of J/(kmol K)
+const RGAS_KMOL: f64 = KB * 6.02214076e26;
+const T0: f64 = 298.15;
+const P0: f64 = 1.0e5;
+const A3: f64 = 1e-30;
+
+impl<D: DualNum<f64>> IdealGasContributionDual<D> for Dippr107 {
+    fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
+        let t = temperature;
+        let f = (temperature * KB / (P0 * A3)).ln();
+        Array1::from_shape_fn(components, |i| {
+            let r = &self.records[i];
+            let h = r.enthalpy(t) - r.enthalpy(T0);
+            let s = r.entropy(t) - r.entropy(T0);
+            (h - t * s) / (t * RGAS_KMOL) + f
+        })
+    }
+}
+
+impl EquationOfState for Dippr107 {
+    fn components(&self) -> usize {
+        self.records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let records = component_list
+            .iter()
+            .map(|&i| self.records[i].clone())
+            .collect();
+        Self::new(records)
+    }
+
+    fn compute_max_density(&self, _moles: &Array1<f64>) -> f64 {
+        1.0
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &[]
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use crate::ideal_gas::WithIdealGas;
+    use crate::{Contributions, State};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::si::*;
+    use std::sync::Arc;
+
+    fn propane() -> Dippr107Record {
+        // Perry's Chemical Engineers' Handbook
+        Dippr107Record::new(0.5192e5, 1.9245e5, 1.6265e3, 1.168e5, 723.6)
+    }
+
+    #[test]
+    fn heat_capacity() -> EosResult<()> {
+        let eos = Arc::new(Dippr107::new(vec![propane()]));
+        let moles = arr1(&[1.0]) * MOL;
+        let t = 298.15 * KELVIN;
+        let state = State::new_nvt(&eos, t, RGAS * t / BAR * MOL, &moles)?;
+        let c_p = state.c_p(Contributions::Total);
+        assert_relative_eq!(c_p, eos.c_p(t, &state.molefracs)?, max_relative = 1e-10);
+        assert_relative_eq!(c_p, 73.6 * JOULE / MOL / KELVIN, max_relative = 5e-3);
+
+        // enthalpy and entropy vanish at the reference state
+        assert!(
+            state
+                .molar_enthalpy(Contributions::Total)
+                .to_reduced(JOULE / MOL)?
+                .abs()
+                < 1e-8
+        );
+        assert!(
+            state
+                .molar_entropy(Contributions::Total)
+                .to_reduced(JOULE / MOL / KELVIN)?
+                .abs()
+                < 1e-4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn enthalpy_integral() -> EosResult<()> {
+        let eos = Arc::new(Dippr107::new(vec![propane()]));
+        let moles = arr1(&[1.0]) * MOL;
+        let (t1, t2) = (300.0, 800.0);
+
+        // integrate the heat capacity with Simpson's rule
+        let n = 1000;
+        let dt = (t2 - t1) / n as f64;
+        let (mut dh, mut ds) = (0.0, 0.0);
+        for k in 0..=n {
+            let t = t1 + k as f64 * dt;
+            let w = if k == 0 || k == n {
+                1.0
+            } else if k % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            let c_p = propane().c_p(t) * 1e-3;
+            dh += w * c_p * dt / 3.0;
+            ds += w * c_p / t * dt / 3.0;
+        }
+
+        let p = BAR;
+        let s1 = State::new_nvt(&eos, t1 * KELVIN, RGAS * t1 * KELVIN / p * MOL, &moles)?;
+        let s2 = State::new_nvt(&eos, t2 * KELVIN, RGAS * t2 * KELVIN / p * MOL, &moles)?;
+        assert_relative_eq!(
+            s2.molar_enthalpy(Contributions::Total) - s1.molar_enthalpy(Contributions::Total),
+            dh * JOULE / MOL,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            s2.molar_entropy(Contributions::Total) - s1.molar_entropy(Contributions::Total),
+            ds * JOULE / MOL / KELVIN,
+            max_relative = 1e-8
+        );
+        Ok(())
+    }
+    #[test]
+    fn peng_robinson_dippr() -> EosResult<()> {
+        let parameters = PengRobinsonParameters::new_simple(
+            &[425.2, 369.96],
+            &[3.8e6, 4.25e6],
+            &[0.199, 0.153],
+            &[58.123, 44.0962],
+        )?;
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let dippr = Dippr107::new(vec![Dippr107Record::default(), propane()]);
+        let eos = WithIdealGas::new(pr.clone(), dippr)?;
+
+        // the subset contains the residual and ideal gas contributions of propane
+        let eos = Arc::new(eos.subset(&[1]));
+        let pr = Arc::new(pr.subset(&[1]));
+        let moles = arr1(&[1.0]) * MOL;
+        let t = 300.0 * KELVIN;
+        let v = 1e-3 * METER.powi(3);
+        let state_pr = State::new_nvt(&pr, t, v, &moles)?;
+        let state = State::new_nvt(&eos, t, v, &moles)?;
+        assert_relative_eq!(
+            state.pressure(Contributions::Total),
+            state_pr.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            state.c_p(Contributions::IdealGas),
+            Dippr107::new(vec![propane()]).c_p(t, &state.molefracs)?,
+            max_relative = 1e-10
+        );
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 use crate::errors::{EosError, EosResult};
-use crate::state::StateHD;
+use crate::profiling;
+use crate::state::{Contributions, Phase, State, StateHD};
 use crate::EosUnit;
 use ndarray::prelude::*;
 use num_dual::{
     Dual, Dual2_64, Dual3, Dual3_64, Dual64, DualNum, DualVec64, HyperDual, HyperDual64,
 };
 use num_traits::{One, Zero};
-use quantity::si::{SIArray1, SINumber, SIUnit};
+use quantity::si::{SIArray1, SIArray2, SINumber, SIUnit, KELVIN, METER, MOL, PASCAL};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Individual Helmholtz energy contribution that can
 /// be evaluated using generalized (hyper) dual numbers.
@@ -16,9 +21,26 @@ use std::fmt;
 /// the specific types in the supertraits of [HelmholtzEnergy]
 /// so that the implementor can be used as a Helmholtz energy
 /// contribution in the equation of state.
+///
+/// Evaluating contributions with complex numbers, e.g., for the
+/// complex-step method, is not supported: `Complex<f64>` does not
+/// implement [DualNum] and neither type is defined in this crate.
+/// To validate derivatives calculated with dual numbers, use
+/// [EquationOfState::derivative_self_check] or
+/// [State::check_derivative_consistency], which compare them to finite
+/// differences of the Helmholtz energy evaluated with `f64`.
 pub trait HelmholtzEnergyDual<D: DualNum<f64>> {
     /// The Helmholtz energy contribution $\beta A$ of a given state in reduced units.
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D;
+
+    /// The Helmholtz energy contribution $\beta A$ of a given state in reduced units
+    /// or an error if the contribution can not be evaluated.
+    ///
+    /// Contributions that contain an iterative solver (e.g. association) override
+    /// this function to return an error instead of NaN if the solver does not converge.
+    fn try_helmholtz_energy(&self, state: &StateHD<D>) -> EosResult<D> {
+        Ok(self.helmholtz_energy(state))
+    }
 }
 
 /// Object safe version of the [HelmholtzEnergyDual] trait.
@@ -38,6 +60,7 @@ pub trait HelmholtzEnergy:
     + HelmholtzEnergyDual<Dual3<Dual64, f64>>
     + HelmholtzEnergyDual<Dual3<DualVec64<2>, f64>>
     + HelmholtzEnergyDual<Dual3<DualVec64<3>, f64>>
+    + HelmholtzEnergyDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
     + fmt::Display
     + Send
     + Sync
@@ -57,6 +80,7 @@ impl<T> HelmholtzEnergy for T where
         + HelmholtzEnergyDual<Dual3<Dual64, f64>>
         + HelmholtzEnergyDual<Dual3<DualVec64<2>, f64>>
         + HelmholtzEnergyDual<Dual3<DualVec64<3>, f64>>
+        + HelmholtzEnergyDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
         + fmt::Display
         + Send
         + Sync
@@ -111,6 +135,7 @@ pub trait IdealGasContribution:
     + IdealGasContributionDual<Dual3<Dual64, f64>>
     + IdealGasContributionDual<Dual3<DualVec64<2>, f64>>
     + IdealGasContributionDual<Dual3<DualVec64<3>, f64>>
+    + IdealGasContributionDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
     + fmt::Display
 {
 }
@@ -128,6 +153,7 @@ impl<T> IdealGasContribution for T where
         + IdealGasContributionDual<Dual3<Dual64, f64>>
         + IdealGasContributionDual<Dual3<DualVec64<2>, f64>>
         + IdealGasContributionDual<Dual3<DualVec64<3>, f64>>
+        + IdealGasContributionDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
         + fmt::Display
 {
 }
@@ -151,6 +177,72 @@ impl fmt::Display for DefaultIdealGasContribution {
 /// specific properties.
 pub trait MolarWeight {
     fn molar_weight(&self) -> SIArray1;
+
+    /// Return `true` if the equation of state provides molar weights.
+    ///
+    /// Only has to be overwritten by collections of equations of state
+    /// in which not every variant provides molar weights.
+    fn has_molar_weight(&self) -> bool {
+        true
+    }
+
+    /// Convert mole fractions into mass fractions.
+    fn mole_to_mass_fractions(&self, molefracs: &Array1<f64>) -> EosResult<Array1<f64>> {
+        let mw = validated_molar_weight(self, molefracs.len())?;
+        let w = molefracs * &mw;
+        Ok(&w / w.sum())
+    }
+
+    /// Convert mass fractions into mole fractions.
+    fn mass_to_mole_fractions(&self, massfracs: &Array1<f64>) -> EosResult<Array1<f64>> {
+        let mw = validated_molar_weight(self, massfracs.len())?;
+        let x = massfracs / &mw;
+        Ok(&x / x.sum())
+    }
+}
+
+/// Return the reduced molar weights after checking the number of components.
+fn validated_molar_weight<M: MolarWeight + ?Sized>(
+    eos: &M,
+    components: usize,
+) -> EosResult<Array1<f64>> {
+    let mw = eos.molar_weight();
+    if mw.len() != components {
+        return Err(EosError::IncompatibleComponents(mw.len(), components));
+    }
+    Ok(mw.to_reduced(SIUnit::reference_molar_mass())?)
+}
+
+/// Minimum number of residual contributions for which
+/// [EquationOfState::evaluate_residual] evaluates the contributions in parallel.
+///
+/// For fewer contributions, the overhead of the thread pool exceeds the
+/// time spent in the contributions (see the `parallel_contributions` benchmark).
+#[cfg(feature = "rayon")]
+pub const MIN_PARALLEL_CONTRIBUTIONS: usize = 4;
+
+/// Evaluate `f` for every contribution and return the results in the
+/// order of the contributions.
+///
+/// If the `rayon` feature is enabled, `parallel` is `true` and there are at
+/// least [MIN_PARALLEL_CONTRIBUTIONS] contributions, the contributions are
+/// evaluated in parallel.
+pub(crate) fn map_contributions<T, F>(
+    contributions: &[Box<dyn HelmholtzEnergy>],
+    parallel: bool,
+    f: F,
+) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&Box<dyn HelmholtzEnergy>) -> T + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    if parallel && contributions.len() >= MIN_PARALLEL_CONTRIBUTIONS {
+        return contributions.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "rayon"))]
+    let _ = parallel;
+    contributions.iter().map(f).collect()
 }
 
 /// A general equation of state.
@@ -175,14 +267,61 @@ pub trait EquationOfState: Send + Sync {
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>];
 
     /// Evaluate the residual reduced Helmholtz energy $\beta A^\mathrm{res}$.
+    ///
+    /// Temperature dependent quantities are shared between the contributions
+    /// (see [StateHD::with_temperature_cache]). If the `rayon` feature is
+    /// enabled, equations of state with at least [MIN_PARALLEL_CONTRIBUTIONS]
+    /// contributions are evaluated in parallel (see
+    /// [EquationOfState::parallel_residual]). In that case, the temperature
+    /// dependent quantities are only shared between the contributions that
+    /// are evaluated on the calling thread, the other threads calculate them
+    /// again. The contributions are always summed up in their original order,
+    /// so that the result does not depend on the number of threads.
     fn evaluate_residual<D: DualNum<f64>>(&self, state: &StateHD<D>) -> D
     where
         dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
     {
-        self.residual()
-            .iter()
-            .map(|c| c.helmholtz_energy(state))
+        let parallel = self.parallel_residual();
+        state.with_temperature_cache(|| {
+            if profiling::is_enabled() {
+                return profiling::evaluate_residual_timed(self.residual(), parallel, state);
+            }
+            map_contributions(self.residual(), parallel, |c| c.helmholtz_energy(state))
+                .into_iter()
+                .sum()
+        })
+    }
+
+    /// Evaluate the residual reduced Helmholtz energy $\beta A^\mathrm{res}$
+    /// and return the error of the first contribution that can not be evaluated
+    /// (see [HelmholtzEnergyDual::try_helmholtz_energy]) instead of NaN.
+    fn try_evaluate_residual<D: DualNum<f64>>(&self, state: &StateHD<D>) -> EosResult<D>
+    where
+        dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
+    {
+        state.with_temperature_cache(|| {
+            map_contributions(self.residual(), self.parallel_residual(), |c| {
+                c.try_helmholtz_energy(state)
+            })
+            .into_iter()
             .sum()
+        })
+    }
+
+    /// Return `false` if the residual contributions must not be evaluated in
+    /// parallel, e.g., because they are implemented in Python and require the
+    /// global interpreter lock. Defaults to `true`.
+    fn parallel_residual(&self) -> bool {
+        true
+    }
+
+    /// Return the wall-clock time spent in each residual contribution during
+    /// the last evaluation of [EquationOfState::evaluate_residual] on the current thread.
+    ///
+    /// The timings are only recorded if profiling is enabled
+    /// via [profiling::enable](crate::profiling::enable).
+    fn last_contribution_timings(&self) -> Vec<(String, Duration)> {
+        profiling::last_timings()
     }
 
     /// Evaluate the reduced Helmholtz energy of each individual contribution
@@ -194,10 +333,11 @@ pub trait EquationOfState: Send + Sync {
     where
         dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
     {
-        self.residual()
-            .iter()
-            .map(|c| (c.to_string(), c.helmholtz_energy(state)))
-            .collect()
+        state.with_temperature_cache(|| {
+            map_contributions(self.residual(), self.parallel_residual(), |c| {
+                (c.to_string(), c.helmholtz_energy(state))
+            })
+        })
     }
 
     /// Return the ideal gas contribution.
@@ -244,6 +384,30 @@ pub trait EquationOfState: Send + Sync {
         Ok(self.compute_max_density(&mr) * SIUnit::reference_density())
     }
 
+    /// Return initial densities of a liquid and a vapor phase (in that
+    /// order) at given temperature, pressure and composition.
+    ///
+    /// Models that can provide better estimates than the maximum density
+    /// and the ideal gas density, e.g., cubic equations of state via the
+    /// roots of the cubic equation, can use this method to speed up the
+    /// density iteration. Defaults to `None`.
+    fn initial_densities(
+        &self,
+        _temperature: SINumber,
+        _pressure: SINumber,
+        _moles: &SIArray1,
+    ) -> Option<[SINumber; 2]> {
+        None
+    }
+
+    /// Return the segment number $m_i$ of every component that is used to
+    /// scale the residual entropy in entropy scaling.
+    ///
+    /// Defaults to 1 for every component.
+    fn segment_numbers(&self) -> Array1<f64> {
+        Array1::ones(self.components())
+    }
+
     /// Calculate the second virial coefficient $B(T)$
     fn second_virial_coefficient(
         &self,
@@ -260,6 +424,39 @@ pub trait EquationOfState: Send + Sync {
         Ok(self.evaluate_residual(&s).eps1eps2[(0, 0)] * 0.5 / SIUnit::reference_density())
     }
 
+    /// Calculate the second virial coefficients $B_{ij}(T)$ of every pair of components.
+    ///
+    /// The matrix elements are calculated from the mixed second partial derivatives
+    /// of the residual Helmholtz energy w.r.t. the partial densities in the limit of
+    /// zero density. All elements, including the diagonal, are evaluated with
+    /// composition dependent mixing rules at the mole fractions given by `moles`, so
+    /// that $B(T)=\sum_{ij}x_ix_jB_{ij}(T)$. For models with composition dependent
+    /// mixing rules, the diagonal elements are therefore only identical to the
+    /// second virial coefficients of the pure components, if `moles` contains
+    /// only the respective component.
+    fn second_virial_coefficient_matrix(
+        &self,
+        temperature: SINumber,
+        moles: Option<&SIArray1>,
+    ) -> EosResult<SIArray2> {
+        let mr = self.validate_moles(moles)?;
+        let x = mr.to_reduced(mr.sum())?;
+        let n = self.components();
+        let t = HyperDual64::from(temperature.to_reduced(SIUnit::reference_temperature())?);
+        let mut b = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in i..n {
+                let mut rho = Array1::from_elem(n, HyperDual64::zero());
+                rho[i].eps1[0] = 1.0;
+                rho[j].eps2[0] = 1.0;
+                let s = StateHD::new_virial_partial(t, rho, x.clone());
+                b[[i, j]] = self.evaluate_residual(&s).eps1eps2[(0, 0)] * 0.5;
+                b[[j, i]] = b[[i, j]];
+            }
+        }
+        Ok(b * (SIUnit::reference_volume() / SIUnit::reference_moles()))
+    }
+
     /// Calculate the third virial coefficient $C(T)$
     fn third_virial_coefficient(
         &self,
@@ -274,6 +471,20 @@ pub trait EquationOfState: Send + Sync {
         Ok(self.evaluate_residual(&s).v3 / 3.0 / SIUnit::reference_density().powi(2))
     }
 
+    /// Calculate the fourth virial coefficient $D(T)$
+    fn fourth_virial_coefficient(
+        &self,
+        temperature: SINumber,
+        moles: Option<&SIArray1>,
+    ) -> EosResult<SINumber> {
+        let mr = self.validate_moles(moles)?;
+        let x = mr.to_reduced(mr.sum())?;
+        let rho = Dual3::from_re(Dual64::zero().derive()).derive();
+        let t = Dual3::from(temperature.to_reduced(SIUnit::reference_temperature())?);
+        let s = StateHD::new_virial(t, rho, x);
+        Ok(self.evaluate_residual(&s).v3.eps[0] / 8.0 / SIUnit::reference_density().powi(3))
+    }
+
     /// Calculate the temperature derivative of the second virial coefficient $B'(T)$
     fn second_virial_coefficient_temperature_derivative(
         &self,
@@ -293,6 +504,39 @@ pub trait EquationOfState: Send + Sync {
             / (SIUnit::reference_density() * SIUnit::reference_temperature()))
     }
 
+    /// Calculate the temperature derivatives of the second virial coefficients
+    /// $B_{ij}'(T)$ of every pair of components.
+    ///
+    /// The matrix elements are the temperature derivatives of the elements of
+    /// [EquationOfState::second_virial_coefficient_matrix] and follow the same
+    /// convention: composition dependent mixing rules are evaluated at the mole
+    /// fractions given by `moles` for all elements, so that $B'(T)=\sum_{ij}x_ix_jB_{ij}'(T)$.
+    fn second_virial_coefficient_matrix_temperature_derivative(
+        &self,
+        temperature: SINumber,
+        moles: Option<&SIArray1>,
+    ) -> EosResult<SIArray2> {
+        let mr = self.validate_moles(moles)?;
+        let x = mr.to_reduced(mr.sum())?;
+        let n = self.components();
+        let t = HyperDual::from_re(
+            Dual64::from(temperature.to_reduced(SIUnit::reference_temperature())?).derive(),
+        );
+        let mut b = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in i..n {
+                let mut rho = Array1::from_elem(n, HyperDual::zero());
+                rho[i].eps1[0] = Dual64::one();
+                rho[j].eps2[0] = Dual64::one();
+                let s = StateHD::new_virial_partial(t, rho, x.clone());
+                b[[i, j]] = self.evaluate_residual(&s).eps1eps2[(0, 0)].eps[0] * 0.5;
+                b[[j, i]] = b[[i, j]];
+            }
+        }
+        Ok(b * (SIUnit::reference_volume()
+            / (SIUnit::reference_moles() * SIUnit::reference_temperature())))
+    }
+
     /// Calculate the temperature derivative of the third virial coefficient $C'(T)$
     fn third_virial_coefficient_temperature_derivative(
         &self,
@@ -310,8 +554,455 @@ pub trait EquationOfState: Send + Sync {
             / 3.0
             / (SIUnit::reference_density().powi(2) * SIUnit::reference_temperature()))
     }
+
+    /// Calculate the Joule-Thomson inversion temperature in the limit of zero pressure,
+    /// i.e., the temperature at which $TB'(T)=B(T)$.
+    ///
+    /// The root is bracketed starting from 100 K and refined using the
+    /// Illinois variant of the regula falsi method. Returns `None` if no
+    /// inversion temperature is found.
+    fn zero_pressure_inversion_temperature(&self, moles: Option<&SIArray1>) -> Option<SINumber> {
+        let f = |t: f64| -> EosResult<f64> {
+            let temperature = t * SIUnit::reference_temperature();
+            let b = self.second_virial_coefficient(temperature, moles)?;
+            let db_dt =
+                self.second_virial_coefficient_temperature_derivative(temperature, moles)?;
+            Ok((temperature * db_dt - b)
+                .to_reduced(SIUnit::reference_volume() / SIUnit::reference_moles())?)
+        };
+
+        // bracket the root; T B' - B is positive below the inversion temperature
+        let mut t1 = 100.0;
+        let mut f1 = f(t1).ok()?;
+        let factor = if f1 > 0.0 { 2.0 } else { 0.5 };
+        let mut t0 = t1;
+        let mut f0 = f1;
+        for _ in 0..20 {
+            t0 = t1;
+            f0 = f1;
+            t1 *= factor;
+            f1 = f(t1).ok()?;
+            if f0 * f1 <= 0.0 {
+                break;
+            }
+        }
+        if f0 * f1 > 0.0 {
+            return None;
+        }
+
+        // Illinois algorithm
+        let mut side = 0;
+        let mut t_old = t0;
+        for _ in 0..MAX_ITER_INVERSION {
+            let t = (t0 * f1 - t1 * f0) / (f1 - f0);
+            let ft = f(t).ok()?;
+            if (t - t_old).abs() < TOL_INVERSION * t || ft == 0.0 {
+                return Some(t * SIUnit::reference_temperature());
+            }
+            t_old = t;
+            if ft * f1 > 0.0 {
+                t1 = t;
+                f1 = ft;
+                if side == -1 {
+                    f0 *= 0.5;
+                }
+                side = -1;
+            } else {
+                t0 = t;
+                f0 = ft;
+                if side == 1 {
+                    f1 *= 0.5;
+                }
+                side = 1;
+            }
+        }
+        None
+    }
+
+    /// Calculate the Boyle temperature, i.e., the temperature at which $B(T)=0$.
+    ///
+    /// Starting from `initial_temperature` (default: 100 K), the root is first
+    /// bracketed by successively doubling or halving the temperature and then
+    /// refined with a Newton iteration that uses the analytic derivative $B'(T)$.
+    /// Newton steps that leave the bracket are replaced by bisection steps.
+    fn boyle_temperature(
+        &self,
+        moles: Option<&SIArray1>,
+        initial_temperature: Option<SINumber>,
+    ) -> EosResult<SINumber> {
+        let b = |t: f64| -> EosResult<(f64, f64)> {
+            let temperature = t * SIUnit::reference_temperature();
+            let b = self
+                .second_virial_coefficient(temperature, moles)?
+                .to_reduced(SIUnit::reference_volume() / SIUnit::reference_moles())?;
+            let db_dt = self
+                .second_virial_coefficient_temperature_derivative(temperature, moles)?
+                .to_reduced(
+                    SIUnit::reference_volume()
+                        / (SIUnit::reference_moles() * SIUnit::reference_temperature()),
+                )?;
+            Ok((b, db_dt))
+        };
+
+        // bracket the root; B is negative below the Boyle temperature
+        let mut t1 = match initial_temperature {
+            Some(t) => t.to_reduced(SIUnit::reference_temperature())?,
+            None => 100.0,
+        };
+        let (mut b1, _) = b(t1)?;
+        let factor = if b1 < 0.0 { 2.0 } else { 0.5 };
+        let mut t0 = t1;
+        let mut b0 = b1;
+        for _ in 0..20 {
+            t0 = t1;
+            b0 = b1;
+            t1 *= factor;
+            b1 = b(t1)?.0;
+            if b0 * b1 <= 0.0 {
+                break;
+            }
+        }
+        if b0 * b1 > 0.0 {
+            return Err(EosError::NotConverged(String::from("Boyle temperature")));
+        }
+        let (mut t_neg, mut t_pos) = if b0 < 0.0 { (t0, t1) } else { (t1, t0) };
+
+        // Newton iteration safeguarded by the bracket
+        let mut t = (t0 * b1 - t1 * b0) / (b1 - b0);
+        for _ in 0..MAX_ITER_BOYLE {
+            let (bt, db_dt) = b(t)?;
+            if bt < 0.0 {
+                t_neg = t;
+            } else {
+                t_pos = t;
+            }
+            let mut t_new = t - bt / db_dt;
+            if !(t_new > t_neg.min(t_pos) && t_new < t_neg.max(t_pos)) {
+                t_new = 0.5 * (t_neg + t_pos);
+            }
+            if (t_new - t).abs() < TOL_BOYLE * t {
+                return Ok(t_new * SIUnit::reference_temperature());
+            }
+            t = t_new;
+        }
+        Err(EosError::NotConverged(String::from("Boyle temperature")))
+    }
+
+    /// Calculate the logarithmic fugacity coefficients $\ln\varphi_i$ at given
+    /// temperature, pressure and composition.
+    ///
+    /// In contrast to [State::ln_phi](crate::State::ln_phi), no [State](crate::State)
+    /// is created. The density is determined with a Newton iteration started from
+    /// the root requested by `phase`. The function is meant to be used in the inner
+    /// loops of phase equilibrium solvers that only require fugacity coefficients.
+    fn ln_phi(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: Option<&SIArray1>,
+        phase: Phase,
+    ) -> EosResult<Array1<f64>> {
+        let mr = self.validate_moles(moles)?;
+        let x = mr.to_reduced(mr.sum())?;
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let p = pressure.to_reduced(SIUnit::reference_pressure())?;
+
+        // reduced residual Helmholtz energy density and its density derivatives
+        let helmholtz_energy_density = |rho: f64| {
+            let mut rho = HyperDual64::from(rho);
+            rho.eps1[0] = 1.0;
+            rho.eps2[0] = 1.0;
+            let f =
+                self.evaluate_residual(&StateHD::new_virial(HyperDual64::from(t), rho, x.clone()));
+            (f.re, f.eps1[0], f.eps1eps2[(0, 0)])
+        };
+
+        let density_iteration = |rho0: f64| -> EosResult<f64> {
+            let mut rho = rho0;
+            for _ in 0..MAX_ITER_LN_PHI {
+                let (f, df, d2f) = helmholtz_energy_density(rho);
+                let dp_drho = t * (1.0 + rho * d2f);
+                if dp_drho <= 0.0 {
+                    break;
+                }
+                let mut delta = (p - t * (rho + rho * df - f)) / dp_drho;
+                delta = delta.max(-0.5 * rho).min(rho);
+                rho += delta;
+                if delta.abs() < TOL_LN_PHI * rho {
+                    return Ok(rho);
+                }
+            }
+            Err(EosError::IterationFailed(String::from("ln_phi")))
+        };
+
+        let rho_vapor = || density_iteration(p / t);
+        let rho_liquid = || density_iteration(self.compute_max_density(&x));
+        let rho = match phase {
+            Phase::Vapor => rho_vapor()?,
+            Phase::Liquid => rho_liquid()?,
+            Phase::Stable => match (rho_vapor(), rho_liquid()) {
+                (Ok(v), Ok(l)) => {
+                    // compare the molar Gibbs energies up to a common constant
+                    let g = |rho: f64| rho.ln() + helmholtz_energy_density(rho).1;
+                    if g(v) < g(l) {
+                        v
+                    } else {
+                        l
+                    }
+                }
+                (Ok(v), Err(_)) => v,
+                (Err(_), Ok(l)) => l,
+                (Err(e), Err(_)) => return Err(e),
+            },
+        };
+
+        // residual chemical potentials
+        let (f, df, _) = helmholtz_energy_density(rho);
+        let ln_z = ((rho + rho * df - f) / rho).ln();
+        let partial_density = &x * rho;
+        Ok(Array1::from_shape_fn(x.len(), |i| {
+            let mut rho_i = partial_density.mapv(Dual64::from);
+            rho_i[i] = rho_i[i].derive();
+            let state = StateHD::new(Dual64::from(t), Dual64::one(), rho_i);
+            self.evaluate_residual(&state).eps[0] - ln_z
+        }))
+    }
+
+    /// Calculate the pressure and its gradient w.r.t. temperature, volume and the
+    /// amount of substance of each component.
+    ///
+    /// All inputs and outputs are plain floats in SI units, i.e., the temperature in K,
+    /// the volume in m³, the amounts of substance in mol and the pressure in Pa. The
+    /// gradient is ordered as
+    /// $\left(\frac{\partial p}{\partial T},\frac{\partial p}{\partial V},\frac{\partial p}{\partial n_1},\ldots,\frac{\partial p}{\partial n_N}\right)$.
+    ///
+    /// The derivatives are evaluated with dual numbers in packages of three variables,
+    /// so that for a pure component the pressure and its gradient are obtained from a
+    /// single evaluation of the Helmholtz energy.
+    fn pressure_and_gradient(
+        &self,
+        temperature: f64,
+        volume: f64,
+        moles: &[f64],
+    ) -> EosResult<(f64, Vec<f64>)> {
+        let n = self.components();
+        if moles.len() != n {
+            return Err(EosError::IncompatibleComponents(n, moles.len()));
+        }
+        let t_ref = KELVIN.to_reduced(SIUnit::reference_temperature())?;
+        let v_ref = (METER * METER * METER).to_reduced(SIUnit::reference_volume())?;
+        let n_ref = MOL.to_reduced(SIUnit::reference_moles())?;
+        let p_ref = SIUnit::reference_pressure().to_reduced(PASCAL)?;
+
+        // reduced variables in the order (T, V, N_1, ..., N_n)
+        let x: Vec<f64> = [temperature * t_ref, volume * v_ref]
+            .iter()
+            .copied()
+            .chain(moles.iter().map(|&n| n * n_ref))
+            .collect();
+        let mut pressure = 0.0;
+        let mut gradient = vec![0.0; n + 2];
+        let variables: Vec<usize> = (0..n + 2).collect();
+        for package in variables.chunks(3) {
+            let mut x_dual: Vec<DualVec64<3>> = x.iter().map(|&x| DualVec64::from_re(x)).collect();
+            for (k, &i) in package.iter().enumerate() {
+                x_dual[i].eps[k] = 1.0;
+            }
+            let t = x_dual[0];
+            let v = x_dual[1];
+            let n_tot = x_dual[2..]
+                .iter()
+                .fold(DualVec64::zero(), |acc, &n| acc + n);
+            let s = StateHD::new(
+                Dual::from_re(t),
+                Dual::from_re(v).derive(),
+                Array1::from_shape_fn(n, |i| Dual::from_re(x_dual[i + 2])),
+            );
+            let a_v = self.evaluate_residual(&s).eps[0];
+            let p = (n_tot / v - a_v) * t;
+            pressure = p.re * p_ref;
+            for (k, &i) in package.iter().enumerate() {
+                gradient[i] = p.eps[k] * p_ref;
+            }
+        }
+        gradient[0] *= t_ref;
+        gradient[1] *= v_ref;
+        gradient[2..].iter_mut().for_each(|g| *g *= n_ref);
+        Ok((pressure, gradient))
+    }
+
+    /// Compare the residual pressure, entropy and chemical potentials calculated
+    /// with dual numbers to central finite differences of the residual Helmholtz
+    /// energy at the given state.
+    ///
+    /// The second derivatives (isochoric heat capacity and the derivatives of
+    /// the pressure w.r.t. volume and temperature) are compared to second order
+    /// central finite differences of the residual Helmholtz energy.
+    ///
+    /// The comparison can be used to validate the implementation of Helmholtz
+    /// energy contributions, in particular of contributions that are not
+    /// evaluated generically for all dual numbers.
+    fn derivative_self_check(&self, state: &State<Self>) -> EosResult<DerivativeReport>
+    where
+        Self: Sized,
+    {
+        let t = state
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let v = state.volume.to_reduced(SIUnit::reference_volume())?;
+        let n = state.moles.to_reduced(SIUnit::reference_moles())?;
+        let a = |t: f64, v: f64, n: Array1<f64>| self.evaluate_residual(&StateHD::new(t, v, n)) * t;
+
+        let h = FD_STEP * t;
+        let s_fd = -(a(t + h, v, n.clone()) - a(t - h, v, n.clone())) / (2.0 * h);
+        let h = FD_STEP * v;
+        let p_fd = -(a(t, v + h, n.clone()) - a(t, v - h, n.clone())) / (2.0 * h);
+        let h = FD_STEP * n.sum();
+        let mu_fd = Array1::from_shape_fn(n.len(), |i| {
+            let mut n_h = n.clone();
+            n_h[i] += h;
+            let a_plus = a(t, v, n_h.clone());
+            n_h[i] -= 2.0 * h;
+            (a_plus - a(t, v, n_h)) / (2.0 * h)
+        });
+
+        let a0 = a(t, v, n.clone());
+        let ht = FD_STEP_2 * t;
+        let hv = FD_STEP_2 * v;
+        let c_v_fd =
+            -t * (a(t + ht, v, n.clone()) - 2.0 * a0 + a(t - ht, v, n.clone())) / (ht * ht);
+        let dp_dv_fd = -(a(t, v + hv, n.clone()) - 2.0 * a0 + a(t, v - hv, n.clone())) / (hv * hv);
+        let dp_dt_fd = -(a(t + ht, v + hv, n.clone())
+            - a(t + ht, v - hv, n.clone())
+            - a(t - ht, v + hv, n.clone())
+            + a(t - ht, v - hv, n.clone()))
+            / (4.0 * ht * hv);
+
+        let p = state
+            .pressure(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_pressure())?;
+        let s = state
+            .entropy(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_entropy())?;
+        let mu = state
+            .chemical_potential(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_molar_energy())?;
+        let c_v = state
+            .isochoric_heat_capacity(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_entropy())?;
+        let dp_dv = state
+            .dp_dv(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_pressure() / SIUnit::reference_volume())?;
+        let dp_dt = state
+            .dp_dt(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_pressure() / SIUnit::reference_temperature())?;
+        Ok(DerivativeReport {
+            pressure: relative_deviation(p, p_fd),
+            entropy: relative_deviation(s, s_fd),
+            chemical_potential: Array1::from_shape_fn(mu.len(), |i| {
+                relative_deviation(mu[i], mu_fd[i])
+            }),
+            isochoric_heat_capacity: relative_deviation(c_v, c_v_fd),
+            dp_dv: relative_deviation(dp_dv, dp_dv_fd),
+            dp_dt: relative_deviation(dp_dt, dp_dt_fd),
+        })
+    }
+
+    /// Return a closure that evaluates the total reduced Helmholtz energy
+    /// $\beta A$ (ideal gas and residual contributions) for given reduced
+    /// temperature, volume and moles.
+    ///
+    /// The closure shares ownership of the equation of state and can be used
+    /// across threads.
+    fn as_helmholtz_closure(self: &Arc<Self>) -> HelmholtzEnergyClosure
+    where
+        Self: Sized + 'static,
+    {
+        let eos = self.clone();
+        Box::new(move |t, v, n| {
+            let state = StateHD::new(t, v, n.clone());
+            eos.evaluate_residual(&state) + eos.ideal_gas().evaluate(&state)
+        })
+    }
+}
+
+/// Total reduced Helmholtz energy as a function of the reduced temperature,
+/// volume and moles as obtained from [EquationOfState::as_helmholtz_closure].
+pub type HelmholtzEnergyClosure = Box<dyn Fn(f64, f64, &Array1<f64>) -> f64 + Send + Sync>;
+
+/// Relative deviations between derivatives calculated with dual numbers
+/// and with finite differences as obtained from
+/// [EquationOfState::derivative_self_check].
+#[derive(Clone, Debug)]
+pub struct DerivativeReport {
+    /// Relative deviation of the residual pressure.
+    pub pressure: f64,
+    /// Relative deviation of the residual entropy.
+    pub entropy: f64,
+    /// Relative deviation of the residual chemical potential of each component.
+    pub chemical_potential: Array1<f64>,
+    /// Relative deviation of the residual isochoric heat capacity.
+    pub isochoric_heat_capacity: f64,
+    /// Relative deviation of the residual derivative of the pressure w.r.t. volume.
+    pub dp_dv: f64,
+    /// Relative deviation of the residual derivative of the pressure w.r.t. temperature.
+    pub dp_dt: f64,
+}
+
+impl DerivativeReport {
+    /// Return the relative deviations labeled with the corresponding
+    /// derivative of the residual Helmholtz energy.
+    pub fn deviations(&self) -> Vec<(String, f64)> {
+        let mut deviations = vec![
+            ("dA/dV".to_string(), self.pressure),
+            ("dA/dT".to_string(), self.entropy),
+        ];
+        for (i, &mu) in self.chemical_potential.iter().enumerate() {
+            deviations.push((format!("dA/dN[{}]", i), mu));
+        }
+        deviations.push(("d2A/dT2".to_string(), self.isochoric_heat_capacity));
+        deviations.push(("d2A/dV2".to_string(), self.dp_dv));
+        deviations.push(("d2A/dTdV".to_string(), self.dp_dt));
+        deviations
+    }
+
+    /// Return the derivatives with a relative deviation larger than `tolerance`.
+    pub fn failed(&self, tolerance: f64) -> Vec<(String, f64)> {
+        self.deviations()
+            .into_iter()
+            .filter(|(_, d)| d.is_nan() || *d > tolerance)
+            .collect()
+    }
+
+    /// Return the largest relative deviation, or NaN if any deviation is NaN.
+    pub fn max_deviation(&self) -> f64 {
+        self.deviations().iter().fold(0.0, |acc: f64, &(_, d)| {
+            if acc.is_nan() || d.is_nan() {
+                f64::NAN
+            } else {
+                acc.max(d)
+            }
+        })
+    }
 }
 
+fn relative_deviation(analytic: f64, numeric: f64) -> f64 {
+    if analytic == numeric {
+        0.0
+    } else {
+        (analytic - numeric).abs() / analytic.abs().max(numeric.abs())
+    }
+}
+
+const MAX_ITER_INVERSION: usize = 100;
+const TOL_INVERSION: f64 = 1e-10;
+const MAX_ITER_BOYLE: usize = 50;
+const TOL_BOYLE: f64 = 1e-10;
+const MAX_ITER_LN_PHI: usize = 50;
+const TOL_LN_PHI: f64 = 1e-12;
+const FD_STEP: f64 = 1e-6;
+const FD_STEP_2: f64 = 1e-4;
+
 /// Reference values and residual entropy correlations for entropy scaling.
 pub trait EntropyScaling {
     fn viscosity_reference(
@@ -1,6 +1,7 @@
 use crate::parameter::ParameterError;
 use num_dual::linalg::LinAlgError;
 use quantity::QuantityError;
+use std::io;
 use thiserror::Error;
 
 /// Error type for improperly defined states and convergence problems.
@@ -24,9 +25,17 @@ pub enum EosError {
     SuperCritical,
     #[error("No phase split according to stability analysis.")]
     NoPhaseSplit,
+    #[error("No real volume root larger than the covolume for {0}.")]
+    NoValidVolumeRoot(String),
+    #[error("Association did not converge at T = {temperature} K and rho = {density} 1/Å³.")]
+    AssociationNotConverged { temperature: f64, density: f64 },
+    #[error("{0} is not supported by this equation of state.")]
+    NotSupported(String),
     #[error("Wrong input units. Expected {0}, got {1}")]
     WrongUnits(String, String),
     #[error(transparent)]
+    FileIO(#[from] io::Error),
+    #[error(transparent)]
     QuantityError(#[from] QuantityError),
     #[error(transparent)]
     ParameterError(#[from] ParameterError),
@@ -0,0 +1,244 @@
+//! Ideal gas contributions that can be combined with the residual
+//! Helmholtz energy of arbitrary equations of state.
+
+use crate::dippr::Dippr107;
+use crate::nasa::NasaPolynomial;
+use crate::{
+    EosError, EosResult, EosUnit, EquationOfState, HelmholtzEnergy, IdealGasContribution,
+    IdealGasContributionDual, MolarWeight,
+};
+use ndarray::{Array1, Axis};
+use num_dual::DualNum;
+use quantity::si::{SIArray1, SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+const PLANCK: f64 = 6.62607015e-34;
+const KB: f64 = 1.380649e-23;
+const NAV: f64 = 6.02214076e23;
+
+/// Ideal gas contribution based on the thermal de Broglie wavelength
+/// $\Lambda_i=\frac{h}{\sqrt{2\pi m_ik_\mathrm{B}T}}$ of each component.
+///
+/// Internal (rotational and vibrational) degrees of freedom are neglected,
+/// i.e., the ideal gas heat capacity is $c_V^\mathrm{ig}=\frac{3}{2}R$.
+/// The contribution only changes absolute ideal gas properties and has no
+/// influence on phase equilibria. In contrast to [Joback](crate::joback::Joback),
+/// the resulting entropy is the absolute (Sackur-Tetrode) entropy, i.e., it does
+/// not depend on a reference pressure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThermalDeBroglie {
+    /// Molar weight of each component in g/mol.
+    pub molarweight: Array1<f64>,
+}
+
+impl ThermalDeBroglie {
+    /// Creates a new ideal gas contribution from the molar weights (in g/mol) of all components.
+    pub fn new(molarweight: Array1<f64>) -> Self {
+        Self { molarweight }
+    }
+
+    /// Thermal de Broglie wavelength of each component at the given temperature.
+    pub fn thermal_wavelength(&self, temperature: SINumber) -> EosResult<SIArray1> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let ln_lambda3 = self.de_broglie_wavelength(t, self.molarweight.len());
+        Ok(ln_lambda3.mapv(|l| (l / 3.0).exp()) * SIUnit::reference_length())
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(self.molarweight.select(Axis(0), component_list))
+    }
+}
+
+impl<D: DualNum<f64>> IdealGasContributionDual<D> for ThermalDeBroglie {
+    fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
+        // squared wavelength in units of Angstrom^2 for a temperature of 1 K
+        // and a molar weight of 1 g/mol
+        let ln_lambda2 = (PLANCK * PLANCK * 1e20 * NAV / (2.0 * PI * 1e-3 * KB)).ln();
+        let ln_t = temperature.ln();
+        Array1::from_shape_fn(components, |i| {
+            -(ln_t + self.molarweight[i].ln() - ln_lambda2) * 1.5
+        })
+    }
+}
+
+impl fmt::Display for ThermalDeBroglie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ideal gas (thermal de Broglie wavelength)")
+    }
+}
+
+/// Collection of ideal gas models that can be combined with the residual
+/// Helmholtz energy of an equation of state in [WithIdealGas].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IdealGasModel {
+    ThermalDeBroglie(ThermalDeBroglie),
+    Nasa(NasaPolynomial),
+    Dippr107(Dippr107),
+}
+
+impl IdealGasModel {
+    /// Number of components of the ideal gas model.
+    pub fn components(&self) -> usize {
+        match self {
+            Self::ThermalDeBroglie(ideal_gas) => ideal_gas.molarweight.len(),
+            Self::Nasa(ideal_gas) => ideal_gas.components(),
+            Self::Dippr107(ideal_gas) => ideal_gas.components(),
+        }
+    }
+
+    /// Return the ideal gas model for a subset of components.
+    pub fn subset(&self, component_list: &[usize]) -> Self {
+        match self {
+            Self::ThermalDeBroglie(ideal_gas) => {
+                Self::ThermalDeBroglie(ideal_gas.subset(component_list))
+            }
+            Self::Nasa(ideal_gas) => Self::Nasa(ideal_gas.subset(component_list)),
+            Self::Dippr107(ideal_gas) => Self::Dippr107(ideal_gas.subset(component_list)),
+        }
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        match self {
+            Self::ThermalDeBroglie(ideal_gas) => ideal_gas,
+            Self::Nasa(ideal_gas) => ideal_gas,
+            Self::Dippr107(ideal_gas) => ideal_gas,
+        }
+    }
+}
+
+impl From<ThermalDeBroglie> for IdealGasModel {
+    fn from(ideal_gas: ThermalDeBroglie) -> Self {
+        Self::ThermalDeBroglie(ideal_gas)
+    }
+}
+
+impl From<NasaPolynomial> for IdealGasModel {
+    fn from(ideal_gas: NasaPolynomial) -> Self {
+        Self::Nasa(ideal_gas)
+    }
+}
+
+impl From<Dippr107> for IdealGasModel {
+    fn from(ideal_gas: Dippr107) -> Self {
+        Self::Dippr107(ideal_gas)
+    }
+}
+
+/// Equation of state that combines the residual Helmholtz energy of an
+/// equation of state with an [IdealGasModel].
+///
+/// The ideal gas contribution of the underlying equation of state is
+/// replaced. The residual contributions, the maximum density and the molar
+/// weights are taken from the underlying equation of state.
+pub struct WithIdealGas<E> {
+    eos: Arc<E>,
+    ideal_gas: IdealGasModel,
+}
+
+impl<E: EquationOfState> WithIdealGas<E> {
+    /// Combine the residual Helmholtz energy of `eos` with the ideal gas model.
+    pub fn new<I: Into<IdealGasModel>>(eos: Arc<E>, ideal_gas: I) -> EosResult<Self> {
+        let ideal_gas = ideal_gas.into();
+        if ideal_gas.components() != eos.components() {
+            return Err(EosError::IncompatibleComponents(
+                eos.components(),
+                ideal_gas.components(),
+            ));
+        }
+        Ok(Self { eos, ideal_gas })
+    }
+
+    /// Return the underlying equation of state.
+    pub fn eos(&self) -> &Arc<E> {
+        &self.eos
+    }
+
+    /// Return the ideal gas model.
+    pub fn ideal_gas_model(&self) -> &IdealGasModel {
+        &self.ideal_gas
+    }
+}
+
+impl<E: EquationOfState> EquationOfState for WithIdealGas<E> {
+    fn components(&self) -> usize {
+        self.eos.components()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self {
+            eos: Arc::new(self.eos.subset(component_list)),
+            ideal_gas: self.ideal_gas.subset(component_list),
+        }
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.eos.compute_max_density(moles)
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        self.eos.residual()
+    }
+
+    fn parallel_residual(&self) -> bool {
+        self.eos.parallel_residual()
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        self.ideal_gas.ideal_gas()
+    }
+
+    fn segment_numbers(&self) -> Array1<f64> {
+        self.eos.segment_numbers()
+    }
+
+    fn initial_densities(
+        &self,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+    ) -> Option<[SINumber; 2]> {
+        self.eos.initial_densities(temperature, pressure, moles)
+    }
+}
+
+impl<E: MolarWeight> MolarWeight for WithIdealGas<E> {
+    fn molar_weight(&self) -> SIArray1 {
+        self.eos.molar_weight()
+    }
+
+    fn has_molar_weight(&self) -> bool {
+        self.eos.has_molar_weight()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::si::*;
+
+    #[test]
+    fn temperature_scaling() -> EosResult<()> {
+        let ig = ThermalDeBroglie::new(arr1(&[4.0026, 39.948]));
+        let lambda1 = ig.thermal_wavelength(100.0 * KELVIN)?;
+        let lambda2 = ig.thermal_wavelength(400.0 * KELVIN)?;
+        // the volume Lambda^3 scales with T^(-3/2)
+        for i in 0..2 {
+            let ratio = (lambda2.get(i) / lambda1.get(i)).into_value()?;
+            assert_relative_eq!(ratio.powi(3), 4f64.powf(-1.5), max_relative = 1e-12);
+        }
+
+        // argon at 300 K
+        let lambda = ig.thermal_wavelength(300.0 * KELVIN)?;
+        assert_relative_eq!(
+            lambda.get(1),
+            0.15947468652585119 * ANGSTROM,
+            max_relative = 1e-12
+        );
+        Ok(())
+    }
+}
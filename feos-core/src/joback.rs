@@ -9,7 +9,7 @@ use crate::{
 use conv::ValueInto;
 use ndarray::Array1;
 use num_dual::*;
-use quantity::si::{SINumber, SIUnit};
+use quantity::si::{SINumber, SIUnit, PASCAL};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -18,6 +18,11 @@ use std::fmt;
 /// Contains an additional fourth order polynomial coefficient `e`
 /// which is not used in the original publication but is used in
 /// parametrization for additional molecules in other publications.
+///
+/// The optional reference enthalpy `h0` (in J/mol) and reference entropy
+/// `s0` (in J/mol/K) are the absolute ideal gas enthalpy and entropy at the
+/// reference temperature and pressure, e.g., the enthalpy of formation
+/// from the elements. If they are not specified, they are zero.
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct JobackRecord {
     a: f64,
@@ -25,12 +30,38 @@ pub struct JobackRecord {
     c: f64,
     d: f64,
     e: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    h0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    s0: Option<f64>,
 }
 
 impl JobackRecord {
     /// Creates a new `JobackRecord`
     pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64) -> Self {
-        Self { a, b, c, d, e }
+        Self {
+            a,
+            b,
+            c,
+            d,
+            e,
+            h0: None,
+            s0: None,
+        }
+    }
+
+    /// Sets the ideal gas enthalpy in J/mol at the reference state.
+    pub fn with_reference_enthalpy(mut self, h0: f64) -> Self {
+        self.h0 = Some(h0);
+        self
+    }
+
+    /// Sets the ideal gas entropy in J/mol/K at the reference state.
+    pub fn with_reference_entropy(mut self, s0: f64) -> Self {
+        self.s0 = Some(s0);
+        self
     }
 }
 
@@ -38,9 +69,16 @@ impl fmt::Display for JobackRecord {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "JobackRecord(a={}, b={}, c={}, d={}, e={})",
+            "JobackRecord(a={}, b={}, c={}, d={}, e={}",
             self.a, self.b, self.c, self.d, self.e
-        )
+        )?;
+        if let Some(h0) = self.h0 {
+            write!(f, ", h0={}", h0)?;
+        }
+        if let Some(s0) = self.s0 {
+            write!(f, ", s0={}", s0)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -61,21 +99,43 @@ impl<T: Copy + ValueInto<f64>> FromSegments<T> for JobackRecord {
             d += s.d * n;
             e += s.e * n;
         });
-        Ok(Self { a, b, c, d, e })
+        Ok(Self::new(a, b, c, d, e))
     }
 }
 
 /// The ideal gas contribution according to
 /// [Joback and Reid, 1987](https://doi.org/10.1080/00986448708960487).
+///
+/// The absolute ideal gas entropy and enthalpy at the reference
+/// temperature $T_0=298.15\,\mathrm{K}$ and the reference pressure $p_0$,
+/// which defaults to $1\,\mathrm{bar}$, are given by the reference enthalpy
+/// and entropy of the [JobackRecord]s and are zero if these are not specified. Changing the reference pressure shifts
+/// the molar entropy by $R\ln\frac{p_0^\mathrm{new}}{p_0^\mathrm{old}}$ and has
+/// no influence on phase equilibria.
 #[derive(Debug, Clone)]
 pub struct Joback {
     pub records: Vec<JobackRecord>,
+    reference_pressure: f64,
 }
 
 impl Joback {
     /// Creates a new Joback contribution.
     pub fn new(records: Vec<JobackRecord>) -> Self {
-        Self { records }
+        Self {
+            records,
+            reference_pressure: P0,
+        }
+    }
+
+    /// Sets the reference pressure $p_0$ of the absolute ideal gas entropy.
+    pub fn with_reference_pressure(mut self, reference_pressure: SINumber) -> EosResult<Self> {
+        self.reference_pressure = reference_pressure.to_reduced(PASCAL)?;
+        Ok(self)
+    }
+
+    /// Returns the reference pressure $p_0$ of the absolute ideal gas entropy.
+    pub fn reference_pressure(&self) -> SINumber {
+        self.reference_pressure * PASCAL
     }
 
     /// Creates a default ($c_p^\mathrm{ig}=0$) ideal gas contribution for the
@@ -111,19 +171,21 @@ impl<D: DualNum<f64>> IdealGasContributionDual<D> for Joback {
     fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
         let t = temperature;
         let t2 = t * t;
-        let f = (temperature * KB / (P0 * A3)).ln();
+        let f = (temperature * KB / (self.reference_pressure * A3)).ln();
         Array1::from_shape_fn(components, |i| {
             let j = &self.records[i];
             let h = (t2 - T0 * T0) * 0.5 * j.b
                 + (t * t2 - T0.powi(3)) * j.c / 3.0
                 + (t2 * t2 - T0.powi(4)) * j.d / 4.0
                 + (t2 * t2 * t - T0.powi(5)) * j.e / 5.0
-                + (t - T0) * j.a;
+                + (t - T0) * j.a
+                + j.h0.unwrap_or(0.0);
             let s = (t - T0) * j.b
                 + (t2 - T0.powi(2)) * 0.5 * j.c
                 + (t2 * t - T0.powi(3)) * j.d / 3.0
                 + (t2 * t2 - T0.powi(4)) * j.e / 4.0
-                + (t / T0).ln() * j.a;
+                + (t / T0).ln() * j.a
+                + j.s0.unwrap_or(0.0);
             (h - t * s) / (t * RGAS) + f
         })
     }
@@ -139,7 +201,10 @@ impl EquationOfState for Joback {
             .iter()
             .map(|&i| self.records[i].clone())
             .collect();
-        Self::new(records)
+        Self {
+            records,
+            reference_pressure: self.reference_pressure,
+        }
     }
 
     fn compute_max_density(&self, _moles: &Array1<f64>) -> f64 {
@@ -298,4 +363,83 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn reference_pressure() -> EosResult<()> {
+        let record1 = JobackRecord::new(1.0, 0.2, 0.03, 0.004, 0.005);
+        let record2 = JobackRecord::new(-5.0, 0.4, 0.03, 0.002, 0.001);
+        let joback = Joback::new(vec![record1, record2]);
+        let joback1 = Arc::new(joback.clone());
+        let joback2 = Arc::new(joback.with_reference_pressure(10.0 * BAR)?);
+        assert_relative_eq!(joback1.reference_pressure(), BAR, max_relative = 1e-14);
+
+        let temperature = 300.0 * KELVIN;
+        let volume = METER.powi(3);
+        let moles = arr1(&[1.0, 3.0]) * MOL;
+        let state1 = State::new_nvt(&joback1, temperature, volume, &moles)?;
+        let state2 = State::new_nvt(&joback2, temperature, volume, &moles)?;
+        let c = Contributions::Total;
+        assert_relative_eq!(
+            state2.molar_entropy(c) - state1.molar_entropy(c),
+            SIUnit::gas_constant() * 10f64.ln(),
+            max_relative = 1e-10
+        );
+
+        // the shift of the chemical potential is the same for all components
+        let dmu = state2.chemical_potential(c) - state1.chemical_potential(c);
+        assert_relative_eq!(
+            dmu.get(0),
+            -SIUnit::gas_constant() * temperature * 10f64.ln(),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(dmu.get(1), dmu.get(0), max_relative = 1e-10);
+        assert_relative_eq!(state2.pressure(c), state1.pressure(c), max_relative = 1e-14);
+        Ok(())
+    }
+
+    #[test]
+    fn reference_enthalpy_and_entropy() -> EosResult<()> {
+        let record1 = JobackRecord::new(1.0, 0.2, 0.03, 0.004, 0.005);
+        let record2 = JobackRecord::new(-5.0, 0.4, 0.03, 0.002, 0.001);
+        let (h0, s0) = (-84000.0, 229.2);
+        let joback1 = Arc::new(Joback::new(vec![record1.clone(), record2.clone()]));
+        let joback2 = Arc::new(Joback::new(vec![
+            record1
+                .with_reference_enthalpy(h0)
+                .with_reference_entropy(s0),
+            record2,
+        ]));
+
+        let temperature = 400.0 * KELVIN;
+        let volume = METER.powi(3);
+        let moles = arr1(&[1.0, 3.0]) * MOL;
+        let state1 = State::new_nvt(&joback1, temperature, volume, &moles)?;
+        let state2 = State::new_nvt(&joback2, temperature, volume, &moles)?;
+        let c = Contributions::Total;
+        assert_relative_eq!(
+            state2.molar_enthalpy(c) - state1.molar_enthalpy(c),
+            0.25 * h0 * JOULE / MOL,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            state2.molar_entropy(c) - state1.molar_entropy(c),
+            0.25 * s0 * JOULE / MOL / KELVIN,
+            max_relative = 1e-10
+        );
+
+        // only the chemical potential of the first component is shifted
+        let dmu = state2.chemical_potential(c) - state1.chemical_potential(c);
+        assert_relative_eq!(
+            dmu.get(0),
+            (h0 * JOULE - temperature * s0 * JOULE / KELVIN) / MOL,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            state2.chemical_potential(c).get(1),
+            state1.chemical_potential(c).get(1),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(state2.c_p(c), state1.c_p(c), max_relative = 1e-10);
+        Ok(())
+    }
 }
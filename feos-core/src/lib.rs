@@ -26,24 +26,34 @@ macro_rules! log_result {
     }
 }
 
+mod cached;
 pub mod cubic;
+pub mod dippr;
 mod density_iteration;
 mod equation_of_state;
 mod errors;
+pub mod ideal_gas;
 pub mod joback;
+pub mod nasa;
 pub mod parameter;
 mod phase_equilibria;
+pub mod profiling;
 mod state;
+pub use cached::CachedEquationOfState;
 pub use equation_of_state::{
-    EntropyScaling, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution,
-    IdealGasContributionDual, MolarWeight,
+    DerivativeReport, EntropyScaling, EquationOfState, HelmholtzEnergy, HelmholtzEnergyClosure,
+    HelmholtzEnergyDual, IdealGasContribution, IdealGasContributionDual, MolarWeight,
 };
+#[cfg(feature = "rayon")]
+pub use equation_of_state::MIN_PARALLEL_CONTRIBUTIONS;
 pub use errors::{EosError, EosResult};
 pub use phase_equilibria::{
-    PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium, SolverOptions, Verbosity,
+    Azeotrope, AzeotropeType, FlashResult, PhaseDiagram, PhaseDiagramHetero, PhaseEnvelope,
+    PhaseEquilibrium, PhaseRole, ResidualScaling, SolverOptions, SolverStats, StabilityResult,
+    Verbosity,
 };
 pub use state::{
-    Contributions, DensityInitialization, Derivative, State, StateBuilder, StateHD, StateVec,
+    Contributions, DensityInitialization, Derivative, Phase, State, StateBuilder, StateHD, StateVec,
 };
 
 #[cfg(feature = "python")]
@@ -0,0 +1,245 @@
+//! Implementation of the ideal gas heat capacity (de Broglie wavelength)
+//! based on the 7-coefficient [NASA polynomials](https://ntrs.nasa.gov/citations/20020085330).
+
+use crate::{
+    EosResult, EosUnit, EquationOfState, HelmholtzEnergy, IdealGasContribution,
+    IdealGasContributionDual,
+};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::si::{SINumber, SIUnit};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Coefficients of the 7-coefficient NASA polynomials for a pure component.
+///
+/// The coefficients $a_1$ to $a_5$ describe the ideal gas heat capacity
+/// $$\frac{c_p^\mathrm{ig}}{R}=a_1+a_2T+a_3T^2+a_4T^3+a_5T^4$$
+/// and $a_6$ and $a_7$ are the integration constants of the enthalpy and the
+/// entropy at the standard pressure of 1 bar. Separate sets of coefficients
+/// are used below and above the intermediate temperature `t_mid`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NasaRecord {
+    /// Temperature in units of Kelvin that separates the two temperature ranges
+    t_mid: f64,
+    /// Coefficients for temperatures below `t_mid`
+    low: [f64; 7],
+    /// Coefficients for temperatures above `t_mid`
+    high: [f64; 7],
+}
+
+impl NasaRecord {
+    /// Creates a new `NasaRecord`.
+    pub fn new(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self { t_mid, low, high }
+    }
+
+    fn coefficients(&self, temperature: f64) -> &[f64; 7] {
+        if temperature < self.t_mid {
+            &self.low
+        } else {
+            &self.high
+        }
+    }
+}
+
+impl fmt::Display for NasaRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "NasaRecord(t_mid={}, low={:?}, high={:?})",
+            self.t_mid, self.low, self.high
+        )
+    }
+}
+
+/// The ideal gas contribution based on the 7-coefficient NASA polynomials.
+///
+/// In contrast to [Joback](crate::joback::Joback), the enthalpy and entropy
+/// are absolute, i.e., they include the enthalpy of formation and the
+/// standard entropy contained in the coefficients $a_6$ and $a_7$.
+/// The residual Helmholtz energy of an equation of state can be combined
+/// with the NASA polynomials using [WithIdealGas](crate::ideal_gas::WithIdealGas).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NasaPolynomial {
+    pub records: Vec<NasaRecord>,
+}
+
+impl NasaPolynomial {
+    /// Creates a new ideal gas contribution from the NASA polynomials of all components.
+    pub fn new(records: Vec<NasaRecord>) -> Self {
+        Self { records }
+    }
+
+    /// Directly calculates the ideal gas heat capacity from the NASA polynomials.
+    pub fn c_p(&self, temperature: SINumber, molefracs: &Array1<f64>) -> EosResult<SINumber> {
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let mut c_p = 0.0;
+        for (r, &x) in self.records.iter().zip(molefracs.iter()) {
+            let a = r.coefficients(t);
+            c_p += x * (a[0] + t * (a[1] + t * (a[2] + t * (a[3] + t * a[4]))));
+        }
+        Ok(c_p * SIUnit::gas_constant())
+    }
+}
+
+impl fmt::Display for NasaPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ideal gas (NASA polynomials)")
+    }
+}
+
+const P0: f64 = 1.0e5;
+const A3: f64 = 1e-30;
+const KB: f64 = 1.380649e-23;
+
+impl<D: DualNum<f64>> IdealGasContributionDual<D> for NasaPolynomial {
+    fn de_broglie_wavelength(&self, temperature: D, components: usize) -> Array1<D> {
+        let t = temperature;
+        let f = (temperature * KB / (P0 * A3)).ln();
+        Array1::from_shape_fn(components, |i| {
+            let a = self.records[i].coefficients(t.re());
+            // h/(RT) and s/R of the ideal gas at the standard pressure
+            let h = t * (t * (t * (t * a[4] / 5.0 + a[3] / 4.0) + a[2] / 3.0) + a[1] / 2.0)
+                + a[0]
+                + t.recip() * a[5];
+            let s = t * (t * (t * (t * a[4] / 4.0 + a[3] / 3.0) + a[2] / 2.0) + a[1])
+                + t.ln() * a[0]
+                + a[6];
+            h - s + f
+        })
+    }
+}
+
+impl EquationOfState for NasaPolynomial {
+    fn components(&self) -> usize {
+        self.records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let records = component_list
+            .iter()
+            .map(|&i| self.records[i].clone())
+            .collect();
+        Self::new(records)
+    }
+
+    fn compute_max_density(&self, _moles: &Array1<f64>) -> f64 {
+        1.0
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &[]
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use crate::ideal_gas::WithIdealGas;
+    use crate::{Contributions, EosError, State};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+    use quantity::si::*;
+    use std::sync::Arc;
+
+    fn nitrogen() -> NasaRecord {
+        // GRI-Mech 3.0
+        NasaRecord::new(
+            1000.0,
+            [
+                3.298677,
+                1.4082404e-3,
+                -3.963222e-6,
+                5.641515e-9,
+                -2.444854e-12,
+                -1020.8999,
+                3.950372,
+            ],
+            [
+                2.92664,
+                1.4879768e-3,
+                -5.68476e-7,
+                1.0097038e-10,
+                -6.753351e-15,
+                -922.7977,
+                5.980528,
+            ],
+        )
+    }
+
+    #[test]
+    fn nitrogen_nist() -> EosResult<()> {
+        let eos = Arc::new(NasaPolynomial::new(vec![nitrogen()]));
+        let moles = arr1(&[1.0]) * MOL;
+
+        // heat capacity at 1000 K (NIST Chemistry WebBook: 32.70 J/mol/K)
+        let t = 1000.0 * KELVIN;
+        let state = State::new_nvt(&eos, t, RGAS * t / BAR * MOL, &moles)?;
+        let c_p = state.c_p(Contributions::Total);
+        assert_relative_eq!(c_p, 32.70 * JOULE / MOL / KELVIN, max_relative = 5e-3);
+        assert_relative_eq!(c_p, eos.c_p(t, &state.molefracs)?, max_relative = 1e-10);
+
+        // standard entropy at 298.15 K (NIST Chemistry WebBook: 191.61 J/mol/K)
+        let t = 298.15 * KELVIN;
+        let state = State::new_nvt(&eos, t, RGAS * t / BAR * MOL, &moles)?;
+        assert_relative_eq!(
+            state.molar_entropy(Contributions::Total),
+            191.61 * JOULE / MOL / KELVIN,
+            max_relative = 1e-3
+        );
+        assert!(
+            state
+                .molar_enthalpy(Contributions::Total)
+                .to_reduced(JOULE / MOL)?
+                .abs()
+                < 10.0
+        );
+        Ok(())
+    }
+    #[test]
+    fn peng_robinson_nasa() -> EosResult<()> {
+        let parameters =
+            PengRobinsonParameters::new_simple(&[126.192], &[3.3958e6], &[0.0372], &[28.0134])?;
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let nasa = NasaPolynomial::new(vec![nitrogen()]);
+        let eos = Arc::new(WithIdealGas::new(pr.clone(), nasa.clone())?);
+        let moles = arr1(&[1.0]) * MOL;
+        let t = 300.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let state_pr = State::new_nvt(&pr, t, v, &moles)?;
+        let state = State::new_nvt(&eos, t, v, &moles)?;
+
+        // the residual properties are those of the Peng-Robinson equation of state
+        assert_relative_eq!(
+            state.pressure(Contributions::Total),
+            state_pr.pressure(Contributions::Total),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            state.c_v(Contributions::ResidualNvt),
+            state_pr.c_v(Contributions::ResidualNvt),
+            max_relative = 1e-12
+        );
+
+        // the ideal gas heat capacity is calculated from the NASA polynomials
+        assert_relative_eq!(
+            state.c_p(Contributions::IdealGas),
+            nasa.c_p(t, &state.molefracs)?,
+            max_relative = 1e-10
+        );
+
+        // the number of components has to match
+        let nasa = NasaPolynomial::new(vec![nitrogen(), nitrogen()]);
+        assert!(matches!(
+            WithIdealGas::new(pr, nasa),
+            Err(EosError::IncompatibleComponents(1, 2))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use super::PhaseDiagram;
+use crate::equation_of_state::EquationOfState;
+use crate::errors::EosResult;
+use crate::state::Contributions;
+use crate::EosUnit;
+use ndarray::Array1;
+use quantity::si::{SINumber, SIUnit};
+
+/// Classification of an azeotrope in a binary mixture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum AzeotropeType {
+    /// Minimum-boiling azeotrope (pressure maximum along an isotherm).
+    MinimumBoiling,
+    /// Maximum-boiling azeotrope (pressure minimum along an isotherm).
+    MaximumBoiling,
+}
+
+/// Azeotropic point of a binary mixture.
+#[derive(Clone)]
+pub struct Azeotrope {
+    /// Mole fraction of the first component.
+    pub molefrac: f64,
+    /// Temperature at the azeotropic point.
+    pub temperature: SINumber,
+    /// Pressure at the azeotropic point.
+    pub pressure: SINumber,
+    /// Classification of the azeotrope.
+    pub azeotrope_type: AzeotropeType,
+}
+
+impl<E: EquationOfState> PhaseDiagram<E, 2> {
+    /// Detect and classify an azeotrope in an isothermal or isobaric
+    /// binary vapor-liquid phase diagram.
+    ///
+    /// The azeotropic point is interpolated from the change of sign of
+    /// $y_1-x_1$ along the phase diagram. The classification is determined
+    /// from the curvature of the bubble curve at the azeotrope.
+    ///
+    /// Returns `None` if the mixture is zeotropic.
+    pub fn azeotrope(&self) -> EosResult<Option<Azeotrope>> {
+        let n = self.states.len();
+        if n < 3 {
+            return Ok(None);
+        }
+
+        let x: Array1<f64> = self
+            .states
+            .iter()
+            .map(|vle| vle.liquid().molefracs[0])
+            .collect();
+        let d: Array1<f64> = self
+            .states
+            .iter()
+            .map(|vle| vle.vapor().molefracs[0] - vle.liquid().molefracs[0])
+            .collect();
+        let t = self
+            .states
+            .iter()
+            .map(|vle| {
+                vle.liquid()
+                    .temperature
+                    .to_reduced(SIUnit::reference_temperature())
+            })
+            .collect::<Result<Array1<f64>, _>>()?;
+        let p = self
+            .states
+            .iter()
+            .map(|vle| {
+                vle.liquid()
+                    .pressure(Contributions::Total)
+                    .to_reduced(SIUnit::reference_pressure())
+            })
+            .collect::<Result<Array1<f64>, _>>()?;
+
+        // the diagram is isothermal if the relative change in temperature
+        // is smaller than the relative change in pressure
+        let relative_span = |f: &Array1<f64>| {
+            let max = f.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+            let min = f.fold(f64::INFINITY, |a, &b| a.min(b));
+            (max - min) / min
+        };
+        let isothermal = relative_span(&t) < relative_span(&p);
+        let f = if isothermal { &p } else { &t };
+
+        // the pure components at the boundaries are excluded; a state that
+        // lies exactly on the azeotrope is used directly, otherwise the
+        // azeotrope is interpolated between two states with a strict change
+        // of sign in y_1-x_1
+        Ok((1..n - 1)
+            .find_map(|i| {
+                if d[i] == 0.0 {
+                    Some((i, 0.0))
+                } else if i < n - 2 && d[i] * d[i + 1] < 0.0 {
+                    Some((i, d[i] / (d[i] - d[i + 1])))
+                } else {
+                    None
+                }
+            })
+            .map(|(i, s)| {
+                let curvature = ((f[i + 1] - f[i]) / (x[i + 1] - x[i])
+                    - (f[i] - f[i - 1]) / (x[i] - x[i - 1]))
+                    / (x[i + 1] - x[i - 1]);
+                let minimum_boiling = if isothermal {
+                    curvature < 0.0
+                } else {
+                    curvature > 0.0
+                };
+                Azeotrope {
+                    molefrac: x[i] + s * (x[i + 1] - x[i]),
+                    temperature: (t[i] + s * (t[i + 1] - t[i])) * SIUnit::reference_temperature(),
+                    pressure: (p[i] + s * (p[i + 1] - p[i])) * SIUnit::reference_pressure(),
+                    azeotrope_type: if minimum_boiling {
+                        AzeotropeType::MinimumBoiling
+                    } else {
+                        AzeotropeType::MaximumBoiling
+                    },
+                }
+            }))
+    }
+}
@@ -0,0 +1,104 @@
+use super::{PhaseEquilibrium, SolverOptions};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::EosResult;
+use crate::state::{Contributions, DensityInitialization, State};
+use quantity::si::{SIArray1, SINumber};
+use std::sync::Arc;
+
+impl<E: EquationOfState> State<E> {
+    /// Calculate the molar enthalpy of a system with given composition
+    /// along an isobar.
+    ///
+    /// The temperatures have to be sorted in ascending order. If the isobar
+    /// crosses the two-phase region, the bubble and dew point are inserted
+    /// into the result, so that the latent heat is resolved. Within the
+    /// two-phase region, the enthalpy is calculated from a Tp-flash.
+    ///
+    /// The options are passed as (inner, outer) as in
+    /// [PhaseEquilibrium::bubble_point]. The inner options are used for the
+    /// Tp-flash and the inner loop of the bubble and dew point calculations,
+    /// the outer options for the outer loop of the bubble and dew point
+    /// calculations or, for a pure component, for the vapor pressure. Options
+    /// that are not set fall back to the defaults of the respective solver.
+    ///
+    /// Returns the temperatures (including the bubble and dew point)
+    /// and the corresponding molar enthalpies.
+    pub fn enthalpy_temperature_isobar(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        temperatures: &SIArray1,
+        moles: &SIArray1,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<(SIArray1, SIArray1)>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let total_moles = moles.sum();
+
+        // boundaries of the two-phase region
+        let boundaries = if eos.components() == 1 {
+            PhaseEquilibrium::pure(eos, pressure, None, options.1)
+                .ok()
+                .map(|vle| (vle.liquid().clone(), vle.vapor().clone()))
+        } else {
+            let molefracs = moles.to_reduced(total_moles)?;
+            let bubble =
+                PhaseEquilibrium::bubble_point(eos, pressure, &molefracs, None, None, options);
+            let dew = PhaseEquilibrium::dew_point(eos, pressure, &molefracs, None, None, options);
+            bubble
+                .ok()
+                .zip(dew.ok())
+                .map(|(bubble, dew)| (bubble.liquid().clone(), dew.vapor().clone()))
+        };
+
+        let mut points = Vec::with_capacity(temperatures.len() + 2);
+        let mut phase_changes = boundaries
+            .iter()
+            .flat_map(|(bubble, dew)| [bubble, dew])
+            .peekable();
+        let mut vle: Option<PhaseEquilibrium<E, 2>> = None;
+        for (i, t) in temperatures.into_iter().enumerate() {
+            // phase changes below the first temperature are skipped
+            while let Some(s) = phase_changes.next_if(|s| s.temperature <= t) {
+                if i > 0 {
+                    points.push((s.temperature, s.molar_enthalpy(Contributions::Total)));
+                }
+            }
+
+            let h = match &boundaries {
+                Some((bubble, _)) if t <= bubble.temperature => {
+                    State::new_npt(eos, t, pressure, moles, DensityInitialization::Liquid)?
+                        .molar_enthalpy(Contributions::Total)
+                }
+                Some((_, dew)) if t >= dew.temperature => {
+                    State::new_npt(eos, t, pressure, moles, DensityInitialization::Vapor)?
+                        .molar_enthalpy(Contributions::Total)
+                }
+                Some(_) => {
+                    let new_vle = PhaseEquilibrium::tp_flash(
+                        eos,
+                        t,
+                        pressure,
+                        moles,
+                        vle.as_ref(),
+                        options.0,
+                        None,
+                    )?;
+                    let h = (new_vle.liquid().enthalpy(Contributions::Total)
+                        + new_vle.vapor().enthalpy(Contributions::Total))
+                        / total_moles;
+                    vle = Some(new_vle);
+                    h
+                }
+                None => State::new_npt(eos, t, pressure, moles, DensityInitialization::None)?
+                    .molar_enthalpy(Contributions::Total),
+            };
+            points.push((t, h));
+        }
+
+        Ok((
+            SIArray1::from_shape_fn(points.len(), |i| points[i].0),
+            SIArray1::from_shape_fn(points.len(), |i| points[i].1),
+        ))
+    }
+}
@@ -2,20 +2,27 @@ use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
 use crate::state::{Contributions, DensityInitialization, State};
 use crate::EosUnit;
+use ndarray::{Array1, Array2};
 use quantity::si::{SIArray1, SINumber, SIUnit};
 use std::fmt;
 use std::fmt::Write;
 use std::sync::Arc;
 
+mod azeotrope;
 mod bubble_dew;
+mod enthalpy_isobar;
 mod phase_diagram_binary;
 mod phase_diagram_pure;
 mod phase_envelope;
 mod stability_analysis;
 mod tp_flash;
 mod vle_pure;
+pub use azeotrope::{Azeotrope, AzeotropeType};
 pub use phase_diagram_binary::PhaseDiagramHetero;
 pub use phase_diagram_pure::PhaseDiagram;
+pub use phase_envelope::PhaseEnvelope;
+pub use stability_analysis::StabilityResult;
+pub use tp_flash::FlashResult;
 
 /// Level of detail in the iteration output.
 #[derive(Copy, Clone, PartialOrd, PartialEq, Eq)]
@@ -35,6 +42,30 @@ impl Default for Verbosity {
     }
 }
 
+/// Scaling of the residuals of a Newton solver.
+///
+/// The norm of the scaled residuals is used in the line search that
+/// damps the Newton steps and in the convergence criterion, so that
+/// residual components of very different magnitude are weighted equally.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ResidualScaling {
+    /// Divide every residual component whose initial magnitude
+    /// exceeds 1 by its initial magnitude.
+    Automatic,
+    /// Multiply the residual components by constant factors.
+    Factors([f64; 2]),
+}
+
+impl ResidualScaling {
+    /// Return the factors for given initial residuals.
+    pub(crate) fn factors(&self, initial_residuals: [f64; 2]) -> [f64; 2] {
+        match self {
+            Self::Automatic => initial_residuals.map(|r| f64::max(r.abs(), 1.0).recip()),
+            Self::Factors(f) => *f,
+        }
+    }
+}
+
 /// Options for the various phase equilibria solvers.
 ///
 /// If the values are [None], solver specific default
@@ -47,6 +78,11 @@ pub struct SolverOptions {
     pub tol: Option<f64>,
     /// Iteration outpput indicated by the [Verbosity] enum.
     pub verbosity: Verbosity,
+    /// Relaxation factor $0<\omega\leq 1$ of the K-value update in
+    /// successive substitution steps (default: 1).
+    pub relaxation: Option<f64>,
+    /// Scaling of the residuals in the critical point solver (default: unscaled).
+    pub scaling: Option<ResidualScaling>,
 }
 
 impl From<(Option<usize>, Option<f64>, Option<Verbosity>)> for SolverOptions {
@@ -55,6 +91,8 @@ impl From<(Option<usize>, Option<f64>, Option<Verbosity>)> for SolverOptions {
             max_iter: options.0,
             tol: options.1,
             verbosity: options.2.unwrap_or(Verbosity::None),
+            relaxation: None,
+            scaling: None,
         }
     }
 }
@@ -79,6 +117,16 @@ impl SolverOptions {
         self
     }
 
+    pub fn relaxation(mut self, relaxation: f64) -> Self {
+        self.relaxation = Some(relaxation);
+        self
+    }
+
+    pub fn scaling(mut self, scaling: ResidualScaling) -> Self {
+        self.scaling = Some(scaling);
+        self
+    }
+
     pub fn unwrap_or(self, max_iter: usize, tol: f64) -> (usize, f64, Verbosity) {
         (
             self.max_iter.unwrap_or(max_iter),
@@ -88,6 +136,31 @@ impl SolverOptions {
     }
 }
 
+/// Statistics of an iterative solver.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SolverStats {
+    /// Number of iterations.
+    pub iterations: usize,
+    /// Norm of the residual in the last iteration.
+    pub final_residual: f64,
+    /// Whether the iteration converged.
+    pub converged: bool,
+}
+
+/// Role of a phase in a phase equilibrium.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum PhaseRole {
+    /// Vapor phase.
+    Vapor,
+    /// Liquid phase of a two phase equilibrium.
+    Liquid,
+    /// Lighter liquid phase of a liquid-liquid or a three phase equilibrium.
+    Liquid1,
+    /// Heavier liquid phase of a liquid-liquid or a three phase equilibrium.
+    Liquid2,
+}
+
 /// A thermodynamic equilibrium state.
 ///
 /// The struct is parametrized over the number of phases with most features
@@ -268,6 +341,58 @@ impl<E: EquationOfState, const N: usize> PhaseEquilibrium<E, N> {
         Ok(())
     }
 
+    /// Role of each phase in the equilibrium.
+    ///
+    /// If both phases of a two phase equilibrium are liquid-like according to
+    /// [State::phase_identification_parameter], they are labeled as liquid 1
+    /// and liquid 2 in the order of increasing density. Otherwise, the phase
+    /// with the lowest density is labeled as vapor. The remaining phase is
+    /// labeled as liquid for two phases and the remaining phases are labeled
+    /// as liquid 1 and liquid 2 in the order of increasing density for three
+    /// phases.
+    pub fn phase_roles(&self) -> [PhaseRole; N] {
+        let density: Vec<_> = self
+            .0
+            .iter()
+            .map(|s| s.density.to_reduced(SIUnit::reference_density()).unwrap())
+            .collect();
+        let lle = N == 2
+            && self
+                .0
+                .iter()
+                .all(|s| s.phase_identification_parameter() > 1.0);
+        let mut index: Vec<_> = (0..N).collect();
+        index.sort_by(|&i, &j| density[i].total_cmp(&density[j]));
+        let mut roles = [PhaseRole::Vapor; N];
+        for (rank, &i) in index.iter().enumerate() {
+            roles[i] = match (N, rank) {
+                (2, 0) if lle => PhaseRole::Liquid1,
+                (2, _) if lle => PhaseRole::Liquid2,
+                (_, 0) => PhaseRole::Vapor,
+                (2, _) => PhaseRole::Liquid,
+                (_, 1) => PhaseRole::Liquid1,
+                _ => PhaseRole::Liquid2,
+            };
+        }
+        roles
+    }
+
+    /// Distribution of each component across the phases.
+    ///
+    /// The element $(i, j)$ is the fraction of the total amount of
+    /// component $i$ that is contained in phase $j$.
+    pub fn component_phase_distribution(&self) -> Array2<f64> {
+        let moles: Vec<_> = self
+            .0
+            .iter()
+            .map(|s| s.moles.to_reduced(SIUnit::reference_moles()).unwrap())
+            .collect();
+        let total = moles
+            .iter()
+            .fold(Array1::<f64>::zeros(moles[0].len()), |acc, m| acc + m);
+        Array2::from_shape_fn((total.len(), N), |(i, j)| moles[j][i] / total[i])
+    }
+
     pub(super) fn total_gibbs_energy(&self) -> SINumber {
         self.0
             .iter()
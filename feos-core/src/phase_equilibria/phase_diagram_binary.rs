@@ -1,11 +1,18 @@
 use super::{PhaseDiagram, PhaseEquilibrium, SolverOptions};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
-use crate::state::{Contributions, DensityInitialization, State, StateBuilder, TPSpec};
+use crate::state::{
+    spinodal_objective, Contributions, DensityInitialization, State, StateBuilder, TPSpec,
+};
 use crate::EosUnit;
+#[cfg(feature = "rayon")]
+use ndarray::ArrayView1;
 use ndarray::{arr1, arr2, concatenate, s, Array1, Array2, Axis};
 use num_dual::linalg::{norm, LU};
+use num_dual::Dual64;
 use quantity::si::{SIArray1, SINumber, SIUnit};
+#[cfg(feature = "rayon")]
+use rayon::{prelude::*, ThreadPool};
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
@@ -15,9 +22,15 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
     /// Create a new binary phase diagram exhibiting a
     /// vapor/liquid equilibrium.
     ///
+    /// For a given temperature, the isothermal p-x-y diagram is calculated
+    /// from bubble point iterations, for a given pressure the isobaric T-x-y
+    /// diagram. If one component is supercritical, the diagram ends at the
+    /// critical point of the mixture.
+    ///
     /// If a heteroazeotrope occurs and the composition of the liquid
     /// phases are known, they can be passed as `x_lle` to avoid
     /// the calculation of unstable branches.
+    #[doc(alias = "binary_pxy")]
     pub fn binary_vle(
         eos: &Arc<E>,
         temperature_or_pressure: SINumber,
@@ -51,8 +64,41 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         };
 
         // look for supercritical components
-        let (x_lim, vle_lim, bubble) = match vle_sat {
-            [None, None] => return Err(EosError::SuperCritical),
+        let (x_lim, vle_lim, bubble) =
+            Self::vle_limits(eos, temperature_or_pressure, vle_sat, bubble)?;
+
+        let mut states = iterate_vle(
+            eos,
+            tp,
+            &x_lim,
+            vle_lim.0,
+            Some(vle_lim.1),
+            npoints,
+            bubble,
+            bubble_dew_options,
+        );
+        if !bubble {
+            states = states.into_iter().rev().collect();
+        }
+        Ok(Self { states })
+    }
+
+    /// Determine the limits of the composition range of a binary phase
+    /// diagram. If one component is supercritical, the diagram ends at the
+    /// critical point of the mixture.
+    #[allow(clippy::type_complexity)]
+    fn vle_limits(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        vle_sat: [Option<PhaseEquilibrium<E, 2>>; 2],
+        bubble: bool,
+    ) -> EosResult<(
+        [f64; 2],
+        (PhaseEquilibrium<E, 2>, PhaseEquilibrium<E, 2>),
+        bool,
+    )> {
+        match vle_sat {
+            [None, None] => Err(EosError::SuperCritical),
             [Some(vle2), None] => {
                 let cp = State::critical_point_binary(
                     eos,
@@ -62,7 +108,7 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
                     SolverOptions::default(),
                 )?;
                 let cp_vle = PhaseEquilibrium::from_states(cp.clone(), cp.clone());
-                ([0.0, cp.molefracs[0]], (vle2, cp_vle), bubble)
+                Ok(([0.0, cp.molefracs[0]], (vle2, cp_vle), bubble))
             }
             [None, Some(vle1)] => {
                 let cp = State::critical_point_binary(
@@ -73,25 +119,10 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
                     SolverOptions::default(),
                 )?;
                 let cp_vle = PhaseEquilibrium::from_states(cp.clone(), cp.clone());
-                ([1.0, cp.molefracs[0]], (vle1, cp_vle), bubble)
+                Ok(([1.0, cp.molefracs[0]], (vle1, cp_vle), bubble))
             }
-            [Some(vle2), Some(vle1)] => ([0.0, 1.0], (vle2, vle1), true),
-        };
-
-        let mut states = iterate_vle(
-            eos,
-            tp,
-            &x_lim,
-            vle_lim.0,
-            Some(vle_lim.1),
-            npoints,
-            bubble,
-            bubble_dew_options,
-        );
-        if !bubble {
-            states = states.into_iter().rev().collect();
+            [Some(vle2), Some(vle1)] => Ok(([0.0, 1.0], (vle2, vle1), true)),
         }
-        Ok(Self { states })
     }
 
     #[allow(clippy::type_complexity)]
@@ -171,6 +202,122 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
     }
 }
 
+#[cfg(feature = "rayon")]
+fn validate_pxy<E: EquationOfState>(eos: &Arc<E>, temperature: SINumber) -> EosResult<()> {
+    if eos.components() != 2 {
+        return Err(EosError::IncompatibleComponents(eos.components(), 2));
+    }
+    if let TPSpec::Pressure(_) = TPSpec::try_from(temperature)? {
+        return Err(EosError::WrongUnits(
+            "temperature".into(),
+            temperature.to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Remove trivial solutions from the interior of a binary phase diagram.
+/// The end points are kept, because they can be critical points.
+#[cfg(feature = "rayon")]
+fn remove_trivial_solutions<E: EquationOfState>(
+    states: Vec<PhaseEquilibrium<E, 2>>,
+) -> Vec<PhaseEquilibrium<E, 2>> {
+    let n = states.len();
+    states
+        .into_iter()
+        .enumerate()
+        .filter(|(i, vle)| {
+            *i == 0
+                || *i == n - 1
+                || !PhaseEquilibrium::is_trivial_solution(vle.vapor(), vle.liquid())
+        })
+        .map(|(_, vle)| vle)
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+impl<E: EquationOfState> PhaseDiagram<E, 2> {
+    fn solve_molefracs(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        molefracs: ArrayView1<f64>,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> Vec<PhaseEquilibrium<E, 2>> {
+        let mut states = Vec::with_capacity(molefracs.len());
+        let mut vle: Option<PhaseEquilibrium<E, 2>> = None;
+        for xi in molefracs {
+            let p_init = vle
+                .as_ref()
+                .map(|vle| vle.vapor().pressure(Contributions::Total));
+            let y_init = vle.as_ref().map(|vle| vle.vapor().molefracs.clone());
+            vle = PhaseEquilibrium::bubble_point(
+                eos,
+                temperature,
+                &arr1(&[*xi, 1.0 - xi]),
+                p_init,
+                y_init.as_ref(),
+                bubble_dew_options,
+            )
+            .ok();
+            if let Some(vle) = vle.as_ref() {
+                states.push(vle.clone());
+            }
+        }
+        states
+    }
+
+    /// Create an isothermal p-x-y phase diagram of a binary mixture
+    /// in parallel.
+    ///
+    /// The composition grid is split into chunks of size `chunksize` that
+    /// are distributed over the `thread_pool`. Within every chunk, each bubble
+    /// point is initialized with the result of the previous composition.
+    /// Bubble points that do not converge or that collapse to the trivial
+    /// solution close to the critical point are skipped. The end points are
+    /// determined as in [PhaseDiagram::binary_vle].
+    pub fn par_binary_pxy(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        npoints: usize,
+        chunksize: usize,
+        thread_pool: ThreadPool,
+        bubble_dew_options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        validate_pxy(eos, temperature)?;
+        if npoints < 2 {
+            return Err(EosError::Error(format!(
+                "At least 2 points are required for a phase diagram, got {npoints}."
+            )));
+        }
+        if chunksize == 0 {
+            return Err(EosError::Error(String::from(
+                "The chunksize has to be larger than 0.",
+            )));
+        }
+
+        // calculate vapor pressures of pure components
+        let vle_sat = PhaseEquilibrium::vle_pure_comps(eos, temperature);
+        let vle_sat = [vle_sat[1].clone(), vle_sat[0].clone()];
+        let (x_lim, (vle_0, vle_1), _) = Self::vle_limits(eos, temperature, vle_sat, true)?;
+
+        let x = Array1::linspace(x_lim[0], x_lim[1], npoints);
+        let x = x.slice(s![1..-1]);
+        let states: Vec<PhaseEquilibrium<E, 2>> = thread_pool.install(|| {
+            x.axis_chunks_iter(Axis(0), chunksize)
+                .into_par_iter()
+                .map(|x| Self::solve_molefracs(eos, temperature, x, bubble_dew_options))
+                .flatten()
+                .collect()
+        });
+
+        let states = std::iter::once(vle_0)
+            .chain(states)
+            .chain(std::iter::once(vle_1))
+            .collect();
+        Ok(Self::new(remove_trivial_solutions(states)))
+    }
+}
+
 fn iterate_vle<E: EquationOfState>(
     eos: &Arc<E>,
     tp: TPSpec,
@@ -370,6 +517,86 @@ where
         }
     }
 
+    /// Calculate the vapor-liquid-liquid equilibrium of a binary system
+    /// at given temperature without initial guesses.
+    ///
+    /// The three phase pressure is estimated as the sum of the pure component
+    /// vapor pressures (i.e. assuming immiscible liquids). At this pressure,
+    /// liquid states are checked against the spinodal criterion to find the
+    /// compositions that are unstable with respect to a liquid-liquid phase
+    /// split. The liquid-liquid tie line through the center of the unstable
+    /// region is used to initialize both liquid phases of the heteroazeotrope.
+    pub fn vlle_binary(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+
+        // estimate the pressure from the pure component vapor pressures
+        let p0 = match PhaseEquilibrium::vapor_pressure(eos, temperature).as_slice() {
+            [Some(p1), Some(p2)] => *p1 + *p2,
+            _ => return Err(EosError::SuperCritical),
+        };
+
+        // liquid-liquid stability analysis
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let x = Array1::linspace(0.0, 1.0, DEFAULT_POINTS + 2);
+        let unstable: Vec<f64> = x
+            .slice(s![1..-1])
+            .iter()
+            .filter_map(|&x| {
+                let molefracs = arr1(&[x, 1.0 - x]);
+                let liquid = State::new_npt(
+                    eos,
+                    temperature,
+                    p0,
+                    &(&molefracs * SIUnit::reference_moles()),
+                    DensityInitialization::Liquid,
+                )
+                .ok()?;
+                let rho = liquid
+                    .density
+                    .to_reduced(SIUnit::reference_density())
+                    .ok()?;
+                let eval =
+                    spinodal_objective(eos, Dual64::from(t), Dual64::from(rho), &molefracs).ok()?;
+                if eval.re < 0.0 {
+                    Some(x)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let (x_spinodal_1, x_spinodal_2) = match unstable.as_slice() {
+            [] => return Err(EosError::NoPhaseSplit),
+            [x1, .., x2] => (*x1, *x2),
+            [x] => (*x, *x),
+        };
+
+        // initialize the liquid phases from the liquid-liquid tie line
+        let z = 0.5 * (x_spinodal_1 + x_spinodal_2);
+        let (lle, _) = State::lle_tie_line(
+            eos,
+            temperature,
+            p0,
+            &arr1(&[z, 1.0 - z]),
+            SolverOptions::default(),
+        )?;
+        let x_init = (lle.vapor().molefracs[0], lle.liquid().molefracs[0]);
+
+        Self::heteroazeotrope_t(
+            eos,
+            temperature,
+            x_init,
+            Some(p0),
+            options,
+            (SolverOptions::default(), SolverOptions::default()),
+        )
+    }
+
     /// Calculate a heteroazeotrope (three phase equilbrium) for a binary
     /// system and given temperature.
     fn heteroazeotrope_t(
@@ -8,9 +8,12 @@ use crate::EosUnit;
 use ndarray::{Array1, ArrayView1, Axis};
 #[cfg(feature = "rayon")]
 use quantity::si::SIUnit;
-use quantity::si::{SIArray1, SINumber};
+use quantity::si::{SIArray1, SINumber, KELVIN, METER, MOL, PASCAL};
 #[cfg(feature = "rayon")]
 use rayon::{prelude::*, ThreadPool};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
 use std::sync::Arc;
 
 /// Pure component and binary mixture phase diagrams.
@@ -42,9 +45,19 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         critical_temperature: Option<SINumber>,
         options: SolverOptions,
     ) -> EosResult<Self> {
-        let mut states = Vec::with_capacity(npoints);
-
         let sc = State::critical_point(eos, None, critical_temperature, SolverOptions::default())?;
+        Self::pure_from_critical_point(eos, min_temperature, npoints, sc, options)
+    }
+
+    /// Calculate a phase diagram for a pure component with a given critical point.
+    pub(crate) fn pure_from_critical_point(
+        eos: &Arc<E>,
+        min_temperature: SINumber,
+        npoints: usize,
+        sc: State<E>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let mut states = Vec::with_capacity(npoints);
 
         let max_temperature = min_temperature
             + (sc.temperature - min_temperature) * ((npoints - 2) as f64 / (npoints - 1) as f64);
@@ -71,6 +84,56 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
     pub fn liquid(&self) -> StateVec<'_, E> {
         self.states.iter().map(|s| s.liquid()).collect()
     }
+
+    /// Return the temperature, pressure and the densities of both phases
+    /// of all states in the diagram as named columns.
+    ///
+    /// For mixtures, the mole fractions of every component `i` in the liquid
+    /// (`x{i}`) and in the vapor phase (`y{i}`) are added as dimensionless columns.
+    pub fn to_arrays(&self) -> Vec<(String, SIArray1)> {
+        let (liquid, vapor) = (self.liquid(), self.vapor());
+        let mut columns = vec![
+            (String::from("temperature"), vapor.temperature()),
+            (String::from("pressure"), vapor.pressure()),
+            (String::from("density liquid"), liquid.density()),
+            (String::from("density vapor"), vapor.density()),
+        ];
+        let n = self
+            .states
+            .first()
+            .map_or(1, |vle| vle.liquid().eos.components());
+        if n > 1 {
+            let (x, y) = (liquid.molefracs(), vapor.molefracs());
+            for i in 0..n {
+                columns.push((format!("x{}", i), x.column(i).to_owned() * (MOL / MOL)));
+            }
+            for i in 0..n {
+                columns.push((format!("y{}", i), y.column(i).to_owned() * (MOL / MOL)));
+            }
+        }
+        columns
+    }
+
+    /// Write the columns returned by [PhaseDiagram::to_arrays] to a CSV file.
+    ///
+    /// Temperatures are written in K, pressures in Pa and densities in mol/m³.
+    pub fn to_csv<P: AsRef<Path>>(&self, path: P) -> EosResult<()> {
+        let units = [KELVIN, PASCAL, MOL / METER.powi(3), MOL / METER.powi(3)];
+        let (names, columns): (Vec<_>, Vec<_>) = self.to_arrays().into_iter().unzip();
+        let columns = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (c / units.get(i).copied().unwrap_or(MOL / MOL)).into_value())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut file = BufWriter::new(File::create(path)?);
+        writeln!(file, "{}", names.join(","))?;
+        for i in 0..self.states.len() {
+            let row: Vec<_> = columns.iter().map(|c| c[i].to_string()).collect();
+            writeln!(file, "{}", row.join(","))?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "rayon")]
@@ -1,11 +1,23 @@
 use super::{PhaseDiagram, PhaseEquilibrium, SolverOptions};
 use crate::equation_of_state::EquationOfState;
-use crate::errors::EosResult;
-use crate::state::State;
-use crate::Contributions;
-use quantity::si::{SIArray1, SINumber};
+use crate::errors::{EosError, EosResult};
+use crate::state::{DensityInitialization, State, StateVec, TPSpec};
+use crate::{Contributions, EosUnit};
+use ndarray::{s, Array1, Array2};
+use num_dual::linalg::{norm, LU};
+use quantity::si::{SIArray1, SINumber, SIUnit, PASCAL};
 use std::sync::Arc;
 
+const START_PRESSURE_ENVELOPE: f64 = 1e5;
+const MAX_STEP_ENVELOPE: f64 = 0.25;
+const MIN_STEP_ENVELOPE: f64 = 1e-6;
+const MAX_ITER_ENVELOPE: usize = 20;
+const TOL_ENVELOPE: f64 = 1e-10;
+const TOL_TRIVIAL: f64 = 1e-6;
+const MAX_ITER_EXTREMUM: usize = 50;
+const TOL_EXTREMUM: f64 = 1e-8;
+const INV_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+
 impl<E: EquationOfState> PhaseDiagram<E, 2> {
     /// Calculate the bubble point line of a mixture with given composition.
     pub fn bubble_point_line(
@@ -121,6 +133,63 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         Ok(PhaseDiagram::new(states))
     }
 
+    /// Calculate the bubble and dew points of a mixture with given composition
+    /// (an isopleth) at the specified temperatures.
+    ///
+    /// Every point is initialized with the pressure and the composition of the
+    /// incipient phase of the previous point. Temperatures at which the bubble or
+    /// dew point iteration does not converge (e.g., above the critical temperature
+    /// or the cricondentherm) are skipped and the following point is calculated
+    /// without initial values. The first phase diagram contains the
+    /// bubble points, the second one the dew points.
+    pub fn isopleth(
+        eos: &Arc<E>,
+        moles: &SIArray1,
+        temperatures: &SIArray1,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<(Self, Self)> {
+        let molefracs = moles.to_reduced(moles.sum())?;
+        let mut bubble_points = Vec::with_capacity(temperatures.len());
+        let mut dew_points = Vec::with_capacity(temperatures.len());
+
+        let mut bubble: Option<PhaseEquilibrium<E, 2>> = None;
+        let mut dew: Option<PhaseEquilibrium<E, 2>> = None;
+        for ti in temperatures {
+            let p_init = bubble
+                .as_ref()
+                .map(|vle| vle.vapor().pressure(Contributions::Total));
+            let vapor_molefracs = bubble.as_ref().map(|vle| &vle.vapor().molefracs);
+            bubble = PhaseEquilibrium::bubble_point(
+                eos,
+                ti,
+                &molefracs,
+                p_init,
+                vapor_molefracs,
+                options,
+            )
+            .ok();
+            if let Some(vle) = bubble.as_ref() {
+                bubble_points.push(vle.clone());
+            }
+
+            let p_init = dew
+                .as_ref()
+                .map(|vle| vle.vapor().pressure(Contributions::Total));
+            let liquid_molefracs = dew.as_ref().map(|vle| &vle.liquid().molefracs);
+            dew =
+                PhaseEquilibrium::dew_point(eos, ti, &molefracs, p_init, liquid_molefracs, options)
+                    .ok();
+            if let Some(vle) = dew.as_ref() {
+                dew_points.push(vle.clone());
+            }
+        }
+
+        Ok((
+            PhaseDiagram::new(bubble_points),
+            PhaseDiagram::new(dew_points),
+        ))
+    }
+
     /// Calculate the spinodal lines for a mixture with fixed composition.
     pub fn spinodal(
         eos: &Arc<E>,
@@ -154,3 +223,442 @@ impl<E: EquationOfState> PhaseDiagram<E, 2> {
         Ok(PhaseDiagram::new(states))
     }
 }
+
+/// Phase envelope of a mixture with given composition.
+pub struct PhaseEnvelope<E> {
+    /// Bubble point line from 1 bar up to the critical point.
+    pub bubble: PhaseDiagram<E, 2>,
+    /// Dew point line from 1 bar up to the critical point (including
+    /// the retrograde region beyond the critical point).
+    pub dew: PhaseDiagram<E, 2>,
+    /// Phase equilibrium at the maximum temperature of the envelope.
+    pub cricondentherm: PhaseEquilibrium<E, 2>,
+    /// Phase equilibrium at the maximum pressure of the envelope.
+    pub cricondenbar: PhaseEquilibrium<E, 2>,
+}
+
+impl<E> Clone for PhaseEnvelope<E> {
+    fn clone(&self) -> Self {
+        Self {
+            bubble: self.bubble.clone(),
+            dew: self.dew.clone(),
+            cricondentherm: self.cricondentherm.clone(),
+            cricondenbar: self.cricondenbar.clone(),
+        }
+    }
+}
+
+impl<E: EquationOfState> PhaseDiagram<E, 2> {
+    /// Calculate the phase envelope of a mixture with given composition.
+    ///
+    /// The envelope starts at the bubble point at 1 bar and is traced with
+    /// a continuation method in the variables $\ln K_i$, $\ln T$ and $\ln p$
+    /// (Michelsen, 1980): in every step, the variable that changes most along
+    /// the envelope is specified, the next point is predicted from the
+    /// sensitivities of the previous point and corrected with a Newton method.
+    /// The $K$ values change sign at the critical point, after which the
+    /// feed is the vapor phase, so that the bubble point line is continued
+    /// through the critical point and down the dew point line until the
+    /// pressure reaches 1 bar again. `npoints` is the maximum number of
+    /// traced points.
+    ///
+    /// The cricondentherm and the cricondenbar are refined from the
+    /// extrema of the traced branches using a golden section search.
+    /// The options are used in the bubble and dew point iterations.
+    pub fn phase_envelope(
+        eos: &Arc<E>,
+        moles: &SIArray1,
+        npoints: usize,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<PhaseEnvelope<E>> {
+        let z = moles.to_reduced(moles.sum())?;
+        let n = z.len();
+
+        // start at the bubble point at low pressure
+        let vle = PhaseEquilibrium::bubble_point(
+            eos,
+            START_PRESSURE_ENVELOPE * PASCAL,
+            &z,
+            None,
+            None,
+            options,
+        )?;
+        let ln_p_min = (START_PRESSURE_ENVELOPE * PASCAL)
+            .to_reduced(SIUnit::reference_pressure())?
+            .ln();
+        let mut u = Array1::zeros(n + 2);
+        for i in 0..n {
+            u[i] = (vle.vapor().molefracs[i] / z[i]).ln();
+        }
+        u[n] = reduced_temperature(&vle).ln();
+        u[n + 1] = reduced_pressure(&vle).ln();
+        let mut point =
+            EnvelopePoint::new(eos, &z, &u, [vle.liquid().density, vle.vapor().density])?;
+
+        let mut bubble = vec![vle];
+        let mut dew = Vec::new();
+        let mut tangent: Option<Array1<f64>> = None;
+        let mut step = MAX_STEP_ENVELOPE;
+        let mut critical_temperature = None;
+        let mut spec = n + 1;
+        while bubble.len() + dew.len() < npoints {
+            // sensitivities along the envelope, oriented in the direction of the last step
+            // (initially in the direction of increasing pressure)
+            let mut v = point.sensitivities(spec)?;
+            let k = (0..n + 2)
+                .max_by(|&i, &j| v[i].abs().total_cmp(&v[j].abs()))
+                .unwrap();
+            v /= v[k].abs();
+            let direction = match tangent.as_ref() {
+                Some(t) => v.dot(t),
+                None => v[n + 1],
+            };
+            if direction < 0.0 {
+                v = -v;
+            }
+
+            let (new_spec, delta, last) =
+                if critical_temperature.is_some() && point.u[n + 1] + step * v[n + 1] < ln_p_min {
+                    // end the dew point line at the initial pressure
+                    (n + 1, ln_p_min - point.u[n + 1], true)
+                } else if k < n && (point.u[k] + step * v[k]) * point.u[k] < 0.0 {
+                    // step over the critical point to the mirrored K values
+                    (k, -2.0 * point.u[k], false)
+                } else {
+                    (k, step * v[k], false)
+                };
+            let u_pred = &point.u + &(&v * (delta / v[new_spec]));
+
+            let new_point = match point.correct(eos, &z, u_pred, new_spec) {
+                Ok((p, iter)) if p.u.slice(s![..n]).iter().any(|k| k.abs() > TOL_TRIVIAL) => {
+                    if iter <= 3 {
+                        step = (1.5 * step).min(MAX_STEP_ENVELOPE);
+                    }
+                    p
+                }
+                _ => {
+                    step *= 0.5;
+                    if step < MIN_STEP_ENVELOPE {
+                        return Err(EosError::NotConverged("phase envelope".into()));
+                    }
+                    continue;
+                }
+            };
+
+            // the K values change sign at the critical point
+            if critical_temperature.is_none() && new_point.u[0] * point.u[0] < 0.0 {
+                let t0 = 0.5 * (point.u[n].exp() + new_point.u[n].exp());
+                critical_temperature = Some(t0 * SIUnit::reference_temperature());
+            }
+            tangent = Some(&new_point.u - &point.u);
+            spec = new_spec;
+            point = new_point;
+            let vle = point.phase_equilibrium();
+            if critical_temperature.is_some() {
+                dew.push(vle);
+            } else {
+                bubble.push(vle);
+            }
+            if last {
+                break;
+            }
+        }
+
+        let sc = State::critical_point(
+            eos,
+            Some(moles),
+            critical_temperature,
+            SolverOptions::default(),
+        )?;
+        bubble.push(PhaseEquilibrium::from_states(sc.clone(), sc.clone()));
+        dew.reverse();
+        dew.push(PhaseEquilibrium::from_states(sc.clone(), sc));
+        let bubble = PhaseDiagram::new(bubble);
+        let dew = PhaseDiagram::new(dew);
+
+        let cricondentherm = cricondentherm(eos, &z, [&bubble, &dew], options);
+        let cricondenbar = cricondenbar(eos, &z, [&bubble, &dew], options);
+        Ok(PhaseEnvelope {
+            bubble,
+            dew,
+            cricondentherm,
+            cricondenbar,
+        })
+    }
+}
+
+/// A converged point on the phase envelope: the feed and the incipient
+/// phase with mole fractions $y_i=K_iz_i$ at the same temperature and pressure.
+struct EnvelopePoint<E> {
+    /// The variables $\ln K_i$, $\ln T$ and $\ln p$ (reduced units).
+    u: Array1<f64>,
+    feed: State<E>,
+    incipient: State<E>,
+}
+
+impl<E: EquationOfState> EnvelopePoint<E> {
+    fn new(
+        eos: &Arc<E>,
+        z: &Array1<f64>,
+        u: &Array1<f64>,
+        densities: [SINumber; 2],
+    ) -> EosResult<Self> {
+        let n = z.len();
+        let temperature = u[n].exp() * SIUnit::reference_temperature();
+        let pressure = u[n + 1].exp() * SIUnit::reference_pressure();
+        let y = z * &u.slice(s![..n]).mapv(f64::exp);
+        let [feed, incipient] = [(z, densities[0]), (&y, densities[1])].map(|(x, rho)| {
+            State::new_npt(
+                eos,
+                temperature,
+                pressure,
+                &(x.clone() * SIUnit::reference_moles()),
+                DensityInitialization::InitialDensity(rho),
+            )
+        });
+        Ok(Self {
+            u: u.clone(),
+            feed: feed?,
+            incipient: incipient?,
+        })
+    }
+
+    /// Residuals of the equilibrium conditions and their Jacobian
+    /// w.r.t. $\ln K_i$, $\ln T$ and $\ln p$.
+    fn residual(&self) -> EosResult<(Array1<f64>, Array2<f64>)> {
+        let n = self.feed.molefracs.len();
+        let (y, z) = (&self.incipient, &self.feed);
+        let n_y = y.moles.to_reduced(SIUnit::reference_moles())?;
+        let t = y.temperature;
+        let p = y.pressure(Contributions::Total);
+
+        let mut g = Array1::zeros(n + 1);
+        g.slice_mut(s![..n])
+            .assign(&(self.u.slice(s![..n]).to_owned() + y.ln_phi() - z.ln_phi()));
+        g[n] = n_y.sum() - 1.0;
+
+        let dln_phi_dnj = (y.dln_phi_dnj() * SIUnit::reference_moles()).into_value()?;
+        let dln_phi_dt = ((y.dln_phi_dt() - z.dln_phi_dt()) * t).into_value()?;
+        let dln_phi_dp = ((y.dln_phi_dp() - z.dln_phi_dp()) * p).into_value()?;
+        let mut jac = Array2::zeros((n + 1, n + 2));
+        for i in 0..n {
+            for j in 0..n {
+                jac[(i, j)] = dln_phi_dnj[(i, j)] * n_y[j];
+            }
+            jac[(i, i)] += 1.0;
+            jac[(i, n)] = dln_phi_dt[i];
+            jac[(i, n + 1)] = dln_phi_dp[i];
+            jac[(n, i)] = n_y[i];
+        }
+        Ok((g, jac))
+    }
+
+    /// Derivatives of all variables along the envelope if the
+    /// variable with index `spec` is used as specification.
+    fn sensitivities(&self, spec: usize) -> EosResult<Array1<f64>> {
+        let n = self.u.len() - 2;
+        let (_, jac) = self.residual()?;
+        let mut rhs = Array1::zeros(n + 2);
+        rhs[n + 1] = 1.0;
+        Ok(LU::new(extended_jacobian(jac, spec))?.solve(&rhs))
+    }
+
+    /// Newton iteration starting from `u` with the variable with index
+    /// `spec` fixed. Returns the converged point and the number of iterations.
+    fn correct(
+        &self,
+        eos: &Arc<E>,
+        z: &Array1<f64>,
+        mut u: Array1<f64>,
+        spec: usize,
+    ) -> EosResult<(Self, usize)> {
+        let mut densities = [self.feed.density, self.incipient.density];
+        for i in 1..=MAX_ITER_ENVELOPE {
+            let point = Self::new(eos, z, &u, densities)?;
+            let (g, jac) = point.residual()?;
+            if norm(&g) < TOL_ENVELOPE {
+                return Ok((point, i));
+            }
+            let mut rhs = Array1::zeros(g.len() + 1);
+            rhs.slice_mut(s![..g.len()]).assign(&(-g));
+            u += &LU::new(extended_jacobian(jac, spec))?.solve(&rhs);
+            densities = [point.feed.density, point.incipient.density];
+        }
+        Err(EosError::NotConverged("phase envelope".into()))
+    }
+
+    fn phase_equilibrium(&self) -> PhaseEquilibrium<E, 2> {
+        PhaseEquilibrium::from_states(self.feed.clone(), self.incipient.clone())
+    }
+}
+
+/// Append the row of the specification equation to the Jacobian.
+fn extended_jacobian(jac: Array2<f64>, spec: usize) -> Array2<f64> {
+    let (m, n) = jac.dim();
+    let mut ext = Array2::zeros((m + 1, n));
+    ext.slice_mut(s![..m, ..]).assign(&jac);
+    ext[(m, spec)] = 1.0;
+    ext
+}
+
+impl<E: EquationOfState> PhaseEnvelope<E> {
+    /// Return the liquid states on the bubble point line.
+    pub fn bubble_points(&self) -> StateVec<'_, E> {
+        self.bubble.liquid()
+    }
+
+    /// Return the vapor states on the dew point line.
+    pub fn dew_points(&self) -> StateVec<'_, E> {
+        self.dew.vapor()
+    }
+}
+
+/// Return the index of the branch and of the state with the largest
+/// value of `f` in both branches.
+fn argmax<E, F: Fn(&PhaseEquilibrium<E, 2>) -> f64>(
+    branches: [&PhaseDiagram<E, 2>; 2],
+    f: F,
+) -> (usize, usize) {
+    let mut max = (0, 0);
+    let mut f_max = f64::NEG_INFINITY;
+    for (b, branch) in branches.iter().enumerate() {
+        for (i, vle) in branch.states.iter().enumerate() {
+            let f_i = f(vle);
+            if f_i > f_max {
+                max = (b, i);
+                f_max = f_i;
+            }
+        }
+    }
+    max
+}
+
+/// Maximize `f` in the interval `[a, b]` using a golden section search.
+/// Returns `None` if any evaluation of `f` fails.
+fn golden_section<T, F: Fn(f64) -> EosResult<(f64, T)>>(mut a: f64, mut b: f64, f: F) -> Option<T> {
+    let mut c = b - (b - a) * INV_GOLDEN_RATIO;
+    let mut d = a + (b - a) * INV_GOLDEN_RATIO;
+    let mut f_c = f(c).ok()?;
+    let mut f_d = f(d).ok()?;
+    for _ in 0..MAX_ITER_EXTREMUM {
+        if (b - a).abs() < TOL_EXTREMUM * (a.abs() + b.abs()) {
+            break;
+        }
+        if f_c.0 > f_d.0 {
+            b = d;
+            d = c;
+            f_d = f_c;
+            c = b - (b - a) * INV_GOLDEN_RATIO;
+            f_c = f(c).ok()?;
+        } else {
+            a = c;
+            c = d;
+            f_c = f_d;
+            d = a + (b - a) * INV_GOLDEN_RATIO;
+            f_d = f(d).ok()?;
+        }
+    }
+    Some(if f_c.0 > f_d.0 { f_c.1 } else { f_d.1 })
+}
+
+fn reduced_temperature<E: EquationOfState>(vle: &PhaseEquilibrium<E, 2>) -> f64 {
+    vle.vapor()
+        .temperature
+        .to_reduced(SIUnit::reference_temperature())
+        .unwrap()
+}
+
+fn reduced_pressure<E: EquationOfState>(vle: &PhaseEquilibrium<E, 2>) -> f64 {
+    vle.vapor()
+        .pressure(Contributions::Total)
+        .to_reduced(SIUnit::reference_pressure())
+        .unwrap()
+}
+
+/// Calculate the cricondentherm by maximizing the temperature as
+/// function of the pressure around the hottest state of the envelope.
+fn cricondentherm<E: EquationOfState>(
+    eos: &Arc<E>,
+    molefracs: &Array1<f64>,
+    branches: [&PhaseDiagram<E, 2>; 2],
+    options: (SolverOptions, SolverOptions),
+) -> PhaseEquilibrium<E, 2> {
+    let (b, i) = argmax(branches, reduced_temperature);
+    let states = &branches[b].states;
+    if i == 0 || i + 1 == states.len() {
+        return states[i].clone();
+    }
+
+    let p_lim = [&states[i - 1], &states[i + 1]].map(reduced_pressure);
+    golden_section(p_lim[0], p_lim[1], |p| {
+        let vle = bubble_dew_point(
+            eos,
+            TPSpec::Pressure(p * SIUnit::reference_pressure()),
+            molefracs,
+            &states[i],
+            b == 0,
+            options,
+        )?;
+        Ok((reduced_temperature(&vle), vle))
+    })
+    .unwrap_or_else(|| states[i].clone())
+}
+
+/// Calculate the cricondenbar by maximizing the pressure as function
+/// of the temperature around the state with the highest pressure.
+fn cricondenbar<E: EquationOfState>(
+    eos: &Arc<E>,
+    molefracs: &Array1<f64>,
+    branches: [&PhaseDiagram<E, 2>; 2],
+    options: (SolverOptions, SolverOptions),
+) -> PhaseEquilibrium<E, 2> {
+    let (b, i) = argmax(branches, reduced_pressure);
+    let states = &branches[b].states;
+    if i == 0 || i + 1 == states.len() {
+        return states[i].clone();
+    }
+
+    let t_lim = [&states[i - 1], &states[i + 1]].map(reduced_temperature);
+    golden_section(t_lim[0], t_lim[1], |t| {
+        let vle = bubble_dew_point(
+            eos,
+            TPSpec::Temperature(t * SIUnit::reference_temperature()),
+            molefracs,
+            &states[i],
+            b == 0,
+            options,
+        )?;
+        Ok((reduced_pressure(&vle), vle))
+    })
+    .unwrap_or_else(|| states[i].clone())
+}
+
+/// Calculate a bubble or dew point that is initialized with
+/// the given phase equilibrium.
+fn bubble_dew_point<E: EquationOfState>(
+    eos: &Arc<E>,
+    tp: TPSpec,
+    molefracs: &Array1<f64>,
+    vle: &PhaseEquilibrium<E, 2>,
+    bubble: bool,
+    options: (SolverOptions, SolverOptions),
+) -> EosResult<PhaseEquilibrium<E, 2>> {
+    let tp_init = match tp {
+        TPSpec::Temperature(_) => vle.vapor().pressure(Contributions::Total),
+        TPSpec::Pressure(_) => vle.vapor().temperature,
+    };
+    let molefracs_init = if bubble {
+        &vle.vapor().molefracs
+    } else {
+        &vle.liquid().molefracs
+    };
+    PhaseEquilibrium::bubble_dew_point(
+        eos,
+        tp,
+        Some(tp_init),
+        molefracs,
+        Some(molefracs_init),
+        bubble,
+        options,
+    )
+}
@@ -6,9 +6,10 @@ use crate::EosUnit;
 use ndarray::*;
 use num_dual::linalg::smallest_ev;
 use num_dual::linalg::LU;
-use quantity::si::SIUnit;
+use quantity::si::{SIArray1, SINumber, SIUnit};
 use std::f64::EPSILON;
 use std::ops::MulAssign;
+use std::sync::Arc;
 
 const X_DOMINANT: f64 = 0.99;
 const MINIMIZE_TOL: f64 = 1E-06;
@@ -17,6 +18,17 @@ const ETA_STEP: f64 = 0.25;
 const MINIMIZE_KMAX: usize = 100;
 const ZERO_TPD: f64 = -1E-08;
 
+/// Result of a tangent plane distance stability test as obtained
+/// from [State::stability_test].
+pub struct StabilityResult<E> {
+    /// `true` if no trial phase with a negative tangent plane distance was found.
+    pub stable: bool,
+    /// The trial phase with the lowest tangent plane distance if the state is unstable.
+    pub incipient_phase: Option<State<E>>,
+    /// The (negative) tangent plane distance of the incipient phase.
+    pub tangent_plane_distance: Option<f64>,
+}
+
 /// # Stability analysis
 impl<E: EquationOfState> State<E> {
     /// Determine if the state is stable, i.e. if a phase split should
@@ -25,14 +37,97 @@ impl<E: EquationOfState> State<E> {
         Ok(self.stability_analysis(options)?.is_empty())
     }
 
+    /// Determine if a feed at given temperature and pressure splits into two
+    /// (or more) phases.
+    ///
+    /// The density of the feed is chosen as the root with the lowest Gibbs energy.
+    pub fn is_two_phase(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+        options: SolverOptions,
+    ) -> EosResult<bool> {
+        let state = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            moles,
+            DensityInitialization::None,
+        )?;
+        Ok(!state.is_stable(options)?)
+    }
+
+    /// Calculate the states of a feed on a grid of temperatures and pressures.
+    ///
+    /// The states are ordered by temperature first and pressure second, i.e.,
+    /// the pressure changes fastest. States in the two-phase region are `None`.
+    pub fn stable_states_grid(
+        eos: &Arc<E>,
+        temperatures: &SIArray1,
+        pressures: &SIArray1,
+        moles: &SIArray1,
+        options: SolverOptions,
+    ) -> EosResult<Vec<(SINumber, SINumber, Option<Self>)>> {
+        let mut states = Vec::with_capacity(temperatures.len() * pressures.len());
+        for t in temperatures {
+            for p in pressures {
+                let state = State::new_npt(eos, t, p, moles, DensityInitialization::None)?;
+                let stable = state.is_stable(options)?;
+                states.push((t, p, if stable { Some(state) } else { None }));
+            }
+        }
+        Ok(states)
+    }
+
+    /// Return the mole fractions of the incipient phase, i.e., the trial phase
+    /// with the lowest tangent plane distance, or `None` if the state is stable.
+    pub fn incipient_phase(&self, options: SolverOptions) -> EosResult<Option<Array1<f64>>> {
+        Ok(self
+            .stability_test(options)?
+            .incipient_phase
+            .map(|s| s.molefracs))
+    }
+
+    /// Perform a tangent plane distance stability test.
+    ///
+    /// The tangent plane distance is minimized starting from a vapor-like and
+    /// a liquid-like trial phase estimated from the fugacity coefficients of
+    /// the state and from nearly pure liquid-like trial phases of every
+    /// component. If the state is unstable, the result contains the trial
+    /// phase with the lowest tangent plane distance which can be used as
+    /// initial estimate for a phase equilibrium calculation.
+    pub fn stability_test(&self, options: SolverOptions) -> EosResult<StabilityResult<E>> {
+        let incipient_phase = self
+            .stability_analysis(options)?
+            .into_iter()
+            .map(|s| (self.tangent_plane_distance(&s), s))
+            .min_by(|(tpd1, _), (tpd2, _)| tpd1.total_cmp(tpd2));
+        Ok(match incipient_phase {
+            Some((tpd, s)) => StabilityResult {
+                stable: false,
+                incipient_phase: Some(s),
+                tangent_plane_distance: Some(tpd),
+            },
+            None => StabilityResult {
+                stable: true,
+                incipient_phase: None,
+                tangent_plane_distance: None,
+            },
+        })
+    }
+
     /// Perform a stability analysis. The result is a list of [State]s with
     /// negative tangent plane distance (i.e. lower Gibbs energy) that can be
     /// used as initial estimates for a phase equilibrium calculation.
     pub fn stability_analysis(&self, options: SolverOptions) -> EosResult<Vec<State<E>>> {
         let mut result = Vec::new();
-        for i_trial in 0..self.eos.components() + 1 {
-            let phase = if i_trial == self.eos.components() {
+        let n = self.eos.components();
+        for i_trial in 0..n + 2 {
+            let phase = if i_trial == n {
                 "Vapor phase".to_string()
+            } else if i_trial == n + 1 {
+                "Liquid phase".to_string()
             } else {
                 format!("Liquid phase {}", i_trial + 1)
             };
@@ -68,6 +163,10 @@ impl<E: EquationOfState> State<E> {
             // try an ideal vapor phase
             let x_trial = self.ln_phi().mapv(f64::exp) * x_feed;
             (&x_trial / x_trial.sum(), DensityInitialization::Vapor)
+        } else if dominant_component == self.eos.components() + 1 {
+            // try a liquid phase in equilibrium with an ideal vapor phase
+            let x_trial = self.ln_phi().mapv(|ln_phi| (-ln_phi).exp()) * x_feed;
+            (&x_trial / x_trial.sum(), DensityInitialization::Liquid)
         } else {
             // try each component as nearly pure phase
             let factor = (1.0 - X_DOMINANT) / (x_feed.sum() - x_feed[dominant_component]);
@@ -2,13 +2,37 @@ use super::{PhaseEquilibrium, SolverOptions, Verbosity};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
 use crate::state::{Contributions, DensityInitialization, State};
+use crate::EosUnit;
 use ndarray::*;
 use num_dual::linalg::norm;
-use quantity::si::{SIArray1, SINumber};
+use quantity::si::{SIArray1, SINumber, SIUnit};
 use std::sync::Arc;
 
 const MAX_ITER_TP: usize = 400;
 const TOL_TP: f64 = 1e-8;
+const MAX_ITER_TV: usize = 50;
+const TOL_TV: f64 = 1e-8;
+const MAX_ITER_PH: usize = 50;
+const TOL_PH: f64 = 1e-10;
+
+/// Result of a flash calculation that can end in a single phase
+/// or in the two-phase region, e.g., for specified pressure and
+/// enthalpy or entropy.
+#[derive(Clone)]
+pub enum FlashResult<E> {
+    SinglePhase(State<E>),
+    TwoPhase(PhaseEquilibrium<E, 2>),
+}
+
+impl<E: EquationOfState> FlashResult<E> {
+    /// Return the temperature of the result.
+    pub fn temperature(&self) -> SINumber {
+        match self {
+            Self::SinglePhase(state) => state.temperature,
+            Self::TwoPhase(vle) => vle.vapor().temperature,
+        }
+    }
+}
 
 /// # Flash calculations
 impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
@@ -35,6 +59,109 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
         )?
         .tp_flash(initial_state, options, non_volatile_components)
     }
+
+    /// Perform a flash calculation for given temperature, total volume
+    /// and feed.
+    ///
+    /// The pressure is iterated with the Illinois method between the
+    /// dew and bubble point pressure of the feed until the volumes of
+    /// both phases add up to the total volume. The phase split for each
+    /// pressure is obtained from a Tp-flash. The options are passed as
+    /// (Tp-flash, pressure iteration).
+    pub fn tv_flash(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        volume: SINumber,
+        feed: &SIArray1,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        let (options_inner, options_outer) = options;
+        let (max_iter, tol, verbosity) = options_outer.unwrap_or(MAX_ITER_TV, TOL_TV);
+
+        // the two-phase region is bounded by the dew and bubble point of the feed
+        let total_moles = feed.sum();
+        let molefracs = feed.to_reduced(total_moles)?;
+        let bubble = PhaseEquilibrium::bubble_point(
+            eos,
+            temperature,
+            &molefracs,
+            None,
+            None,
+            (options_inner, options_inner),
+        )?;
+        let dew = PhaseEquilibrium::dew_point(
+            eos,
+            temperature,
+            &molefracs,
+            None,
+            None,
+            (options_inner, options_inner),
+        )?;
+        let mut p_dew = dew.vapor().pressure(Contributions::Total);
+        let mut p_bubble = bubble.liquid().pressure(Contributions::Total);
+        let mut f_dew = (total_moles / dew.vapor().density - volume).to_reduced(volume)?;
+        let mut f_bubble = (total_moles / bubble.liquid().density - volume).to_reduced(volume)?;
+        if f_dew <= 0.0 || f_bubble >= 0.0 {
+            return Err(EosError::Error(String::from(
+                "The feed is not in the two-phase region for the given temperature and volume.",
+            )));
+        }
+
+        log_iter!(verbosity, " iter |    residual    |    pressure    ");
+        log_iter!(verbosity, "{:-<38}", "");
+
+        let mut vle: Option<Self> = None;
+        let mut side = 0;
+        for i in 1..=max_iter {
+            let pressure = (p_dew * f_bubble - p_bubble * f_dew) / (f_bubble - f_dew);
+            let new_vle = Self::tp_flash(
+                eos,
+                temperature,
+                pressure,
+                feed,
+                vle.as_ref(),
+                options_inner,
+                None,
+            )?;
+            let f =
+                (new_vle.vapor().volume + new_vle.liquid().volume - volume).to_reduced(volume)?;
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:12.8}",
+                i,
+                f.abs(),
+                pressure
+            );
+
+            if f.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "Tv-flash: calculation converged in {} step(s)\n",
+                    i
+                );
+                return Ok(new_vle);
+            }
+
+            // Illinois modification of the regula falsi
+            if f > 0.0 {
+                p_dew = pressure;
+                f_dew = f;
+                if side == 1 {
+                    f_bubble *= 0.5;
+                }
+                side = 1;
+            } else {
+                p_bubble = pressure;
+                f_bubble = f;
+                if side == -1 {
+                    f_dew *= 0.5;
+                }
+                side = -1;
+            }
+            vle = Some(new_vle);
+        }
+        Err(EosError::NotConverged(String::from("Tv-flash")))
+    }
 }
 
 /// # Flash calculations
@@ -53,6 +180,12 @@ impl<E: EquationOfState> State<E> {
     ) -> EosResult<PhaseEquilibrium<E, 2>> {
         // set options
         let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_TP, TOL_TP);
+        let relaxation = options.relaxation.unwrap_or(1.0);
+        if relaxation <= 0.0 || relaxation > 1.0 {
+            return Err(EosError::Error(format!(
+                "The relaxation factor has to be in (0, 1], got {relaxation}."
+            )));
+        }
 
         // initialization
         let mut new_vle_state = match initial_state {
@@ -84,6 +217,7 @@ impl<E: EquationOfState> State<E> {
                 &mut iter,
                 &mut None,
                 tol,
+                relaxation,
                 verbosity,
                 &non_volatile_components,
             )?;
@@ -110,6 +244,7 @@ impl<E: EquationOfState> State<E> {
                     &mut iter,
                     &mut None,
                     tol,
+                    relaxation,
                     verbosity,
                     &non_volatile_components,
                 )?;
@@ -129,6 +264,7 @@ impl<E: EquationOfState> State<E> {
                     &mut iter,
                     &mut None,
                     tol,
+                    relaxation,
                     verbosity,
                     &non_volatile_components,
                 )?;
@@ -141,6 +277,7 @@ impl<E: EquationOfState> State<E> {
             &mut iter,
             max_iter,
             tol,
+            relaxation,
             verbosity,
             &non_volatile_components,
         )?;
@@ -148,7 +285,232 @@ impl<E: EquationOfState> State<E> {
         Ok(new_vle_state)
     }
 
-    fn tangent_plane_distance(&self, trial_state: &State<E>) -> f64 {
+    /// Calculate the liquid-liquid tie line through a feed with given
+    /// temperature, pressure and mole fractions.
+    ///
+    /// The feed is initialized as a liquid. If it is stable, [EosError::NoPhaseSplit]
+    /// is returned. Otherwise, the coexisting phases are calculated from a Tp-flash.
+    /// Returns the phase equilibrium and the fractions of the total amount of
+    /// substance in both phases (in the order of the phases in the [PhaseEquilibrium]).
+    pub fn lle_tie_line(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        molefracs: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<(PhaseEquilibrium<E, 2>, [f64; 2])> {
+        let moles = molefracs.clone() * SIUnit::reference_moles();
+        let feed = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            &moles,
+            DensityInitialization::Liquid,
+        )?;
+        if feed.is_stable(options)? {
+            return Err(EosError::NoPhaseSplit);
+        }
+        let vle = feed.tp_flash(None, options, None)?;
+        let phase_fractions = [
+            vle.vapor().total_moles.to_reduced(feed.total_moles)?,
+            vle.liquid().total_moles.to_reduced(feed.total_moles)?,
+        ];
+        Ok((vle, phase_fractions))
+    }
+
+    /// Perform a flash calculation for given pressure, molar enthalpy
+    /// and feed.
+    ///
+    /// The temperature is iterated with a Newton method using the isobaric
+    /// heat capacity as derivative. For every temperature, the stability of
+    /// the feed is checked and the phase split of unstable feeds is obtained
+    /// from a Tp-flash. In the two-phase region, the enthalpy of the feed is
+    /// the sum of the phase enthalpies weighted by the phase fractions and
+    /// the derivative is approximated by a secant. The options are passed as
+    /// (stability analysis and Tp-flash, temperature iteration).
+    pub fn new_ph(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        molar_enthalpy: SINumber,
+        moles: &SIArray1,
+        initial_temperature: Option<SINumber>,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<FlashResult<E>> {
+        Self::isobaric_flash(
+            eos,
+            pressure,
+            molar_enthalpy,
+            moles,
+            initial_temperature,
+            options,
+            |s| {
+                (
+                    s.molar_enthalpy(Contributions::Total),
+                    s.c_p(Contributions::Total),
+                )
+            },
+            "Ph-flash",
+        )
+    }
+
+    /// Perform a flash calculation for given pressure, molar entropy
+    /// and feed.
+    ///
+    /// The algorithm is the same as in [State::new_ph] with the
+    /// derivative of the entropy $\left(\frac{\partial s}{\partial T}\right)_p=\frac{c_p}{T}$.
+    pub fn new_ps(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        molar_entropy: SINumber,
+        moles: &SIArray1,
+        initial_temperature: Option<SINumber>,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<FlashResult<E>> {
+        Self::isobaric_flash(
+            eos,
+            pressure,
+            molar_entropy,
+            moles,
+            initial_temperature,
+            options,
+            |s| {
+                (
+                    s.molar_entropy(Contributions::Total),
+                    s.c_p(Contributions::Total) / s.temperature,
+                )
+            },
+            "Ps-flash",
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn isobaric_flash<F>(
+        eos: &Arc<E>,
+        pressure: SINumber,
+        target: SINumber,
+        moles: &SIArray1,
+        initial_temperature: Option<SINumber>,
+        options: (SolverOptions, SolverOptions),
+        property: F,
+        name: &str,
+    ) -> EosResult<FlashResult<E>>
+    where
+        F: Fn(&State<E>) -> (SINumber, SINumber),
+    {
+        let (options_inner, options_outer) = options;
+        let (max_iter, tol, verbosity) = options_outer.unwrap_or(MAX_ITER_PH, TOL_PH);
+        let t_ref = SIUnit::reference_temperature();
+
+        // The specified property increases monotonically with temperature,
+        // so every iterate is a lower or upper bound of the solution.
+        let mut t_min = 0.0;
+        let mut t_max = f64::INFINITY;
+        let mut t0 = initial_temperature;
+
+        // For pure components, the two-phase region collapses to the boiling
+        // temperature. Above the critical pressure, no phase equilibrium is
+        // found and the iteration is only done for a single phase.
+        if eos.components() == 1 {
+            if let Ok(vle) = PhaseEquilibrium::pure(eos, pressure, None, options_inner) {
+                let t_sat = vle.vapor().temperature;
+                let (x_vapor, _) = property(vle.vapor());
+                let (x_liquid, _) = property(vle.liquid());
+                if target < x_liquid {
+                    t_max = t_sat.to_reduced(t_ref)?;
+                } else if target > x_vapor {
+                    t_min = t_sat.to_reduced(t_ref)?;
+                } else {
+                    let beta = (target - x_liquid).to_reduced(x_vapor - x_liquid)?;
+                    let n_vapor = moles.clone() * beta;
+                    let n_liquid = moles.clone() * (1.0 - beta);
+                    let vapor =
+                        State::new_nvt(eos, t_sat, n_vapor.sum() / vle.vapor().density, &n_vapor)?;
+                    let liquid = State::new_nvt(
+                        eos,
+                        t_sat,
+                        n_liquid.sum() / vle.liquid().density,
+                        &n_liquid,
+                    )?;
+                    return Ok(FlashResult::TwoPhase(PhaseEquilibrium::from_states(
+                        vapor, liquid,
+                    )));
+                }
+                t0 = t0.or(Some(t_sat));
+            }
+        }
+        let mut t = t0
+            .unwrap_or(298.15 * SIUnit::reference_temperature())
+            .to_reduced(t_ref)?;
+
+        log_iter!(verbosity, " iter |    residual    |  temperature   ");
+        log_iter!(verbosity, "{:-<38}", "");
+
+        let mut vle: Option<PhaseEquilibrium<E, 2>> = None;
+        let mut last_two_phase: Option<(f64, SINumber)> = None;
+        for i in 1..=max_iter {
+            let temperature = t * t_ref;
+            let feed = State::new_npt(
+                eos,
+                temperature,
+                pressure,
+                moles,
+                DensityInitialization::None,
+            )?;
+            let (f, df, result) = if feed.is_stable(options_inner)? {
+                last_two_phase = None;
+                let (x, dx) = property(&feed);
+                (x - target, dx, FlashResult::SinglePhase(feed))
+            } else {
+                let new_vle = feed.tp_flash(vle.as_ref(), options_inner, None)?;
+                let beta = new_vle.vapor_phase_fraction();
+                let (x_vapor, dx_vapor) = property(new_vle.vapor());
+                let (x_liquid, dx_liquid) = property(new_vle.liquid());
+                let f = x_vapor * beta + x_liquid * (1.0 - beta) - target;
+
+                // The change of the phase fractions is not included in the
+                // weighted derivative, so a secant is used if possible.
+                let df = match last_two_phase {
+                    Some((t_last, f_last)) => (f - f_last) / ((t - t_last) * t_ref),
+                    _ => dx_vapor * beta + dx_liquid * (1.0 - beta),
+                };
+                last_two_phase = Some((t, f));
+                vle = Some(new_vle.clone());
+                (f, df, FlashResult::TwoPhase(new_vle))
+            };
+
+            // relative Newton step of the temperature
+            let delta = f.to_reduced(df * temperature)?;
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:12.8}",
+                i,
+                delta.abs(),
+                temperature
+            );
+            if delta.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "{}: calculation converged in {} step(s)\n",
+                    name,
+                    i
+                );
+                return Ok(result);
+            }
+
+            if delta > 0.0 {
+                t_max = t;
+            } else {
+                t_min = t;
+            }
+            t *= 1.0 - delta.clamp(-0.5, 0.5);
+            if t <= t_min || t >= t_max {
+                t = 0.5 * (t_min + t_max);
+            }
+        }
+        Err(EosError::NotConverged(name.into()))
+    }
+
+    pub(super) fn tangent_plane_distance(&self, trial_state: &State<E>) -> f64 {
         let ln_phi_z = self.ln_phi();
         let ln_phi_w = trial_state.ln_phi();
         let z = &self.molefracs;
@@ -164,6 +526,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
         iter: &mut usize,
         max_iter: usize,
         tol: f64,
+        relaxation: f64,
         verbosity: Verbosity,
         non_volatile_components: &Option<Vec<usize>>,
     ) -> EosResult<()> {
@@ -176,6 +539,7 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
                 iter,
                 &mut Some(&mut k_vec),
                 tol,
+                relaxation,
                 verbosity,
                 non_volatile_components,
             )? {
@@ -230,13 +594,21 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
         iter: &mut usize,
         k_vec: &mut Option<&mut Array2<f64>>,
         abs_tol: f64,
+        relaxation: f64,
         verbosity: Verbosity,
         non_volatile_components: &Option<Vec<usize>>,
     ) -> EosResult<bool> {
         for i in 0..iterations {
             let ln_phi_v = self.vapor().ln_phi();
             let ln_phi_l = self.liquid().ln_phi();
-            let mut k = (&ln_phi_l - &ln_phi_v).mapv(f64::exp);
+            let mut ln_k = &ln_phi_l - &ln_phi_v;
+
+            // damp the update of the K-values in logarithmic space
+            if relaxation < 1.0 {
+                let ln_k_old = (&self.vapor().molefracs / &self.liquid().molefracs).mapv(f64::ln);
+                ln_k = relaxation * ln_k + (1.0 - relaxation) * ln_k_old;
+            }
+            let mut k = ln_k.mapv(f64::exp);
 
             // Set k = 0 for non-volatile components
             if let Some(nvc) = non_volatile_components.as_ref() {
@@ -11,6 +11,8 @@ use std::sync::Arc;
 const SCALE_T_NEW: f64 = 0.7;
 const MAX_ITER_PURE: usize = 50;
 const TOL_PURE: f64 = 1e-12;
+const MAX_ITER_BANCROFT: usize = 50;
+const TOL_BANCROFT: f64 = 1e-10;
 
 /// # Pure component phase equilibria
 impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
@@ -418,4 +420,77 @@ impl<E: EquationOfState> PhaseEquilibrium<E, 2> {
             })
             .collect()
     }
+
+    /// Calculate the Bancroft point of a binary mixture, i.e., the
+    /// temperature and pressure at which the vapor pressures of both
+    /// pure components are equal.
+    ///
+    /// The temperature is determined with a secant method on the logarithm
+    /// of the ratio of the pure component vapor pressures as a function of the
+    /// inverse temperature.
+    pub fn bancroft_point(
+        eos: &Arc<E>,
+        initial_temperature: SINumber,
+        options: SolverOptions,
+    ) -> EosResult<(SINumber, SINumber)> {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_BANCROFT, TOL_BANCROFT);
+        let eos1 = Arc::new(eos.subset(&[0]));
+        let eos2 = Arc::new(eos.subset(&[1]));
+        let vapor_pressures = |x: f64| -> EosResult<(SINumber, SINumber)> {
+            let t = x.recip() * SIUnit::reference_temperature();
+            let p1 = Self::pure_t(&eos1, t, None, SolverOptions::default())?
+                .vapor()
+                .pressure(Contributions::Total);
+            let p2 = Self::pure_t(&eos2, t, None, SolverOptions::default())?
+                .vapor()
+                .pressure(Contributions::Total);
+            Ok((p1, p2))
+        };
+
+        // secant method in the inverse temperature
+        let mut x0 = initial_temperature
+            .to_reduced(SIUnit::reference_temperature())?
+            .recip();
+        let (p1, p2) = vapor_pressures(x0)?;
+        let mut f0 = (p1 / p2).into_value()?.ln();
+        let mut x1 = x0 * 0.99;
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |  temperature   |    pressure    "
+        );
+        log_iter!(verbosity, "{:-<64}", "");
+        for i in 1..=max_iter {
+            let (p1, p2) = vapor_pressures(x1)?;
+            let f1 = (p1 / p2).into_value()?.ln();
+            let t = x1.recip() * SIUnit::reference_temperature();
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:.8} | {:.8}",
+                i,
+                f1.abs(),
+                t,
+                p1
+            );
+            if f1.abs() < tol {
+                log_result!(
+                    verbosity,
+                    "Bancroft point: calculation converged in {} step(s)\n",
+                    i
+                );
+                return Ok((t, p1));
+            }
+            let x2 = x1 - f1 * (x1 - x0) / (f1 - f0);
+            if !x2.is_finite() || x2 <= 0.0 {
+                return Err(EosError::IterationFailed("Bancroft point".to_owned()));
+            }
+            x0 = x1;
+            f0 = f1;
+            x1 = x2;
+        }
+        Err(EosError::NotConverged("Bancroft point".to_owned()))
+    }
 }
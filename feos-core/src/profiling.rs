@@ -0,0 +1,65 @@
+//! Opt-in timing of the individual Helmholtz energy contributions.
+//!
+//! Profiling is disabled by default. If it is enabled via [enable], every
+//! evaluation of the residual Helmholtz energy on the current thread records
+//! the wall-clock time spent in each residual contribution. The timings of the
+//! last evaluation on the current thread are available via
+//! [EquationOfState::last_contribution_timings](crate::EquationOfState::last_contribution_timings).
+//!
+//! Profiling is enabled per thread, so that enabling it does not affect
+//! evaluations on other threads. The profiling mode does not change the
+//! results of the evaluation. If it is disabled, the only overhead is a
+//! single thread-local lookup per evaluation.
+use crate::equation_of_state::{map_contributions, HelmholtzEnergy, HelmholtzEnergyDual};
+use crate::state::StateHD;
+use num_dual::DualNum;
+use std::cell::{Cell, RefCell};
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static ENABLED: Cell<bool> = Cell::new(false);
+    static TIMINGS: RefCell<Vec<(String, Duration)>> = RefCell::new(Vec::new());
+}
+
+/// Enable the timing of Helmholtz energy contributions on the current thread.
+pub fn enable() {
+    ENABLED.with(|e| e.set(true));
+}
+
+/// Disable the timing of Helmholtz energy contributions on the current thread.
+pub fn disable() {
+    ENABLED.with(|e| e.set(false));
+}
+
+/// Return `true` if the timing of Helmholtz energy contributions is enabled
+/// on the current thread.
+pub fn is_enabled() -> bool {
+    ENABLED.with(|e| e.get())
+}
+
+/// Evaluate the residual Helmholtz energy and store the time spent in each contribution.
+///
+/// If the contributions are evaluated in parallel, the timings are stored on the
+/// calling thread.
+pub(crate) fn evaluate_residual_timed<D: DualNum<f64>>(
+    contributions: &[Box<dyn HelmholtzEnergy>],
+    parallel: bool,
+    state: &StateHD<D>,
+) -> D
+where
+    dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
+{
+    let results = map_contributions(contributions, parallel, |c| {
+        let start = Instant::now();
+        let a = c.helmholtz_energy(state);
+        (a, (c.to_string(), start.elapsed()))
+    });
+    let helmholtz_energy = results.iter().map(|(a, _)| *a).sum();
+    TIMINGS.with(|t| *t.borrow_mut() = results.into_iter().map(|(_, t)| t).collect());
+    helmholtz_energy
+}
+
+/// Return the timings of the last evaluation on the current thread.
+pub(crate) fn last_timings() -> Vec<(String, Duration)> {
+    TIMINGS.with(|t| t.borrow().clone())
+}
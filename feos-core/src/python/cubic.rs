@@ -1,4 +1,4 @@
-use crate::cubic::{PengRobinsonParameters, PengRobinsonRecord};
+use crate::cubic::{PengRobinsonBinaryRecord, PengRobinsonParameters, PengRobinsonRecord};
 use crate::joback::JobackRecord;
 use crate::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
@@ -7,22 +7,51 @@ use crate::python::joback::PyJobackRecord;
 use crate::python::parameter::PyIdentifier;
 use crate::*;
 use ndarray::Array2;
-use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use numpy::{PyArray2, PyReadonlyArray1, PyReadonlyArray2, ToPyArray};
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
 /// A pure substance parameter for the Peng-Robinson equation of state.
+///
+/// Parameters
+/// ----------
+/// tc : float
+///     Critical temperature in units of Kelvin.
+/// pc : float
+///     Critical pressure in units of Pascal.
+/// acentric_factor : float
+///     Acentric factor.
+/// mathias_copeman : [float], optional
+///     The three coefficients of the Mathias-Copeman alpha function.
+///     Defaults to the Soave alpha function.
+/// volume_translation : float, optional
+///     Constant volume translation in units of m³/mol.
+///     Defaults to 0.
 #[pyclass(name = "PengRobinsonRecord")]
+#[pyo3(text_signature = "(tc, pc, acentric_factor, mathias_copeman=None, volume_translation=None)")]
 #[derive(Clone)]
 pub struct PyPengRobinsonRecord(PengRobinsonRecord);
 
 #[pymethods]
 impl PyPengRobinsonRecord {
     #[new]
-    fn new(tc: f64, pc: f64, acentric_factor: f64) -> Self {
-        Self(PengRobinsonRecord::new(tc, pc, acentric_factor))
+    fn new(
+        tc: f64,
+        pc: f64,
+        acentric_factor: f64,
+        mathias_copeman: Option<[f64; 3]>,
+        volume_translation: Option<f64>,
+    ) -> Self {
+        let mut record = PengRobinsonRecord::new(tc, pc, acentric_factor);
+        if let Some(c) = mathias_copeman {
+            record = record.with_mathias_copeman(c);
+        }
+        if let Some(c) = volume_translation {
+            record = record.with_volume_translation(c);
+        }
+        Self(record)
     }
 
     fn __repr__(&self) -> PyResult<String> {
@@ -39,7 +68,43 @@ impl_pure_record!(
     PyJobackRecord
 );
 
-impl_binary_record!();
+/// Binary interaction parameters for the Peng-Robinson equation of state.
+///
+/// Parameters
+/// ----------
+/// k_ij : float
+///     Binary interaction parameter.
+/// k_ij_t : float, optional
+///     Temperature dependence of the binary interaction parameter
+///     in units of 1/K. Defaults to 0.
+#[pyclass(name = "PengRobinsonBinaryRecord")]
+#[pyo3(text_signature = "(k_ij, k_ij_t=None)")]
+#[derive(Clone)]
+pub struct PyPengRobinsonBinaryRecord(PengRobinsonBinaryRecord);
+
+#[pymethods]
+impl PyPengRobinsonBinaryRecord {
+    #[new]
+    fn new(k_ij: f64, k_ij_t: Option<f64>) -> Self {
+        Self(PengRobinsonBinaryRecord::new(k_ij, k_ij_t))
+    }
+
+    #[getter]
+    fn get_k_ij(&self) -> f64 {
+        self.0.k_ij
+    }
+
+    #[getter]
+    fn get_k_ij_t(&self) -> Option<f64> {
+        self.0.k_ij_t
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_binary_record!(PengRobinsonBinaryRecord, PyPengRobinsonBinaryRecord);
 
 /// Create a set of Peng-Robinson parameters from records.
 ///
@@ -67,6 +132,42 @@ impl_parameter!(PengRobinsonParameters, PyPengRobinsonParameters);
 
 #[pymethods]
 impl PyPengRobinsonParameters {
+    /// Return a copy of the parameters with constant volume translations.
+    ///
+    /// Parameters
+    /// ----------
+    /// c : numpy.ndarray[float]
+    ///     Volume translation of every component in units of m³/mol.
+    ///
+    /// Returns
+    /// -------
+    /// PengRobinsonParameters
+    #[pyo3(text_signature = "($self, c)")]
+    fn with_volume_translation(&self, c: PyReadonlyArray1<f64>) -> PyResult<Self> {
+        Ok(Self(Arc::new(
+            self.0.with_volume_translation(&c.as_array().to_vec())?,
+        )))
+    }
+
+    /// Return a copy of the parameters with the coefficients of the
+    /// Mathias-Copeman alpha function.
+    ///
+    /// Parameters
+    /// ----------
+    /// mathias_copeman : [[float] or None]
+    ///     The three coefficients of the Mathias-Copeman alpha function of every
+    ///     component. Components with `None` use the Soave alpha function.
+    ///
+    /// Returns
+    /// -------
+    /// PengRobinsonParameters
+    #[pyo3(text_signature = "($self, mathias_copeman)")]
+    fn with_mathias_copeman(&self, mathias_copeman: Vec<Option<[f64; 3]>>) -> PyResult<Self> {
+        Ok(Self(Arc::new(
+            self.0.with_mathias_copeman(&mathias_copeman)?,
+        )))
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
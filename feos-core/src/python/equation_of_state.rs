@@ -52,6 +52,35 @@ macro_rules! impl_virial_coefficients {
                     .into())
             }
 
+            /// Calculate the second Virial coefficients B_ij(T)
+            /// of all pairs of components.
+            ///
+            /// All elements are evaluated at the composition given
+            /// by `moles`, so that B(T,x) = sum_ij x_i x_j B_ij(T).
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SINumber
+            ///     The temperature for which B_ij should be computed.
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            #[pyo3(text_signature = "(temperature, moles=None)")]
+            fn second_virial_coefficient_matrix(
+                &self,
+                temperature: PySINumber,
+                moles: Option<PySIArray1>,
+            ) -> PyResult<PySIArray2> {
+                let m = moles.as_deref();
+                Ok(self
+                    .0
+                    .second_virial_coefficient_matrix(temperature.into(), m)?
+                    .into())
+            }
+
             /// Calculate the third Virial coefficient C(T,x).
             ///
             /// Parameters
@@ -77,6 +106,31 @@ macro_rules! impl_virial_coefficients {
                     .into())
             }
 
+            /// Calculate the fourth Virial coefficient D(T,x).
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SINumber
+            ///     The temperature for which D should be computed.
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(text_signature = "(temperature, moles=None)")]
+            fn fourth_virial_coefficient(
+                &self,
+                temperature: PySINumber,
+                moles: Option<PySIArray1>,
+            ) -> PyResult<PySINumber> {
+                let m = moles.as_deref();
+                Ok(self
+                    .0
+                    .fourth_virial_coefficient(temperature.into(), m)?
+                    .into())
+            }
+
             /// Calculate the derivative of the second Virial coefficient B(T,x)
             /// with respect to temperature.
             ///
@@ -103,6 +157,35 @@ macro_rules! impl_virial_coefficients {
                     .into())
             }
 
+            /// Calculate the derivatives of the second Virial coefficients B_ij(T)
+            /// of all pairs of components with respect to temperature.
+            ///
+            /// All elements are evaluated at the composition given
+            /// by `moles`, so that B'(T,x) = sum_ij x_i x_j B_ij'(T).
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SINumber
+            ///     The temperature for which B_ij' should be computed.
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            #[pyo3(text_signature = "(temperature, moles=None)")]
+            fn second_virial_coefficient_matrix_temperature_derivative(
+                &self,
+                temperature: PySINumber,
+                moles: Option<PySIArray1>,
+            ) -> PyResult<PySIArray2> {
+                let m = moles.as_deref();
+                Ok(self
+                    .0
+                    .second_virial_coefficient_matrix_temperature_derivative(temperature.into(), m)?
+                    .into())
+            }
+
             /// Calculate the derivative of the third Virial coefficient C(T,x)
             /// with respect to temperature.
             ///
@@ -128,6 +211,55 @@ macro_rules! impl_virial_coefficients {
                     .third_virial_coefficient_temperature_derivative(temperature.into(), m)?
                     .into())
             }
+
+            /// Calculate the Joule-Thomson inversion temperature in the limit
+            /// of zero pressure, i.e., the temperature at which T B'(T) = B(T).
+            ///
+            /// Parameters
+            /// ----------
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber, optional
+            #[pyo3(text_signature = "(moles=None)")]
+            fn zero_pressure_inversion_temperature(
+                &self,
+                moles: Option<PySIArray1>,
+            ) -> Option<PySINumber> {
+                let m = moles.as_deref();
+                self.0
+                    .zero_pressure_inversion_temperature(m)
+                    .map(|t| t.into())
+            }
+
+            /// Calculate the Boyle temperature, i.e., the temperature
+            /// at which B(T) = 0.
+            ///
+            /// Parameters
+            /// ----------
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            /// initial_temperature : SINumber, optional
+            ///     The temperature from which the root is bracketed.
+            ///     Defaults to 100 K.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(text_signature = "(moles=None, initial_temperature=None)")]
+            fn boyle_temperature(
+                &self,
+                moles: Option<PySIArray1>,
+                initial_temperature: Option<PySINumber>,
+            ) -> PyResult<PySINumber> {
+                let m = moles.as_deref();
+                Ok(self
+                    .0
+                    .boyle_temperature(m, initial_temperature.map(|t| t.into()))?
+                    .into())
+            }
         }
     };
 }
@@ -0,0 +1,266 @@
+use crate::dippr::{Dippr107, Dippr107Record};
+use crate::ideal_gas::{IdealGasModel, ThermalDeBroglie};
+use crate::nasa::{NasaPolynomial, NasaRecord};
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+use quantity::python::{PySIArray1, PySINumber};
+
+/// Ideal gas contribution based on the thermal de Broglie wavelength
+/// of each component.
+///
+/// Internal degrees of freedom are neglected, i.e., only the
+/// translational contribution to the ideal gas is considered.
+///
+/// Parameters
+/// ----------
+/// molarweight : numpy.ndarray[float]
+///     Molar weight of each component in units of g/mol.
+///
+/// Returns
+/// -------
+/// ThermalDeBroglie
+#[pyclass(name = "ThermalDeBroglie")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(molarweight)")]
+pub struct PyThermalDeBroglie(pub ThermalDeBroglie);
+
+#[pymethods]
+impl PyThermalDeBroglie {
+    #[new]
+    fn new(molarweight: PyReadonlyArray1<f64>) -> Self {
+        Self(ThermalDeBroglie::new(molarweight.to_owned_array()))
+    }
+
+    /// Return the thermal de Broglie wavelength of each component.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    ///
+    /// Returns
+    /// -------
+    /// SIArray1
+    #[pyo3(text_signature = "($self, temperature)")]
+    fn thermal_wavelength(&self, temperature: PySINumber) -> PyResult<PySIArray1> {
+        Ok(self.0.thermal_wavelength(temperature.into())?.into())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Coefficients of the 7-coefficient NASA polynomials for a pure component.
+///
+/// Parameters
+/// ----------
+/// t_mid : float
+///     Temperature in units of Kelvin that separates the two temperature ranges.
+/// low : [float]
+///     The seven coefficients for temperatures below `t_mid`.
+/// high : [float]
+///     The seven coefficients for temperatures above `t_mid`.
+///
+/// Returns
+/// -------
+/// NasaRecord
+#[pyclass(name = "NasaRecord")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(t_mid, low, high)")]
+pub struct PyNasaRecord(pub NasaRecord);
+
+#[pymethods]
+impl PyNasaRecord {
+    #[new]
+    fn new(t_mid: f64, low: [f64; 7], high: [f64; 7]) -> Self {
+        Self(NasaRecord::new(t_mid, low, high))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Ideal gas contribution based on the 7-coefficient NASA polynomials
+/// of each component.
+///
+/// Parameters
+/// ----------
+/// records : [NasaRecord]
+///     The NASA polynomials of all components.
+///
+/// Returns
+/// -------
+/// NasaPolynomial
+#[pyclass(name = "NasaPolynomial")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(records)")]
+pub struct PyNasaPolynomial(pub NasaPolynomial);
+
+#[pymethods]
+impl PyNasaPolynomial {
+    #[new]
+    fn new(records: Vec<PyNasaRecord>) -> Self {
+        Self(NasaPolynomial::new(
+            records.into_iter().map(|r| r.0).collect(),
+        ))
+    }
+
+    /// Return the ideal gas heat capacity of a mixture.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fractions of all components.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    #[pyo3(text_signature = "($self, temperature, molefracs)")]
+    fn c_p(
+        &self,
+        temperature: PySINumber,
+        molefracs: PyReadonlyArray1<f64>,
+    ) -> PyResult<PySINumber> {
+        Ok(self
+            .0
+            .c_p(temperature.into(), &molefracs.to_owned_array())?
+            .into())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Coefficients of the DIPPR-107 ideal gas heat capacity correlation
+/// for a pure component.
+///
+/// Parameters
+/// ----------
+/// a : float
+///     Coefficient A in units of J/(kmol K).
+/// b : float
+///     Coefficient B in units of J/(kmol K).
+/// c : float
+///     Coefficient C in units of K.
+/// d : float
+///     Coefficient D in units of J/(kmol K).
+/// e : float
+///     Coefficient E in units of K.
+///
+/// Returns
+/// -------
+/// Dippr107Record
+#[pyclass(name = "Dippr107Record")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(a, b, c, d, e)")]
+pub struct PyDippr107Record(pub Dippr107Record);
+
+#[pymethods]
+impl PyDippr107Record {
+    #[new]
+    fn new(a: f64, b: f64, c: f64, d: f64, e: f64) -> Self {
+        Self(Dippr107Record::new(a, b, c, d, e))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Ideal gas contribution based on the DIPPR-107 heat capacity
+/// correlation of each component.
+///
+/// Parameters
+/// ----------
+/// records : [Dippr107Record]
+///     The DIPPR-107 coefficients of all components.
+///
+/// Returns
+/// -------
+/// Dippr107
+#[pyclass(name = "Dippr107")]
+#[derive(Clone)]
+#[pyo3(text_signature = "(records)")]
+pub struct PyDippr107(pub Dippr107);
+
+#[pymethods]
+impl PyDippr107 {
+    #[new]
+    fn new(records: Vec<PyDippr107Record>) -> Self {
+        Self(Dippr107::new(records.into_iter().map(|r| r.0).collect()))
+    }
+
+    /// Return the ideal gas heat capacity of a mixture.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fractions of all components.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    #[pyo3(text_signature = "($self, temperature, molefracs)")]
+    fn c_p(
+        &self,
+        temperature: PySINumber,
+        molefracs: PyReadonlyArray1<f64>,
+    ) -> PyResult<PySINumber> {
+        Ok(self
+            .0
+            .c_p(temperature.into(), &molefracs.to_owned_array())?
+            .into())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Ideal gas models that can be combined with the residual Helmholtz
+/// energy of an equation of state.
+#[derive(Clone, FromPyObject)]
+pub enum PyIdealGasModel {
+    ThermalDeBroglie(PyThermalDeBroglie),
+    Nasa(PyNasaPolynomial),
+    Dippr107(PyDippr107),
+}
+
+impl From<PyIdealGasModel> for IdealGasModel {
+    fn from(ideal_gas: PyIdealGasModel) -> Self {
+        match ideal_gas {
+            PyIdealGasModel::ThermalDeBroglie(ideal_gas) => Self::ThermalDeBroglie(ideal_gas.0),
+            PyIdealGasModel::Nasa(ideal_gas) => Self::Nasa(ideal_gas.0),
+            PyIdealGasModel::Dippr107(ideal_gas) => Self::Dippr107(ideal_gas.0),
+        }
+    }
+}
+
+impl From<IdealGasModel> for PyIdealGasModel {
+    fn from(ideal_gas: IdealGasModel) -> Self {
+        match ideal_gas {
+            IdealGasModel::ThermalDeBroglie(ideal_gas) => {
+                Self::ThermalDeBroglie(PyThermalDeBroglie(ideal_gas))
+            }
+            IdealGasModel::Nasa(ideal_gas) => Self::Nasa(PyNasaPolynomial(ideal_gas)),
+            IdealGasModel::Dippr107(ideal_gas) => Self::Dippr107(PyDippr107(ideal_gas)),
+        }
+    }
+}
+
+impl IntoPy<PyObject> for PyIdealGasModel {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::ThermalDeBroglie(ideal_gas) => ideal_gas.into_py(py),
+            Self::Nasa(ideal_gas) => ideal_gas.into_py(py),
+            Self::Dippr107(ideal_gas) => ideal_gas.into_py(py),
+        }
+    }
+}
@@ -23,19 +23,33 @@ use pyo3::prelude::*;
 ///     third order coefficient
 /// e : float
 ///     fourth order coefficient
+/// h0 : float, optional
+///     ideal gas enthalpy at the reference state in units of J/mol,
+///     e.g., the enthalpy of formation. Defaults to 0.
+/// s0 : float, optional
+///     ideal gas entropy at the reference state in units of J/mol/K.
+///     Defaults to 0.
 ///
 /// Returns
 /// -------
 /// JobackRecord
 #[pyclass(name = "JobackRecord")]
+#[pyo3(text_signature = "(a, b, c, d, e, h0=None, s0=None)")]
 #[derive(Clone)]
 pub struct PyJobackRecord(pub JobackRecord);
 
 #[pymethods]
 impl PyJobackRecord {
     #[new]
-    fn new(a: f64, b: f64, c: f64, d: f64, e: f64) -> Self {
-        Self(JobackRecord::new(a, b, c, d, e))
+    fn new(a: f64, b: f64, c: f64, d: f64, e: f64, h0: Option<f64>, s0: Option<f64>) -> Self {
+        let mut record = JobackRecord::new(a, b, c, d, e);
+        if let Some(h0) = h0 {
+            record = record.with_reference_enthalpy(h0);
+        }
+        if let Some(s0) = s0 {
+            record = record.with_reference_entropy(s0);
+        }
+        Self(record)
     }
 
     fn __repr__(&self) -> PyResult<String> {
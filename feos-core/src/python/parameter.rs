@@ -723,6 +723,27 @@ macro_rules! impl_parameter {
                 )?)))
             }
 
+            /// Creates parameters from a json string containing
+            /// the pure and binary records.
+            #[staticmethod]
+            fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+                let (pure_records, binary_records) = serde_json::from_str(json)?;
+                Ok(Self(Arc::new(<$parameter>::from_records(
+                    pure_records,
+                    binary_records,
+                ))))
+            }
+
+            /// Creates a json string containing the pure and binary records.
+            fn to_json_str(&self) -> Result<String, ParameterError> {
+                Ok(serde_json::to_string(&self.0.records())?)
+            }
+
+            fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                Ok((from_json_str.into(), (self.to_json_str()?,)))
+            }
+
             #[getter]
             fn get_pure_records(&self) -> Vec<PyPureRecord> {
                 self.0
@@ -733,14 +754,22 @@ macro_rules! impl_parameter {
                     .collect()
             }
 
+            /// The binary interaction parameters as matrix.
+            ///
+            /// Raises a RuntimeError if a binary record can not be represented
+            /// by a single number, e.g., if the binary interaction parameter
+            /// is temperature dependent.
             #[getter]
-            fn get_binary_records<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
-                self.0
-                    .records()
-                    .1
-                    .mapv(|r| f64::try_from(r).unwrap())
+            fn get_binary_records<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f64>> {
+                let binary_records = self.0.records().1;
+                let k_ij = binary_records
+                    .iter()
+                    .map(|r| Ok(f64::try_from(r.clone())?))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(Array2::from_shape_vec(binary_records.raw_dim(), k_ij)
+                    .unwrap()
                     .view()
-                    .to_pyarray(py)
+                    .to_pyarray(py))
             }
         }
     };
@@ -829,6 +858,11 @@ macro_rules! impl_json_handling {
             fn to_json_str(&self) -> Result<String, ParameterError> {
                 Ok(serde_json::to_string(&self.0)?)
             }
+
+            fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (String,))> {
+                let from_json_str = py.get_type::<Self>().getattr("from_json_str")?;
+                Ok((from_json_str.into(), (self.to_json_str()?,)))
+            }
         }
     };
 }
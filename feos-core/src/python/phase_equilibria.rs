@@ -109,6 +109,63 @@ macro_rules! impl_phase_equilibrium {
                 )?))
             }
 
+            /// Create a liquid and vapor state in equilibrium
+            /// for given temperature, total volume and feed composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// volume : SINumber
+            ///     The total volume of the system.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations of the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of pressure iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance of the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the pressure iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the feed is not in the two-phase region or the iteration fails.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, volume, feed, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn tv_flash(
+                eos: $py_eos,
+                temperature: PySINumber,
+                volume: PySINumber,
+                feed: &PySIArray1,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(PhaseEquilibrium::tv_flash(
+                    &eos.0,
+                    temperature.into(),
+                    volume.into(),
+                    feed,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into()
+                    )
+                )?))
+            }
+
             /// Compute a phase equilibrium for given temperature
             /// or pressure and liquid mole fractions.
             ///
@@ -314,6 +371,68 @@ macro_rules! impl_phase_equilibrium {
                     .collect()
             }
 
+            /// Calculate the Bancroft point of a binary mixture, i.e., the
+            /// temperature and pressure at which the vapor pressures of both
+            /// pure components are equal.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// initial_temperature : SINumber
+            ///     The initial guess for the temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (SINumber, SINumber)
+            ///     The temperature and pressure of the Bancroft point.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, initial_temperature, max_iter=None, tol=None, verbosity=None)")]
+            fn bancroft_point(
+                eos: $py_eos,
+                initial_temperature: PySINumber,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(PySINumber, PySINumber)> {
+                let (t, p) = PhaseEquilibrium::bancroft_point(
+                    &eos.0,
+                    initial_temperature.into(),
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((t.into(), p.into()))
+            }
+
+            /// Role of each phase in the equilibrium.
+            ///
+            /// If both phases are liquid-like, they are labeled as liquid 1 and
+            /// liquid 2 in the order of increasing density. Otherwise, the phase
+            /// with the lowest density is labeled as vapor.
+            ///
+            /// Returns
+            /// -------
+            /// [PhaseRole]
+            fn phase_roles(&self) -> Vec<PhaseRole> {
+                self.0.phase_roles().to_vec()
+            }
+
+            /// Distribution of each component across the phases.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray
+            ///     The fraction of the total amount of each component (rows)
+            ///     that is contained in each phase (columns).
+            fn component_phase_distribution<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+                self.0.component_phase_distribution().view().to_pyarray(py)
+            }
+
             fn _repr_markdown_(&self) -> String {
                 self.0._repr_markdown_()
             }
@@ -389,6 +508,44 @@ macro_rules! impl_phase_equilibrium {
                     )
                 )?))
             }
+
+            /// Calculate the vapor-liquid-liquid equilibrium of a binary mixture
+            /// for a given temperature without initial guesses.
+            ///
+            /// The liquid phases are initialized from a liquid-liquid stability
+            /// analysis at the sum of the pure component vapor pressures.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// ThreePhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, max_iter=None, tol=None, verbosity=None)")]
+            fn vlle_binary(
+                eos: $py_eos,
+                temperature: PySINumber,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyThreePhaseEquilibrium> {
+                Ok(PyThreePhaseEquilibrium(PhaseEquilibrium::vlle_binary(
+                    &eos.0,
+                    temperature.into(),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
         }
 
         #[pymethods]
@@ -408,6 +565,28 @@ macro_rules! impl_phase_equilibrium {
                 PyState(self.0.liquid2().clone())
             }
 
+            /// Role of each phase in the equilibrium.
+            ///
+            /// The phase with the lowest density is labeled as vapor.
+            ///
+            /// Returns
+            /// -------
+            /// [PhaseRole]
+            fn phase_roles(&self) -> Vec<PhaseRole> {
+                self.0.phase_roles().to_vec()
+            }
+
+            /// Distribution of each component across the phases.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray
+            ///     The fraction of the total amount of each component (rows)
+            ///     that is contained in each phase (columns).
+            fn component_phase_distribution<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+                self.0.component_phase_distribution().view().to_pyarray(py)
+            }
+
             fn _repr_markdown_(&self) -> String {
                 self.0._repr_markdown_()
             }
@@ -705,6 +884,58 @@ macro_rules! impl_phase_equilibrium {
                 Ok(Self(dia))
             }
 
+            /// Calculate the bubble and dew points of a mixture with given
+            /// composition (an isopleth) at the specified temperatures.
+            ///
+            /// Temperatures at which no bubble or dew point is found are skipped.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: Eos
+            ///     The equation of state.
+            /// moles: SIArray1
+            ///     The moles of the individual components
+            /// temperatures: SIArray1
+            ///     The temperatures of the isopleth.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// (PhaseDiagram, PhaseDiagram)
+            ///     The bubble points and the dew points.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles, temperatures, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn isopleth(
+                eos: &$py_eos,
+                moles: PySIArray1,
+                temperatures: PySIArray1,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, Self)> {
+                let (bubble, dew) = PhaseDiagram::isopleth(
+                    &eos.0,
+                    &moles,
+                    &temperatures,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    )
+                )?;
+                Ok((Self(bubble), Self(dew)))
+            }
+
             /// Calculate the spinodal lines for a mixture with fixed composition.
             ///
             /// Parameters
@@ -815,8 +1046,35 @@ macro_rules! impl_phase_equilibrium {
                 Ok(dict)
             }
 
+            /// Write the temperatures, pressures, densities and
+            /// mole fractions of the phase diagram to a CSV file.
+            ///
+            /// Units
+            /// -----
+            /// temperature : K
+            /// pressure : Pa
+            /// densities : mol / m³
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     The path of the CSV file.
+            ///
+            /// Notes
+            /// -----
+            /// xi: liquid molefraction of component i
+            /// yi: vapor molefraction of component i
+            /// i: component index according to order in parameters.
+            #[pyo3(text_signature = "($self, path)")]
+            pub fn to_csv(&self, path: &str) -> PyResult<()> {
+                Ok(self.0.to_csv(path)?)
+            }
+
             /// Binary phase diagram calculated using bubble/dew point iterations.
             ///
+            /// For a given temperature, the isothermal p-x-y diagram is
+            /// calculated, for a given pressure the isobaric T-x-y diagram.
+            ///
             /// Parameters
             /// ----------
             /// eos : EquationOfState
@@ -868,6 +1126,68 @@ macro_rules! impl_phase_equilibrium {
                 Ok(Self(dia))
             }
 
+            /// Isothermal p-x-y phase diagram of a binary mixture calculated
+            /// in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature: SINumber
+            ///     The constant temperature.
+            /// npoints: int
+            ///     The number of points.
+            /// chunksize : int
+            ///     The number of points that are calculated in sequence
+            ///     within a thread.
+            /// nthreads : int
+            ///     Number of threads.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseDiagram
+            #[cfg(feature = "rayon")]
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, npoints, chunksize, nthreads, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn par_binary_pxy(
+                eos: $py_eos,
+                temperature: PySINumber,
+                npoints: usize,
+                chunksize: usize,
+                nthreads: usize,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> EosResult<Self> {
+                let thread_pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(nthreads)
+                    .build()?;
+                let dia = PhaseDiagram::par_binary_pxy(
+                    &eos.0,
+                    temperature.into(),
+                    npoints,
+                    chunksize,
+                    thread_pool,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    )
+                )?;
+                Ok(Self(dia))
+            }
+
             /// Create a new phase diagram using Tp flash calculations.
             ///
             /// The usual use case for this function is the calculation of
@@ -1013,5 +1333,195 @@ macro_rules! impl_phase_equilibrium {
                     .map(|d| PyPhaseDiagram(d.clone()))
             }
         }
+
+        /// Phase envelope of a mixture with given composition.
+        #[pyclass(name = "PhaseEnvelope")]
+        #[derive(Clone)]
+        pub struct PyPhaseEnvelope(PhaseEnvelope<$eos>);
+
+        #[pymethods]
+        impl PyPhaseDiagram {
+            /// Calculate the phase envelope of a mixture with given composition.
+            ///
+            /// The envelope is traced with a continuation method from the bubble
+            /// point at 1 bar through the critical point of the mixture and down
+            /// the dew point line to 1 bar.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state.
+            /// moles: SIArray1
+            ///     The moles of the individual components
+            /// npoints: int
+            ///     The maximum number of points on the envelope.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations in the bubble/dew point iteration.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations in the bubble/dew point iteration.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop of the bubble/dew point iteration.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop of the bubble/dew point iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the bubble/dew point iteration.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEnvelope
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles, npoints, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn phase_envelope(
+                eos: $py_eos,
+                moles: PySIArray1,
+                npoints: usize,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyPhaseEnvelope> {
+                let envelope = PhaseDiagram::phase_envelope(
+                    &eos.0,
+                    &moles,
+                    npoints,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    )
+                )?;
+                Ok(PyPhaseEnvelope(envelope))
+            }
+        }
+
+        #[pymethods]
+        impl PyPhaseEnvelope {
+            #[getter]
+            pub fn get_bubble(&self) -> PyPhaseDiagram {
+                PyPhaseDiagram(self.0.bubble.clone())
+            }
+
+            #[getter]
+            pub fn get_dew(&self) -> PyPhaseDiagram {
+                PyPhaseDiagram(self.0.dew.clone())
+            }
+
+            #[getter]
+            pub fn get_bubble_points(&self) -> PyStateVec {
+                self.0.bubble_points().into()
+            }
+
+            #[getter]
+            pub fn get_dew_points(&self) -> PyStateVec {
+                self.0.dew_points().into()
+            }
+
+            #[getter]
+            pub fn get_cricondentherm(&self) -> PyPhaseEquilibrium {
+                PyPhaseEquilibrium(self.0.cricondentherm.clone())
+            }
+
+            #[getter]
+            pub fn get_cricondenbar(&self) -> PyPhaseEquilibrium {
+                PyPhaseEquilibrium(self.0.cricondenbar.clone())
+            }
+        }
+
+        /// Azeotropic point of a binary mixture.
+        #[pyclass(name = "Azeotrope")]
+        #[derive(Clone)]
+        pub struct PyAzeotrope(Azeotrope);
+
+        #[pymethods]
+        impl PyAzeotrope {
+            #[getter]
+            pub fn get_molefrac(&self) -> f64 {
+                self.0.molefrac
+            }
+
+            #[getter]
+            pub fn get_temperature(&self) -> PySINumber {
+                PySINumber::from(self.0.temperature)
+            }
+
+            #[getter]
+            pub fn get_pressure(&self) -> PySINumber {
+                PySINumber::from(self.0.pressure)
+            }
+
+            #[getter]
+            pub fn get_azeotrope_type(&self) -> AzeotropeType {
+                self.0.azeotrope_type
+            }
+        }
+
+        #[pymethods]
+        impl PyPhaseDiagram {
+            /// Detect and classify an azeotrope in an isothermal or isobaric
+            /// binary vapor-liquid phase diagram.
+            ///
+            /// The classification is determined from the curvature of the
+            /// bubble curve at the azeotropic point.
+            ///
+            /// Returns
+            /// -------
+            /// Azeotrope, optional : None if the mixture is zeotropic.
+            pub fn azeotrope(&self) -> PyResult<Option<PyAzeotrope>> {
+                Ok(self.0.azeotrope()?.map(PyAzeotrope))
+            }
+        }
+
+        #[pymethods]
+        impl PyState {
+            /// Calculate the liquid-liquid tie line through a feed
+            /// with given temperature, pressure and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// molefracs : numpy.ndarray
+            ///     The mole fractions of the feed.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (PhaseEquilibrium, [float]) : The coexisting phases and the fractions
+            ///     of the total amount of substance in both phases.
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the feed is stable or the flash does not converge.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, molefracs, max_iter=None, tol=None, verbosity=None)")]
+            pub fn lle_tie_line(
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                molefracs: &PyArray1<f64>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(PyPhaseEquilibrium, [f64; 2])> {
+                let (lle, phase_fractions) = State::lle_tie_line(
+                    &eos.0,
+                    temperature.into(),
+                    pressure.into(),
+                    &molefracs.to_owned_array(),
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((PyPhaseEquilibrium(lle), phase_fractions))
+            }
+        }
     }
 }
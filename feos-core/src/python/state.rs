@@ -109,6 +109,34 @@ macro_rules! impl_state {
                 Ok(Self(s))
             }
 
+            /// Create a thermodynamic state from temperature and partial densities.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// partial_density: SIArray1
+            ///     The partial densities of all components.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, partial_density)")]
+            fn from_partial_densities(
+                eos: $py_eos,
+                temperature: PySINumber,
+                partial_density: PySIArray1,
+            ) -> PyResult<Self> {
+                Ok(Self(State::from_partial_densities(
+                    &eos.0,
+                    temperature.into(),
+                    &partial_density,
+                )?))
+            }
+
             /// Return a list of thermodynamic state at critical conditions
             /// for each pure substance in the system.
             ///
@@ -127,7 +155,7 @@ macro_rules! impl_state {
             ///
             /// Returns
             /// -------
-            /// State : tate at critical conditions
+            /// List[State] : States at critical conditions of each pure substance.
             #[staticmethod]
             #[pyo3(text_signature = "(eos, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
             fn critical_point_pure(
@@ -136,23 +164,760 @@ macro_rules! impl_state {
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
-            ) -> PyResult<Vec<Self>> {
-                let t = initial_temperature.and_then(|t0| Some(t0.into()));
-                let cp = State::critical_point_pure(&eos.0, t, (max_iter, tol, verbosity).into())?;
-                Ok(cp.into_iter().map(Self).collect())
+            ) -> PyResult<Vec<Self>> {
+                let t = initial_temperature.and_then(|t0| Some(t0.into()));
+                let cp = State::critical_point_pure(&eos.0, t, (max_iter, tol, verbosity).into())?;
+                Ok(cp.into_iter().map(Self).collect())
+            }
+
+            /// Calculate the acentric factor of a component.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// component: int
+            ///     The index of the component.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, component, max_iter=None, tol=None, verbosity=None)")]
+            fn acentric_factor(
+                eos: $py_eos,
+                component: usize,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<f64> {
+                Ok(State::acentric_factor(
+                    &eos.0,
+                    component,
+                    (max_iter, tol, verbosity).into(),
+                )?)
+            }
+
+            /// Create a thermodynamic state at critical conditions.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// initial_temperature: SINumber, optional
+            ///     The initial temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point(
+                eos: $py_eos,
+                moles: Option<PySIArray1>,
+                initial_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point(
+                    &eos.0,
+                    moles.as_deref(),
+                    initial_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Calculate the critical points of a batch of pure component
+            /// equations of state in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: [EquationOfState]
+            ///     The equations of state to use.
+            /// initial_temperature: SINumber, optional
+            ///     The initial temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// [State] : States at critical conditions. None for failed calculations.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            fn par_critical_point(
+                eos: Vec<$py_eos>,
+                initial_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> Vec<Option<Self>> {
+                State::par_critical_point(
+                    eos,
+                    |eos| eos.0,
+                    initial_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )
+                .into_iter()
+                .map(|s| s.map(Self))
+                .collect()
+            }
+
+            /// Create a state at given reduced temperature T/Tc and reduced
+            /// density rho/rho_c relative to the critical point of the system.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// reduced_temperature: float
+            ///     The temperature divided by the critical temperature.
+            /// reduced_density: float
+            ///     The density divided by the critical density.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the critical point calculation.
+            /// tol: float, optional
+            ///     The solution tolerance of the critical point calculation.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at the reduced conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reduced_temperature, reduced_density, moles=None, max_iter=None, tol=None, verbosity=None)")]
+            fn from_reduced(
+                eos: $py_eos,
+                reduced_temperature: f64,
+                reduced_density: f64,
+                moles: Option<PySIArray1>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::from_reduced(
+                    &eos.0,
+                    reduced_temperature,
+                    reduced_density,
+                    moles.as_deref(),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Calculate the reduced residual Helmholtz energy A^res/(NkT) of
+            /// a pure component on a grid of reduced temperatures T/Tc and
+            /// reduced densities rho/rho_c.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// reduced_temperature: numpy.ndarray[float]
+            ///     The temperatures divided by the critical temperature.
+            /// reduced_density: numpy.ndarray[float]
+            ///     The densities divided by the critical density.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the critical point calculation.
+            /// tol: float, optional
+            ///     The solution tolerance of the critical point calculation.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///     The reduced residual Helmholtz energy with a row for every
+            ///     reduced temperature and a column for every reduced density.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reduced_temperature, reduced_density, max_iter=None, tol=None, verbosity=None)")]
+            fn reduced_residual_helmholtz_energy_surface<'py>(
+                py: Python<'py>,
+                eos: $py_eos,
+                reduced_temperature: PyReadonlyArray1<f64>,
+                reduced_density: PyReadonlyArray1<f64>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<&'py PyArray2<f64>> {
+                Ok(State::reduced_residual_helmholtz_energy_surface(
+                    &eos.0,
+                    &reduced_temperature.to_owned_array(),
+                    &reduced_density.to_owned_array(),
+                    (max_iter, tol, verbosity).into(),
+                )?
+                .view()
+                .to_pyarray(py))
+            }
+
+            /// Calculate the Krichevskii parameter (dp/dx_2)_T,V of a solute at
+            /// infinite dilution at the critical point of the pure solvent.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state of the binary mixture.
+            /// solute: int
+            ///     The index of the solute.
+            /// initial_temperature: SINumber, optional
+            ///     The initial temperature of the critical point calculation.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the critical point calculation.
+            /// tol: float, optional
+            ///     The solution tolerance of the critical point calculation.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            ///     The Krichevskii parameter. Negative values indicate an
+            ///     attractive, positive values a repulsive solute.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, solute, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            fn krichevskii_parameter(
+                eos: $py_eos,
+                solute: usize,
+                initial_temperature: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PySINumber> {
+                Ok(State::krichevskii_parameter(
+                    &eos.0,
+                    solute,
+                    initial_temperature.map(|t| t.into()),
+                    (max_iter, tol, verbosity).into(),
+                )?
+                .into())
+            }
+
+            /// Create a thermodynamic state at critical conditions for a binary system.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature_or_pressure: SINumber
+            ///     temperature_or_pressure.
+            /// initial_temperature: SINumber, optional
+            ///     An initial guess for the temperature.
+            /// initial_molefracs: [float], optional
+            ///     An initial guess for the composition.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point_binary(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                initial_temperature: Option<PySINumber>,
+                initial_molefracs: Option<[f64; 2]>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point_binary(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    initial_temperature.map(|t| t.into()),
+                    initial_molefracs,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a thermodynamic state at critical conditions for a binary system
+            /// starting from a previously calculated critical state.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature_or_pressure: SINumber
+            ///     temperature_or_pressure.
+            /// initial_state: State
+            ///     A critical state used as initial guess, e.g.,
+            ///     from a calculation at a neighboring temperature or pressure.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, initial_state, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point_binary_from(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                initial_state: &PyState,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point_binary_from(
+                    &eos.0,
+                    temperature_or_pressure.into(),
+                    &initial_state.0,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a thermodynamic state at the tricritical point of a binary system.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// initial_temperature: SINumber, optional
+            ///     An initial guess for the temperature.
+            /// initial_molefracs: [float], optional
+            ///     An initial guess for the composition.
+            /// initial_state: State, optional
+            ///     A state used as initial guess, e.g.,
+            ///     from a previous calculation.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at the tricritical point.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, initial_temperature=None, initial_molefracs=None, initial_state=None, max_iter=None, tol=None, verbosity=None)")]
+            fn tricritical_point_binary(
+                eos: $py_eos,
+                initial_temperature: Option<PySINumber>,
+                initial_molefracs: Option<[f64; 2]>,
+                initial_state: Option<&PyState>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::tricritical_point_binary(
+                    &eos.0,
+                    initial_temperature.map(|t| t.into()),
+                    initial_molefracs,
+                    initial_state.map(|s| &s.0),
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a thermodynamic state at critical conditions for a binary system
+            /// with given critical temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The critical temperature.
+            /// pressure: SINumber
+            ///     The critical pressure.
+            /// initial_molefracs: [float], optional
+            ///     An initial guess for the composition.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, initial_molefracs=None, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point_binary_tp(
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                initial_molefracs: Option<[f64; 2]>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point_binary_tp(
+                    &eos.0,
+                    temperature.into(),
+                    pressure.into(),
+                    initial_molefracs,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Trace the critical line of a binary system from the critical point
+            /// of the first component to the critical point of the second component.
+            ///
+            /// If the continuation fails, the states that converged up to that
+            /// point are returned.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// npoints: int
+            ///     The approximate number of points on the critical line.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// StateVec : States at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, npoints, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_locus_binary(
+                eos: $py_eos,
+                npoints: usize,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyStateVec> {
+                Ok(PyStateVec(State::critical_locus_binary(
+                    &eos.0,
+                    npoints,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Create a thermodynamic state at critical conditions for a mixture
+            /// with given temperature.
+            ///
+            /// Only the mole fraction of the first component is varied, the ratios
+            /// of the mole fractions of all other components are fixed at the
+            /// values of the initial composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The critical temperature.
+            /// initial_molefracs: [float]
+            ///     An initial guess for the composition.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State at critical conditions.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, initial_molefracs, max_iter=None, tol=None, verbosity=None)")]
+            fn critical_point_mix_t(
+                eos: $py_eos,
+                temperature: PySINumber,
+                initial_molefracs: Vec<f64>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(PyState(State::critical_point_mix_t(
+                    &eos.0,
+                    temperature.into(),
+                    &initial_molefracs,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Calculate the compressibility factor of the gas phase along an isotherm.
+            ///
+            /// Points inside the two-phase region are returned as NaN.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// pressure: SIArray1
+            ///     The pressures at which the compressibility factor is evaluated.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations of the stability analysis.
+            /// tol: float, optional
+            ///     The solution tolerance of the stability analysis.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, moles=None, max_iter=None, tol=None, verbosity=None)")]
+            fn z_factor_isotherm<'py>(
+                py: Python<'py>,
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySIArray1,
+                moles: Option<PySIArray1>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<&'py PyArray1<f64>> {
+                Ok(State::z_factor_isotherm(
+                    &eos.0,
+                    temperature.into(),
+                    &pressure,
+                    moles.as_deref(),
+                    (max_iter, tol, verbosity).into(),
+                )?
+                .view()
+                .to_pyarray(py))
+            }
+
+            /// Calculate spinodal states for a given temperature and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (State, State) : The vapor and liquid spinodal states.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, moles=None, max_iter=None, tol=None, verbosity=None)")]
+            fn spinodal(
+                eos: $py_eos,
+                temperature: PySINumber,
+                moles: Option<PySIArray1>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, Self)> {
+                let [state1, state2] = State::spinodal(
+                    &eos.0,
+                    temperature.into(),
+                    moles.as_deref(),
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((PyState(state1), PyState(state2)))
+            }
+
+            /// Calculate the spinodal of a binary mixture for a given temperature.
+            ///
+            /// Compositions for which no spinodal exists at the given temperature
+            /// are skipped.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// npoints: int
+            ///     The number of compositions.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// StateVec : The vapor branch followed by the liquid branch of the spinodal.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, npoints, max_iter=None, tol=None, verbosity=None)")]
+            fn spinodal_curve(
+                eos: $py_eos,
+                temperature: PySINumber,
+                npoints: usize,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyStateVec> {
+                Ok(PyStateVec(State::spinodal_curve(
+                    &eos.0,
+                    temperature.into(),
+                    npoints,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            /// Performs a stability analysis and returns a list of stable
+            /// candidate states.
+            ///
+            /// Parameters
+            /// ----------
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
+            fn stability_analysis(&self,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Vec<Self>> {
+                Ok(self
+                    .0
+                    .stability_analysis((max_iter, tol, verbosity).into())?
+                    .into_iter()
+                    .map(Self)
+                    .collect())
+            }
+
+            /// Performs a stability analysis and returns whether the state
+            /// is stable
+            ///
+            /// Parameters
+            /// ----------
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// bool
+            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
+            fn is_stable(&self,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<bool> {
+                Ok(self.0.is_stable((max_iter, tol, verbosity).into())?)
+            }
+
+            /// Return the mole fractions of the incipient phase, i.e., the
+            /// trial phase with the lowest tangent plane distance.
+            ///
+            /// Parameters
+            /// ----------
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray, optional
+            ///     The mole fractions of the incipient phase or None
+            ///     if the state is stable.
+            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
+            fn incipient_phase<'py>(&self,
+                py: Python<'py>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Option<&'py PyArray1<f64>>> {
+                Ok(self
+                    .0
+                    .incipient_phase((max_iter, tol, verbosity).into())?
+                    .map(|x| x.view().to_pyarray(py)))
+            }
+
+            /// Perform a tangent plane distance stability test starting
+            /// from vapor-like and liquid-like trial phases.
+            ///
+            /// Parameters
+            /// ----------
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (bool, float, State)
+            ///     Whether the state is stable, and the tangent plane distance
+            ///     and the state of the incipient phase (None if the state is stable).
+            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
+            fn stability_test(&self,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(bool, Option<f64>, Option<Self>)> {
+                let result = self.0.stability_test((max_iter, tol, verbosity).into())?;
+                Ok((
+                    result.stable,
+                    result.tangent_plane_distance,
+                    result.incipient_phase.map(Self),
+                ))
             }
 
-            /// Create a thermodynamic state at critical conditions.
+            /// Determine whether a feed at given temperature and pressure
+            /// splits into two (or more) phases.
             ///
             /// Parameters
             /// ----------
-            /// eos: EquationOfState
-            ///     The equation of state to use.
-            /// moles: SIArray1, optional
-            ///     Amount of substance of each component.
-            ///     Only optional for a pure component.
-            /// initial_temperature: SINumber, optional
-            ///     The initial temperature.
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The temperature.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// moles : SIArray1
+            ///     The amount of substance of each component.
             /// max_iter : int, optional
             ///     The maximum number of iterations.
             /// tol: float, optional
@@ -162,158 +927,283 @@ macro_rules! impl_state {
             ///
             /// Returns
             /// -------
-            /// State : State at critical conditions.
+            /// bool
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
-            fn critical_point(
+            #[pyo3(text_signature = "(eos, temperature, pressure, moles, max_iter=None, tol=None, verbosity=None)")]
+            fn is_two_phase(
                 eos: $py_eos,
-                moles: Option<PySIArray1>,
-                initial_temperature: Option<PySINumber>,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                moles: PySIArray1,
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
-            ) -> PyResult<Self> {
-                Ok(PyState(State::critical_point(
+            ) -> PyResult<bool> {
+                Ok(State::is_two_phase(
                     &eos.0,
-                    moles.as_deref(),
-                    initial_temperature.map(|t| t.into()),
+                    temperature.into(),
+                    pressure.into(),
+                    &moles,
                     (max_iter, tol, verbosity).into(),
-                )?))
+                )?)
             }
 
-            /// Create a thermodynamic state at critical conditions for a binary system.
+            /// Perform a flash calculation for given pressure, molar enthalpy
+            /// and amount of substance.
             ///
             /// Parameters
             /// ----------
-            /// eos: EquationOfState
-            ///     The equation of state to use.
-            /// temperature_or_pressure: SINumber
-            ///     temperature_or_pressure.
-            /// initial_temperature: SINumber, optional
-            ///     An initial guess for the temperature.
-            /// initial_molefracs: [float], optional
-            ///     An initial guess for the composition.
-            /// max_iter : int, optional
-            ///     The maximum number of iterations.
-            /// tol: float, optional
-            ///     The solution tolerance.
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// molar_enthalpy : SINumber
+            ///     The molar enthalpy of the feed.
+            /// moles : SIArray1
+            ///     The amount of substance of each component.
+            /// initial_temperature : SINumber, optional
+            ///     The initial temperature of the iteration.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations of the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of temperature iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance of the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the temperature iteration.
             /// verbosity : Verbosity, optional
             ///     The verbosity.
             ///
             /// Returns
             /// -------
-            /// State : State at critical conditions.
+            /// State | PhaseEquilibrium
+            ///     The state if the feed is stable and the phase equilibrium otherwise.
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, max_iter=None, tol=None, verbosity=None)")]
-            fn critical_point_binary(
+            #[pyo3(text_signature = "(eos, pressure, molar_enthalpy, moles, initial_temperature=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            fn new_ph(
+                py: Python,
                 eos: $py_eos,
-                temperature_or_pressure: PySINumber,
+                pressure: PySINumber,
+                molar_enthalpy: PySINumber,
+                moles: PySIArray1,
                 initial_temperature: Option<PySINumber>,
-                initial_molefracs: Option<[f64; 2]>,
-                max_iter: Option<usize>,
-                tol: Option<f64>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
                 verbosity: Option<Verbosity>,
-            ) -> PyResult<Self> {
-                Ok(PyState(State::critical_point_binary(
+            ) -> PyResult<PyObject> {
+                let result = State::new_ph(
                     &eos.0,
-                    temperature_or_pressure.into(),
+                    pressure.into(),
+                    molar_enthalpy.into(),
+                    &moles,
                     initial_temperature.map(|t| t.into()),
-                    initial_molefracs,
-                    (max_iter, tol, verbosity).into(),
-                )?))
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
+                )?;
+                Ok(match result {
+                    FlashResult::SinglePhase(state) => Self(state).into_py(py),
+                    FlashResult::TwoPhase(vle) => PyPhaseEquilibrium(vle).into_py(py),
+                })
             }
 
-            /// Calculate spinodal states for a given temperature and composition.
+            /// Perform a flash calculation for given pressure, molar entropy
+            /// and amount of substance.
             ///
             /// Parameters
             /// ----------
-            /// eos: EquationOfState
-            ///     The equation of state to use.
-            /// temperature: SINumber
-            ///     The temperature.
-            /// moles: SIArray1, optional
-            ///     Amount of substance of each component.
-            ///     Only optional for a pure component.
-            /// max_iter : int, optional
-            ///     The maximum number of iterations.
-            /// tol: float, optional
-            ///     The solution tolerance.
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// molar_entropy : SINumber
+            ///     The molar entropy of the feed.
+            /// moles : SIArray1
+            ///     The amount of substance of each component.
+            /// initial_temperature : SINumber, optional
+            ///     The initial temperature of the iteration.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations of the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of temperature iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance of the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the temperature iteration.
             /// verbosity : Verbosity, optional
             ///     The verbosity.
             ///
             /// Returns
             /// -------
-            /// State : State at critical conditions.
+            /// State | PhaseEquilibrium
+            ///     The state if the feed is stable and the phase equilibrium otherwise.
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, temperature, moles=None, max_iter=None, tol=None, verbosity=None)")]
-            fn spinodal(
+            #[pyo3(text_signature = "(eos, pressure, molar_entropy, moles, initial_temperature=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            fn new_ps(
+                py: Python,
                 eos: $py_eos,
-                temperature: PySINumber,
-                moles: Option<PySIArray1>,
-                max_iter: Option<usize>,
-                tol: Option<f64>,
+                pressure: PySINumber,
+                molar_entropy: PySINumber,
+                moles: PySIArray1,
+                initial_temperature: Option<PySINumber>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
                 verbosity: Option<Verbosity>,
-            ) -> PyResult<(Self, Self)> {
-                let [state1, state2] = State::spinodal(
+            ) -> PyResult<PyObject> {
+                let result = State::new_ps(
                     &eos.0,
-                    temperature.into(),
-                    moles.as_deref(),
-                    (max_iter, tol, verbosity).into(),
+                    pressure.into(),
+                    molar_entropy.into(),
+                    &moles,
+                    initial_temperature.map(|t| t.into()),
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
                 )?;
-                Ok((PyState(state1), PyState(state2)))
+                Ok(match result {
+                    FlashResult::SinglePhase(state) => Self(state).into_py(py),
+                    FlashResult::TwoPhase(vle) => PyPhaseEquilibrium(vle).into_py(py),
+                })
             }
 
-            /// Performs a stability analysis and returns a list of stable
-            /// candidate states.
+            /// Calculate the molar enthalpy of a system with given
+            /// composition along an isobar.
+            ///
+            /// If the isobar crosses the two-phase region, the bubble
+            /// and dew point are inserted, so that the latent heat is resolved.
+            /// Solver options that are not given default to the settings
+            /// of the respective solver.
             ///
             /// Parameters
             /// ----------
-            /// max_iter : int, optional
-            ///     The maximum number of iterations.
-            /// tol: float, optional
-            ///     The solution tolerance.
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The pressure.
+            /// temperatures : SIArray1
+            ///     The temperatures in ascending order.
+            /// moles : SIArray1
+            ///     The amount of substance of each component.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations of the Tp-flash
+            ///     and of the inner loop of the bubble and dew point calculations.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of iterations of the outer loop of the bubble
+            ///     and dew point calculations (of the vapor pressure calculation
+            ///     for a pure component).
+            /// tol_inner : float, optional
+            ///     The solution tolerance of the Tp-flash
+            ///     and of the inner loop of the bubble and dew point calculations.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the outer loop of the bubble
+            ///     and dew point calculations (of the vapor pressure calculation
+            ///     for a pure component).
             /// verbosity : Verbosity, optional
-            ///     The verbosity.
+            ///     The verbosity of all calculations.
             ///
             /// Returns
             /// -------
-            /// State
-            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
-            fn stability_analysis(&self,
-                max_iter: Option<usize>,
-                tol: Option<f64>,
+            /// (SIArray1, SIArray1)
+            ///     The temperatures (including the bubble and dew point)
+            ///     and the corresponding molar enthalpies.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, temperatures, moles, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            fn enthalpy_temperature_isobar(
+                eos: $py_eos,
+                pressure: PySINumber,
+                temperatures: PySIArray1,
+                moles: PySIArray1,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
                 verbosity: Option<Verbosity>,
-            ) -> PyResult<Vec<Self>> {
+            ) -> PyResult<(PySIArray1, PySIArray1)> {
+                let (t, h) = State::enthalpy_temperature_isobar(
+                    &eos.0,
+                    pressure.into(),
+                    &temperatures,
+                    &moles,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
+                )?;
+                Ok((t.into(), h.into()))
+            }
+
+            /// Compare the residual pressure, entropy and chemical potentials
+            /// calculated with dual numbers to finite differences of the
+            /// residual Helmholtz energy.
+            ///
+            /// Can be used to validate the implementation of a (user defined)
+            /// equation of state.
+            ///
+            /// Returns
+            /// -------
+            /// (float, float, numpy.ndarray)
+            ///     The relative deviations of the pressure, the entropy
+            ///     and the chemical potentials.
+            fn derivative_self_check<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<(f64, f64, &'py PyArray1<f64>)> {
+                let report = self.0.eos.derivative_self_check(&self.0)?;
+                Ok((
+                    report.pressure,
+                    report.entropy,
+                    report.chemical_potential.view().to_pyarray(py),
+                ))
+            }
+
+            /// Check that the first and second derivatives of the residual
+            /// Helmholtz energy calculated with dual numbers agree with
+            /// finite differences.
+            ///
+            /// Parameters
+            /// ----------
+            /// tolerance : float, optional
+            ///     The maximum relative deviation. Defaults to 1e-6.
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the deviation of any derivative is larger than
+            ///     `tolerance`. The message lists every failed derivative
+            ///     together with its relative deviation.
+            #[pyo3(text_signature = "($self, tolerance=1e-6)")]
+            fn check_derivative_consistency(&self, tolerance: Option<f64>) -> PyResult<()> {
                 Ok(self
                     .0
-                    .stability_analysis((max_iter, tol, verbosity).into())?
-                    .into_iter()
-                    .map(Self)
-                    .collect())
+                    .check_derivative_consistency(tolerance.unwrap_or(1e-6))?)
             }
 
-            /// Performs a stability analysis and returns whether the state
-            /// is stable
+            /// Return the state that is reached by an isentropic change of
+            /// the pressure, e.g., in a compressor or a turbine.
             ///
             /// Parameters
             /// ----------
-            /// max_iter : int, optional
-            ///     The maximum number of iterations.
-            /// tol: float, optional
-            ///     The solution tolerance.
-            /// verbosity : Verbosity, optional
-            ///     The verbosity.
+            /// pressure : SINumber
+            ///     The final pressure.
             ///
             /// Returns
             /// -------
-            /// bool
-            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
-            fn is_stable(&self,
-                max_iter: Option<usize>,
-                tol: Option<f64>,
-                verbosity: Option<Verbosity>,
-            ) -> PyResult<bool> {
-                Ok(self.0.is_stable((max_iter, tol, verbosity).into())?)
+            /// State
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the temperature iteration fails, e.g., because the
+            ///     isentropic path ends in the two-phase region.
+            #[pyo3(text_signature = "($self, pressure)")]
+            fn isentropic_state(&self, pressure: PySINumber) -> PyResult<Self> {
+                Ok(Self(self.0.isentropic_state(pressure.into())?))
             }
 
             /// Return pressure.
@@ -361,6 +1251,17 @@ macro_rules! impl_state {
                 self.0.compressibility(contributions)
             }
 
+            /// Return the critical compressibility factor.
+            ///
+            /// Only meaningful for states at critical conditions.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn critical_compressibility(&self) -> f64 {
+                self.0.critical_compressibility()
+            }
+
             /// Return partial derivative of pressure w.r.t. volume.
             ///
             /// Parameters
@@ -393,6 +1294,69 @@ macro_rules! impl_state {
                 PySINumber::from(self.0.dp_drho(contributions))
             }
 
+            /// Return the Hessian of the residual Helmholtz energy w.r.t.
+            /// temperature and volume.
+            ///
+            /// Returns
+            /// -------
+            /// [[SINumber]]
+            fn residual_tv_hessian(&self) -> [[PySINumber; 2]; 2] {
+                self.0.residual_tv_hessian().map(|row| row.map(PySINumber::from))
+            }
+
+            /// Return the first derivatives of the reduced residual Helmholtz
+            /// energy w.r.t. temperature, volume and number of particles.
+            ///
+            /// All values are given in the reduced units that are used
+            /// internally to evaluate the Helmholtz energy.
+            ///
+            /// Returns
+            /// -------
+            /// (float, float, numpy.ndarray)
+            fn reduced_residual_derivatives<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> (f64, f64, &'py PyArray1<f64>) {
+                let (a_t, a_v, a_n) = self.0.reduced_residual_derivatives();
+                (a_t, a_v, a_n.view().to_pyarray(py))
+            }
+
+            /// Return the Taylor coefficients of the pressure in density.
+            ///
+            /// The pressure is approximated as
+            /// p(rho + drho) = sum_k c_k drho^k with c_k = 1/k! d^kp/drho^k.
+            ///
+            /// Parameters
+            /// ----------
+            /// order: int
+            ///     The order of the Taylor expansion (at most 3).
+            ///
+            /// Returns
+            /// -------
+            /// [SINumber]
+            #[pyo3(text_signature = "($self, order)")]
+            fn pressure_density_taylor(&self, order: usize) -> PyResult<Vec<PySINumber>> {
+                Ok(self
+                    .0
+                    .pressure_density_taylor(order)?
+                    .into_iter()
+                    .map(PySINumber::from)
+                    .collect())
+            }
+
+            /// Return the phase identification parameter of Venkatarathnam and Oellrich.
+            ///
+            /// States with a parameter larger than 1 are liquid-like and states
+            /// with a parameter smaller than 1 are vapor-like.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            #[pyo3(text_signature = "($self)")]
+            fn phase_identification_parameter(&self) -> f64 {
+                self.0.phase_identification_parameter()
+            }
+
             /// Return partial derivative of pressure w.r.t. temperature.
             ///
             /// Parameters
@@ -568,6 +1532,15 @@ macro_rules! impl_state {
                 Ok(self.0.ln_symmetric_activity_coefficient()?.view().to_pyarray(py))
             }
 
+            /// Return excess chemical potentials w.r.t. an ideal solution.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn excess_chemical_potentials(&self) -> PyResult<PySIArray1> {
+                Ok(PySIArray1::from(self.0.excess_chemical_potentials()?))
+            }
+
             /// Return derivative of logarithmic fugacity coefficient w.r.t. temperature.
             ///
             /// Returns
@@ -604,6 +1577,22 @@ macro_rules! impl_state {
                 self.0.thermodynamic_factor().view().to_pyarray(py)
             }
 
+            /// Return total isochoric heat capacity.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn isochoric_heat_capacity(&self, contributions: Contributions) -> PySINumber {
+                PySINumber::from(self.0.isochoric_heat_capacity(contributions))
+            }
+
             /// Return isochoric heat capacity.
             ///
             /// Parameters
@@ -700,6 +1689,18 @@ macro_rules! impl_state {
                 PySINumber::from(self.0.molar_entropy(contributions))
             }
 
+            /// Return reduced residual entropy as used in entropy scaling.
+            ///
+            /// The negative residual molar entropy divided by the
+            /// gas constant and the mean segment number.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn reduced_residual_entropy(&self) -> f64 {
+                self.0.reduced_residual_entropy()
+            }
+
 
             /// Return partial molar entropy of each component.
             ///
@@ -811,6 +1812,20 @@ macro_rules! impl_state {
                     .collect()
             }
 
+            /// Return the residual helmholtz energy of each
+            /// residual contribution of the equation of state.
+            ///
+            /// Returns
+            /// -------
+            /// Dict[str, SINumber]
+            fn residual_helmholtz_energy_contributions(&self) -> std::collections::HashMap<String, PySINumber> {
+                self.0
+                    .residual_helmholtz_energy_contributions()
+                    .into_iter()
+                    .map(|(s, q)| (s, PySINumber::from(q)))
+                    .collect()
+            }
+
             /// Return gibbs_energy.
             ///
             /// Parameters
@@ -843,6 +1858,22 @@ macro_rules! impl_state {
                 PySINumber::from(self.0.molar_gibbs_energy(contributions))
             }
 
+            /// Return grand potential density.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn grand_potential_density(&self, contributions: Contributions) -> PySINumber {
+                PySINumber::from(self.0.grand_potential_density(contributions))
+            }
+
 
             /// Return internal_energy.
             ///
@@ -921,6 +1952,15 @@ macro_rules! impl_state {
                 PySINumber::from(self.0.thermal_expansivity())
             }
 
+            /// Return partial derivative of density w.r.t. temperature at constant pressure.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn density_temperature_derivative_isobaric(&self) -> PySINumber {
+                PySINumber::from(self.0.density_temperature_derivative_isobaric())
+            }
+
             /// Return Grueneisen parameter.
             ///
             /// Returns
@@ -930,6 +1970,15 @@ macro_rules! impl_state {
                 self.0.grueneisen_parameter()
             }
 
+            /// Return heat capacity ratio (adiabatic index) c_p/c_v.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn heat_capacity_ratio(&self) -> f64 {
+                self.0.heat_capacity_ratio()
+            }
+
             /// Return structure factor.
             ///
             /// Returns
@@ -1084,12 +2133,96 @@ macro_rules! impl_state {
                 StateVec::from(self).molar_entropy().into()
             }
         }
+
+        #[pymethods]
+        impl $py_eos {
+            /// Evaluate properties of the stable phase on a grid of
+            /// temperatures and pressures.
+            ///
+            /// The result is a table in long format that can be used
+            /// to create a pandas DataFrame. Grid points in the two-phase
+            /// region are marked and their properties are set to None.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperatures : SIArray1
+            ///     The temperatures of the grid.
+            /// pressures : SIArray1
+            ///     The pressures of the grid.
+            /// moles : SIArray1
+            ///     The amount of substance of each component.
+            /// properties : [str]
+            ///     The names of State methods without required arguments,
+            ///     e.g., 'molar_enthalpy' or 'c_p'.
+            ///
+            /// Returns
+            /// -------
+            /// dict
+            ///     The columns 'temperature', 'pressure' and 'two_phase'
+            ///     and one column for every property.
+            #[pyo3(text_signature = "($self, temperatures, pressures, moles, properties)")]
+            fn property_grid<'py>(
+                &self,
+                py: Python<'py>,
+                temperatures: PySIArray1,
+                pressures: PySIArray1,
+                moles: PySIArray1,
+                properties: Vec<String>,
+            ) -> PyResult<&'py pyo3::types::PyDict> {
+                let states = State::stable_states_grid(
+                    &self.0,
+                    &temperatures,
+                    &pressures,
+                    &moles,
+                    SolverOptions::default(),
+                )?;
+                let n = states.len();
+                let mut temperature = Vec::with_capacity(n);
+                let mut pressure = Vec::with_capacity(n);
+                let mut two_phase = Vec::with_capacity(n);
+                let mut columns: Vec<Vec<PyObject>> =
+                    vec![Vec::with_capacity(n); properties.len()];
+                for (t, p, state) in states {
+                    let state = state.map(|s| Py::new(py, PyState(s))).transpose()?;
+                    for (name, column) in properties.iter().zip(columns.iter_mut()) {
+                        column.push(match &state {
+                            Some(state) => state.call_method0(py, name.as_str())?,
+                            None => py.None(),
+                        });
+                    }
+                    temperature.push(PySINumber::from(t).into_py(py));
+                    pressure.push(PySINumber::from(p).into_py(py));
+                    two_phase.push(state.is_none());
+                }
+
+                let table = pyo3::types::PyDict::new(py);
+                table.set_item("temperature", temperature)?;
+                table.set_item("pressure", pressure)?;
+                table.set_item("two_phase", two_phase)?;
+                for (name, column) in properties.iter().zip(columns) {
+                    table.set_item(name, column)?;
+                }
+                Ok(table)
+            }
+        }
     };
 }
 
 #[macro_export]
 macro_rules! impl_state_molarweight {
     ($eos:ty, $py_eos:ty) => {
+        impl PyState {
+            fn check_molar_weight(&self) -> PyResult<()> {
+                if self.0.eos.has_molar_weight() {
+                    Ok(())
+                } else {
+                    Err(pyo3::exceptions::PyValueError::new_err(
+                        "The equation of state does not provide molar weights.",
+                    ))
+                }
+            }
+        }
+
         #[pymethods]
         impl PyState {
             /// Return total molar weight.
@@ -1097,8 +2230,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// SINumber
-            fn total_molar_weight(&self) -> PySINumber {
-                PySINumber::from(self.0.total_molar_weight())
+            fn total_molar_weight(&self) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.total_molar_weight()))
             }
 
             /// Return speed of sound.
@@ -1106,8 +2240,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// SINumber
-            fn speed_of_sound(&self) -> PySINumber {
-                PySINumber::from(self.0.speed_of_sound())
+            fn speed_of_sound(&self) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.speed_of_sound()))
             }
 
             /// Returns mass of each component in the system.
@@ -1115,8 +2250,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// SIArray1
-            fn mass(&self) -> PySIArray1 {
-                PySIArray1::from(self.0.mass())
+            fn mass(&self) -> PyResult<PySIArray1> {
+                self.check_molar_weight()?;
+                Ok(PySIArray1::from(self.0.mass()))
             }
 
             /// Returns system's total mass.
@@ -1124,8 +2260,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// SINumber
-            fn total_mass(&self) -> PySINumber {
-                PySINumber::from(self.0.total_mass())
+            fn total_mass(&self) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.total_mass()))
             }
 
             /// Returns system's mass density.
@@ -1133,8 +2270,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// SINumber
-            fn mass_density(&self) -> PySINumber {
-                PySINumber::from(self.0.mass_density())
+            fn mass_density(&self) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.mass_density()))
             }
 
             /// Returns mass fractions for each component.
@@ -1142,8 +2280,9 @@ macro_rules! impl_state_molarweight {
             /// Returns
             /// -------
             /// numpy.ndarray[Float64]
-            fn massfracs<'py>(&self, py: Python<'py>) -> &'py PyArray1<f64> {
-                self.0.massfracs().view().to_pyarray(py)
+            fn massfracs<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray1<f64>> {
+                self.check_molar_weight()?;
+                Ok(self.0.massfracs().view().to_pyarray(py))
             }
 
             /// Return mass specific helmholtz_energy.
@@ -1158,8 +2297,9 @@ macro_rules! impl_state_molarweight {
             /// -------
             /// SINumber
             #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            fn specific_helmholtz_energy(&self, contributions: Contributions) -> PySINumber {
-                PySINumber::from(self.0.specific_helmholtz_energy(contributions))
+            fn specific_helmholtz_energy(&self, contributions: Contributions) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.specific_helmholtz_energy(contributions)))
             }
 
             /// Return mass specific entropy.
@@ -1174,8 +2314,9 @@ macro_rules! impl_state_molarweight {
             /// -------
             /// SINumber
             #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            fn specific_entropy(&self, contributions: Contributions) -> PySINumber {
-                PySINumber::from(self.0.specific_entropy(contributions))
+            fn specific_entropy(&self, contributions: Contributions) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.specific_entropy(contributions)))
             }
 
             /// Return mass specific internal_energy.
@@ -1190,8 +2331,9 @@ macro_rules! impl_state_molarweight {
             /// -------
             /// SINumber
             #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            fn specific_internal_energy(&self, contributions: Contributions) -> PySINumber {
-                PySINumber::from(self.0.specific_internal_energy(contributions))
+            fn specific_internal_energy(&self, contributions: Contributions) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.specific_internal_energy(contributions)))
             }
 
             /// Return mass specific gibbs_energy.
@@ -1206,8 +2348,9 @@ macro_rules! impl_state_molarweight {
             /// -------
             /// SINumber
             #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            fn specific_gibbs_energy(&self, contributions: Contributions) -> PySINumber {
-                PySINumber::from(self.0.specific_gibbs_energy(contributions))
+            fn specific_gibbs_energy(&self, contributions: Contributions) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.specific_gibbs_energy(contributions)))
             }
 
             /// Return mass specific enthalpy.
@@ -1222,31 +2365,49 @@ macro_rules! impl_state_molarweight {
             /// -------
             /// SINumber
             #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            fn specific_enthalpy(&self, contributions: Contributions) -> PySINumber {
-                PySINumber::from(self.0.specific_enthalpy(contributions))
+            fn specific_enthalpy(&self, contributions: Contributions) -> PyResult<PySINumber> {
+                self.check_molar_weight()?;
+                Ok(PySINumber::from(self.0.specific_enthalpy(contributions)))
+            }
+        }
+
+        impl PyStateVec {
+            fn check_molar_weight(&self) -> PyResult<()> {
+                match self.0.first() {
+                    Some(s) if !s.eos.has_molar_weight() => {
+                        Err(pyo3::exceptions::PyValueError::new_err(
+                            "The equation of state does not provide molar weights.",
+                        ))
+                    }
+                    _ => Ok(()),
+                }
             }
         }
 
         #[pymethods]
         impl PyStateVec {
             #[getter]
-            fn get_mass_density(&self) -> PySIArray1 {
-                StateVec::from(self).mass_density().into()
+            fn get_mass_density(&self) -> PyResult<PySIArray1> {
+                self.check_molar_weight()?;
+                Ok(StateVec::from(self).mass_density().into())
             }
 
             #[getter]
-            fn get_massfracs<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
-                StateVec::from(self).massfracs().view().to_pyarray(py)
+            fn get_massfracs<'py>(&self, py: Python<'py>) -> PyResult<&'py PyArray2<f64>> {
+                self.check_molar_weight()?;
+                Ok(StateVec::from(self).massfracs().view().to_pyarray(py))
             }
 
             #[getter]
-            fn get_specific_enthalpy(&self) -> PySIArray1 {
-                StateVec::from(self).specific_enthalpy().into()
+            fn get_specific_enthalpy(&self) -> PyResult<PySIArray1> {
+                self.check_molar_weight()?;
+                Ok(StateVec::from(self).specific_enthalpy().into())
             }
 
             #[getter]
-            fn get_specific_entropy(&self) -> PySIArray1 {
-                StateVec::from(self).specific_entropy().into()
+            fn get_specific_entropy(&self) -> PyResult<PySIArray1> {
+                self.check_molar_weight()?;
+                Ok(StateVec::from(self).specific_entropy().into())
             }
         }
     };
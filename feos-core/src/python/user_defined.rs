@@ -1,16 +1,25 @@
-use crate::{EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, MolarWeight, StateHD};
+use crate::{
+    EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution, MolarWeight,
+    StateHD,
+};
 use ndarray::Array1;
 use num_dual::*;
 use numpy::convert::IntoPyArray;
 use numpy::{PyReadonlyArrayDyn, PyArray};
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use quantity::python::PySIArray1;
 use quantity::si::{SIArray1};
 use std::fmt;
+use std::sync::Arc;
 
 struct PyHelmholtzEnergy(Py<PyAny>);
 
+/// Fallible evaluation of the Helmholtz energy of the Python class.
+trait TryHelmholtzEnergy<D> {
+    fn try_helmholtz_energy(&self, state: &StateHD<D>) -> PyResult<D>;
+}
+
 /// Struct containing pointer to Python Class that implements Helmholtz energy.
 pub struct PyEoSObj {
     obj: Py<PyAny>,
@@ -46,6 +55,81 @@ impl PyEoSObj {
             })
         })
     }
+
+    /// Return the Python object that implements the Helmholtz energy.
+    pub fn obj(&self) -> &Py<PyAny> {
+        &self.obj
+    }
+
+    /// Evaluate the Helmholtz energy of the Python class with every dual number
+    /// type that is required by the equation of state.
+    ///
+    /// Returns the name of every dual number type together with an error message
+    /// if the evaluation failed or if the real part of the result differs from
+    /// the evaluation using floats.
+    pub fn self_test(&self) -> Vec<(String, Option<String>)> {
+        let helmholtz_energy = PyHelmholtzEnergy(self.obj.clone());
+        let moles = Array1::ones(self.components());
+        let temperature = 300.0;
+        let volume = 10.0 * moles.sum() / self.compute_max_density(&moles);
+        let reference = helmholtz_energy
+            .try_helmholtz_energy(&StateHD::new(temperature, volume, moles.clone()))
+            .ok();
+        let test = SelfTest {
+            helmholtz_energy,
+            temperature,
+            volume,
+            moles,
+            reference,
+        };
+        vec![
+            test.dual::<f64>("f64"),
+            test.dual::<Dual64>("Dual64"),
+            test.dual::<Dual<DualVec64<3>, f64>>("Dual<DualVec64<3>, f64>"),
+            test.dual::<HyperDual64>("HyperDual64"),
+            test.dual::<Dual2_64>("Dual2_64"),
+            test.dual::<Dual3_64>("Dual3_64"),
+            test.dual::<HyperDual<Dual64, f64>>("HyperDual<Dual64, f64>"),
+            test.dual::<HyperDual<DualVec64<2>, f64>>("HyperDual<DualVec64<2>, f64>"),
+            test.dual::<HyperDual<DualVec64<3>, f64>>("HyperDual<DualVec64<3>, f64>"),
+            test.dual::<Dual3<Dual64, f64>>("Dual3<Dual64, f64>"),
+            test.dual::<Dual3<DualVec64<2>, f64>>("Dual3<DualVec64<2>, f64>"),
+            test.dual::<Dual3<DualVec64<3>, f64>>("Dual3<DualVec64<3>, f64>"),
+            test.dual::<Dual3<Dual<DualVec64<3>, f64>, f64>>("Dual3<Dual<DualVec64<3>, f64>, f64>"),
+        ]
+    }
+}
+
+struct SelfTest {
+    helmholtz_energy: PyHelmholtzEnergy,
+    temperature: f64,
+    volume: f64,
+    moles: Array1<f64>,
+    reference: Option<f64>,
+}
+
+impl SelfTest {
+    fn dual<D: DualNum<f64>>(&self, name: &str) -> (String, Option<String>)
+    where
+        PyHelmholtzEnergy: TryHelmholtzEnergy<D>,
+    {
+        let state = StateHD::new(
+            D::from(self.temperature),
+            D::from(self.volume),
+            self.moles.mapv(D::from),
+        );
+        let result = self.helmholtz_energy.try_helmholtz_energy(&state);
+        let error = match (result, self.reference) {
+            (Err(e), _) => Some(e.to_string()),
+            (Ok(a), Some(r)) if (a.re() - r).abs() > 1e-10 * r.abs().max(1.0) => Some(format!(
+                "The real part of the result ({}) differs from the result for floats ({}).",
+                a.re(),
+                r
+            )),
+            (Ok(_), _) => None,
+        };
+        (name.to_owned(), error)
+    }
 }
 
 impl MolarWeight for PyEoSObj {
@@ -102,6 +186,179 @@ impl EquationOfState for PyEoSObj {
     fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
         &self.contributions
     }
+
+    /// The contribution is evaluated sequentially, because evaluating it
+    /// on another thread requires the global interpreter lock.
+    fn parallel_residual(&self) -> bool {
+        false
+    }
+}
+
+/// Residual Helmholtz energy contribution of another equation of state.
+struct ResidualContribution<E> {
+    eos: Arc<E>,
+    index: usize,
+}
+
+impl<D: DualNum<f64>, E: EquationOfState> HelmholtzEnergyDual<D> for ResidualContribution<E>
+where
+    dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
+{
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        self.eos.residual()[self.index].helmholtz_energy(state)
+    }
+}
+
+impl<E: EquationOfState> fmt::Display for ResidualContribution<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.eos.residual()[self.index])
+    }
+}
+
+/// Equation of state that combines residual Helmholtz energy contributions of
+/// an existing equation of state with contributions implemented in Python.
+///
+/// The residual Helmholtz energy is the sum of all contributions. The number of
+/// components, the maximum density, the molar weights and the ideal gas
+/// contribution are those of the existing equation of state.
+pub struct PyCompositeEoS<E> {
+    eos: Arc<E>,
+    objs: Vec<Py<PyAny>>,
+    residual_contributions: Option<Vec<String>>,
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+}
+
+impl<E: EquationOfState + Send + Sync + 'static> PyCompositeEoS<E> {
+    /// Combine the residual contributions of `eos` with the Python objects `objs`.
+    ///
+    /// If `residual_contributions` is given, only the contributions of `eos` whose
+    /// names are listed are used.
+    pub fn new(
+        eos: Arc<E>,
+        objs: Vec<Py<PyAny>>,
+        residual_contributions: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        Python::with_gil(|py| {
+            for obj in &objs {
+                if !obj.as_ref(py).hasattr("helmholtz_energy")? {
+                    return Err(PyTypeError::new_err("Every contribution has to have a method 'helmholtz_energy' with signature:\n\tdef helmholtz_energy(self, state: StateHD) -> HD"));
+                }
+                if !obj.as_ref(py).hasattr("subset")? {
+                    return Err(PyTypeError::new_err("Every contribution has to have a method 'subset' with signature:\n\tdef subset(self, component_list: List[int]) -> Self"));
+                }
+            }
+            Ok(())
+        })?;
+        if let Some(names) = &residual_contributions {
+            let available: Vec<_> = eos.residual().iter().map(|c| c.to_string()).collect();
+            if let Some(name) = names.iter().find(|name| !available.contains(name)) {
+                return Err(PyValueError::new_err(format!(
+                    "The equation of state has no contribution '{}'. Available contributions are: {}.",
+                    name,
+                    available.join(", ")
+                )));
+            }
+        }
+        Ok(Self::build(eos, objs, residual_contributions))
+    }
+
+    fn build(
+        eos: Arc<E>,
+        objs: Vec<Py<PyAny>>,
+        residual_contributions: Option<Vec<String>>,
+    ) -> Self {
+        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::new();
+        for (index, c) in eos.residual().iter().enumerate() {
+            let name = c.to_string();
+            if residual_contributions
+                .as_ref()
+                .map_or(true, |names| names.contains(&name))
+            {
+                contributions.push(Box::new(ResidualContribution {
+                    eos: eos.clone(),
+                    index,
+                }));
+            }
+        }
+        for obj in &objs {
+            contributions.push(Box::new(PyHelmholtzEnergy(obj.clone())));
+        }
+        Self {
+            eos,
+            objs,
+            residual_contributions,
+            contributions,
+        }
+    }
+
+    /// Return the equation of state that provides the reference contributions.
+    pub fn eos(&self) -> &Arc<E> {
+        &self.eos
+    }
+
+    /// Return the Python objects that implement the additional contributions.
+    pub fn objs(&self) -> &[Py<PyAny>] {
+        &self.objs
+    }
+
+    /// Return the names of the contributions of the equation of state that are used.
+    pub fn residual_contributions(&self) -> Option<&[String]> {
+        self.residual_contributions.as_deref()
+    }
+}
+
+impl<E: MolarWeight> MolarWeight for PyCompositeEoS<E> {
+    fn molar_weight(&self) -> SIArray1 {
+        self.eos.molar_weight()
+    }
+
+    fn has_molar_weight(&self) -> bool {
+        self.eos.has_molar_weight()
+    }
+}
+
+impl<E: EquationOfState + Send + Sync + 'static> EquationOfState for PyCompositeEoS<E> {
+    fn components(&self) -> usize {
+        self.eos.components()
+    }
+
+    /// The Python contributions are reduced using their `subset` method.
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let objs = Python::with_gil(|py| {
+            self.objs
+                .iter()
+                .map(|obj| {
+                    obj.as_ref(py)
+                        .call_method1("subset", (component_list.to_vec(),))
+                        .unwrap()
+                        .into()
+                })
+                .collect()
+        });
+        Self::build(
+            Arc::new(self.eos.subset(component_list)),
+            objs,
+            self.residual_contributions.clone(),
+        )
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.eos.compute_max_density(moles)
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    /// The contributions are evaluated sequentially, because the Python
+    /// contributions require the global interpreter lock.
+    fn parallel_residual(&self) -> bool {
+        false
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        self.eos.ideal_gas()
+    }
 }
 
 impl fmt::Display for PyHelmholtzEnergy {
@@ -177,18 +434,23 @@ macro_rules! dual_number {
 
 macro_rules! helmholtz_energy {
     ($py_state_id:ident, $py_hd_id:ident, $hd_ty:ty) => {
-        impl HelmholtzEnergyDual<$hd_ty> for PyHelmholtzEnergy {
-            fn helmholtz_energy(&self, state: &StateHD<$hd_ty>) -> $hd_ty {
+        impl TryHelmholtzEnergy<$hd_ty> for PyHelmholtzEnergy {
+            fn try_helmholtz_energy(&self, state: &StateHD<$hd_ty>) -> PyResult<$hd_ty> {
                 Python::with_gil(|py| {
                     let py_result = self
                         .0
                         .as_ref(py)
-                        .call_method1("helmholtz_energy", (<$py_state_id>::from(state.clone()),))
-                        .unwrap();
-                    <$hd_ty>::from(py_result.extract::<$py_hd_id>().unwrap())
+                        .call_method1("helmholtz_energy", (<$py_state_id>::from(state.clone()),))?;
+                    Ok(<$hd_ty>::from(py_result.extract::<$py_hd_id>()?))
                 })
             }
         }
+
+        impl HelmholtzEnergyDual<$hd_ty> for PyHelmholtzEnergy {
+            fn helmholtz_energy(&self, state: &StateHD<$hd_ty>) -> $hd_ty {
+                self.try_helmholtz_energy(state).unwrap()
+            }
+        }
     };
 }
 
@@ -252,3 +514,179 @@ impl_dual_state_helmholtz_energy!(
     Dual3<DualVec64<3>, f64>,
     PyDualVec3
 );
+impl_dual_state_helmholtz_energy!(
+    PyStateD3DDVec3,
+    PyDual3DualDualVec3,
+    Dual3<Dual<DualVec64<3>, f64>, f64>,
+    PyDualDualVec3
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cubic::{PengRobinson, PengRobinsonParameters};
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    const CONTRIBUTIONS: &str = "
+class Linear:
+    def __init__(self, c):
+        self.c = c
+
+    def helmholtz_energy(self, state):
+        return sum(c * n for c, n in zip(self.c, state.moles))
+
+    def subset(self, component_list):
+        return Linear([self.c[i] for i in component_list])
+
+
+class NoSubset:
+    def helmholtz_energy(self, state):
+        return 0.0
+
+
+class Model:
+    def __init__(self, broken=None):
+        self.broken = broken
+
+    def components(self):
+        return 1
+
+    def subset(self, component_list):
+        return self
+
+    def molar_weight(self):
+        return None
+
+    def max_density(self, moles):
+        return 0.01
+
+    def helmholtz_energy(self, state):
+        if self.broken is None or isinstance(state.temperature, float):
+            return state.temperature * state.moles[0]
+        if self.broken == 'real part':
+            return state.temperature * 0.0
+        raise TypeError('only floats are supported')
+";
+
+    fn contribution(name: &str, args: impl IntoPy<Py<pyo3::types::PyTuple>>) -> Py<PyAny> {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            PyModule::from_code(py, CONTRIBUTIONS, "contributions.py", "contributions")
+                .and_then(|module| module.getattr(name)?.call1(args))
+                .unwrap()
+                .into()
+        })
+    }
+
+    fn peng_robinson() -> Arc<PengRobinson> {
+        let parameters = PengRobinsonParameters::new_simple(
+            &[369.96, 425.2],
+            &[4.25e6, 3.8e6],
+            &[0.153, 0.199],
+            &[44.0962, 58.123],
+        )
+        .unwrap();
+        Arc::new(PengRobinson::new(Arc::new(parameters)))
+    }
+
+    #[test]
+    fn self_test() {
+        let model = PyEoSObj::new(contribution("Model", ())).unwrap();
+        let results = model.self_test();
+        assert_eq!(results.len(), 13);
+        assert!(results.iter().all(|(_, error)| error.is_none()));
+
+        // a contribution that fails for dual numbers
+        let model = PyEoSObj::new(contribution("Model", ("exception",))).unwrap();
+        let results = model.self_test();
+        assert_eq!(results[0], (String::from("f64"), None));
+        for (_, error) in &results[1..] {
+            assert!(error
+                .as_ref()
+                .unwrap()
+                .contains("only floats are supported"));
+        }
+
+        // a contribution with a wrong real part for dual numbers
+        let model = PyEoSObj::new(contribution("Model", ("real part",))).unwrap();
+        let results = model.self_test();
+        assert_eq!(results[0], (String::from("f64"), None));
+        for (_, error) in &results[1..] {
+            assert!(error.as_ref().unwrap().contains("differs"));
+        }
+    }
+
+    #[test]
+    fn composite_sum_of_contributions() {
+        let eos = peng_robinson();
+        let linear = contribution("Linear", (vec![1.0, 10.0],));
+        let state = StateHD::new(300.0, 1e5, arr1(&[2.0, 3.0]));
+        let a_eos = eos.evaluate_residual(&state);
+
+        let composite = PyCompositeEoS::new(eos.clone(), vec![linear.clone()], None).unwrap();
+        assert_eq!(composite.residual().len(), 2);
+        assert_relative_eq!(
+            composite.evaluate_residual(&state),
+            a_eos + 32.0,
+            max_relative = 1e-14
+        );
+
+        // only the selected contributions of the equation of state are used
+        let composite =
+            PyCompositeEoS::new(eos.clone(), vec![linear.clone()], Some(vec![])).unwrap();
+        assert_eq!(composite.residual().len(), 1);
+        assert_relative_eq!(composite.evaluate_residual(&state), 32.0);
+        let names = Some(vec![String::from("Peng Robinson")]);
+        let composite = PyCompositeEoS::new(eos.clone(), vec![linear.clone()], names).unwrap();
+        assert_eq!(composite.residual().len(), 2);
+        assert!(PyCompositeEoS::new(eos, vec![linear], Some(vec![String::from("SAFT")])).is_err());
+    }
+
+    #[test]
+    fn composite_subset() {
+        let eos = peng_robinson();
+        let linear = contribution("Linear", (vec![1.0, 10.0],));
+        let composite = PyCompositeEoS::new(eos.clone(), vec![linear], None).unwrap();
+        let subset = composite.subset(&[1]);
+        assert_eq!(subset.components(), 1);
+
+        let state = StateHD::new(300.0, 1e5, arr1(&[3.0]));
+        let a_eos = eos.subset(&[1]).evaluate_residual(&state);
+        assert_relative_eq!(
+            subset.evaluate_residual(&state),
+            a_eos + 30.0,
+            max_relative = 1e-14
+        );
+
+        // contributions without a subset method are rejected
+        let no_subset = contribution("NoSubset", ());
+        assert!(PyCompositeEoS::new(eos, vec![no_subset], None).is_err());
+    }
+
+    #[test]
+    fn composite_reconstruction() {
+        // the accessors provide everything that is required for pickling
+        let eos = peng_robinson();
+        let linear = contribution("Linear", (vec![1.0, 10.0],));
+        let names = Some(vec![String::from("Peng Robinson")]);
+        let composite = PyCompositeEoS::new(eos, vec![linear], names).unwrap();
+        let reconstructed = PyCompositeEoS::new(
+            composite.eos().clone(),
+            composite.objs().to_vec(),
+            composite.residual_contributions().map(|c| c.to_vec()),
+        )
+        .unwrap();
+
+        let state = StateHD::new(300.0, 1e5, arr1(&[2.0, 3.0]));
+        assert_eq!(
+            reconstructed.residual_contributions(),
+            composite.residual_contributions()
+        );
+        assert_relative_eq!(
+            reconstructed.evaluate_residual(&state),
+            composite.evaluate_residual(&state),
+            max_relative = 1e-14
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use super::{Derivative, PartialDerivative};
 use num_dual::*;
+use std::any::Any;
+use std::cell::RefCell;
 use std::cmp::{max, min};
 use std::collections::HashMap;
 
@@ -126,3 +128,71 @@ impl Cache {
         }
     }
 }
+
+/// Temperature dependent quantities of a single [StateHD](super::StateHD).
+struct TemperatureCache {
+    /// Address of the state for which the cache is active.
+    state: usize,
+    entries: Vec<((usize, &'static str), Box<dyn Any>)>,
+}
+
+thread_local! {
+    static TEMPERATURE_CACHES: RefCell<Vec<TemperatureCache>> = RefCell::new(Vec::new());
+}
+
+/// Removes the innermost cache, even if the evaluation panics.
+struct TemperatureCacheGuard;
+
+impl Drop for TemperatureCacheGuard {
+    fn drop(&mut self) {
+        TEMPERATURE_CACHES.with(|c| c.borrow_mut().pop());
+    }
+}
+
+/// Evaluate `f` with an empty temperature cache for the state at address `state`.
+pub(crate) fn with_temperature_cache<R, F: FnOnce() -> R>(state: usize, f: F) -> R {
+    TEMPERATURE_CACHES.with(|c| {
+        c.borrow_mut().push(TemperatureCache {
+            state,
+            entries: Vec::new(),
+        })
+    });
+    let _guard = TemperatureCacheGuard;
+    f()
+}
+
+/// Return the cached quantity `key` of the state at address `state` or
+/// calculate it using `f`. Nothing is cached if there is no active cache
+/// for the state.
+pub(crate) fn temperature_dependent<T: Clone + 'static, F: FnOnce() -> T>(
+    state: usize,
+    key: (usize, &'static str),
+    f: F,
+) -> T {
+    let cached = TEMPERATURE_CACHES.with(|c| {
+        c.borrow()
+            .iter()
+            .rev()
+            .find(|cache| cache.state == state)
+            .and_then(|cache| cache.entries.iter().find(|(k, _)| *k == key))
+            .and_then(|(_, value)| value.downcast_ref::<T>().cloned())
+    });
+    if let Some(value) = cached {
+        return value;
+    }
+
+    // The cache is not borrowed during the evaluation of `f`, so that `f`
+    // can use the cache itself.
+    let value = f();
+    TEMPERATURE_CACHES.with(|c| {
+        if let Some(cache) = c
+            .borrow_mut()
+            .iter_mut()
+            .rev()
+            .find(|cache| cache.state == state)
+        {
+            cache.entries.push((key, Box::new(value.clone())));
+        }
+    });
+    value
+}
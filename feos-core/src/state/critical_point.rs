@@ -1,19 +1,24 @@
-use super::{State, StateHD, TPSpec};
+use super::{Contributions, State, StateHD, TPSpec};
 use crate::equation_of_state::EquationOfState;
 use crate::errors::{EosError, EosResult};
-use crate::phase_equilibria::{SolverOptions, Verbosity};
+use crate::phase_equilibria::{
+    PhaseEquilibrium, ResidualScaling, SolverOptions, SolverStats, Verbosity,
+};
 use crate::{DensityInitialization, EosUnit};
-use ndarray::{arr1, arr2, Array1, Array2};
+use ndarray::{arr1, arr2, s, Array1, Array2};
 use num_dual::linalg::{norm, smallest_ev, LU};
 use num_dual::{Dual, Dual3, Dual64, DualNum, DualVec64, HyperDual, StaticVec};
 use num_traits::{One, Zero};
 use quantity::si::{SIArray1, SINumber, SIUnit};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
 const MAX_ITER_CRIT_POINT: usize = 50;
 const MAX_ITER_CRIT_POINT_BINARY: usize = 200;
 const TOL_CRIT_POINT: f64 = 1e-8;
+const TOL_CRIT_POINT_PRESSURE: f64 = 1e-6;
 
 /// # Critical points
 impl<E: EquationOfState> State<E> {
@@ -38,6 +43,7 @@ impl<E: EquationOfState> State<E> {
             .collect()
     }
 
+    /// Calculate the critical point of a binary system for given temperature or pressure.
     pub fn critical_point_binary(
         eos: &Arc<E>,
         temperature_or_pressure: SINumber,
@@ -45,23 +51,190 @@ impl<E: EquationOfState> State<E> {
         initial_molefracs: Option<[f64; 2]>,
         options: SolverOptions,
     ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        Self::critical_point_binary_with_stats(
+            eos,
+            temperature_or_pressure,
+            initial_temperature,
+            initial_molefracs,
+            options,
+        )
+        .0
+    }
+
+    /// Calculate the critical point of a binary system for given temperature or pressure
+    /// starting from the temperature and partial densities of `initial_state`, e.g.,
+    /// the result of a previous calculation at a neighboring temperature or pressure.
+    ///
+    /// See [State::critical_point_binary].
+    pub fn critical_point_binary_from(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        initial_state: &Self,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        Self::critical_point_binary_dispatch(
+            eos,
+            temperature_or_pressure,
+            None,
+            None,
+            Some(initial_state),
+            options,
+            &mut SolverStats::default(),
+        )
+    }
+
+    /// Calculate the critical point of a binary system for given temperature or pressure
+    /// and return statistics of the iteration.
+    ///
+    /// See [State::critical_point_binary].
+    pub fn critical_point_binary_with_stats(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        initial_temperature: Option<SINumber>,
+        initial_molefracs: Option<[f64; 2]>,
+        options: SolverOptions,
+    ) -> (EosResult<Self>, SolverStats)
+    where
+        SINumber: std::fmt::Display,
+    {
+        let mut stats = SolverStats::default();
+        let cp = Self::critical_point_binary_dispatch(
+            eos,
+            temperature_or_pressure,
+            initial_temperature,
+            initial_molefracs,
+            None,
+            options,
+            &mut stats,
+        );
+        (cp, stats)
+    }
+
+    fn critical_point_binary_dispatch(
+        eos: &Arc<E>,
+        temperature_or_pressure: SINumber,
+        initial_temperature: Option<SINumber>,
+        initial_molefracs: Option<[f64; 2]>,
+        initial_state: Option<&Self>,
+        options: SolverOptions,
+        stats: &mut SolverStats,
+    ) -> EosResult<Self>
     where
         SINumber: std::fmt::Display,
     {
         match TPSpec::try_from(temperature_or_pressure)? {
-            TPSpec::Temperature(t) => {
-                Self::critical_point_binary_t(eos, t, initial_molefracs, options)
-            }
+            TPSpec::Temperature(t) => Self::critical_point_binary_t(
+                eos,
+                t,
+                initial_molefracs,
+                initial_state,
+                options,
+                stats,
+            ),
             TPSpec::Pressure(p) => Self::critical_point_binary_p(
                 eos,
                 p,
                 initial_temperature,
                 initial_molefracs,
+                initial_state,
                 options,
+                stats,
             ),
         }
     }
 
+    /// Calculate the acentric factors $\omega=-\log_{10}\left(p^\mathrm{sat}(0.7T_c)/p_c\right)-1$
+    /// of all components.
+    ///
+    /// The critical points are calculated once using [State::critical_point_pure].
+    pub fn acentric_factors(eos: &Arc<E>, options: SolverOptions) -> EosResult<Array1<f64>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let critical_points = Self::critical_point_pure(eos, None, options)?;
+        Self::acentric_factors_from_critical_points(&critical_points, options)
+    }
+
+    /// Calculate the acentric factors from the given pure component critical points.
+    pub(crate) fn acentric_factors_from_critical_points(
+        critical_points: &[Self],
+        options: SolverOptions,
+    ) -> EosResult<Array1<f64>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let omega = critical_points
+            .iter()
+            .map(|cp| {
+                let pc = cp.pressure(Contributions::Total);
+                let vle = PhaseEquilibrium::pure(&cp.eos, 0.7 * cp.temperature, None, options)?;
+                let psat = vle.vapor().pressure(Contributions::Total);
+                Ok(-(psat / pc).into_value()?.log10() - 1.0)
+            })
+            .collect::<EosResult<Vec<_>>>()?;
+        Ok(Array1::from_vec(omega))
+    }
+
+    /// Calculate the acentric factor of a single component.
+    ///
+    /// See [State::acentric_factors].
+    pub fn acentric_factor(eos: &Arc<E>, component: usize, options: SolverOptions) -> EosResult<f64>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if component >= eos.components() {
+            return Err(EosError::Error(format!(
+                "Component {} does not exist in a system with {} components.",
+                component,
+                eos.components()
+            )));
+        }
+        Ok(Self::acentric_factors(&Arc::new(eos.subset(&[component])), options)?[0])
+    }
+
+    /// Calculate the composition of a binary system that has its critical
+    /// point at the given temperature and pressure.
+    ///
+    /// For a binary system, the critical temperature already determines the
+    /// composition and the critical pressure (on a given branch of the critical
+    /// line, which can be selected with `initial_molefracs`). Therefore, the
+    /// critical point is calculated at the given temperature and an error is
+    /// returned if its pressure deviates from the target pressure. The error
+    /// message contains the closest achievable critical pressure and composition.
+    pub fn critical_point_binary_tp(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        initial_molefracs: Option<[f64; 2]>,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let cp = Self::critical_point_binary_t(
+            eos,
+            temperature,
+            initial_molefracs,
+            None,
+            options,
+            &mut SolverStats::default(),
+        )?;
+        let p = cp.pressure(Contributions::Total);
+        if ((p - pressure) / pressure).into_value()?.abs() > TOL_CRIT_POINT_PRESSURE {
+            return Err(EosError::Error(format!(
+                "No critical point at T = {} and p = {}. The closest critical point at this temperature is at p = {} with x = {}.",
+                temperature, pressure, p, cp.molefracs
+            )));
+        }
+        Ok(cp)
+    }
+
     /// Calculate the critical point of a system for given moles.
     pub fn critical_point(
         eos: &Arc<E>,
@@ -72,22 +245,45 @@ impl<E: EquationOfState> State<E> {
     where
         SINumber: std::fmt::Display,
     {
-        let moles = eos.validate_moles(moles)?;
+        Self::critical_point_with_stats(eos, moles, initial_temperature, options).0
+    }
+
+    /// Calculate the critical point of a system for given moles and
+    /// return statistics of the iteration.
+    ///
+    /// If no initial temperature is given and several initial temperatures
+    /// have to be tried, the number of iterations is summed over all attempts.
+    pub fn critical_point_with_stats(
+        eos: &Arc<E>,
+        moles: Option<&SIArray1>,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> (EosResult<Self>, SolverStats)
+    where
+        SINumber: std::fmt::Display,
+    {
+        let mut stats = SolverStats::default();
+        let moles = match eos.validate_moles(moles) {
+            Ok(moles) => moles,
+            Err(e) => return (Err(e), stats),
+        };
         let trial_temperatures = [
             300.0 * SIUnit::reference_temperature(),
             700.0 * SIUnit::reference_temperature(),
             500.0 * SIUnit::reference_temperature(),
         ];
         if let Some(t) = initial_temperature {
-            return Self::critical_point_hkm(eos, &moles, t, options);
+            let cp = Self::critical_point_hkm(eos, &moles, t, options, &mut stats);
+            return (cp, stats);
         }
         for &t in trial_temperatures.iter() {
-            let s = Self::critical_point_hkm(eos, &moles, t, options);
+            let s = Self::critical_point_hkm(eos, &moles, t, options, &mut stats);
             if s.is_ok() {
-                return s;
+                return (s, stats);
             }
         }
-        Err(EosError::NotConverged(String::from("Critical point")))
+        let err = EosError::NotConverged(String::from("Critical point"));
+        (Err(err), stats)
     }
 
     fn critical_point_hkm(
@@ -95,17 +291,18 @@ impl<E: EquationOfState> State<E> {
         moles: &SIArray1,
         initial_temperature: SINumber,
         options: SolverOptions,
+        stats: &mut SolverStats,
     ) -> EosResult<Self>
     where
         SINumber: std::fmt::Display,
     {
         let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CRIT_POINT, TOL_CRIT_POINT);
 
-        let mut t = initial_temperature.to_reduced(SIUnit::reference_temperature())?;
+        let t = initial_temperature.to_reduced(SIUnit::reference_temperature())?;
         let max_density = eos
             .max_density(Some(moles))?
             .to_reduced(SIUnit::reference_density())?;
-        let mut rho = 0.3 * max_density;
+        let rho = 0.3 * max_density;
         let n = moles.to_reduced(SIUnit::reference_moles())?;
 
         log_iter!(
@@ -121,57 +318,62 @@ impl<E: EquationOfState> State<E> {
             rho * SIUnit::reference_density(),
         );
 
-        for i in 1..=max_iter {
-            // calculate residuals and derivatives w.r.t. temperature and density
+        // calculate residuals and derivatives w.r.t. temperature and density
+        let objective = |[t, rho]: [f64; 2]| -> EosResult<([f64; 2], [[f64; 2]; 2])> {
             let [t_dual, rho_dual] = *StaticVec::new_vec([t, rho])
                 .map(DualVec64::<2>::from_re)
                 .derive()
                 .raw_array();
             let res = critical_point_objective(eos, t_dual, rho_dual, &n)?;
-            let h = arr2(res.jacobian().raw_data());
-            let res = arr1(res.map(|r| r.re()).raw_array());
-
-            // calculate Newton step
-            let mut delta = LU::new(h)?.solve(&res);
+            let jac = *res.jacobian().raw_data();
+            Ok((*res.map(|r| r.re()).raw_array(), jac))
+        };
 
-            // reduce step if necessary
+        // reduce step if necessary
+        let limit_step = |[t, rho]: [f64; 2], mut delta: [f64; 2]| {
             if delta[0].abs() > 0.25 * t {
-                delta *= 0.25 * t / delta[0].abs()
+                let f = 0.25 * t / delta[0].abs();
+                delta = delta.map(|d| d * f);
             }
             if delta[1].abs() > 0.03 * max_density {
-                delta *= 0.03 * max_density / delta[1].abs()
+                let f = 0.03 * max_density / delta[1].abs();
+                delta = delta.map(|d| d * f);
             }
+            delta[1] = f64::min(delta[1], rho - 1e-4 * max_density);
+            delta
+        };
 
-            // apply step
-            t -= delta[0];
-            rho -= delta[1];
-            rho = f64::max(rho, 1e-4 * max_density);
-
-            log_iter!(
-                verbosity,
-                " {:4} | {:14.8e} | {:13.8} | {:12.8}",
-                i,
-                norm(&res),
-                t * SIUnit::reference_temperature(),
-                rho * SIUnit::reference_density(),
-            );
-
-            // check convergence
-            if norm(&res) < tol {
-                log_result!(
+        let iterations = stats.iterations;
+        let [t, rho] = scaled_newton(
+            [t, rho],
+            objective,
+            limit_step,
+            options.scaling,
+            max_iter,
+            tol,
+            stats,
+            |i, res, [t, rho]| {
+                log_iter!(
                     verbosity,
-                    "Critical point calculation converged in {} step(s)\n",
-                    i
-                );
-                return State::new_nvt(
-                    eos,
+                    " {:4} | {:14.8e} | {:13.8} | {:12.8}",
+                    i,
+                    res,
                     t * SIUnit::reference_temperature(),
-                    moles.sum() / (rho * SIUnit::reference_density()),
-                    moles,
-                );
-            }
-        }
-        Err(EosError::NotConverged(String::from("Critical point")))
+                    rho * SIUnit::reference_density(),
+                )
+            },
+        )?;
+        log_result!(
+            verbosity,
+            "Critical point calculation converged in {} step(s)\n",
+            stats.iterations - iterations
+        );
+        State::new_nvt(
+            eos,
+            t * SIUnit::reference_temperature(),
+            moles.sum() / (rho * SIUnit::reference_density()),
+            moles,
+        )
     }
 
     /// Calculate the critical point of a binary system for given temperature.
@@ -179,7 +381,9 @@ impl<E: EquationOfState> State<E> {
         eos: &Arc<E>,
         temperature: SINumber,
         initial_molefracs: Option<[f64; 2]>,
+        initial_state: Option<&Self>,
         options: SolverOptions,
+        stats: &mut SolverStats,
     ) -> EosResult<Self>
     where
         SINumber: std::fmt::Display,
@@ -192,7 +396,10 @@ impl<E: EquationOfState> State<E> {
         let max_density = eos
             .max_density(Some(&(arr1(x.raw_array()) * SIUnit::reference_moles())))?
             .to_reduced(SIUnit::reference_density())?;
-        let mut rho = x * 0.3 * max_density;
+        let mut rho = match initial_state {
+            Some(state) => initial_partial_densities(state)?,
+            None => x * 0.3 * max_density,
+        };
 
         log_iter!(
             verbosity,
@@ -211,11 +418,13 @@ impl<E: EquationOfState> State<E> {
             // calculate residuals and derivatives w.r.t. partial densities
             let r = StaticVec::new_vec([DualVec64::from_re(rho[0]), DualVec64::from_re(rho[1])])
                 .derive();
-            let res = critical_point_objective_t(eos, t, r)?;
+            let res = critical_point_objective_t(eos, t, &arr1(r.raw_array()))?;
 
             // calculate Newton step
             let h = res.jacobian();
             let res = res.map(|r| r.re);
+            stats.iterations += 1;
+            stats.final_residual = res.norm();
             let mut delta = StaticVec::new_vec([
                 h[(1, 1)] * res[0] - h[(0, 1)] * res[1],
                 h[(0, 0)] * res[1] - h[(1, 0)] * res[0],
@@ -244,6 +453,7 @@ impl<E: EquationOfState> State<E> {
 
             // check convergence
             if res.norm() < tol {
+                stats.converged = true;
                 log_result!(
                     verbosity,
                     "Critical point calculation converged in {} step(s)\n",
@@ -260,13 +470,285 @@ impl<E: EquationOfState> State<E> {
         Err(EosError::NotConverged(String::from("Critical point")))
     }
 
+    /// Calculate a critical point of a multicomponent system for given temperature.
+    ///
+    /// At given temperature, the critical points of a system with more than two
+    /// components are not unique. Therefore, only the partial density of the first
+    /// component and the total density of all other components are varied, while
+    /// the ratios of the mole fractions of all but the first component are fixed at
+    /// the values given by `initial_molefracs`. For a binary system, the result
+    /// is the same as for [State::critical_point_binary].
+    pub fn critical_point_mix_t(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        initial_molefracs: &[f64],
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let (max_iter, tol, verbosity) =
+            options.unwrap_or(MAX_ITER_CRIT_POINT_BINARY, TOL_CRIT_POINT);
+
+        let n = eos.components();
+        if initial_molefracs.len() != n {
+            return Err(EosError::IncompatibleComponents(n, initial_molefracs.len()));
+        }
+        if n < 2 {
+            return Err(EosError::Error(String::from(
+                "The critical point at given temperature requires at least two components.",
+            )));
+        }
+
+        let t = temperature.to_reduced(SIUnit::reference_temperature())?;
+        let x = arr1(initial_molefracs);
+        let x = &x / x.sum();
+        let x_rest = x.slice(s![1..]).to_owned() / (1.0 - x[0]);
+        let max_density = eos
+            .max_density(Some(&(x.clone() * SIUnit::reference_moles())))?
+            .to_reduced(SIUnit::reference_density())?;
+        let mut rho = arr1(&[x[0], 1.0 - x[0]]) * 0.3 * max_density;
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |      density 1       |    density (rest)    "
+        );
+        log_iter!(verbosity, "{:-<69}", "");
+        log_iter!(
+            verbosity,
+            " {:4} |                | {:12.8} | {:12.8}",
+            0,
+            rho[0] * SIUnit::reference_density(),
+            rho[1] * SIUnit::reference_density(),
+        );
+
+        for i in 1..=max_iter {
+            // calculate residuals and derivatives w.r.t. the independent densities
+            let r = StaticVec::new_vec([DualVec64::from_re(rho[0]), DualVec64::from_re(rho[1])])
+                .derive();
+            let res =
+                critical_point_objective_t(eos, t, &fixed_ratio_density(r[0], r[1], &x_rest))?;
+
+            // calculate Newton step
+            let h = arr2(res.jacobian().raw_data());
+            let res = arr1(res.map(|r| r.re).raw_array());
+            let mut delta = LU::new(h)?.solve(&res);
+
+            // reduce step if necessary
+            for i in 0..2 {
+                if delta[i].abs() > 0.03 * max_density {
+                    delta *= 0.03 * max_density / delta[i].abs()
+                }
+            }
+
+            // apply step
+            rho -= &delta;
+            rho.mapv_inplace(|r| f64::max(r, 1e-4 * max_density));
+
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:12.8} | {:12.8}",
+                i,
+                norm(&res),
+                rho[0] * SIUnit::reference_density(),
+                rho[1] * SIUnit::reference_density(),
+            );
+
+            // check convergence
+            if norm(&res) < tol {
+                log_result!(
+                    verbosity,
+                    "Critical point calculation converged in {} step(s)\n",
+                    i
+                );
+                return State::new_nvt(
+                    eos,
+                    t * SIUnit::reference_temperature(),
+                    SIUnit::reference_volume(),
+                    &(fixed_ratio_density(rho[0], rho[1], &x_rest) * SIUnit::reference_moles()),
+                );
+            }
+        }
+        Err(EosError::NotConverged(String::from("Critical point")))
+    }
+
+    /// Trace the critical line of a binary system from the critical point
+    /// of the first component to the critical point of the second component.
+    ///
+    /// The critical line is parametrized by its arc length in the space of
+    /// temperature and partial densities (scaled with the critical point of the
+    /// first component), so that extrema of the temperature along the critical
+    /// line can be passed. The step size is chosen such that approximately
+    /// `npoints` states are calculated. If the continuation fails, the states
+    /// that converged up to that point are returned.
+    pub fn critical_locus_binary(
+        eos: &Arc<E>,
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<Vec<Self>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        let critical_points = Self::critical_point_pure(eos, None, options)?;
+        Self::critical_locus_binary_from_critical_points(eos, &critical_points, npoints, options)
+    }
+
+    /// Trace the critical line of a binary system between the given pure
+    /// component critical points.
+    ///
+    /// See [State::critical_locus_binary].
+    pub(crate) fn critical_locus_binary_from_critical_points(
+        eos: &Arc<E>,
+        critical_points: &[Self],
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<Vec<Self>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CRIT_POINT, TOL_CRIT_POINT);
+
+        // critical points of the pure components in scaled variables
+        let cp = critical_points;
+        let t_scale = cp[0]
+            .temperature
+            .to_reduced(SIUnit::reference_temperature())?;
+        let rho_scale = cp[0].density.to_reduced(SIUnit::reference_density())?;
+        let scale = arr1(&[t_scale, rho_scale, rho_scale]);
+        let z_start = arr1(&[1.0, 1.0, 0.0]);
+        let z_end = arr1(&[
+            cp[1]
+                .temperature
+                .to_reduced(SIUnit::reference_temperature())?
+                / t_scale,
+            0.0,
+            cp[1].density.to_reduced(SIUnit::reference_density())? / rho_scale,
+        ]);
+        let ds = norm(&(&z_end - &z_start)) / (npoints.max(2) - 1) as f64;
+
+        let state = |z: &Array1<f64>| {
+            let y = z * &scale;
+            State::new_nvt(
+                eos,
+                y[0] * SIUnit::reference_temperature(),
+                SIUnit::reference_volume(),
+                &(arr1(&[y[1], y[2]]) * SIUnit::reference_moles()),
+            )
+        };
+
+        // residuals and Jacobian w.r.t. the scaled variables
+        let objective = |z: &Array1<f64>| -> EosResult<(Array1<f64>, Array2<f64>)> {
+            let y = z * &scale;
+            let y = StaticVec::new_vec([y[0], y[1], y[2]])
+                .map(DualVec64::<3>::from_re)
+                .derive();
+            // the pressure residual is not used
+            let res = critical_point_objective_p(eos, 0.0, y[0], StaticVec::new_vec([y[1], y[2]]))?;
+            let h = arr2(res.jacobian().raw_data());
+            let h = Array2::from_shape_fn((2, 3), |(i, j)| h[(i, j)] * scale[j]);
+            Ok((arr1(&[res[0].re, res[1].re]), h))
+        };
+
+        // Newton iteration on the hyperplane through the predictor normal to tau
+        let correct =
+            |mut z: Array1<f64>, tau: &Array1<f64>| -> EosResult<(Array1<f64>, Array2<f64>)> {
+                let z_pred = z.clone();
+                for _ in 0..max_iter {
+                    let (res, h) = objective(&z)?;
+                    let res = arr1(&[res[0], res[1], tau.dot(&(&z - &z_pred))]);
+                    if norm(&res) < tol {
+                        return Ok((z, h));
+                    }
+                    let mut jac = Array2::zeros((3, 3));
+                    jac.slice_mut(s![..2, ..]).assign(&h);
+                    jac.row_mut(2).assign(tau);
+                    z -= &LU::new(jac)?.solve(&res);
+                    if z.iter().any(|&zi| zi < 0.0) {
+                        break;
+                    }
+                }
+                Err(EosError::NotConverged(String::from("Critical locus")))
+            };
+
+        // unit tangent of the critical line in the direction of tau
+        let tangent = |h: &Array2<f64>, tau: &Array1<f64>| {
+            let t = arr1(&[
+                h[(0, 1)] * h[(1, 2)] - h[(0, 2)] * h[(1, 1)],
+                h[(0, 2)] * h[(1, 0)] - h[(0, 0)] * h[(1, 2)],
+                h[(0, 0)] * h[(1, 1)] - h[(0, 1)] * h[(1, 0)],
+            ]);
+            let t = &t / norm(&t);
+            if t.dot(tau) < 0.0 {
+                -t
+            } else {
+                t
+            }
+        };
+
+        log_iter!(
+            verbosity,
+            " point |   temperature   |      density 1       |      density 2       "
+        );
+        log_iter!(verbosity, "{:-<72}", "");
+        let mut states = vec![state(&z_start)?];
+
+        // first point with a small density of the second component
+        let mut tau = arr1(&[0.0, 0.0, 1.0]);
+        let (mut z, mut h) = match correct(arr1(&[1.0, 1.0, 1e-3]), &tau) {
+            Ok(zh) => zh,
+            Err(_) => return Ok(states),
+        };
+        for i in 1..=10 * npoints {
+            let s = state(&z)?;
+            log_iter!(
+                verbosity,
+                " {:5} | {:13.8} | {:12.8} | {:12.8}",
+                i,
+                s.temperature,
+                s.partial_density.get(0),
+                s.partial_density.get(1),
+            );
+            states.push(s);
+
+            // predictor step along the tangent
+            tau = tangent(&h, &tau);
+            let z_pred = &z + &(&tau * ds);
+            if z_pred[1] <= 0.0 {
+                log_result!(
+                    verbosity,
+                    "Critical locus calculation converged with {} point(s)\n",
+                    states.len() + 1
+                );
+                states.push(state(&z_end)?);
+                return Ok(states);
+            }
+
+            // corrector step
+            (z, h) = match correct(z_pred, &tau) {
+                Ok(zh) => zh,
+                Err(_) => break,
+            };
+        }
+        log_result!(
+            verbosity,
+            "Critical locus calculation stopped after {} point(s)\n",
+            states.len()
+        );
+        Ok(states)
+    }
+
     /// Calculate the critical point of a binary system for given pressure.
     fn critical_point_binary_p(
         eos: &Arc<E>,
         pressure: SINumber,
         initial_temperature: Option<SINumber>,
         initial_molefracs: Option<[f64; 2]>,
+        initial_state: Option<&Self>,
         options: SolverOptions,
+        stats: &mut SolverStats,
     ) -> EosResult<Self>
     where
         SINumber: std::fmt::Display,
@@ -275,7 +757,9 @@ impl<E: EquationOfState> State<E> {
             options.unwrap_or(MAX_ITER_CRIT_POINT_BINARY, TOL_CRIT_POINT);
 
         let p = pressure.to_reduced(SIUnit::reference_pressure())?;
-        let mut t = initial_temperature
+        let mut t = initial_state
+            .map(|s| s.temperature)
+            .or(initial_temperature)
             .map(|t| t.to_reduced(SIUnit::reference_temperature()))
             .transpose()?
             .unwrap_or(300.0);
@@ -283,7 +767,10 @@ impl<E: EquationOfState> State<E> {
         let max_density = eos
             .max_density(Some(&(arr1(x.raw_array()) * SIUnit::reference_moles())))?
             .to_reduced(SIUnit::reference_density())?;
-        let mut rho = x * 0.3 * max_density;
+        let mut rho = match initial_state {
+            Some(state) => initial_partial_densities(state)?,
+            None => x * 0.3 * max_density,
+        };
 
         log_iter!(
             verbosity,
@@ -313,6 +800,8 @@ impl<E: EquationOfState> State<E> {
             // calculate Newton step
             let h = arr2(res.jacobian().raw_data());
             let res = arr1(res.map(|r| r.re).raw_array());
+            stats.iterations += 1;
+            stats.final_residual = norm(&res);
             let mut delta = LU::new(h)?.solve(&res);
 
             // reduce step if necessary
@@ -345,6 +834,7 @@ impl<E: EquationOfState> State<E> {
 
             // check convergence
             if norm(&res) < tol {
+                stats.converged = true;
                 log_result!(
                     verbosity,
                     "Critical point calculation converged in {} step(s)\n",
@@ -361,6 +851,225 @@ impl<E: EquationOfState> State<E> {
         Err(EosError::NotConverged(String::from("Critical point")))
     }
 
+    /// Calculate a tricritical point of a binary system.
+    ///
+    /// In addition to the critical point conditions (vanishing smallest
+    /// eigenvalue of the stability matrix and vanishing third derivative of the
+    /// Helmholtz energy along the corresponding eigenvector), the fourth derivative
+    /// along the eigenvector, corrected for the coupling with the second eigenvector,
+    /// has to vanish. The temperature and both partial densities are determined
+    /// simultaneously with a Newton iteration. All derivatives, including the
+    /// fourth derivative and the Jacobian of the system, are calculated with
+    /// nested dual numbers.
+    ///
+    /// The initial values are determined in the same way as in
+    /// [State::critical_point_binary] for a given pressure.
+    pub fn tricritical_point_binary(
+        eos: &Arc<E>,
+        initial_temperature: Option<SINumber>,
+        initial_molefracs: Option<[f64; 2]>,
+        initial_state: Option<&Self>,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        let (max_iter, tol, verbosity) =
+            options.unwrap_or(MAX_ITER_CRIT_POINT_BINARY, TOL_CRIT_POINT);
+
+        let mut t = initial_state
+            .map(|s| s.temperature)
+            .or(initial_temperature)
+            .map(|t| t.to_reduced(SIUnit::reference_temperature()))
+            .transpose()?
+            .unwrap_or(300.0);
+        let x = StaticVec::new_vec(initial_molefracs.unwrap_or([0.5, 0.5]));
+        let max_density = eos
+            .max_density(Some(&(arr1(x.raw_array()) * SIUnit::reference_moles())))?
+            .to_reduced(SIUnit::reference_density())?;
+        let mut rho = match initial_state {
+            Some(state) => initial_partial_densities(state)?,
+            None => x * 0.3 * max_density,
+        };
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |   temperature   |      density 1       |      density 2       "
+        );
+        log_iter!(verbosity, "{:-<87}", "");
+        log_iter!(
+            verbosity,
+            " {:4} |                | {:13.8} | {:12.8} | {:12.8}",
+            0,
+            t * SIUnit::reference_temperature(),
+            rho[0] * SIUnit::reference_density(),
+            rho[1] * SIUnit::reference_density(),
+        );
+
+        for i in 1..=max_iter {
+            // calculate residuals and derivatives w.r.t. temperature and partial densities
+            let x = StaticVec::new_vec([
+                DualVec64::from_re(t),
+                DualVec64::from_re(rho[0]),
+                DualVec64::from_re(rho[1]),
+            ])
+            .derive();
+            let r = StaticVec::new_vec([x[1], x[2]]);
+            let res = tricritical_point_objective(eos, x[0], r)?;
+
+            // calculate Newton step
+            let h = arr2(res.jacobian().raw_data());
+            let res = arr1(res.map(|r| r.re).raw_array());
+            let mut delta = LU::new(h)?.solve(&res);
+
+            // reduce step if necessary
+            if delta[0].abs() > 0.25 * t {
+                delta *= 0.25 * t / delta[0].abs()
+            }
+            if delta[1].abs() > 0.03 * max_density {
+                delta *= 0.03 * max_density / delta[1].abs()
+            }
+            if delta[2].abs() > 0.03 * max_density {
+                delta *= 0.03 * max_density / delta[2].abs()
+            }
+
+            // apply step
+            t -= delta[0];
+            rho[0] -= delta[1];
+            rho[1] -= delta[2];
+            rho[0] = f64::max(rho[0], 1e-4 * max_density);
+            rho[1] = f64::max(rho[1], 1e-4 * max_density);
+
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.8} | {:12.8} | {:12.8}",
+                i,
+                norm(&res),
+                t * SIUnit::reference_temperature(),
+                rho[0] * SIUnit::reference_density(),
+                rho[1] * SIUnit::reference_density(),
+            );
+
+            // check convergence
+            if norm(&res) < tol {
+                log_result!(
+                    verbosity,
+                    "Tricritical point calculation converged in {} step(s)\n",
+                    i
+                );
+                return State::new_nvt(
+                    eos,
+                    t * SIUnit::reference_temperature(),
+                    SIUnit::reference_volume(),
+                    &(arr1(rho.raw_array()) * SIUnit::reference_moles()),
+                );
+            }
+        }
+        Err(EosError::NotConverged(String::from("Tricritical point")))
+    }
+
+    /// Create a state at the given reduced temperature $T/T_c$ and reduced
+    /// density $\rho/\rho_c$ relative to the critical point of the system.
+    pub fn from_reduced(
+        eos: &Arc<E>,
+        reduced_temperature: f64,
+        reduced_density: f64,
+        moles: Option<&SIArray1>,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let cp = Self::critical_point(eos, moles, None, options)?;
+        Self::new_nvt(
+            eos,
+            reduced_temperature * cp.temperature,
+            cp.volume / reduced_density,
+            &cp.moles,
+        )
+    }
+
+    /// Calculate the reduced residual Helmholtz energy $\frac{A^\mathrm{res}}{Nk_\mathrm{B}T}$
+    /// of a pure component on a grid of reduced temperatures $T/T_c$ and reduced
+    /// densities $\rho/\rho_c$.
+    ///
+    /// The scaling with the critical point of the equation of state allows comparing
+    /// the residual surfaces of different fluids according to the principle of
+    /// corresponding states. The result contains a row for every reduced temperature
+    /// and a column for every reduced density.
+    pub fn reduced_residual_helmholtz_energy_surface(
+        eos: &Arc<E>,
+        reduced_temperature: &Array1<f64>,
+        reduced_density: &Array1<f64>,
+        options: SolverOptions,
+    ) -> EosResult<Array2<f64>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 1));
+        }
+        let cp = Self::critical_point(eos, None, None, options)?;
+        let t_c = cp.reduced_temperature;
+        let rho_c = cp.reduced_moles[0] / cp.reduced_volume;
+        let moles = arr1(&[1.0]);
+        Ok(Array2::from_shape_fn(
+            (reduced_temperature.len(), reduced_density.len()),
+            |(i, j)| {
+                let t = reduced_temperature[i] * t_c;
+                let v = 1.0 / (reduced_density[j] * rho_c);
+                eos.evaluate_residual(&StateHD::new(t, v, moles.clone()))
+            },
+        ))
+    }
+
+    /// Calculate the Krichevskii parameter $A_\mathrm{Kr}=\left(\frac{\partial p}{\partial x_2}\right)_{T,V}^\infty$
+    /// of a solute at infinite dilution in a binary mixture, evaluated at the critical point
+    /// of the pure solvent.
+    ///
+    /// Negative values indicate an attractive (nonvolatile) solute, positive values a
+    /// repulsive (volatile) solute.
+    pub fn krichevskii_parameter(
+        eos: &Arc<E>,
+        solute: usize,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> EosResult<SINumber>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        if solute > 1 {
+            return Err(EosError::Error(format!(
+                "Invalid solute index {solute} for a binary mixture."
+            )));
+        }
+        let solvent = 1 - solute;
+        let cp = Self::critical_point(
+            &Arc::new(eos.subset(&[solvent])),
+            None,
+            initial_temperature,
+            options,
+        )?;
+        let mut moles = Array1::zeros(2);
+        moles[solvent] = cp.reduced_moles[0];
+        let state = State::new_nvt(
+            eos,
+            cp.temperature,
+            cp.volume,
+            &(moles * SIUnit::reference_moles()),
+        )?;
+
+        // the ideal gas contributions of both components cancel
+        let dp_dn = state.dp_dni(Contributions::ResidualNvt);
+        Ok((dp_dn.get(solute) - dp_dn.get(solvent)) * state.total_moles)
+    }
+
     pub fn spinodal(
         eos: &Arc<E>,
         temperature: SINumber,
@@ -370,7 +1079,41 @@ impl<E: EquationOfState> State<E> {
     where
         SINumber: std::fmt::Display,
     {
-        let critical_point = Self::critical_point(eos, moles, None, options)?;
+        Self::spinodal_with_stats(eos, temperature, moles, options).0
+    }
+
+    /// Calculate spinodal states for a given temperature and composition
+    /// and return statistics of the iterations for the critical point, the
+    /// vapor spinodal and the liquid spinodal.
+    pub fn spinodal_with_stats(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        moles: Option<&SIArray1>,
+        options: SolverOptions,
+    ) -> (EosResult<[Self; 2]>, [SolverStats; 3])
+    where
+        SINumber: std::fmt::Display,
+    {
+        let mut stats = [SolverStats::default(); 3];
+        let spinodal =
+            Self::spinodal_from_critical_point(eos, temperature, moles, options, &mut stats);
+        (spinodal, stats)
+    }
+
+    fn spinodal_from_critical_point(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        moles: Option<&SIArray1>,
+        options: SolverOptions,
+        stats: &mut [SolverStats; 3],
+    ) -> EosResult<[Self; 2]>
+    where
+        SINumber: std::fmt::Display,
+    {
+        let (critical_point, critical_point_stats) =
+            Self::critical_point_with_stats(eos, moles, None, options);
+        stats[0] = critical_point_stats;
+        let critical_point = critical_point?;
         let moles = eos.validate_moles(moles)?;
         let spinodal_vapor = Self::calculate_spinodal(
             eos,
@@ -378,6 +1121,7 @@ impl<E: EquationOfState> State<E> {
             &moles,
             DensityInitialization::Vapor,
             options,
+            &mut stats[1],
         )?;
         let rho = 2.0 * critical_point.density - spinodal_vapor.density;
         let spinodal_liquid = Self::calculate_spinodal(
@@ -386,16 +1130,54 @@ impl<E: EquationOfState> State<E> {
             &moles,
             DensityInitialization::InitialDensity(rho),
             options,
+            &mut stats[2],
         )?;
         Ok([spinodal_vapor, spinodal_liquid])
     }
 
+    /// Calculate the spinodal of a binary mixture at given temperature.
+    ///
+    /// The spinodal states are calculated with [State::spinodal] for `npoints`
+    /// equidistant mole fractions of the first component between 0 and 1
+    /// (excluding the pure components). Compositions for which the temperature is
+    /// supercritical or the calculation does not converge, e.g., close to the
+    /// critical point where both branches merge, are skipped.
+    ///
+    /// The result contains the vapor branch with increasing mole fraction of the
+    /// first component followed by the liquid branch with decreasing mole fraction
+    /// of the first component, so that the states trace a continuous curve.
+    pub fn spinodal_curve(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<Vec<Self>>
+    where
+        SINumber: std::fmt::Display,
+    {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        let x = Array1::linspace(0.0, 1.0, npoints + 2);
+        let (vapor, liquid): (Vec<_>, Vec<_>) = x
+            .slice(s![1..-1])
+            .iter()
+            .filter_map(|&x| {
+                let moles = arr1(&[x, 1.0 - x]) * SIUnit::reference_moles();
+                Self::spinodal(eos, temperature, Some(&moles), options).ok()
+            })
+            .map(|[vapor, liquid]| (vapor, liquid))
+            .unzip();
+        Ok(vapor.into_iter().chain(liquid.into_iter().rev()).collect())
+    }
+
     fn calculate_spinodal(
         eos: &Arc<E>,
         temperature: SINumber,
         moles: &SIArray1,
         density_initialization: DensityInitialization,
         options: SolverOptions,
+        stats: &mut SolverStats,
     ) -> EosResult<Self>
     where
         SINumber: std::fmt::Display,
@@ -429,6 +1211,9 @@ impl<E: EquationOfState> State<E> {
             // calculate residuals and derivative w.r.t. density
             let res = spinodal_objective(eos, Dual64::from(t), Dual64::from(rho).derive(), &n)?;
 
+            stats.iterations += 1;
+            stats.final_residual = res.re.abs();
+
             // calculate Newton step
             let mut delta = res.re / res.eps[0];
 
@@ -451,6 +1236,7 @@ impl<E: EquationOfState> State<E> {
 
             // check convergence
             if res.re.abs() < tol {
+                stats.converged = true;
                 log_result!(
                     verbosity,
                     "Spinodal calculation converged in {} step(s)\n",
@@ -468,6 +1254,101 @@ impl<E: EquationOfState> State<E> {
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<E: EquationOfState> State<E> {
+    /// Calculate the critical points of a batch of pure component equations
+    /// of state in parallel.
+    ///
+    /// Every equation of state is constructed from the corresponding entry in
+    /// `parameters` using `eos`. Critical point calculations that fail are
+    /// returned as `None`.
+    pub fn par_critical_point<P, F>(
+        parameters: Vec<P>,
+        eos: F,
+        initial_temperature: Option<SINumber>,
+        options: SolverOptions,
+    ) -> Vec<Option<Self>>
+    where
+        SINumber: std::fmt::Display,
+        P: Send,
+        F: Fn(P) -> Arc<E> + Sync,
+    {
+        parameters
+            .into_par_iter()
+            .map(|p| Self::critical_point(&eos(p), None, initial_temperature, options).ok())
+            .collect()
+    }
+}
+
+/// Maximum number of step halvings in the line search of [scaled_newton].
+const MAX_LINE_SEARCH: usize = 10;
+
+/// Damped Newton iteration for two equations in two variables.
+///
+/// The residuals and the rows of the Jacobian are multiplied by the factors
+/// of the [ResidualScaling] that are determined from the initial residuals.
+/// The norm of the scaled residuals is the merit function of a backtracking
+/// line search on the (limited) Newton step and is used in the convergence
+/// criterion.
+#[allow(clippy::too_many_arguments)]
+fn scaled_newton<F, L, P>(
+    mut x: [f64; 2],
+    mut objective: F,
+    limit_step: L,
+    scaling: Option<ResidualScaling>,
+    max_iter: usize,
+    tol: f64,
+    stats: &mut SolverStats,
+    log: P,
+) -> EosResult<[f64; 2]>
+where
+    F: FnMut([f64; 2]) -> EosResult<([f64; 2], [[f64; 2]; 2])>,
+    L: Fn([f64; 2], [f64; 2]) -> [f64; 2],
+    P: Fn(usize, f64, [f64; 2]),
+{
+    let (res, mut jac) = objective(x)?;
+    let factors = scaling.map_or([1.0, 1.0], |s| s.factors(res));
+    let scale = |r: [f64; 2]| [r[0] * factors[0], r[1] * factors[1]];
+    let mut res = scale(res);
+
+    for i in 1..=max_iter {
+        stats.iterations += 1;
+
+        // Newton step of the scaled system
+        let h = arr2(&[
+            [jac[0][0] * factors[0], jac[0][1] * factors[0]],
+            [jac[1][0] * factors[1], jac[1][1] * factors[1]],
+        ]);
+        let delta = LU::new(h)?.solve(&arr1(&res));
+        let delta = limit_step(x, [delta[0], delta[1]]);
+
+        // halve the step until the norm of the scaled residuals decreases
+        let norm_0 = norm(&arr1(&res));
+        let mut alpha = 1.0;
+        for k in 0..=MAX_LINE_SEARCH {
+            let x_trial = [x[0] - alpha * delta[0], x[1] - alpha * delta[1]];
+            let (res_trial, jac_trial) = objective(x_trial)?;
+            let res_trial = scale(res_trial);
+            if norm(&arr1(&res_trial)) < norm_0 || k == MAX_LINE_SEARCH {
+                x = x_trial;
+                res = res_trial;
+                jac = jac_trial;
+                break;
+            }
+            alpha *= 0.5;
+        }
+
+        // check convergence
+        stats.final_residual = norm(&arr1(&res));
+        log(i, stats.final_residual, x);
+        if stats.final_residual < tol {
+            stats.converged = true;
+            return Ok(x);
+        }
+    }
+    Err(EosError::NotConverged(String::from("Critical point")))
+}
+
 fn critical_point_objective<E: EquationOfState>(
     eos: &Arc<E>,
     temperature: DualVec64<2>,
@@ -508,19 +1389,43 @@ fn critical_point_objective<E: EquationOfState>(
     Ok(StaticVec::new_vec([eval, res.v3]))
 }
 
+/// Reduced partial densities of a binary state used as initial values.
+fn initial_partial_densities<E>(state: &State<E>) -> EosResult<StaticVec<f64, 2>> {
+    let rho = state
+        .partial_density
+        .to_reduced(SIUnit::reference_density())?;
+    Ok(StaticVec::new_vec([rho[0], rho[1]]))
+}
+
+/// Partial densities of all components, given the partial density of the first
+/// component and the total density of all other components with fixed composition.
+fn fixed_ratio_density<D: DualNum<f64> + Copy>(
+    density_1: D,
+    density_rest: D,
+    molefracs_rest: &Array1<f64>,
+) -> Array1<D> {
+    Array1::from_shape_fn(molefracs_rest.len() + 1, |i| {
+        if i == 0 {
+            density_1
+        } else {
+            density_rest * molefracs_rest[i - 1]
+        }
+    })
+}
+
 fn critical_point_objective_t<E: EquationOfState>(
     eos: &Arc<E>,
     temperature: f64,
-    density: StaticVec<DualVec64<2>, 2>,
+    density: &Array1<DualVec64<2>>,
 ) -> EosResult<StaticVec<DualVec64<2>, 2>> {
     // calculate second partial derivatives w.r.t. moles
     let t = HyperDual::from(temperature);
     let v = HyperDual::from(1.0);
     let qij = Array2::from_shape_fn((eos.components(), eos.components()), |(i, j)| {
-        let mut m = density.map(HyperDual::from_re);
+        let mut m = density.mapv(HyperDual::from_re);
         m[i].eps1[0] = DualVec64::one();
         m[j].eps2[0] = DualVec64::one();
-        let state = StateHD::new(t, v, arr1(&[m[0], m[1]]));
+        let state = StateHD::new(t, v, m);
         (eos.evaluate_residual(&state).eps1eps2[(0, 0)]
             + eos.ideal_gas().evaluate(&state).eps1eps2[(0, 0)])
             * (density[i] * density[j]).sqrt()
@@ -590,7 +1495,68 @@ fn critical_point_objective_p<E: EquationOfState>(
     ]))
 }
 
-fn spinodal_objective<E: EquationOfState>(
+fn tricritical_point_objective<E: EquationOfState>(
+    eos: &Arc<E>,
+    temperature: DualVec64<3>,
+    density: StaticVec<DualVec64<3>, 2>,
+) -> EosResult<StaticVec<DualVec64<3>, 3>> {
+    // calculate second partial derivatives w.r.t. moles
+    let t = HyperDual::from_re(temperature);
+    let v = HyperDual::from(1.0);
+    let qij = Array2::from_shape_fn((2, 2), |(i, j)| {
+        let mut m = density.map(HyperDual::from_re);
+        m[i].eps1[0] = DualVec64::one();
+        m[j].eps2[0] = DualVec64::one();
+        let state = StateHD::new(t, v, arr1(&[m[0], m[1]]));
+        (eos.evaluate_residual(&state).eps1eps2[(0, 0)]
+            + eos.ideal_gas().evaluate(&state).eps1eps2[(0, 0)])
+            * (density[i] * density[j]).sqrt()
+    });
+
+    // calculate both eigenvalues and eigenvectors of q
+    let (eval, evec) = smallest_ev(qij.clone());
+    let eval_2 = qij[(0, 0)] + qij[(1, 1)] - eval;
+    let evec_2 = [-evec[1], evec[0]];
+
+    // third derivative in direction u and its derivative along the critical eigenvector
+    let sqrt_rho = density.map(|r| r.sqrt());
+    let third_derivative = |u: [DualVec64<3>; 2]| {
+        let moles_hd = Array1::from_shape_fn(2, |i| {
+            let mut rho = Dual::from_re(density[i]);
+            rho.eps[0] = evec[i] * sqrt_rho[i];
+            Dual3::new(
+                rho,
+                Dual::from_re(u[i] * sqrt_rho[i]),
+                Dual::zero(),
+                Dual::zero(),
+            )
+        });
+        let state_s = StateHD::new(
+            Dual3::from_re(Dual::from_re(temperature)),
+            Dual3::from(1.0),
+            moles_hd,
+        );
+        (eos.evaluate_residual(&state_s) + eos.ideal_gas().evaluate(&state_s)).v3
+    };
+    let e = [evec[0], evec[1]];
+    let a_sss = third_derivative(e);
+    let a_ssss = a_sss.eps[0];
+    let a_sss = a_sss.re;
+
+    // mixed third derivative from the derivatives along e+t, e-t and t
+    let a_sst = (third_derivative([e[0] + evec_2[0], e[1] + evec_2[1]]).re
+        - third_derivative([e[0] - evec_2[0], e[1] - evec_2[1]]).re
+        - third_derivative(evec_2).re * 2.0)
+        / 6.0;
+
+    Ok(StaticVec::new_vec([
+        eval,
+        a_sss,
+        a_ssss - a_sst.powi(2) * 3.0 / eval_2,
+    ]))
+}
+
+pub(crate) fn spinodal_objective<E: EquationOfState>(
     eos: &Arc<E>,
     temperature: Dual64,
     density: Dual64,
@@ -614,3 +1580,45 @@ fn spinodal_objective<E: EquationOfState>(
 
     Ok(eval)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn scaled_newton_badly_scaled() {
+        // the residual of the first equation cannot drop below the tolerance
+        // in double precision unless it is scaled
+        let objective = |x: [f64; 2]| -> EosResult<_> {
+            let res = [1e8 * (x[0] * x[0] - 2.0), x[1] * x[1] - 3.0];
+            Ok((res, [[2e8 * x[0], 0.0], [0.0, 2.0 * x[1]]]))
+        };
+        let solve = |scaling| {
+            let mut stats = SolverStats::default();
+            let x = scaled_newton(
+                [1.0, 1.0],
+                objective,
+                |_, delta| delta,
+                scaling,
+                50,
+                1e-10,
+                &mut stats,
+                |_, _, _| (),
+            );
+            (x, stats)
+        };
+
+        let (x, stats) = solve(None);
+        assert!(x.is_err());
+        assert!(!stats.converged);
+        assert_eq!(stats.iterations, 50);
+
+        let (x, stats) = solve(Some(ResidualScaling::Automatic));
+        let x = x.unwrap();
+        assert!(stats.converged);
+        assert!(stats.iterations < 10);
+        assert_relative_eq!(x[0], 2f64.sqrt(), max_relative = 1e-14);
+        assert_relative_eq!(x[1], 3f64.sqrt(), max_relative = 1e-14);
+    }
+}
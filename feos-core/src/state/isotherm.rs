@@ -0,0 +1,45 @@
+use super::{Contributions, DensityInitialization, State};
+use crate::equation_of_state::EquationOfState;
+use crate::errors::EosResult;
+use crate::phase_equilibria::SolverOptions;
+use ndarray::Array1;
+use quantity::si::{SIArray1, SINumber};
+use std::sync::Arc;
+
+/// # Isotherms
+impl<E: EquationOfState> State<E> {
+    /// Calculate the compressibility factor of the gas phase along an isotherm.
+    ///
+    /// The density iteration for every pressure is initialized with the density
+    /// of the previous point. Points at which the gas phase is not stable (i.e.
+    /// inside the two-phase region) or at which no gas phase exists are
+    /// returned as `NaN`.
+    pub fn z_factor_isotherm(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: &SIArray1,
+        moles: Option<&SIArray1>,
+        options: SolverOptions,
+    ) -> EosResult<Array1<f64>> {
+        let moles = eos.validate_moles(moles)?;
+        let mut density_initialization = DensityInitialization::Vapor;
+        let mut z = Array1::from_elem(pressure.len(), f64::NAN);
+        for (i, z_i) in z.iter_mut().enumerate() {
+            let state = match State::new_npt(
+                eos,
+                temperature,
+                pressure.get(i),
+                &moles,
+                density_initialization,
+            ) {
+                Ok(state) => state,
+                Err(_) => continue,
+            };
+            density_initialization = DensityInitialization::InitialDensity(state.density);
+            if state.is_stable(options)? {
+                *z_i = state.compressibility(Contributions::Total);
+            }
+        }
+        Ok(z)
+    }
+}
@@ -39,6 +39,27 @@ pub enum DensityInitialization {
     None,
 }
 
+/// Phase of a state calculated for given temperature and pressure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// Calculate the vapor root of the density iteration.
+    Vapor,
+    /// Calculate the liquid root of the density iteration.
+    Liquid,
+    /// Calculate both roots and return the one with the lower molar Gibbs energy.
+    Stable,
+}
+
+impl From<Phase> for DensityInitialization {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::Vapor => Self::Vapor,
+            Phase::Liquid => Self::Liquid,
+            Phase::Stable => Self::None,
+        }
+    }
+}
+
 /// Thermodynamic state of the system in reduced variables
 /// including their derivatives.
 ///
@@ -74,9 +95,44 @@ impl<D: DualNum<f64>> StateHD<D> {
         }
     }
 
-    // Since the molefracs can not be reproduced from moles if the density is zero,
-    // this constructor exists specifically for these cases.
-    pub(crate) fn new_virial(temperature: D, density: D, molefracs: Array1<f64>) -> Self {
+    /// Create a new `StateHD` for given temperature and partial densities.
+    ///
+    /// The volume is set to 1, so that the moles equal the partial densities.
+    pub fn from_partial_densities(temperature: D, partial_density: Array1<D>) -> Self {
+        Self::new(temperature, D::one(), partial_density)
+    }
+
+    /// Evaluate `f` with a cache for temperature dependent quantities of this state.
+    ///
+    /// Within `f`, [StateHD::temperature_dependent] calculates every quantity
+    /// only once for this state. The cache is local to the current thread and
+    /// is discarded when `f` returns. [EquationOfState::evaluate_residual] evaluates
+    /// all Helmholtz energy contributions with such a cache.
+    pub fn with_temperature_cache<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        cache::with_temperature_cache(self as *const Self as usize, f)
+    }
+
+    /// Return the temperature dependent quantity identified by `key`, e.g.,
+    /// the hard-sphere diameters of a parameter set.
+    ///
+    /// The quantity is calculated from the temperature using `f`. Within
+    /// [StateHD::with_temperature_cache], the result is cached, so that
+    /// Helmholtz energy contributions that are evaluated with the same state
+    /// can reuse it. Outside of it, the quantity is calculated on every call.
+    pub fn temperature_dependent<F: FnOnce(D) -> Array1<D>>(
+        &self,
+        key: (usize, &'static str),
+        f: F,
+    ) -> Array1<D> {
+        cache::temperature_dependent(self as *const Self as usize, key, || f(self.temperature))
+    }
+
+    /// Create a new `StateHD` for given temperature, total density and mole fractions.
+    ///
+    /// Since the mole fractions can not be reproduced from the moles if the density
+    /// is zero, this constructor exists specifically for these cases, e.g., for the
+    /// calculation of virial coefficients. The volume is set to 1.
+    pub fn new_virial(temperature: D, density: D, molefracs: Array1<f64>) -> Self {
         let volume = D::one();
         let partial_density = molefracs.mapv(|x| density * x);
         let moles = partial_density.mapv(|pd| pd * volume);
@@ -89,6 +145,123 @@ impl<D: DualNum<f64>> StateHD<D> {
             partial_density,
         }
     }
+
+    /// Same as [StateHD::new_virial], but with the partial densities given explicitly,
+    /// so that derivatives w.r.t. individual partial densities at zero density are accessible.
+    pub fn new_virial_partial(
+        temperature: D,
+        partial_density: Array1<D>,
+        molefracs: Array1<f64>,
+    ) -> Self {
+        let volume = D::one();
+        let moles = partial_density.mapv(|pd| pd * volume);
+        let molefracs = molefracs.mapv(D::from);
+        Self {
+            temperature,
+            volume,
+            moles,
+            molefracs,
+            partial_density,
+        }
+    }
+}
+
+/// # Derivative seeds
+///
+/// The following methods create a copy of the state in which the derivatives
+/// w.r.t. temperature, volume or individual mole numbers are seeded. The
+/// derivatives of the Helmholtz energy are then available in the dual parts
+/// of the result of [HelmholtzEnergyDual::helmholtz_energy](crate::HelmholtzEnergyDual::helmholtz_energy)
+/// or [EquationOfState::evaluate_residual].
+///
+/// If the total number of moles is zero (see [StateHD::new_virial]), the mole
+/// fractions of the state are kept constant and the derivatives w.r.t. the mole
+/// numbers correspond to derivatives w.r.t. the partial densities at zero density.
+///
+/// ```
+/// # use feos_core::{Derivative, StateHD};
+/// # use ndarray::arr1;
+/// let state = StateHD::new(300.0, 1000.0, arr1(&[1.0, 2.0]));
+/// let state_hd = state.derive2_mixed(Derivative::DN(0), Derivative::DN(1));
+/// assert_eq!(state_hd.moles[0].eps1[0], 1.0);
+/// assert_eq!(state_hd.moles[1].eps2[0], 1.0);
+/// ```
+impl StateHD<f64> {
+    fn seed<D: DualNum<f64>>(&self, temperature: D, volume: D, moles: Array1<D>) -> StateHD<D> {
+        if self.moles.sum() == 0.0 {
+            let partial_density = moles.mapv(|n| n / volume);
+            StateHD {
+                temperature,
+                volume,
+                moles,
+                molefracs: self.molefracs.mapv(D::from),
+                partial_density,
+            }
+        } else {
+            StateHD::new(temperature, volume, moles)
+        }
+    }
+
+    /// Creates a [StateHD] taking the first derivative.
+    pub fn derive1(&self, derivative: Derivative) -> StateHD<Dual64> {
+        let mut t = Dual64::from(self.temperature);
+        let mut v = Dual64::from(self.volume);
+        let mut n = self.moles.mapv(Dual64::from);
+        match derivative {
+            Derivative::DT => t = t.derive(),
+            Derivative::DV => v = v.derive(),
+            Derivative::DN(i) => n[i] = n[i].derive(),
+        }
+        self.seed(t, v, n)
+    }
+
+    /// Creates a [StateHD] taking the first and second (partial) derivatives.
+    pub fn derive2(&self, derivative: Derivative) -> StateHD<Dual2_64> {
+        let mut t = Dual2_64::from(self.temperature);
+        let mut v = Dual2_64::from(self.volume);
+        let mut n = self.moles.mapv(Dual2_64::from);
+        match derivative {
+            Derivative::DT => t = t.derive(),
+            Derivative::DV => v = v.derive(),
+            Derivative::DN(i) => n[i] = n[i].derive(),
+        }
+        self.seed(t, v, n)
+    }
+
+    /// Creates a [StateHD] taking the first and second (partial) derivatives.
+    pub fn derive2_mixed(
+        &self,
+        derivative1: Derivative,
+        derivative2: Derivative,
+    ) -> StateHD<HyperDual64> {
+        let mut t = HyperDual64::from(self.temperature);
+        let mut v = HyperDual64::from(self.volume);
+        let mut n = self.moles.mapv(HyperDual64::from);
+        match derivative1 {
+            Derivative::DT => t = t.derive1(),
+            Derivative::DV => v = v.derive1(),
+            Derivative::DN(i) => n[i] = n[i].derive1(),
+        }
+        match derivative2 {
+            Derivative::DT => t = t.derive2(),
+            Derivative::DV => v = v.derive2(),
+            Derivative::DN(i) => n[i] = n[i].derive2(),
+        }
+        self.seed(t, v, n)
+    }
+
+    /// Creates a [StateHD] taking the first, second, and third derivative with respect to a single property.
+    pub fn derive3(&self, derivative: Derivative) -> StateHD<Dual3_64> {
+        let mut t = Dual3_64::from(self.temperature);
+        let mut v = Dual3_64::from(self.volume);
+        let mut n = self.moles.mapv(Dual3_64::from);
+        match derivative {
+            Derivative::DT => t = t.derive(),
+            Derivative::DV => v = v.derive(),
+            Derivative::DN(i) => n[i] = n[i].derive(),
+        };
+        self.seed(t, v, n)
+    }
 }
 
 /// Thermodynamic state of the system.
@@ -235,6 +408,19 @@ impl<E: EquationOfState> State<E> {
         Ok(Self::new_nvt_unchecked(eos, temperature, volume, moles))
     }
 
+    /// Return a new `State` given a temperature and the partial densities of all components.
+    ///
+    /// The total amount of substance is set to the reference value, i.e., extensive
+    /// properties of the state refer to a single particle.
+    pub fn from_partial_densities(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        partial_density: &SIArray1,
+    ) -> EosResult<Self> {
+        let volume = SIUnit::reference_moles() / partial_density.sum();
+        Self::new_nvt(eos, temperature, volume, &(partial_density * volume))
+    }
+
     pub(super) fn new_nvt_unchecked(
         eos: &Arc<E>,
         temperature: SINumber,
@@ -429,52 +615,45 @@ impl<E: EquationOfState> State<E> {
     }
 
     /// Return a new `State` using a density iteration. [DensityInitialization] is used to
-    /// influence the calculation with respect to the possible solutions.
+    /// influence the calculation with respect to the possible solutions. Alternatively,
+    /// the requested root can be specified explicitly using [Phase].
     pub fn new_npt(
         eos: &Arc<E>,
         temperature: SINumber,
         pressure: SINumber,
         moles: &SIArray1,
-        density_initialization: DensityInitialization,
+        density_initialization: impl Into<DensityInitialization>,
     ) -> EosResult<Self> {
+        // model specific initial densities, e.g., from the roots of a cubic equation
+        let initial_densities = eos.initial_densities(temperature, pressure, moles);
+        let vapor_density = || {
+            initial_densities.map_or(pressure / temperature / SIUnit::gas_constant(), |[_, v]| v)
+        };
+        let liquid_density = || match initial_densities {
+            Some([l, _]) => Ok(l),
+            None => eos.max_density(Some(moles)),
+        };
+
         // calculate state from initial density or given phase
-        match density_initialization {
+        match density_initialization.into() {
             DensityInitialization::InitialDensity(rho0) => {
                 return density_iteration(eos, temperature, pressure, moles, rho0)
             }
             DensityInitialization::Vapor => {
-                return density_iteration(
-                    eos,
-                    temperature,
-                    pressure,
-                    moles,
-                    pressure / temperature / SIUnit::gas_constant(),
-                )
+                return density_iteration(eos, temperature, pressure, moles, vapor_density())
             }
             DensityInitialization::Liquid => {
-                return density_iteration(
-                    eos,
-                    temperature,
-                    pressure,
-                    moles,
-                    eos.max_density(Some(moles))?,
-                )
+                return density_iteration(eos, temperature, pressure, moles, liquid_density()?)
             }
             DensityInitialization::None => (),
         }
 
         // calculate stable phase
         let max_density = eos.max_density(Some(moles))?;
-        let liquid = density_iteration(eos, temperature, pressure, moles, max_density);
+        let liquid = density_iteration(eos, temperature, pressure, moles, liquid_density()?);
 
         if pressure < max_density * temperature * SIUnit::gas_constant() {
-            let vapor = density_iteration(
-                eos,
-                temperature,
-                pressure,
-                moles,
-                pressure / temperature / SIUnit::gas_constant(),
-            );
+            let vapor = density_iteration(eos, temperature, pressure, moles, vapor_density());
             match (&liquid, &vapor) {
                 (Ok(_), Err(_)) => liquid,
                 (Err(_), Ok(_)) => vapor,
@@ -496,6 +675,25 @@ impl<E: EquationOfState> State<E> {
         }
     }
 
+    /// Return the molar Gibbs energy of the stable phase at given temperature,
+    /// pressure and composition together with the [Phase] of the stable root.
+    pub fn stable_gibbs_energy(
+        eos: &Arc<E>,
+        temperature: SINumber,
+        pressure: SINumber,
+        moles: &SIArray1,
+    ) -> EosResult<(SINumber, Phase)> {
+        let g = |s: Self| s.molar_gibbs_energy(Contributions::Total);
+        let liquid = Self::new_npt(eos, temperature, pressure, moles, Phase::Liquid).map(g);
+        let vapor = Self::new_npt(eos, temperature, pressure, moles, Phase::Vapor).map(g);
+        match (liquid, vapor) {
+            (Ok(gl), Ok(gv)) if gl < gv => Ok((gl, Phase::Liquid)),
+            (_, Ok(gv)) => Ok((gv, Phase::Vapor)),
+            (Ok(gl), Err(_)) => Ok((gl, Phase::Liquid)),
+            (Err(e), Err(_)) => Err(e),
+        }
+    }
+
     /// Return a new `State` for given pressure $p$, volume $V$, temperature $T$ and composition $x_i$.
     pub fn new_npvx(
         eos: &Arc<E>,
@@ -627,6 +825,23 @@ impl<E: EquationOfState> State<E> {
         Self::new_nvt(&self.eos, temperature, self.volume, &self.moles)
     }
 
+    /// Return the state that is reached by an isentropic change of the pressure,
+    /// e.g., in a compressor or a turbine.
+    ///
+    /// The final state is determined as a single phase at the given pressure. If the
+    /// isentropic path ends in the two-phase region, the iteration either fails or
+    /// returns a metastable state, which can be detected using [State::is_stable].
+    pub fn isentropic_state(&self, pressure: SINumber) -> EosResult<Self> {
+        Self::new_nps(
+            &self.eos,
+            pressure,
+            self.molar_entropy(Contributions::Total),
+            &self.moles,
+            DensityInitialization::None,
+            Some(self.temperature),
+        )
+    }
+
     /// Update the state with the given chemical potential.
     pub fn update_chemical_potential(&mut self, chemical_potential: &SIArray1) -> EosResult<()> {
         for _ in 0..50 {
@@ -682,28 +897,12 @@ impl<E: EquationOfState> State<E> {
 
     /// Creates a [StateHD] taking the first derivative.
     pub fn derive1(&self, derivative: Derivative) -> StateHD<Dual64> {
-        let mut t = Dual64::from(self.reduced_temperature);
-        let mut v = Dual64::from(self.reduced_volume);
-        let mut n = self.reduced_moles.mapv(Dual64::from);
-        match derivative {
-            Derivative::DT => t = t.derive(),
-            Derivative::DV => v = v.derive(),
-            Derivative::DN(i) => n[i] = n[i].derive(),
-        }
-        StateHD::new(t, v, n)
+        self.derive0().derive1(derivative)
     }
 
     /// Creates a [StateHD] taking the first and second (partial) derivatives.
     pub fn derive2(&self, derivative: Derivative) -> StateHD<Dual2_64> {
-        let mut t = Dual2_64::from(self.reduced_temperature);
-        let mut v = Dual2_64::from(self.reduced_volume);
-        let mut n = self.reduced_moles.mapv(Dual2_64::from);
-        match derivative {
-            Derivative::DT => t = t.derive(),
-            Derivative::DV => v = v.derive(),
-            Derivative::DN(i) => n[i] = n[i].derive(),
-        }
-        StateHD::new(t, v, n)
+        self.derive0().derive2(derivative)
     }
 
     /// Creates a [StateHD] taking the first and second (partial) derivatives.
@@ -712,33 +911,12 @@ impl<E: EquationOfState> State<E> {
         derivative1: Derivative,
         derivative2: Derivative,
     ) -> StateHD<HyperDual64> {
-        let mut t = HyperDual64::from(self.reduced_temperature);
-        let mut v = HyperDual64::from(self.reduced_volume);
-        let mut n = self.reduced_moles.mapv(HyperDual64::from);
-        match derivative1 {
-            Derivative::DT => t = t.derive1(),
-            Derivative::DV => v = v.derive1(),
-            Derivative::DN(i) => n[i] = n[i].derive1(),
-        }
-        match derivative2 {
-            Derivative::DT => t = t.derive2(),
-            Derivative::DV => v = v.derive2(),
-            Derivative::DN(i) => n[i] = n[i].derive2(),
-        }
-        StateHD::new(t, v, n)
+        self.derive0().derive2_mixed(derivative1, derivative2)
     }
 
     /// Creates a [StateHD] taking the first, second, and third derivative with respect to a single property.
     pub fn derive3(&self, derivative: Derivative) -> StateHD<Dual3_64> {
-        let mut t = Dual3_64::from(self.reduced_temperature);
-        let mut v = Dual3_64::from(self.reduced_volume);
-        let mut n = self.reduced_moles.mapv(Dual3_64::from);
-        match derivative {
-            Derivative::DT => t = t.derive(),
-            Derivative::DV => v = v.derive(),
-            Derivative::DN(i) => n[i] = n[i].derive(),
-        };
-        StateHD::new(t, v, n)
+        self.derive0().derive3(derivative)
     }
 }
 
@@ -828,6 +1006,8 @@ where
 }
 
 mod critical_point;
+mod isotherm;
+pub(crate) use critical_point::spinodal_objective;
 
 #[cfg(test)]
 mod tests {
@@ -882,4 +1062,48 @@ mod tests {
         let moles = arr1(&[0.01, 0.02, 0.05]) * MOL;
         assert!(validate(temperature, volume, &moles).is_err());
     }
+
+    #[test]
+    fn test_temperature_cache() {
+        let calls = std::cell::Cell::new(0);
+        let f = |t: Dual64| {
+            calls.set(calls.get() + 1);
+            arr1(&[t * 2.0])
+        };
+        let key = (0, "test");
+
+        let t = Dual64::from(300.0).derive();
+        let state = StateHD::new(t, Dual64::from(100.0), arr1(&[Dual64::from(1.0)]));
+
+        // nothing is cached outside of an evaluation
+        state.temperature_dependent(key, f);
+        state.temperature_dependent(key, f);
+        assert_eq!(calls.get(), 2);
+
+        state.with_temperature_cache(|| {
+            let d = state.temperature_dependent(key, f);
+            assert_eq!(d[0].eps[0], 2.0);
+            assert_eq!(state.temperature_dependent(key, f)[0].eps[0], 2.0);
+            assert_eq!(calls.get(), 3);
+
+            // different key
+            state.temperature_dependent((1, "test"), f);
+            assert_eq!(calls.get(), 4);
+
+            // different state with the same real part but a different derivative
+            let t2 = Dual64::from(300.0);
+            let state2 = StateHD::new(t2, Dual64::from(100.0), arr1(&[Dual64::from(1.0)]));
+            assert_eq!(state2.temperature_dependent(key, f)[0].eps[0], 0.0);
+            assert_eq!(calls.get(), 5);
+            state2.with_temperature_cache(|| {
+                assert_eq!(state2.temperature_dependent(key, f)[0].eps[0], 0.0);
+                assert_eq!(state.temperature_dependent(key, f)[0].eps[0], 2.0);
+            });
+            assert_eq!(calls.get(), 6);
+        });
+
+        // the cache is discarded after the evaluation
+        state.temperature_dependent(key, f);
+        assert_eq!(calls.get(), 7);
+    }
 }
@@ -1,9 +1,10 @@
-use super::{Derivative::*, PartialDerivative, State};
+use super::{Derivative, Derivative::*, PartialDerivative, State, StateHD};
 use crate::equation_of_state::{EntropyScaling, EquationOfState, MolarWeight};
-use crate::errors::EosResult;
+use crate::errors::{EosError, EosResult};
 use crate::EosUnit;
 use ndarray::{arr1, Array1, Array2};
-use num_dual::DualNum;
+use num_dual::{Dual3, Dual64, DualNum, DualVec64, HyperDual, StaticVec};
+use num_traits::One;
 use quantity::si::*;
 use std::iter::FromIterator;
 use std::ops::{Add, Deref, Sub};
@@ -259,6 +260,14 @@ impl<E: EquationOfState> State<E> {
             .unwrap()
     }
 
+    /// Critical compressibility factor: $Z_c=\frac{p_cV_c}{NRT_c}$
+    ///
+    /// The result is only meaningful for states at critical conditions,
+    /// e.g., from [State::critical_point].
+    pub fn critical_compressibility(&self) -> f64 {
+        self.compressibility(Contributions::Total)
+    }
+
     /// Partial derivative of pressure w.r.t. volume: $\left(\frac{\partial p}{\partial V}\right)_{T,N_i}$
     pub fn dp_dv(&self, contributions: Contributions) -> SINumber {
         self.evaluate_property(Self::dp_dv_, contributions, true)
@@ -290,6 +299,102 @@ impl<E: EquationOfState> State<E> {
             * (self.volume * self.d2p_dv2(contributions) + 2.0 * self.dp_dv(contributions))
     }
 
+    /// Taylor coefficients of the pressure in density up to the given order (at most 3):
+    /// $p(\rho+\Delta\rho)\approx\sum_{k=0}^n c_k\Delta\rho^k$ with $c_k=\frac{1}{k!}\left(\frac{\partial^kp}{\partial\rho^k}\right)_{T,x_i}$
+    ///
+    /// The coefficients are evaluated with a single evaluation of the Helmholtz energy
+    /// using third order dual numbers.
+    pub fn pressure_density_taylor(&self, order: usize) -> EosResult<Vec<SINumber>> {
+        if order > 3 {
+            return Err(EosError::Error(format!(
+                "The Taylor expansion of the pressure is available up to order 3, got {order}."
+            )));
+        }
+        let n = self.reduced_moles.sum();
+        let mut rho: Dual3<Dual64, f64> = Dual3::from_re(Dual64::from(n / self.reduced_volume));
+        rho.v1 = Dual64::from(1.0);
+        let t = Dual3::from_re(Dual64::from(self.reduced_temperature));
+        let v = rho.recip() * n + Dual3::from_re(Dual64::from(0.0).derive());
+        let moles = self.reduced_moles.mapv(|n| Dual3::from_re(Dual64::from(n)));
+        let state = StateHD::new(t, v, moles);
+        let a = self.eos.evaluate_residual(&state) + self.eos.ideal_gas().evaluate(&state);
+        let dp_drho = [a.re, a.v1, a.v2, a.v3];
+
+        let mut factorial = 1.0;
+        Ok((0..=order)
+            .map(|k| {
+                factorial *= k.max(1) as f64;
+                -dp_drho[k].eps[0] * self.reduced_temperature / factorial
+                    * (SIUnit::reference_pressure() / SIUnit::reference_density().powi(k as i32))
+            })
+            .collect())
+    }
+
+    /// Phase identification parameter of Venkatarathnam and Oellrich: $\Pi=V\left(\frac{\frac{\partial^2p}{\partial T\partial V}}{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}-\frac{\left(\frac{\partial^2p}{\partial V^2}\right)_{T,N_i}}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}\right)$
+    ///
+    /// States with $\Pi>1$ are liquid-like and states with $\Pi<1$ are vapor-like.
+    /// The derivatives are evaluated with a single evaluation of the Helmholtz energy
+    /// using third order dual numbers.
+    pub fn phase_identification_parameter(&self) -> f64 {
+        let t = Dual3::from_re(Dual64::from(self.reduced_temperature).derive());
+        let mut v: Dual3<Dual64, f64> = Dual3::from_re(Dual64::from(self.reduced_volume));
+        v.v1 = Dual64::from(1.0);
+        let moles = self.reduced_moles.mapv(|n| Dual3::from_re(Dual64::from(n)));
+        let state = StateHD::new(t, v, moles);
+        let a = (self.eos.evaluate_residual(&state) + self.eos.ideal_gas().evaluate(&state)) * t;
+
+        // the pressure derivatives are the negative volume derivatives of the Helmholtz energy
+        let (a_vt, a_vv, a_vvt, a_vvv) = (a.v1.eps[0], a.v2.re, a.v2.eps[0], a.v3.re);
+        self.reduced_volume * (a_vvt / a_vt - a_vvv / a_vv)
+    }
+
+    /// Hessian of the residual Helmholtz energy w.r.t. temperature and volume: $\left(\frac{\partial^2A^\mathrm{res}}{\partial x\partial y}\right)_{N_i}$ with $x,y\in\lbrace T,V\rbrace$
+    ///
+    /// All entries are obtained from a single evaluation of the Helmholtz energy.
+    pub fn residual_tv_hessian(&self) -> [[SINumber; 2]; 2] {
+        let [t, v] = *StaticVec::new_vec([self.reduced_temperature, self.reduced_volume])
+            .map(DualVec64::<2>::from_re)
+            .derive()
+            .raw_array();
+        let mut t = HyperDual::from_re(t);
+        t.eps1[0] = DualVec64::one();
+        let mut v = HyperDual::from_re(v);
+        v.eps2[0] = DualVec64::one();
+        let n = self
+            .reduced_moles
+            .mapv(|n| HyperDual::from_re(DualVec64::from_re(n)));
+        let a = self.eos.evaluate_residual(&StateHD::new(t, v, n)) * t;
+
+        let e = SIUnit::reference_energy();
+        let t_ref = SIUnit::reference_temperature();
+        let v_ref = SIUnit::reference_volume();
+        let a_tt = a.eps1[0].eps[0] * e / (t_ref * t_ref);
+        let a_tv = a.eps1[0].eps[1] * e / (t_ref * v_ref);
+        let a_vv = a.eps2[0].eps[1] * e / (v_ref * v_ref);
+        [[a_tt, a_tv], [a_tv, a_vv]]
+    }
+
+    /// First derivatives of the reduced residual Helmholtz energy $\beta A^\mathrm{res}$
+    /// w.r.t. temperature, volume and the number of particles of each component.
+    ///
+    /// All quantities are in the reduced units used internally for the evaluation
+    /// of the Helmholtz energy, i.e., temperatures in units of
+    /// [reference_temperature](EosUnit::reference_temperature), volumes in units of
+    /// [reference_volume](EosUnit::reference_volume) and amounts of substance in units of
+    /// [reference_moles](EosUnit::reference_moles). The residual pressure, e.g., follows as
+    /// $p^\mathrm{res}=-T\left(\frac{\partial\beta A^\mathrm{res}}{\partial V}\right)_{T,N_i}$
+    /// in units of [reference_pressure](EosUnit::reference_pressure).
+    pub fn reduced_residual_derivatives(&self) -> (f64, f64, Array1<f64>) {
+        let derivative = |d: Derivative| self.eos.evaluate_residual(&self.derive1(d)).eps[0];
+        (
+            derivative(DT),
+            derivative(DV),
+            (0..self.eos.components())
+                .map(|i| derivative(DN(i)))
+                .collect(),
+        )
+    }
+
     /// Partial molar volume: $v_i=\left(\frac{\partial V}{\partial N_i}\right)_{T,p,N_j}$
     pub fn partial_molar_volume(&self, contributions: Contributions) -> SIArray1 {
         let func = |s: &Self, evaluate: Evaluate| -s.dp_dni_(evaluate) / s.dp_dv_(evaluate);
@@ -345,6 +450,11 @@ impl<E: EquationOfState> State<E> {
         }
     }
 
+    /// Excess chemical potential w.r.t. an ideal solution: $\mu_i^E=RT\ln\gamma_i$
+    pub fn excess_chemical_potentials(&self) -> EosResult<SIArray1> {
+        Ok(self.ln_symmetric_activity_coefficient()? * (SIUnit::gas_constant() * self.temperature))
+    }
+
     /// Partial derivative of the logarithm of the fugacity coefficient w.r.t. temperature: $\left(\frac{\partial\ln\varphi_i}{\partial T}\right)_{p,N_i}$
     pub fn dln_phi_dt(&self) -> SIArray1 {
         let func = |s: &Self, evaluate: Evaluate| {
@@ -385,6 +495,12 @@ impl<E: EquationOfState> State<E> {
         })
     }
 
+    /// Isochoric heat capacity: $C_V=\left(\frac{\partial U}{\partial T}\right)_{V,N_i}$
+    pub fn isochoric_heat_capacity(&self, contributions: Contributions) -> SINumber {
+        let func = |s: &Self, evaluate: Evaluate| s.temperature * s.ds_dt_(evaluate);
+        self.evaluate_property(func, contributions, true)
+    }
+
     /// Molar isochoric heat capacity: $c_v=\left(\frac{\partial u}{\partial T}\right)_{V,N_i}$
     pub fn c_v(&self, contributions: Contributions) -> SINumber {
         let func =
@@ -425,6 +541,17 @@ impl<E: EquationOfState> State<E> {
         self.entropy(contributions) / self.total_moles
     }
 
+    /// Reduced residual entropy as used in entropy scaling: $s^*=-\frac{s^\mathrm{res}}{R\sum_im_ix_i}$
+    ///
+    /// The segment numbers $m_i$ are provided by the equation of state.
+    pub fn reduced_residual_entropy(&self) -> f64 {
+        let s_res = self
+            .molar_entropy(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::gas_constant())
+            .unwrap();
+        -s_res / (&self.eos.segment_numbers() * &self.molefracs).sum()
+    }
+
     /// Enthalpy: $H=A+TS+pV$
     pub fn enthalpy(&self, contributions: Contributions) -> SINumber {
         let func = |s: &Self, evaluate: Evaluate| {
@@ -445,6 +572,18 @@ impl<E: EquationOfState> State<E> {
         self.evaluate_property(Self::helmholtz_energy_, contributions, true)
     }
 
+    /// Residual Helmholtz energy: $A^\text{res}$
+    ///
+    /// In contrast to [State::helmholtz_energy], the error of a failing
+    /// contribution (e.g. [EosError::AssociationNotConverged]) is returned
+    /// instead of NaN.
+    pub fn try_residual_helmholtz_energy(&self) -> EosResult<SINumber> {
+        let new_state = self.derive0();
+        Ok(self.eos.try_evaluate_residual(&new_state)?
+            * new_state.temperature
+            * SIUnit::reference_energy())
+    }
+
     /// molar Helmholtz energy: $a=\frac{A}{N}$
     pub fn molar_helmholtz_energy(&self, contributions: Contributions) -> SINumber {
         self.helmholtz_energy(contributions) / self.total_moles
@@ -476,6 +615,14 @@ impl<E: EquationOfState> State<E> {
         self.gibbs_energy(contributions) / self.total_moles
     }
 
+    /// Grand potential density: $\frac{\Omega}{V}=\frac{A-\sum_i\mu_iN_i}{V}$
+    pub fn grand_potential_density(&self, contributions: Contributions) -> SINumber {
+        let mu = self.chemical_potential(contributions);
+        (0..self.eos.components()).fold(self.helmholtz_energy(contributions), |omega, i| {
+            omega - mu.get(i) * self.moles.get(i)
+        }) / self.volume
+    }
+
     /// Partial molar entropy: $s_i=\left(\frac{\partial S}{\partial N_i}\right)_{T,p,N_j}$
     pub fn partial_molar_entropy(&self, contributions: Contributions) -> SIArray1 {
         let func = |s: &Self, evaluate: Evaluate| {
@@ -521,6 +668,12 @@ impl<E: EquationOfState> State<E> {
         -self.dp_dt(c) / self.dp_dv(c) / self.volume
     }
 
+    /// Partial derivative of the density w.r.t. temperature: $\left(\frac{\partial\rho}{\partial T}\right)_{p,N_i}=-\frac{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}{\left(\frac{\partial p}{\partial\rho}\right)_{T,N_i}}$
+    pub fn density_temperature_derivative_isobaric(&self) -> SINumber {
+        let c = Contributions::Total;
+        -self.dp_dt(c) / self.dp_drho(c)
+    }
+
     /// Grueneisen parameter: $\phi=V\left(\frac{\partial p}{\partial U}\right)_{V,n_i}=\frac{v}{c_v}\left(\frac{\partial p}{\partial T}\right)_{v,n_i}=\frac{\rho}{T}\left(\frac{\partial T}{\partial \rho}\right)_{s, n_i}$
     pub fn grueneisen_parameter(&self) -> f64 {
         let c = Contributions::Total;
@@ -529,6 +682,12 @@ impl<E: EquationOfState> State<E> {
             .unwrap()
     }
 
+    /// Heat capacity ratio (adiabatic index): $\gamma=\frac{c_p}{c_v}$
+    pub fn heat_capacity_ratio(&self) -> f64 {
+        let c = Contributions::Total;
+        (self.c_p(c) / self.c_v(c)).into_value().unwrap()
+    }
+
     /// Structure factor: $S(0)=k_BT\left(\frac{\partial\rho}{\partial p}\right)_{T,N_i}$
     pub fn structure_factor(&self) -> f64 {
         -(SIUnit::gas_constant() * self.temperature * self.density)
@@ -552,6 +711,37 @@ impl<E: EquationOfState> State<E> {
         res
     }
 
+    /// Check that the first and second derivatives of the residual Helmholtz energy
+    /// calculated with dual numbers agree with finite differences within the
+    /// relative `tolerance`.
+    ///
+    /// Returns an error that lists every derivative with a larger deviation.
+    /// The full report is available from [EquationOfState::derivative_self_check].
+    pub fn check_derivative_consistency(&self, tolerance: f64) -> EosResult<()> {
+        let failed = self.eos.derivative_self_check(self)?.failed(tolerance);
+        if failed.is_empty() {
+            return Ok(());
+        }
+        let failed: Vec<_> = failed
+            .iter()
+            .map(|(d, dev)| format!("{} (relative deviation {:e})", d, dev))
+            .collect();
+        Err(EosError::Error(format!(
+            "Inconsistent derivatives of the Helmholtz energy: {}",
+            failed.join(", ")
+        )))
+    }
+
+    /// Residual Helmholtz energy $A^\mathrm{res}$ evaluated for each residual contribution of the equation of state.
+    pub fn residual_helmholtz_energy_contributions(&self) -> Vec<(String, SINumber)> {
+        let new_state = self.derive0();
+        self.eos
+            .evaluate_residual_contributions(&new_state)
+            .into_iter()
+            .map(|(s, v)| (s, v * new_state.temperature * SIUnit::reference_energy()))
+            .collect()
+    }
+
     /// Pressure $p$ evaluated for each contribution of the equation of state.
     pub fn pressure_contributions(&self) -> Vec<(String, SINumber)> {
         let new_state = self.derive1(DV);
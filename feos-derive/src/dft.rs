@@ -164,6 +164,7 @@ fn impl_molar_weight(
     variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let mut molar_weight = Vec::new();
+    let mut has_molar_weight = Vec::new();
 
     for v in variants.iter() {
         if implement("molar_weight", v, &OPT_IMPLS)? {
@@ -171,6 +172,9 @@ fn impl_molar_weight(
             molar_weight.push(quote! {
                 Self::#name(functional) => functional.molar_weight()
             });
+            has_molar_weight.push(quote! {
+                Self::#name(_) => true
+            });
         }
     }
     Ok(quote! {
@@ -181,6 +185,14 @@ fn impl_molar_weight(
                     _ => unimplemented!()
                 }
             }
+
+            fn has_molar_weight(&self) -> bool {
+                match self {
+                    #(#has_molar_weight,)*
+                    #[allow(unreachable_patterns)]
+                    _ => false
+                }
+            }
         }
     })
 }
@@ -56,6 +56,24 @@ fn impl_equation_of_state(
             Self::#name(eos) => eos.ideal_gas()
         }
     });
+    let parallel_residual = variants.iter().map(|v| {
+        let name = &v.ident;
+        quote! {
+            Self::#name(eos) => eos.parallel_residual()
+        }
+    });
+    let segment_numbers = variants.iter().map(|v| {
+        let name = &v.ident;
+        quote! {
+            Self::#name(eos) => eos.segment_numbers()
+        }
+    });
+    let initial_densities = variants.iter().map(|v| {
+        let name = &v.ident;
+        quote! {
+            Self::#name(eos) => eos.initial_densities(temperature, pressure, moles)
+        }
+    });
 
     quote! {
         impl EquationOfState for EosVariant {
@@ -84,6 +102,26 @@ fn impl_equation_of_state(
                     #(#ideal_gas,)*
                 }
             }
+            fn parallel_residual(&self) -> bool {
+                match self {
+                    #(#parallel_residual,)*
+                }
+            }
+            fn segment_numbers(&self) -> Array1<f64> {
+                match self {
+                    #(#segment_numbers,)*
+                }
+            }
+            fn initial_densities(
+                &self,
+                temperature: SINumber,
+                pressure: SINumber,
+                moles: &SIArray1,
+            ) -> Option<[SINumber; 2]> {
+                match self {
+                    #(#initial_densities,)*
+                }
+            }
         }
     }
 }
@@ -92,6 +130,7 @@ fn impl_molar_weight(
     variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let mut molar_weight = Vec::new();
+    let mut has_molar_weight = Vec::new();
 
     for v in variants.iter() {
         if implement("molar_weight", v, &OPT_IMPLS)? {
@@ -99,6 +138,9 @@ fn impl_molar_weight(
             molar_weight.push(quote! {
                 Self::#name(eos) => eos.molar_weight()
             });
+            has_molar_weight.push(quote! {
+                Self::#name(eos) => eos.has_molar_weight()
+            });
         }
     }
     Ok(quote! {
@@ -109,6 +151,14 @@ fn impl_molar_weight(
                     _ => unimplemented!()
                 }
             }
+
+            fn has_molar_weight(&self) -> bool {
+                match self {
+                    #(#has_molar_weight,)*
+                    #[allow(unreachable_patterns)]
+                    _ => false
+                }
+            }
         }
     })
 }
@@ -157,14 +207,16 @@ fn impl_entropy_scaling(
             ) -> EosResult<SINumber> {
                 match self {
                     #(#etar,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
 
             fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
                 match self {
                     #(#etac,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
 
@@ -176,14 +228,16 @@ fn impl_entropy_scaling(
             ) -> EosResult<SINumber> {
                 match self {
                     #(#dr,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
 
             fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
                 match self {
                     #(#dc,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
 
@@ -195,14 +249,16 @@ fn impl_entropy_scaling(
             ) -> EosResult<SINumber> {
                 match self {
                     #(#thcr,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
 
             fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
                 match self {
                     #(#thcc,)*
-                    _ => unimplemented!(),
+                    #[allow(unreachable_patterns)]
+                    _ => Err(EosError::NotSupported(String::from("Entropy scaling"))),
                 }
             }
         }
@@ -48,6 +48,10 @@ impl<T: MolarWeight> MolarWeight for DFT<T> {
     fn molar_weight(&self) -> SIArray1 {
         (self as &T).molar_weight()
     }
+
+    fn has_molar_weight(&self) -> bool {
+        (self as &T).has_molar_weight()
+    }
 }
 
 struct DefaultIdealGasContribution();
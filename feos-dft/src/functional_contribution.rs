@@ -44,6 +44,7 @@ impl_helmholtz_energy!(HyperDual<DualVec64<3>, f64>);
 impl_helmholtz_energy!(Dual3<Dual64, f64>);
 impl_helmholtz_energy!(Dual3<DualVec64<2>, f64>);
 impl_helmholtz_energy!(Dual3<DualVec64<3>, f64>);
+impl_helmholtz_energy!(Dual3<Dual<DualVec64<3>, f64>, f64>);
 
 /// Individual functional contribution that can
 /// be evaluated using generalized (hyper) dual numbers.
@@ -86,6 +87,7 @@ pub trait FunctionalContribution:
     + FunctionalContributionDual<Dual3<Dual64, f64>>
     + FunctionalContributionDual<Dual3<DualVec64<2>, f64>>
     + FunctionalContributionDual<Dual3<DualVec64<3>, f64>>
+    + FunctionalContributionDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
     + Display
     + Sync
     + Send
@@ -198,6 +200,7 @@ impl<T> FunctionalContribution for T where
         + FunctionalContributionDual<Dual3<Dual64, f64>>
         + FunctionalContributionDual<Dual3<DualVec64<2>, f64>>
         + FunctionalContributionDual<Dual3<DualVec64<3>, f64>>
+        + FunctionalContributionDual<Dual3<Dual<DualVec64<3>, f64>, f64>>
         + Display
         + Sync
         + Send
@@ -12,7 +12,7 @@ pub const N0_CUTOFF: f64 = 1e-9;
 
 impl<N, P> FunctionalContributionDual<N> for Association<P>
 where
-    N: DualNum<f64> + ScalarOperand,
+    N: DualNum<f64> + ScalarOperand + Send + Sync,
     P: HardSphereProperties,
 {
     fn weight_functions(&self, temperature: N) -> WeightFunctionInfo<N> {
@@ -114,29 +114,28 @@ where
         // only one associating component
         if nassoc == 1 {
             // association strength
-            let k = &n2 * &n3i * diameter[self.association_parameters.assoc_comp[0]] * 0.5;
-            let deltarho = (((&k / 18.0 + 0.5) * &k * xi + 1.0) * n3i)
-                * ((temperature.recip() * self.association_parameters.epsilon_k_aibj[(0, 0)])
-                    .exp_m1()
-                    * self.association_parameters.sigma3_kappa_aibj[(0, 0)])
-                * rho0.index_axis(Axis(0), 0);
+            let p = &self.association_parameters;
+            let k = &n2 * &n3i * diameter[p.assoc_comp[0]] * 0.5;
+            let deltarho = |site_pair| {
+                let [sigma3_kappa, epsilon_k] = p.site_pair(site_pair);
+                (((&k / 18.0 + 0.5) * &k * &xi + 1.0) * &n3i)
+                    * ((temperature.recip() * epsilon_k[(0, 0)]).exp_m1() * sigma3_kappa[(0, 0)])
+                    * rho0.index_axis(Axis(0), 0)
+            };
 
-            let na = self.association_parameters.na[0];
-            let nb = self.association_parameters.nb[0];
-            let f = |x: N| x.ln() - x * 0.5 + 0.5;
-            if nb > 0.0 {
-                // no cross association, two association sites
-                let xa = deltarho.mapv(|d| Self::assoc_site_frac_ab(d, na, nb));
-                let xb = (&xa - 1.0) * (na / nb) + 1.0;
-                Ok((xa.mapv(f) * na + xb.mapv(f) * nb) * rho0.index_axis(Axis(0), 0))
-            } else {
-                // no cross association, one association site
-                let xa = deltarho.mapv(|d| Self::assoc_site_frac_a(d, na));
-
-                Ok(xa.mapv(f) * na * rho0.index_axis(Axis(0), 0))
+            // no cross association, the site pairs A-B and C-D are independent
+            let mut f = deltarho(0).mapv(|d| Self::helmholtz_energy_site_pair(d, p.na[0], p.nb[0]));
+            if p.has_cd_sites() {
+                f += &deltarho(1).mapv(|d| Self::helmholtz_energy_site_pair(d, p.nc[0], p.nd[0]));
             }
+            Ok(f * rho0.index_axis(Axis(0), 0))
         } else {
-            let mut x: Array1<f64> = Array::from_elem(2 * nassoc, 0.2);
+            let site_pairs = if self.association_parameters.has_cd_sites() {
+                2
+            } else {
+                1
+            };
+            let mut x: Array1<f64> = Array::from_elem(2 * nassoc * site_pairs, 0.2);
             Ok(rho0
                 .view()
                 .into_shape([nassoc, rho0.len() / nassoc])
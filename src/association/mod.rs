@@ -1,11 +1,15 @@
 //! Generic implementation of the SAFT association contribution
 //! that can be used across models.
 use crate::hard_sphere::HardSphereProperties;
-use feos_core::{EosError, HelmholtzEnergyDual, StateHD};
+use feos_core::parameter::ParameterError;
+use feos_core::{EosError, EosResult, HelmholtzEnergyDual, StateHD};
 use ndarray::*;
 use num_dual::linalg::{norm, LU};
 use num_dual::*;
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops::SubAssign;
 use std::sync::Arc;
@@ -18,7 +22,14 @@ mod python;
 pub use python::PyAssociationRecord;
 
 /// Pure component association parameters.
+///
+/// The default record has vanishing association parameters. Records with
+/// `kappa_ab` or `epsilon_k_ab` equal to zero do not contribute to the
+/// association and are ignored in [AssociationParameters].
+///
+/// Deserialized records are validated in the same way as in [AssociationRecord::new].
 #[derive(Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "AssociationRecordUnchecked")]
 pub struct AssociationRecord {
     /// Association volume parameter
     pub kappa_ab: f64,
@@ -30,25 +41,199 @@ pub struct AssociationRecord {
     /// \# of association sites of type B
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nb: Option<f64>,
+    /// \# of association sites of type C (only associating with sites of type D)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nc: Option<f64>,
+    /// \# of association sites of type D (only associating with sites of type C)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nd: Option<f64>,
+    /// Association volume parameter of the C-D pair (defaults to `kappa_ab`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kappa_cd: Option<f64>,
+    /// Association energy parameter of the C-D pair in units of Kelvin (defaults to `epsilon_k_ab`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epsilon_k_cd: Option<f64>,
+}
+
+/// Association record as it is read from a parameter file before validation.
+#[derive(Deserialize)]
+struct AssociationRecordUnchecked {
+    kappa_ab: f64,
+    epsilon_k_ab: f64,
+    na: Option<f64>,
+    nb: Option<f64>,
+    nc: Option<f64>,
+    nd: Option<f64>,
+    kappa_cd: Option<f64>,
+    epsilon_k_cd: Option<f64>,
+}
+
+impl TryFrom<AssociationRecordUnchecked> for AssociationRecord {
+    type Error = ParameterError;
+
+    fn try_from(record: AssociationRecordUnchecked) -> Result<Self, Self::Error> {
+        Self {
+            kappa_ab: record.kappa_ab,
+            epsilon_k_ab: record.epsilon_k_ab,
+            na: record.na,
+            nb: record.nb,
+            nc: record.nc,
+            nd: record.nd,
+            kappa_cd: record.kappa_cd,
+            epsilon_k_cd: record.epsilon_k_cd,
+        }
+        .validate()
+    }
 }
 
 impl AssociationRecord {
-    pub fn new(kappa_ab: f64, epsilon_k_ab: f64, na: Option<f64>, nb: Option<f64>) -> Self {
+    /// Create a new association record.
+    ///
+    /// Returns an error if `kappa_ab` or `epsilon_k_ab` are negative
+    /// or if `na` or `nb` are not positive.
+    pub fn new(
+        kappa_ab: f64,
+        epsilon_k_ab: f64,
+        na: Option<f64>,
+        nb: Option<f64>,
+    ) -> Result<Self, ParameterError> {
         Self {
             kappa_ab,
             epsilon_k_ab,
             na,
             nb,
+            nc: None,
+            nd: None,
+            kappa_cd: None,
+            epsilon_k_cd: None,
         }
+        .validate()
+    }
+
+    /// Add a second pair of association sites C and D to the record.
+    ///
+    /// Sites of type C only associate with sites of type D (of any component)
+    /// with the association parameters `kappa_cd` and `epsilon_k_cd` (in units
+    /// of Kelvin). Together with two sites of type A and B, this corresponds
+    /// to a 4C scheme with distinguishable site pairs.
+    ///
+    /// Returns an error if `kappa_cd` or `epsilon_k_cd` are negative
+    /// or if `nc` or `nd` are not positive.
+    pub fn with_cd_sites(
+        mut self,
+        kappa_cd: f64,
+        epsilon_k_cd: f64,
+        nc: f64,
+        nd: f64,
+    ) -> Result<Self, ParameterError> {
+        self.kappa_cd = Some(kappa_cd);
+        self.epsilon_k_cd = Some(epsilon_k_cd);
+        self.nc = Some(nc);
+        self.nd = Some(nd);
+        self.validate()
+    }
+
+    /// Association volume parameter of the C-D pair.
+    pub fn kappa_cd(&self) -> f64 {
+        self.kappa_cd.unwrap_or(self.kappa_ab)
+    }
+
+    /// Association energy parameter of the C-D pair in units of Kelvin.
+    pub fn epsilon_k_cd(&self) -> f64 {
+        self.epsilon_k_cd.unwrap_or(self.epsilon_k_ab)
+    }
+
+    fn validate(self) -> Result<Self, ParameterError> {
+        for (name, value) in [
+            ("kappa_ab", self.kappa_ab),
+            ("epsilon_k_ab", self.epsilon_k_ab),
+            ("kappa_cd", self.kappa_cd()),
+            ("epsilon_k_cd", self.epsilon_k_cd()),
+        ] {
+            if !(value >= 0.0) {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "Invalid association record: {name} = {value} has to be non-negative."
+                )));
+            }
+        }
+        for (name, value) in [
+            ("na", self.na),
+            ("nb", self.nb),
+            ("nc", self.nc),
+            ("nd", self.nd),
+        ] {
+            if let Some(value) = value.filter(|&n| !(n > 0.0)) {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "Invalid association record: {name} = {value} has to be positive."
+                )));
+            }
+        }
+        Ok(self)
     }
 }
 
+/// Error message for records in which only one of `kappa_ab` and `epsilon_k_ab` is given.
+pub(crate) const ASSOCIATION_MISMATCH: &str =
+    "To model association, both kappa_ab and epsilon_k_ab need to be specified.";
+
+/// Deserialize the (flattened) association parameters of a pure component
+/// or segment record.
+///
+/// Serde turns any error in a flattened optional field into `None`, which
+/// would silently remove invalid association parameters. Therefore, the
+/// record is read without validation and validated afterwards.
+pub(crate) fn deserialize_association_record<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<AssociationRecord>, D::Error> {
+    Option::<AssociationRecordUnchecked>::deserialize(deserializer)?
+        .map(AssociationRecord::try_from)
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
 impl fmt::Display for AssociationRecord {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "AssociationRecord(kappa_ab={}", self.kappa_ab)?;
         write!(f, ", epsilon_k_ab={}", self.epsilon_k_ab)?;
         write!(f, ", na={}", self.na.unwrap_or(1.0))?;
-        write!(f, ", nb={})", self.nb.unwrap_or(1.0))
+        write!(f, ", nb={}", self.nb.unwrap_or(1.0))?;
+        if let Some(nc) = self.nc {
+            write!(f, ", nc={}", nc)?;
+        }
+        if let Some(nd) = self.nd {
+            write!(f, ", nd={}", nd)?;
+        }
+        if let Some(kappa_cd) = self.kappa_cd {
+            write!(f, ", kappa_cd={}", kappa_cd)?;
+        }
+        if let Some(epsilon_k_cd) = self.epsilon_k_cd {
+            write!(f, ", epsilon_k_cd={}", epsilon_k_cd)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Combining rule for the cross association parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum AssociationCombiningRule {
+    /// Geometric mean of the segment diameters and association volumes and
+    /// arithmetic mean of the association energies (Wolbach and Sandler).
+    Geometric,
+    /// Arithmetic mean of the segment diameters, geometric mean of the association
+    /// volumes and arithmetic mean of the association energies.
+    CR1,
+    /// Geometric mean of the association strengths (Elliott et al.).
+    ///
+    /// The cross parameters are set to the [Geometric](AssociationCombiningRule::Geometric)
+    /// values, which correspond to the limit of large association energies.
+    /// The association strength itself is combined exactly.
+    Elliott,
+}
+
+impl Default for AssociationCombiningRule {
+    fn default() -> Self {
+        Self::Geometric
     }
 }
 
@@ -62,15 +247,31 @@ pub struct AssociationParameters {
     pub epsilon_k_ab: Array1<f64>,
     pub sigma3_kappa_aibj: Array2<f64>,
     pub epsilon_k_aibj: Array2<f64>,
+    pub kappa_cd: Array1<f64>,
+    pub epsilon_k_cd: Array1<f64>,
+    pub sigma3_kappa_cidj: Array2<f64>,
+    pub epsilon_k_cidj: Array2<f64>,
     pub na: Array1<f64>,
     pub nb: Array1<f64>,
+    pub nc: Array1<f64>,
+    pub nd: Array1<f64>,
+    pub threshold_temperature_aibj: Array2<f64>,
+    pub threshold_width_aibj: Array2<f64>,
+    pub combining_rule: AssociationCombiningRule,
 }
 
 impl AssociationParameters {
+    /// Create association parameters from the records of all components (or segments).
+    ///
+    /// The optional binary corrections `beta_assoc` are indexed like `records`
+    /// and scale the cross association energies by $(1-\beta_{ij})$. They are
+    /// not used with the [Elliott](AssociationCombiningRule::Elliott) combining rule.
     pub fn new(
         records: &[Option<AssociationRecord>],
         sigma: &Array1<f64>,
         component_index: Option<&Array1<usize>>,
+        combining_rule: AssociationCombiningRule,
+        beta_assoc: Option<&Array2<f64>>,
     ) -> Self {
         let mut assoc_comp = Vec::new();
         let mut sigma_assoc = Vec::new();
@@ -78,6 +279,10 @@ impl AssociationParameters {
         let mut epsilon_k_ab = Vec::new();
         let mut na = Vec::new();
         let mut nb = Vec::new();
+        let mut nc = Vec::new();
+        let mut nd = Vec::new();
+        let mut kappa_cd = Vec::new();
+        let mut epsilon_k_cd = Vec::new();
 
         for (i, record) in records.iter().enumerate() {
             if let Some(record) = record.as_ref() {
@@ -88,16 +293,39 @@ impl AssociationParameters {
                     epsilon_k_ab.push(record.epsilon_k_ab);
                     na.push(record.na.unwrap_or(1.0));
                     nb.push(record.nb.unwrap_or(1.0));
+                    nc.push(record.nc.unwrap_or(0.0));
+                    nd.push(record.nd.unwrap_or(0.0));
+                    kappa_cd.push(record.kappa_cd());
+                    epsilon_k_cd.push(record.epsilon_k_cd());
                 }
             }
         }
 
-        let sigma3_kappa_aibj = Array2::from_shape_fn([kappa_ab.len(); 2], |(i, j)| {
-            (sigma_assoc[i] * sigma_assoc[j]).powf(1.5) * (kappa_ab[i] * kappa_ab[j]).sqrt()
-        });
-        let epsilon_k_aibj = Array2::from_shape_fn([epsilon_k_ab.len(); 2], |(i, j)| {
-            0.5 * (epsilon_k_ab[i] + epsilon_k_ab[j])
-        });
+        let sigma3_kappa = |kappa: &[f64]| {
+            Array2::from_shape_fn([kappa.len(); 2], |(i, j)| {
+                let sigma3 = match combining_rule {
+                    AssociationCombiningRule::CR1 => {
+                        (0.5 * (sigma_assoc[i] + sigma_assoc[j])).powi(3)
+                    }
+                    _ => (sigma_assoc[i] * sigma_assoc[j]).powf(1.5),
+                };
+                sigma3 * (kappa[i] * kappa[j]).sqrt()
+            })
+        };
+        let epsilon_k = |epsilon_k: &[f64]| {
+            Array2::from_shape_fn([epsilon_k.len(); 2], |(i, j)| {
+                let beta = match beta_assoc {
+                    Some(beta) if i != j => beta[(assoc_comp[i], assoc_comp[j])],
+                    _ => 0.0,
+                };
+                0.5 * (epsilon_k[i] + epsilon_k[j]) * (1.0 - beta)
+            })
+        };
+        let sigma3_kappa_aibj = sigma3_kappa(&kappa_ab);
+        let epsilon_k_aibj = epsilon_k(&epsilon_k_ab);
+        let sigma3_kappa_cidj = sigma3_kappa(&kappa_cd);
+        let epsilon_k_cidj = epsilon_k(&epsilon_k_cd);
+        let nassoc = assoc_comp.len();
 
         Self {
             component_index: component_index
@@ -108,10 +336,137 @@ impl AssociationParameters {
             epsilon_k_ab: Array1::from_vec(epsilon_k_ab),
             sigma3_kappa_aibj,
             epsilon_k_aibj,
+            kappa_cd: Array1::from_vec(kappa_cd),
+            epsilon_k_cd: Array1::from_vec(epsilon_k_cd),
+            sigma3_kappa_cidj,
+            epsilon_k_cidj,
             na: Array1::from_vec(na),
             nb: Array1::from_vec(nb),
+            nc: Array1::from_vec(nc),
+            nd: Array1::from_vec(nd),
+            threshold_temperature_aibj: Array2::from_elem([nassoc; 2], f64::INFINITY),
+            threshold_width_aibj: Array2::zeros([nassoc; 2]),
+            combining_rule,
+        }
+    }
+
+    /// Returns `true` if any component has association sites of type C or D.
+    pub fn has_cd_sites(&self) -> bool {
+        self.nc.iter().chain(self.nd.iter()).any(|&n| n > 0.0)
+    }
+
+    /// Association volumes and the cross parameters $\sigma^3\kappa$ and
+    /// $\frac{\varepsilon}{k}$ of the A-B (`site_pair = 0`) or the
+    /// C-D (`site_pair = 1`) pair.
+    pub(crate) fn site_pair(&self, site_pair: usize) -> [&Array2<f64>; 2] {
+        match site_pair {
+            0 => [&self.sigma3_kappa_aibj, &self.epsilon_k_aibj],
+            _ => [&self.sigma3_kappa_cidj, &self.epsilon_k_cidj],
+        }
+    }
+
+    /// Pure component association volumes of the A-B or the C-D pair.
+    fn kappa(&self, site_pair: usize) -> &Array1<f64> {
+        match site_pair {
+            0 => &self.kappa_ab,
+            _ => &self.kappa_cd,
         }
     }
+
+    /// Add solvation (induced association) between records `i` and `j` with
+    /// the cross association parameters `kappa_ab` and `epsilon_k_ab` (in units of Kelvin).
+    ///
+    /// Records without self association are added with a single site of type B
+    /// that only associates with the A sites of their solvation partners. For
+    /// records that are already associating, the cross parameters are overwritten.
+    pub fn with_solvation(
+        mut self,
+        solvation: &[(usize, usize, f64, f64)],
+        sigma: &Array1<f64>,
+    ) -> Self {
+        for &(i, j, kappa_ab, epsilon_k_ab) in solvation {
+            let a = self.solvation_site(i);
+            let b = self.solvation_site(j);
+            for (a, b) in [(a, b), (b, a)] {
+                self.sigma3_kappa_aibj[(a, b)] = (sigma[i] * sigma[j]).powf(1.5) * kappa_ab;
+                self.epsilon_k_aibj[(a, b)] = epsilon_k_ab;
+            }
+        }
+        self
+    }
+
+    /// Return the index of record `i` in the associating records and add
+    /// a solvation site if the record is not associating.
+    fn solvation_site(&mut self, i: usize) -> usize {
+        if let Some(a) = self.assoc_comp.iter().position(|&k| k == i) {
+            return a;
+        }
+        let n = self.assoc_comp.len();
+        let append = |x: &Array1<f64>, value: f64| {
+            let mut y = Array1::from_elem(n + 1, value);
+            y.slice_mut(s![..n]).assign(x);
+            y
+        };
+        let extend = |x: &Array2<f64>, value: f64| {
+            let mut y = Array2::from_elem([n + 1; 2], value);
+            y.slice_mut(s![..n, ..n]).assign(x);
+            y
+        };
+        let mut assoc_comp = Array1::from_elem(n + 1, i);
+        assoc_comp.slice_mut(s![..n]).assign(&self.assoc_comp);
+        self.assoc_comp = assoc_comp;
+        self.kappa_ab = append(&self.kappa_ab, 0.0);
+        self.epsilon_k_ab = append(&self.epsilon_k_ab, 0.0);
+        self.na = append(&self.na, 0.0);
+        self.nb = append(&self.nb, 1.0);
+        self.nc = append(&self.nc, 0.0);
+        self.nd = append(&self.nd, 0.0);
+        self.kappa_cd = append(&self.kappa_cd, 0.0);
+        self.epsilon_k_cd = append(&self.epsilon_k_cd, 0.0);
+        self.sigma3_kappa_aibj = extend(&self.sigma3_kappa_aibj, 0.0);
+        self.epsilon_k_aibj = extend(&self.epsilon_k_aibj, 0.0);
+        self.sigma3_kappa_cidj = extend(&self.sigma3_kappa_cidj, 0.0);
+        self.epsilon_k_cidj = extend(&self.epsilon_k_cidj, 0.0);
+        self.threshold_temperature_aibj = extend(&self.threshold_temperature_aibj, f64::INFINITY);
+        self.threshold_width_aibj = extend(&self.threshold_width_aibj, 0.0);
+        n
+    }
+
+    /// Deactivate the cross association between records `i` and `j` above
+    /// the threshold `temperature` (in units of Kelvin).
+    ///
+    /// The association strength is reduced smoothly to zero over the
+    /// temperature interval of (positive) `width` below the threshold,
+    /// so that the Helmholtz energy stays continuous. The threshold applies
+    /// to all associating records of `i` and `j`.
+    pub fn cross_association_threshold(
+        mut self,
+        i: usize,
+        j: usize,
+        temperature: f64,
+        width: f64,
+    ) -> Result<Self, ParameterError> {
+        if !(width > 0.0) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Invalid cross association threshold: width = {width} has to be positive."
+            )));
+        }
+        let records = |i| -> Vec<usize> {
+            (0..self.assoc_comp.len())
+                .filter(|&a| self.assoc_comp[a] == i)
+                .collect()
+        };
+        let (records_i, records_j) = (records(i), records(j));
+        for &a in &records_i {
+            for &b in &records_j {
+                for (a, b) in [(a, b), (b, a)] {
+                    self.threshold_temperature_aibj[(a, b)] = temperature;
+                    self.threshold_width_aibj[(a, b)] = width;
+                }
+            }
+        }
+        Ok(self)
+    }
 }
 
 /// Implementation of the SAFT association Helmholtz energy
@@ -151,6 +506,7 @@ impl<P: HardSphereProperties> Association<P> {
         res
     }
 
+    /// Association strength of the A-B (`site_pair = 0`) or the C-D (`site_pair = 1`) pair.
     fn association_strength<D: DualNum<f64>>(
         &self,
         temperature: D,
@@ -158,24 +514,68 @@ impl<P: HardSphereProperties> Association<P> {
         n2: D,
         n3i: D,
         xi: D,
+        site_pair: usize,
     ) -> Array2<D> {
         // Calculate association strength
-        let ac = &self.association_parameters.assoc_comp;
-        Array2::from_shape_fn([ac.len(); 2], |(i, j)| {
+        let p = &self.association_parameters;
+        let ac = &p.assoc_comp;
+        let [sigma3_kappa, epsilon_k] = p.site_pair(site_pair);
+        let delta = square_matrix_from_fn(ac.len(), |i, j| {
             let k = diameter[ac[i]] * diameter[ac[j]] / (diameter[ac[i]] + diameter[ac[j]])
                 * (n2 * n3i);
             n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
-                * self.association_parameters.sigma3_kappa_aibj[(i, j)]
-                * (temperature.recip() * self.association_parameters.epsilon_k_aibj[(i, j)])
-                    .exp_m1()
+                * sigma3_kappa[(i, j)]
+                * (temperature.recip() * epsilon_k[(i, j)]).exp_m1()
+                * p.threshold_switch(temperature, i, j)
+        });
+        if p.combining_rule != AssociationCombiningRule::Elliott {
+            return delta;
+        }
+
+        // Elliott combining rule: geometric mean of the pure association strengths
+        // (solvation sites without self association keep their cross parameters)
+        let kappa = p.kappa(site_pair);
+        square_matrix_from_fn(ac.len(), |i, j| {
+            if i == j || kappa[i] * kappa[j] == 0.0 {
+                delta[(i, j)]
+            } else {
+                (delta[(i, i)] * delta[(j, j)]).sqrt() * p.threshold_switch(temperature, i, j)
+            }
         })
     }
 }
 
-impl<D: DualNum<f64> + ScalarOperand, P: HardSphereProperties> HelmholtzEnergyDual<D>
+impl AssociationParameters {
+    /// Smooth switching function that deactivates the cross association
+    /// above the threshold temperature.
+    fn threshold_switch<D: DualNum<f64>>(&self, temperature: D, i: usize, j: usize) -> D {
+        let threshold = self.threshold_temperature_aibj[(i, j)];
+        if threshold.is_infinite() {
+            return D::one();
+        }
+        let width = self.threshold_width_aibj[(i, j)];
+        let u = (temperature - threshold + width) / width;
+        if u.re() <= 0.0 {
+            D::one()
+        } else if u.re() >= 1.0 {
+            D::zero()
+        } else {
+            -u * u * (-u * 2.0 + 3.0) + 1.0
+        }
+    }
+}
+
+impl<D: DualNum<f64> + ScalarOperand + Send + Sync, P: HardSphereProperties> HelmholtzEnergyDual<D>
     for Association<P>
 {
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        self.try_helmholtz_energy(state)
+            .unwrap_or_else(|_| D::from(std::f64::NAN))
+    }
+
+    /// Returns [EosError::AssociationNotConverged] if the cross association
+    /// does not converge.
+    fn try_helmholtz_energy(&self, state: &StateHD<D>) -> EosResult<D> {
         let p: &P = &self.parameters;
 
         // temperature dependent segment diameter
@@ -194,41 +594,45 @@ impl<D: DualNum<f64> + ScalarOperand, P: HardSphereProperties> HelmholtzEnergyDu
                 .mapv(|a| state.partial_density[self.association_parameters.component_index[a]]);
 
             // Helmholtz energy
-            self.helmholtz_energy_density_cross_association(
-                state.temperature,
-                &rho_assoc,
-                &diameter,
-                n2,
-                n3i,
-                D::one(),
-                self.max_iter,
-                self.tol,
-                None,
-            )
-            .unwrap_or_else(|_| D::from(std::f64::NAN))
-                * state.volume
+            let f = self
+                .helmholtz_energy_density_cross_association(
+                    state.temperature,
+                    &rho_assoc,
+                    &diameter,
+                    n2,
+                    n3i,
+                    D::one(),
+                    self.max_iter,
+                    self.tol,
+                    None,
+                )
+                .map_err(|_| EosError::AssociationNotConverged {
+                    temperature: state.temperature.re(),
+                    density: state.partial_density.sum().re(),
+                })?;
+            Ok(f * state.volume)
         } else {
             // association strength
-            let c = self.association_parameters.component_index
-                [self.association_parameters.assoc_comp[0]];
-            let deltarho =
-                self.association_strength(state.temperature, &diameter, n2, n3i, D::one())[(0, 0)]
-                    * state.partial_density[c];
-
-            let na = self.association_parameters.na[0];
-            let nb = self.association_parameters.nb[0];
-            if nb > 0.0 {
-                // no cross association, two association sites
-                let xa = Self::assoc_site_frac_ab(deltarho, na, nb);
-                let xb = (xa - 1.0) * (na / nb) + 1.0;
-
-                state.moles[c] * ((xa.ln() - xa * 0.5 + 0.5) * na + (xb.ln() - xb * 0.5 + 0.5) * nb)
-            } else {
-                // no cross association, one association site
-                let xa = Self::assoc_site_frac_a(deltarho, na);
+            let p = &self.association_parameters;
+            let c = p.component_index[p.assoc_comp[0]];
+            let deltarho = |site_pair| {
+                self.association_strength(
+                    state.temperature,
+                    &diameter,
+                    n2,
+                    n3i,
+                    D::one(),
+                    site_pair,
+                )[(0, 0)]
+                    * state.partial_density[c]
+            };
 
-                state.moles[c] * (xa.ln() - xa * 0.5 + 0.5) * na
+            // no cross association, the site pairs A-B and C-D are independent
+            let mut a = Self::helmholtz_energy_site_pair(deltarho(0), p.na[0], p.nb[0]);
+            if p.has_cd_sites() {
+                a += Self::helmholtz_energy_site_pair(deltarho(1), p.nc[0], p.nd[0]);
             }
+            Ok(state.moles[c] * a)
         }
     }
 }
@@ -251,10 +655,69 @@ impl<P: HardSphereProperties> Association<P> {
         ((deltarho * 4.0 * na + 1.0).sqrt() + 1.0).recip() * 2.0
     }
 
+    /// Reduced Helmholtz energy per molecule of a pure component with `na`
+    /// sites that only associate with its `nb` sites of the complementary type.
+    pub fn helmholtz_energy_site_pair<D: DualNum<f64>>(deltarho: D, na: f64, nb: f64) -> D {
+        let f = |x: D| x.ln() - x * 0.5 + 0.5;
+        if na == 0.0 && nb == 0.0 {
+            D::zero()
+        } else if nb > 0.0 {
+            // two association sites
+            let xa = Self::assoc_site_frac_ab(deltarho, na, nb);
+            let xb = (xa - 1.0) * (na / nb) + 1.0;
+            f(xa) * na + f(xb) * nb
+        } else {
+            // one association site
+            f(Self::assoc_site_frac_a(deltarho, na)) * na
+        }
+    }
+
+    /// Converged fractions of non-bonded association sites.
+    ///
+    /// The result contains the fractions of the A sites of all associating
+    /// segments followed by the fractions of the B sites. If any segment has
+    /// sites of type C or D, their fractions are appended in the same order.
+    pub fn site_fractions(&self, state: &StateHD<f64>) -> EosResult<Array1<f64>> {
+        let p: &P = &self.parameters;
+        let a = &self.association_parameters;
+
+        // temperature dependent segment diameter
+        let diameter = p.hs_diameter(state.temperature);
+
+        // auxiliary variables
+        let [zeta2, n3] = p.zeta(state.temperature, &state.partial_density, [2, 3]);
+        let n2 = zeta2 * 6.0;
+        let n3i = (-n3 + 1.0).recip();
+
+        // extract densities of associating segments
+        let rho_assoc = a
+            .assoc_comp
+            .mapv(|i| state.partial_density[a.component_index[i]]);
+
+        let site_pairs = if a.has_cd_sites() { 2 } else { 1 };
+        let mut x = Array::from_elem(2 * a.assoc_comp.len() * site_pairs, 0.2);
+        self.helmholtz_energy_density_cross_association(
+            state.temperature,
+            &rho_assoc,
+            &diameter,
+            n2,
+            n3i,
+            1.0,
+            self.max_iter,
+            self.tol,
+            Some(&mut x),
+        )
+        .map_err(|_| EosError::AssociationNotConverged {
+            temperature: state.temperature,
+            density: state.partial_density.sum(),
+        })?;
+        Ok(x)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn helmholtz_energy_density_cross_association<
-        S: Data<Elem = D>,
-        D: DualNum<f64> + ScalarOperand,
+        S: Data<Elem = D> + Sync,
+        D: DualNum<f64> + ScalarOperand + Send + Sync,
     >(
         &self,
         temperature: D,
@@ -265,7 +728,7 @@ impl<P: HardSphereProperties> Association<P> {
         xi: D,
         max_iter: usize,
         tol: f64,
-        x0: Option<&mut Array1<f64>>,
+        mut x0: Option<&mut Array1<f64>>,
     ) -> Result<D, EosError> {
         // check if density is close to 0
         if density.sum().re() < f64::EPSILON {
@@ -275,27 +738,60 @@ impl<P: HardSphereProperties> Association<P> {
             return Ok(D::zero());
         }
 
-        let assoc_comp = &self.association_parameters.assoc_comp;
-        let nassoc = assoc_comp.len();
+        let p = &self.association_parameters;
+        let nassoc = p.assoc_comp.len();
+
+        // the site pairs A-B and C-D are solved independently
+        let mut site_pairs = vec![(&p.na, &p.nb)];
+        if p.has_cd_sites() {
+            site_pairs.push((&p.nc, &p.nd));
+        }
 
-        // association strength
-        let delta = self.association_strength(temperature, diameter, n2, n3i, xi);
+        let mut f = D::zero();
+        for (k, (na, nb)) in site_pairs.into_iter().enumerate() {
+            let sites = s![2 * nassoc * k..2 * nassoc * (k + 1)];
 
-        // extract parameters of associating components
-        let na = &self.association_parameters.na;
-        let nb = &self.association_parameters.nb;
+            // initialize monomer fraction; without explicit initial values the
+            // solver always starts from the same guess, so that the result does
+            // not depend on previously evaluated states
+            let mut x = match &x0 {
+                Some(x0) if x0.len() >= 2 * nassoc * (k + 1) => x0.slice(sites).to_owned(),
+                _ => Array::from_elem(2 * nassoc, 0.2),
+            };
+            let delta = self.association_strength(temperature, diameter, n2, n3i, xi, k);
+            f += Self::helmholtz_energy_density_site_pair(
+                &mut x, &delta, na, nb, density, max_iter, tol,
+            )?;
 
-        // cross-association according to Michelsen2006
-        // initialize monomer fraction
-        let mut x = match &x0 {
-            Some(x0) => (*x0).clone(),
-            None => Array::from_elem(2 * nassoc, 0.2),
-        };
+            // save monomer fraction
+            if let Some(x0) = x0.as_mut() {
+                if x0.len() >= 2 * nassoc * (k + 1) {
+                    x0.slice_mut(sites).assign(&x);
+                }
+            }
+        }
+        Ok(f)
+    }
 
+    /// Helmholtz energy density of a pair of site types from the
+    /// cross-association algorithm according to Michelsen2006.
+    fn helmholtz_energy_density_site_pair<
+        S: Data<Elem = D> + Sync,
+        D: DualNum<f64> + ScalarOperand + Send + Sync,
+    >(
+        x: &mut Array1<f64>,
+        delta: &Array2<D>,
+        na: &Array1<f64>,
+        nb: &Array1<f64>,
+        density: &ArrayBase<S, Ix1>,
+        max_iter: usize,
+        tol: f64,
+    ) -> Result<D, EosError> {
+        let nassoc = na.len();
         for k in 0..max_iter {
             if Self::newton_step_cross_association::<_, f64>(
                 nassoc,
-                &mut x,
+                x,
                 &delta.map(D::re),
                 na,
                 nb,
@@ -309,15 +805,14 @@ impl<P: HardSphereProperties> Association<P> {
             }
         }
 
-        // calculate derivatives
+        // calculate derivatives using the implicit function theorem; starting
+        // from the converged site fractions, every Newton step with the exact
+        // Jacobian doubles the order up to which the derivatives are correct
         let mut x_dual = x.mapv(D::from);
-        for _ in 0..D::NDERIV {
-            Self::newton_step_cross_association(nassoc, &mut x_dual, &delta, na, nb, density, tol)?;
-        }
-
-        // save monomer fraction
-        if let Some(x0) = x0 {
-            *x0 = x;
+        let mut order = 0;
+        while order < D::NDERIV {
+            Self::implicit_step_cross_association(nassoc, &mut x_dual, delta, na, nb, density)?;
+            order = 2 * order + 1;
         }
 
         // Helmholtz energy density
@@ -327,7 +822,10 @@ impl<P: HardSphereProperties> Association<P> {
         Ok((density * (xa.mapv(f) * na + xb.mapv(f) * nb)).sum())
     }
 
-    fn newton_step_cross_association<S: Data<Elem = D>, D: DualNum<f64> + ScalarOperand>(
+    fn newton_step_cross_association<
+        S: Data<Elem = D> + Sync,
+        D: DualNum<f64> + ScalarOperand + Send + Sync,
+    >(
         nassoc: usize,
         x: &mut Array1<D>,
         delta: &Array2<D>,
@@ -343,22 +841,19 @@ impl<P: HardSphereProperties> Association<P> {
 
         // split x array
         let (xa, xb) = x.view().split_at(Axis(0), nassoc);
+        let sums = site_sums(nassoc, &xa, &xb, delta, na, nb, rho);
 
         // calculate gradients and approximate Hessian
-        for i in 0..nassoc {
-            let d = &delta.index_axis(Axis(0), i) * rho;
-
-            let dnx = (&xb * nb * &d).sum() + 1.0;
-            g[i] -= dnx;
+        for (i, (dnxa, dnxb, d)) in sums.into_iter().enumerate() {
+            g[i] -= dnxa;
             for j in 0..nassoc {
                 h[(i, nassoc + j)] = -d[j] * nb[j];
                 h[(nassoc + i, j)] = -d[j] * na[j];
             }
-            h[(i, i)] = -dnx / xa[i];
+            h[(i, i)] = -dnxa / xa[i];
 
-            let dnx = (&xa * na * &d).sum() + 1.0;
-            g[nassoc + i] -= dnx;
-            h[(nassoc + i, nassoc + i)] = -dnx / xb[i];
+            g[nassoc + i] -= dnxb;
+            h[(nassoc + i, nassoc + i)] = -dnxb / xb[i];
         }
 
         // Newton step
@@ -367,6 +862,99 @@ impl<P: HardSphereProperties> Association<P> {
         // check convergence
         Ok(norm(&g.map(D::re)) < tol)
     }
+
+    /// Newton step with the exact Jacobian of the site fraction residual.
+    fn implicit_step_cross_association<
+        S: Data<Elem = D> + Sync,
+        D: DualNum<f64> + ScalarOperand + Send + Sync,
+    >(
+        nassoc: usize,
+        x: &mut Array1<D>,
+        delta: &Array2<D>,
+        na: &Array1<f64>,
+        nb: &Array1<f64>,
+        rho: &ArrayBase<S, Ix1>,
+    ) -> Result<(), EosError> {
+        // residual
+        let mut g = x.map(D::recip);
+        // Jacobian
+        let mut h: Array2<D> = Array::zeros((2 * nassoc, 2 * nassoc));
+
+        // split x array
+        let (xa, xb) = x.view().split_at(Axis(0), nassoc);
+        let sums = site_sums(nassoc, &xa, &xb, delta, na, nb, rho);
+
+        // calculate residual and Jacobian
+        for (i, (dnxa, dnxb, d)) in sums.into_iter().enumerate() {
+            g[i] -= dnxa;
+            g[nassoc + i] -= dnxb;
+            for j in 0..nassoc {
+                h[(i, nassoc + j)] = -d[j] * nb[j];
+                h[(nassoc + i, j)] = -d[j] * na[j];
+            }
+            h[(i, i)] = -xa[i].powi(2).recip();
+            h[(nassoc + i, nassoc + i)] = -xb[i].powi(2).recip();
+        }
+
+        // Newton step
+        x.sub_assign(&LU::new(h)?.solve(&g));
+        Ok(())
+    }
+}
+
+/// Minimum number of associating segments for which the association strengths
+/// and the site sums are evaluated in parallel.
+///
+/// For fewer segments, the overhead of the thread pool exceeds the time spent
+/// in the (small) loops.
+#[cfg(feature = "rayon")]
+const MIN_PARALLEL_SITES: usize = 8;
+
+/// Construct a square matrix element-wise, in parallel if the `rayon` feature is
+/// enabled and the matrix has at least `MIN_PARALLEL_SITES` rows.
+fn square_matrix_from_fn<D, F>(n: usize, f: F) -> Array2<D>
+where
+    D: Send,
+    F: Fn(usize, usize) -> D + Send + Sync,
+{
+    let f = |k: usize| f(k / n, k % n);
+    #[cfg(feature = "rayon")]
+    if n >= MIN_PARALLEL_SITES {
+        return Array2::from_shape_vec((n, n), (0..n * n).into_par_iter().map(f).collect())
+            .unwrap();
+    }
+    Array2::from_shape_vec((n, n), (0..n * n).map(f).collect()).unwrap()
+}
+
+/// Sums over the bonding partners of the A and B sites of every associating
+/// segment, i.e. $1+\sum_j\Delta_{ij}\rho_jn_{B,j}X_{B,j}$ and the same for the A sites,
+/// together with the products $\Delta_{ij}\rho_j$. The rows are evaluated in parallel
+/// if the `rayon` feature is enabled and there are at least `MIN_PARALLEL_SITES`
+/// associating segments.
+fn site_sums<S, D>(
+    nassoc: usize,
+    xa: &ArrayView1<D>,
+    xb: &ArrayView1<D>,
+    delta: &Array2<D>,
+    na: &Array1<f64>,
+    nb: &Array1<f64>,
+    rho: &ArrayBase<S, Ix1>,
+) -> Vec<(D, D, Array1<D>)>
+where
+    S: Data<Elem = D> + Sync,
+    D: DualNum<f64> + ScalarOperand + Send + Sync,
+{
+    let f = |i: usize| {
+        let d = &delta.index_axis(Axis(0), i) * rho;
+        let dnxa = (xb * nb * &d).sum() + 1.0;
+        let dnxb = (xa * na * &d).sum() + 1.0;
+        (dnxa, dnxb, d)
+    };
+    #[cfg(feature = "rayon")]
+    if nassoc >= MIN_PARALLEL_SITES {
+        return (0..nassoc).into_par_iter().map(f).collect();
+    }
+    (0..nassoc).map(f).collect()
 }
 
 #[cfg(test)]
@@ -374,9 +962,42 @@ impl<P: HardSphereProperties> Association<P> {
 mod tests_pcsaft {
     use super::*;
     use crate::pcsaft::parameters::utils::water_parameters;
-    use crate::pcsaft::PcSaftParameters;
+    use crate::pcsaft::{PcSaftParameters, PcSaftRecord};
     use approx::assert_relative_eq;
-    use feos_core::parameter::Parameter;
+    use feos_core::parameter::{IdentifierOption, Parameter};
+
+    #[test]
+    fn invalid_record() {
+        assert!(AssociationRecord::new(0.03, 2500.0, Some(1.0), None).is_ok());
+        assert!(AssociationRecord::new(0.0, 0.0, None, None).is_ok());
+        let err = AssociationRecord::new(0.03, -2500.0, None, None).unwrap_err();
+        assert!(err.to_string().contains("epsilon_k_ab ="));
+        let err = AssociationRecord::new(-0.03, 2500.0, None, None).unwrap_err();
+        assert!(err.to_string().contains("kappa_ab ="));
+        let err = AssociationRecord::new(0.03, 2500.0, Some(0.0), None).unwrap_err();
+        assert!(err.to_string().contains("na ="));
+        let err = AssociationRecord::new(0.03, 2500.0, None, Some(-1.0)).unwrap_err();
+        assert!(err.to_string().contains("nb ="));
+        let record = AssociationRecord::new(0.03, 2500.0, None, None).unwrap();
+        let err = record.with_cd_sites(0.03, 2500.0, 1.0, 0.0).unwrap_err();
+        assert!(err.to_string().contains("nd ="));
+        let err = record.with_cd_sites(-0.03, 2500.0, 1.0, 1.0).unwrap_err();
+        assert!(err.to_string().contains("kappa_cd ="));
+
+        // deserialized records are validated as well
+        let json = r#"{"kappa_ab": 0.03, "epsilon_k_ab": 2500.0, "nc": -1.0}"#;
+        let err = serde_json::from_str::<AssociationRecord>(json).unwrap_err();
+        assert!(err.to_string().contains("nc ="));
+        let json = r#"{"kappa_ab": 0.03, "epsilon_k_ab": 2500.0, "epsilon_k_cd": -1.0}"#;
+        let err = serde_json::from_str::<AssociationRecord>(json).unwrap_err();
+        assert!(err.to_string().contains("epsilon_k_cd ="));
+        let json = r#"{"m": 1.0, "sigma": 3.0, "epsilon_k": 300.0, "kappa_ab": -0.03, "epsilon_k_ab": 2500.0}"#;
+        let err = serde_json::from_str::<PcSaftRecord>(json).unwrap_err();
+        assert!(err.to_string().contains("kappa_ab ="));
+        let json = r#"{"m": 1.0, "sigma": 3.0, "epsilon_k": 300.0}"#;
+        let record: PcSaftRecord = serde_json::from_str(json).unwrap();
+        assert!(record.association_record.is_none());
+    }
 
     #[test]
     fn helmholtz_energy() {
@@ -421,6 +1042,229 @@ mod tests_pcsaft {
         let a_cross_assoc = cross_assoc.helmholtz_energy(&s) / n;
         assert_relative_eq!(a_assoc, a_cross_assoc, epsilon = 1e-10);
     }
+
+    #[test]
+    fn helmholtz_energy_cross_4c() {
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let helmholtz_energy = |kappa_cd, epsilon_k_cd| {
+            let mut params = water_parameters();
+            let mut record = params.pure_records.pop().unwrap();
+            let association_record = record.model_record.association_record.unwrap();
+            record.model_record.association_record = Some(
+                association_record
+                    .with_cd_sites(kappa_cd, epsilon_k_cd, 1.0, 1.0)
+                    .unwrap(),
+            );
+            let params = Arc::new(PcSaftParameters::new_pure(record));
+            let assoc = Association::new(&params, &params.association, 50, 1e-10);
+            let cross_assoc =
+                Association::new_cross_association(&params, &params.association, 50, 1e-10);
+            let a_assoc = assoc.helmholtz_energy(&s) / n;
+            let a_cross_assoc = cross_assoc.helmholtz_energy(&s) / n;
+            assert_relative_eq!(a_assoc, a_cross_assoc, epsilon = 1e-10);
+            a_assoc
+        };
+
+        // with the parameters of the A-B pair, the C-D pair contributes as much as the A-B pair
+        assert_relative_eq!(
+            helmholtz_energy(0.034867983, 2500.6706),
+            2.0 * -4.229878997054543,
+            epsilon = 1e-10
+        );
+
+        // 4C water with a weaker C-D pair compared to the analytic solution of
+        // Wertheim's theory for two independent pairs of sites
+        assert_relative_eq!(
+            helmholtz_energy(0.01, 1500.0),
+            -5.252051893077513,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn site_fractions() -> EosResult<()> {
+        let params = Arc::new(water_parameters());
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let x = assoc.site_fractions(&s)?;
+
+        // compare to the analytic solution for a pure component
+        let diameter = params.hs_diameter(t);
+        let [zeta2, n3] = params.zeta(t, &s.partial_density, [2, 3]);
+        let n3i = (1.0 - n3).recip();
+        let deltarho =
+            assoc.association_strength(t, &diameter, zeta2 * 6.0, n3i, 1.0, 0)[(0, 0)] * n / v;
+        let xa = Association::<PcSaftParameters>::assoc_site_frac_ab(deltarho, 1.0, 1.0);
+        assert_eq!(x.len(), 2);
+        assert_relative_eq!(x[0], xa, epsilon = 1e-10);
+        assert_relative_eq!(x[1], xa, epsilon = 1e-10);
+
+        // sites of all types are free in the ideal gas limit
+        let s = StateHD::new(t, 1e20, arr1(&[n]));
+        assert_relative_eq!(
+            assoc.site_fractions(&s)?,
+            arr1(&[1.0, 1.0]),
+            epsilon = 1e-10
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn helmholtz_energy_combining_rules() {
+        let params = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["water", "methanol"],
+                "parameters/pcsaft/gross2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let records: Vec<_> = params
+            .pure_records
+            .iter()
+            .map(|r| r.model_record.association_record)
+            .collect();
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let s = StateHD::new(t, v, arr1(&[0.6, 0.4]));
+        let a: Vec<_> = [
+            AssociationCombiningRule::Geometric,
+            AssociationCombiningRule::CR1,
+            AssociationCombiningRule::Elliott,
+        ]
+        .iter()
+        .map(|&rule| {
+            let association_parameters =
+                AssociationParameters::new(&records, &params.sigma, None, rule, None);
+            let diag = association_parameters.sigma3_kappa_aibj.diag().to_owned();
+            assert_relative_eq!(
+                diag,
+                params.association.sigma3_kappa_aibj.diag(),
+                max_relative = 1e-14
+            );
+            Association::new(&params, &association_parameters, 50, 1e-10).helmholtz_energy(&s)
+        })
+        .collect();
+
+        // the default combining rule is unchanged
+        assert_relative_eq!(
+            a[0],
+            Association::new(&params, &params.association, 50, 1e-10).helmholtz_energy(&s),
+            epsilon = 1e-14
+        );
+        assert!((a[0] - a[1]).abs() > 1e-6);
+        assert!((a[0] - a[2]).abs() > 1e-6);
+        assert!((a[1] - a[2]).abs() > 1e-6);
+    }
+
+    #[test]
+    fn helmholtz_energy_not_converged() {
+        let params = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["water", "methanol"],
+                "parameters/pcsaft/gross2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let assoc = Association::new(&params, &params.association, 1, 1e-10);
+        let s = StateHD::new(350.0, 41.248289328513216, arr1(&[0.6, 0.4]));
+        match assoc.try_helmholtz_energy(&s) {
+            Err(EosError::AssociationNotConverged {
+                temperature,
+                density,
+            }) => {
+                assert_eq!(temperature, 350.0);
+                assert_relative_eq!(density, 1.0 / 41.248289328513216);
+            }
+            _ => panic!("expected AssociationNotConverged"),
+        }
+        assert!(assoc.helmholtz_energy(&s).is_nan());
+    }
+
+    #[test]
+    fn helmholtz_energy_solvation() {
+        let params = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["carbon-dioxide", "water_np"],
+                "tests/pcsaft/test_parameters.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let solvation = params
+            .association
+            .clone()
+            .with_solvation(&[(0, 1, 0.05, 1500.0)], &params.sigma);
+        assert_eq!(solvation.assoc_comp, arr1(&[1, 0]));
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+        let assoc_solvation = Association::new(&params, &solvation, 50, 1e-10);
+
+        // carbon dioxide alone does not associate
+        let s = StateHD::new(300.0, 100.0, arr1(&[1.0, 0.0]));
+        assert_relative_eq!(assoc_solvation.helmholtz_energy(&s), 0.0, epsilon = 1e-10);
+
+        // the solvation of carbon dioxide increases the association in the mixture
+        let s = StateHD::new(300.0, 41.248289328513216, arr1(&[0.3, 0.7]));
+        let a = assoc.helmholtz_energy(&s);
+        let a_solvation = assoc_solvation.helmholtz_energy(&s);
+        assert!(a_solvation < a);
+    }
+
+    #[test]
+    fn cross_association_energy_correction() {
+        let params = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["ethanol", "water"],
+                "parameters/pcsaft/gross2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let records: Vec<_> = params
+            .pure_records
+            .iter()
+            .map(|r| r.model_record.association_record)
+            .collect();
+        let rule = AssociationCombiningRule::default();
+        let beta = arr2(&[[0.0, 0.05], [0.05, 0.0]]);
+        let uncorrected = AssociationParameters::new(&records, &params.sigma, None, rule, None);
+        let corrected =
+            AssociationParameters::new(&records, &params.sigma, None, rule, Some(&beta));
+
+        // without corrections, the parameters are unchanged
+        assert_eq!(
+            uncorrected.epsilon_k_aibj,
+            params.association.epsilon_k_aibj
+        );
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let epsilon_k = uncorrected.epsilon_k_aibj[(i, j)];
+                let expected = if i == j {
+                    epsilon_k
+                } else {
+                    epsilon_k * (1.0 - beta[(i, j)])
+                };
+                assert_eq!(corrected.epsilon_k_aibj[(i, j)], expected);
+            }
+        }
+
+        let s = StateHD::new(350.0, 41.248289328513216, arr1(&[0.6, 0.4]));
+        let a_uncorrected = Association::new(&params, &uncorrected, 50, 1e-10).helmholtz_energy(&s);
+        let a_corrected = Association::new(&params, &corrected, 50, 1e-10).helmholtz_energy(&s);
+        assert!(a_corrected > a_uncorrected);
+    }
 }
 
 #[cfg(test)]
@@ -474,6 +1318,30 @@ mod tests_gc_pcsaft {
         assert_relative_eq!(pressure, -3.6819598891967344 * PASCAL, max_relative = 1e-10);
     }
 
+    #[test]
+    fn test_cross_assoc_derivatives_propanol() {
+        let params = Arc::new(propanol());
+        let contrib = Association::new(&params, &params.association, 50, 1e-10);
+        let contrib_cross =
+            Association::new_cross_association(&params, &params.association, 50, 1e-10);
+        let temperature = 300.0;
+        let volume = (1e-3 * METER.powi(3))
+            .to_reduced(EosUnit::reference_volume())
+            .unwrap();
+        let moles = (1.5 * MOL).to_reduced(EosUnit::reference_moles()).unwrap();
+        let state = StateHD::new(
+            Dual3_64::from(temperature),
+            Dual3_64::from(volume).derive(),
+            arr1(&[Dual3_64::from(moles)]),
+        );
+        let a = contrib.helmholtz_energy(&state);
+        let a_cross = contrib_cross.helmholtz_energy(&state);
+        assert_relative_eq!(a.re, a_cross.re, max_relative = 1e-8);
+        assert_relative_eq!(a.v1, a_cross.v1, max_relative = 1e-8);
+        assert_relative_eq!(a.v2, a_cross.v2, max_relative = 1e-8);
+        assert_relative_eq!(a.v3, a_cross.v3, max_relative = 1e-8);
+    }
+
     #[test]
     fn test_cross_assoc_ethanol_propanol() {
         let params = Arc::new(ethanol_propanol(false));
@@ -495,4 +1363,70 @@ mod tests_gc_pcsaft {
             -contrib.helmholtz_energy(&state).eps[0] * temperature * EosUnit::reference_pressure();
         assert_relative_eq!(pressure, -26.105606376765632 * PASCAL, max_relative = 1e-10);
     }
+
+    #[test]
+    fn test_cross_assoc_threshold_ethanol_propanol() {
+        let params = Arc::new(ethanol_propanol(false));
+        let [i, j] = [
+            params.association.assoc_comp[0],
+            params.association.assoc_comp[1],
+        ];
+        let threshold = params
+            .association
+            .clone()
+            .cross_association_threshold(i, j, 350.0, 20.0)
+            .unwrap();
+        let contrib = Association::new(&params, &params.association, 50, 1e-10);
+        let contrib_threshold = Association::new(&params, &threshold, 50, 1e-10);
+        let volume = METER
+            .powi(3)
+            .to_reduced(EosUnit::reference_volume())
+            .unwrap();
+        let moles = (arr1(&[1.5, 2.5]) * MOL)
+            .to_reduced(EosUnit::reference_moles())
+            .unwrap();
+        let a = |contrib: &Association<_>, temperature: f64| {
+            contrib.helmholtz_energy(&StateHD::new(temperature, volume, moles.clone()))
+        };
+
+        // unchanged below the switching interval
+        assert_relative_eq!(
+            a(&contrib_threshold, 320.0),
+            a(&contrib, 320.0),
+            max_relative = 1e-10
+        );
+
+        // continuous at the boundaries of the switching interval
+        for t in [330.0, 350.0] {
+            assert_relative_eq!(
+                a(&contrib_threshold, t - 1e-6),
+                a(&contrib_threshold, t + 1e-6),
+                max_relative = 1e-6
+            );
+        }
+
+        // cross association is reduced within and removed above the switching interval
+        let removed = params
+            .association
+            .clone()
+            .cross_association_threshold(i, j, 0.0, 1.0)
+            .unwrap();
+        let contrib_removed = Association::new(&params, &removed, 50, 1e-10);
+        assert!(a(&contrib, 340.0) < a(&contrib_threshold, 340.0));
+        assert!(a(&contrib_threshold, 340.0) < a(&contrib_removed, 340.0));
+        assert_relative_eq!(
+            a(&contrib_threshold, 360.0),
+            a(&contrib_removed, 360.0),
+            max_relative = 1e-10
+        );
+
+        // the switching interval needs a positive width
+        for width in [0.0, -1.0, f64::NAN] {
+            assert!(params
+                .association
+                .clone()
+                .cross_association_threshold(i, j, 350.0, width)
+                .is_err());
+        }
+    }
 }
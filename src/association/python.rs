@@ -6,17 +6,37 @@ use pyo3::prelude::*;
 /// Pure component association parameters
 #[pyclass(
     name = "AssociationRecord",
-    text_signature = "(kappa_ab, epsilon_k_ab, na=None, nb=None)"
+    text_signature = "(kappa_ab, epsilon_k_ab, na=None, nb=None, nc=None, nd=None, kappa_cd=None, epsilon_k_cd=None)"
 )]
 #[derive(Clone)]
 pub struct PyAssociationRecord(pub AssociationRecord);
 
 #[pymethods]
 impl PyAssociationRecord {
-    #[pyo3(signature = (kappa_ab, epsilon_k_ab, na=None, nb=None))]
+    #[pyo3(
+        signature = (kappa_ab, epsilon_k_ab, na=None, nb=None, nc=None, nd=None, kappa_cd=None, epsilon_k_cd=None)
+    )]
     #[new]
-    fn new(kappa_ab: f64, epsilon_k_ab: f64, na: Option<f64>, nb: Option<f64>) -> Self {
-        Self(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb))
+    fn new(
+        kappa_ab: f64,
+        epsilon_k_ab: f64,
+        na: Option<f64>,
+        nb: Option<f64>,
+        nc: Option<f64>,
+        nd: Option<f64>,
+        kappa_cd: Option<f64>,
+        epsilon_k_cd: Option<f64>,
+    ) -> PyResult<Self> {
+        Ok(Self(
+            AssociationRecord {
+                nc,
+                nd,
+                kappa_cd,
+                epsilon_k_cd,
+                ..AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb)?
+            }
+            .validate()?,
+        ))
     }
 
     #[getter]
@@ -39,6 +59,26 @@ impl PyAssociationRecord {
         self.0.nb
     }
 
+    #[getter]
+    fn get_nc(&self) -> Option<f64> {
+        self.0.nc
+    }
+
+    #[getter]
+    fn get_nd(&self) -> Option<f64> {
+        self.0.nd
+    }
+
+    #[getter]
+    fn get_kappa_cd(&self) -> Option<f64> {
+        self.0.kappa_cd
+    }
+
+    #[getter]
+    fn get_epsilon_k_cd(&self) -> Option<f64> {
+        self.0.epsilon_k_cd
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
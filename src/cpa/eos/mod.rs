@@ -0,0 +1,232 @@
+use super::parameters::CpaParameters;
+use crate::association::Association;
+use feos_core::cubic::PengRobinson;
+use feos_core::joback::Joback;
+use feos_core::parameter::Parameter;
+use feos_core::{EquationOfState, HelmholtzEnergy, IdealGasContribution, MolarWeight};
+use ndarray::Array1;
+use quantity::si::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+pub(crate) mod physical;
+use physical::Physical;
+
+/// Customization options for the CPA equation of state.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CpaOptions {
+    pub max_iter_cross_assoc: usize,
+    pub tol_cross_assoc: f64,
+}
+
+impl Default for CpaOptions {
+    fn default() -> Self {
+        Self {
+            max_iter_cross_assoc: 50,
+            tol_cross_assoc: 1e-10,
+        }
+    }
+}
+
+/// Cubic-Plus-Association equation of state.
+pub struct Cpa {
+    parameters: Arc<CpaParameters>,
+    options: CpaOptions,
+    peng_robinson: Arc<PengRobinson>,
+    ideal_gas: Joback,
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+}
+
+impl Cpa {
+    pub fn new(parameters: Arc<CpaParameters>) -> Self {
+        Self::with_options(parameters, CpaOptions::default())
+    }
+
+    pub fn with_options(parameters: Arc<CpaParameters>, options: CpaOptions) -> Self {
+        let peng_robinson = Arc::new(PengRobinson::new(parameters.cubic.clone()));
+        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(2);
+        contributions.push(Box::new(Physical {
+            peng_robinson: peng_robinson.clone(),
+        }));
+        if !parameters.association.assoc_comp.is_empty() {
+            contributions.push(Box::new(Association::new(
+                &parameters,
+                &parameters.association,
+                options.max_iter_cross_assoc,
+                options.tol_cross_assoc,
+            )));
+        };
+
+        let ideal_gas = parameters.joback_records.as_ref().map_or_else(
+            || Joback::default(parameters.b.len()),
+            |j| Joback::new(j.clone()),
+        );
+
+        Self {
+            parameters,
+            options,
+            peng_robinson,
+            ideal_gas,
+            contributions,
+        }
+    }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<CpaParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &CpaOptions {
+        &self.options
+    }
+}
+
+impl EquationOfState for Cpa {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.peng_robinson.compute_max_density(moles)
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl MolarWeight for Cpa {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpa::parameters::utils::water_parameters;
+    use crate::cpa::{CpaBinaryRecord, CpaRecord};
+    use approx::assert_relative_eq;
+    use feos_core::cubic::{PengRobinsonBinaryRecord, PengRobinsonParameters, PengRobinsonRecord};
+    use feos_core::joback::JobackRecord;
+    use feos_core::parameter::{Identifier, PureRecord};
+    use feos_core::{Contributions, HelmholtzEnergyDual, PhaseEquilibrium, SolverOptions, State};
+    use feos_core::{EosResult, StateHD};
+    use ndarray::arr1;
+
+    #[test]
+    fn helmholtz_energy() {
+        // reference values from the closed form of the association
+        // Helmholtz energy with the Carnahan-Starling contact value
+        let p = water_parameters();
+        let s = StateHD::new(350.0, 35.0, arr1(&[1.0]));
+        let a_phys = Cpa::new(p.clone()).residual()[0].helmholtz_energy(&s);
+        let a_assoc = Association::new(&p, &p.association, 50, 1e-10).helmholtz_energy(&s);
+        assert_relative_eq!(a_phys, -0.7093770068021397, max_relative = 1e-10);
+        assert_relative_eq!(a_assoc, -6.2553977287770035, max_relative = 1e-10);
+    }
+
+    #[test]
+    fn non_associating_peng_robinson() {
+        // CPA parameters that correspond to the Peng-Robinson equation of state
+        // for propane and n-butane with volume translations and a temperature
+        // dependent binary interaction parameter
+        let rgas = 8.31446261815324;
+        let components = [
+            (369.96, 4250000.0, 0.153, 44.0962, -1e-6),
+            (425.12, 3796000.0, 0.2002, 58.123, 2e-6),
+        ];
+        let cpa_records = components
+            .iter()
+            .map(|&(tc, pc, acentric_factor, mw, c)| {
+                let a0 = 0.45724 * (rgas * tc).powi(2) / pc;
+                let b = 0.07780 * rgas * tc / pc;
+                let c1 = 0.37464 + (1.54226 - 0.26992 * acentric_factor) * acentric_factor;
+                let record = CpaRecord::new(a0, b, c1, tc, None, None, None, None).unwrap();
+                PureRecord::new(
+                    Identifier::default(),
+                    mw,
+                    record.with_volume_translation(c),
+                    None,
+                )
+            })
+            .collect();
+        let pr_records = components
+            .iter()
+            .map(|&(tc, pc, acentric_factor, mw, c)| {
+                let record = PengRobinsonRecord::new(tc, pc, acentric_factor);
+                PureRecord::<_, JobackRecord>::new(
+                    Identifier::default(),
+                    mw,
+                    record.with_volume_translation(c),
+                    None,
+                )
+            })
+            .collect();
+        let cpa = Arc::new(Cpa::new(Arc::new(CpaParameters::new_binary(
+            cpa_records,
+            Some(CpaBinaryRecord::new(0.01, Some(1e-4))),
+        ))));
+        let pr = Arc::new(PengRobinson::new(Arc::new(
+            PengRobinsonParameters::new_binary(
+                pr_records,
+                Some(PengRobinsonBinaryRecord::new(0.01, Some(1e-4))),
+            ),
+        )));
+
+        let v = 1e-3 * METER.powi(3);
+        let n = arr1(&[0.3, 0.7]) * MOL;
+        for &t in &[250.0, 400.0] {
+            let s_cpa = State::new_nvt(&cpa, t * KELVIN, v, &n).unwrap();
+            let s_pr = State::new_nvt(&pr, t * KELVIN, v, &n).unwrap();
+            assert_relative_eq!(
+                s_cpa.pressure(Contributions::ResidualNvt),
+                s_pr.pressure(Contributions::ResidualNvt),
+                max_relative = 1e-8
+            );
+        }
+        assert_relative_eq!(
+            cpa.max_density(Some(&n)).unwrap(),
+            pr.max_density(Some(&n)).unwrap(),
+            max_relative = 1e-8
+        );
+    }
+
+    #[test]
+    fn vapor_pressure_water() -> EosResult<()> {
+        // reference values from an independent implementation of the
+        // same model (Peng-Robinson and 4C association with the
+        // Carnahan-Starling contact value)
+        let eos = Arc::new(Cpa::new(water_parameters()));
+        let vle = PhaseEquilibrium::pure(&eos, 373.15 * KELVIN, None, SolverOptions::default())?;
+        assert_relative_eq!(
+            vle.vapor().pressure(Contributions::Total),
+            110031.12058090652 * PASCAL,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            vle.liquid().density,
+            52334.86720663496 * MOL / METER.powi(3),
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(
+            vle.vapor().density,
+            36.63353685596883 * MOL / METER.powi(3),
+            max_relative = 1e-8
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use feos_core::cubic::PengRobinson;
+use feos_core::{EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, StateHD};
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// Physical contribution of the Peng-Robinson equation of state.
+///
+/// The contribution is evaluated by [PengRobinson], including the volume
+/// translation and the temperature dependent binary interaction parameters.
+pub struct Physical {
+    pub peng_robinson: Arc<PengRobinson>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Physical
+where
+    dyn HelmholtzEnergy: HelmholtzEnergyDual<D>,
+{
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        self.peng_robinson
+            .residual()
+            .iter()
+            .map(|c| c.helmholtz_energy(state))
+            .sum()
+    }
+}
+
+impl fmt::Display for Physical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Peng Robinson")
+    }
+}
@@ -0,0 +1,32 @@
+//! Cubic-Plus-Association (CPA) equation of state.
+//!
+//! The physical contribution is the Peng-Robinson equation of state with the
+//! Soave alpha function $a(T)=a_0\left(1+c_1\left(1-\sqrt{T/T_c}\right)\right)^2$,
+//! to which the generic [association](crate::association) contribution is added.
+//! The physical contribution is evaluated by [PengRobinson](feos_core::cubic::PengRobinson),
+//! so that volume translations and temperature dependent binary interaction
+//! parameters $k_{ij}(T)=k_{ij}+k_{ij,T}T$ can be used.
+//!
+//! The association strength of the generic implementation requires hard-sphere
+//! properties that are not part of a cubic equation of state. They are defined
+//! from the covolume $b_i$: the spherical monomers have the temperature independent
+//! diameter $d_i$ with $\frac{\pi}{6}d_i^3=\frac{b_i}{4}$, so that the packing fraction
+//! is $\eta=\frac{b\rho}{4}$ as in the original CPA, and the size parameter of the
+//! association volume is $\sigma_i^3=b_i$. The association strength of a pure component
+//! is then $\Delta=g(\eta)\left(e^{\varepsilon^{AB}/kT}-1\right)b\beta^{AB}$ with the
+//! Carnahan-Starling contact value $g(\eta)=\frac{1-\eta/2}{(1-\eta)^3}$ instead of the
+//! simplified $g(\eta)=\frac{1}{1-1.9\eta}$ of sCPA. The association volume
+//! $\beta^{AB}$ is given as `kappa_ab`.
+//!
+//! # Literature
+//! - [Kontogeorgis et al. (1996)](https://doi.org/10.1021/ie9600203)
+#![warn(clippy::all)]
+#![allow(clippy::too_many_arguments)]
+mod eos;
+pub(crate) mod parameters;
+
+pub use eos::{Cpa, CpaOptions};
+pub use parameters::{CpaBinaryRecord, CpaParameters, CpaRecord};
+
+#[cfg(feature = "python")]
+pub mod python;
@@ -0,0 +1,353 @@
+use crate::association::{
+    deserialize_association_record, AssociationCombiningRule, AssociationParameters,
+    AssociationRecord, ASSOCIATION_MISMATCH,
+};
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use feos_core::cubic::{PengRobinsonBinaryRecord, PengRobinsonParameters, PengRobinsonRecord};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use ndarray::{Array, Array1, Array2};
+use num_dual::DualNum;
+use num_traits::Zero;
+use quantity::si::{ANGSTROM, METER, MOL, NAV};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::f64::consts::FRAC_PI_6;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// CPA pure-component parameters.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct CpaRecord {
+    /// Attraction parameter in units of Pa m⁶/mol²
+    pub a0: f64,
+    /// Covolume in units of m³/mol
+    pub b: f64,
+    /// Coefficient of the Soave alpha function
+    pub c1: f64,
+    /// Critical temperature in units of Kelvin
+    pub tc: f64,
+    /// Volume translation in units of m³/mol
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub volume_translation: Option<f64>,
+    /// Association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "deserialize_association_record")]
+    pub association_record: Option<AssociationRecord>,
+}
+
+impl std::fmt::Display for CpaRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CpaRecord(a0={}", self.a0)?;
+        write!(f, ", b={}", self.b)?;
+        write!(f, ", c1={}", self.c1)?;
+        write!(f, ", tc={}", self.tc)?;
+        if let Some(c) = self.volume_translation {
+            write!(f, ", volume_translation={}", c)?;
+        }
+        if let Some(n) = &self.association_record {
+            write!(f, ", association_record={}", n)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl CpaRecord {
+    pub fn new(
+        a0: f64,
+        b: f64,
+        c1: f64,
+        tc: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+    ) -> Result<CpaRecord, ParameterError> {
+        let association_record = match (kappa_ab, epsilon_k_ab) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb)?)
+            }
+            (None, None) => None,
+            _ => {
+                return Err(ParameterError::IncompatibleParameters(
+                    ASSOCIATION_MISMATCH.into(),
+                ))
+            }
+        };
+        Ok(CpaRecord {
+            a0,
+            b,
+            c1,
+            tc,
+            volume_translation: None,
+            association_record,
+        })
+    }
+
+    /// Use a constant (Peneloux) volume translation in units of m³/mol.
+    pub fn with_volume_translation(mut self, c: f64) -> Self {
+        self.volume_translation = Some(c);
+        self
+    }
+
+    /// Pure component record of the physical (Peng-Robinson) contribution.
+    fn cubic_record(&self) -> PengRobinsonRecord {
+        let record =
+            PengRobinsonRecord::from_attraction_and_covolume(self.tc, self.a0, self.b, self.c1);
+        match self.volume_translation {
+            Some(c) => record.with_volume_translation(c),
+            None => record,
+        }
+    }
+}
+
+/// CPA binary mixture parameters.
+///
+/// The binary interaction parameter can depend linearly on temperature:
+/// $k_{ij}(T)=k_{ij}+k_{ij,T}T$
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct CpaBinaryRecord {
+    /// correction to the attraction parameter
+    pub k_ij: f64,
+    /// temperature dependence of the binary interaction parameter in 1/K
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<f64>,
+}
+
+impl CpaBinaryRecord {
+    pub fn new(k_ij: f64, k_ij_t: Option<f64>) -> Self {
+        Self { k_ij, k_ij_t }
+    }
+}
+
+impl From<f64> for CpaBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self { k_ij, k_ij_t: None }
+    }
+}
+
+impl TryFrom<CpaBinaryRecord> for f64 {
+    type Error = ParameterError;
+
+    fn try_from(binary_record: CpaBinaryRecord) -> Result<Self, Self::Error> {
+        match binary_record.k_ij_t {
+            None => Ok(binary_record.k_ij),
+            Some(_) => Err(ParameterError::IncompatibleParameters(String::from(
+                "a temperature dependent binary interaction parameter can not be converted to a single number.",
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for CpaBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CpaBinaryRecord(k_ij={}", self.k_ij)?;
+        if let Some(k_ij_t) = self.k_ij_t {
+            write!(f, ", k_ij_t={}", k_ij_t)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Parameter set required for the CPA equation of state.
+pub struct CpaParameters {
+    pub molarweight: Array1<f64>,
+    /// Parameters of the physical (Peng-Robinson) contribution
+    pub cubic: Arc<PengRobinsonParameters>,
+    /// Covolume in units of Å³
+    pub b: Array1<f64>,
+    /// Size parameter $\sigma_i=b_i^{1/3}$ of the association volume in units of Å
+    pub sigma: Array1<f64>,
+    pub association: AssociationParameters,
+    pub k_ij: Array2<f64>,
+    pub pure_records: Vec<PureRecord<CpaRecord, JobackRecord>>,
+    pub binary_records: Array2<CpaBinaryRecord>,
+    pub joback_records: Option<Vec<JobackRecord>>,
+}
+
+impl Parameter for CpaParameters {
+    type Pure = CpaRecord;
+    type IdealGas = JobackRecord;
+    type Binary = CpaBinaryRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<CpaBinaryRecord>,
+    ) -> Self {
+        let n = pure_records.len();
+
+        // conversion of the covolume from m³/mol to Å³
+        let m3_mol = (METER.powi(3) / MOL / NAV)
+            .to_reduced(ANGSTROM.powi(3))
+            .unwrap();
+
+        let mut molarweight = Array::zeros(n);
+        let mut b = Array::zeros(n);
+        let mut association_records = Vec::with_capacity(n);
+        let mut cubic_records = Vec::with_capacity(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            b[i] = r.b * m3_mol;
+            association_records.push(r.association_record);
+            molarweight[i] = record.molarweight;
+            cubic_records.push(PureRecord::new(
+                record.identifier.clone(),
+                record.molarweight,
+                r.cubic_record(),
+                None,
+            ));
+        }
+        let cubic = Arc::new(PengRobinsonParameters::from_records(
+            cubic_records,
+            binary_records.map(|br| PengRobinsonBinaryRecord::new(br.k_ij, br.k_ij_t)),
+        ));
+
+        let sigma = b.mapv(f64::cbrt);
+        let association = AssociationParameters::new(
+            &association_records,
+            &sigma,
+            None,
+            AssociationCombiningRule::default(),
+            None,
+        );
+
+        let k_ij = binary_records.map(|br| br.k_ij);
+
+        let joback_records = pure_records
+            .iter()
+            .map(|r| r.ideal_gas_record.clone())
+            .collect();
+
+        Self {
+            molarweight,
+            cubic,
+            b,
+            sigma,
+            association,
+            k_ij,
+            pure_records,
+            binary_records,
+            joback_records,
+        }
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<CpaRecord, JobackRecord>],
+        &Array2<CpaBinaryRecord>,
+    ) {
+        (&self.pure_records, &self.binary_records)
+    }
+}
+
+impl HardSphereProperties for CpaParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        MonomerShape::Spherical(self.b.len())
+    }
+
+    /// Temperature independent diameter with $\frac{\pi}{6}d_i^3=\frac{b_i}{4}$.
+    fn hs_diameter<D: DualNum<f64>>(&self, _: D) -> Array1<D> {
+        self.b.mapv(|b| D::from((0.25 * b / FRAC_PI_6).cbrt()))
+    }
+}
+
+impl CpaParameters {
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|$a_0$|$b$|$c_1$|$T_c$|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|\n|-|-|-|-|-|-|-|-|-|-|"
+        )
+        .unwrap();
+        for (i, record) in self.pure_records.iter().enumerate() {
+            let component = record.identifier.name.clone();
+            let component = component.unwrap_or(format!("Component {}", i + 1));
+            let association = record.model_record.association_record.unwrap_or_default();
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                component,
+                record.molarweight,
+                record.model_record.a0,
+                record.model_record.b,
+                record.model_record.c1,
+                record.model_record.tc,
+                association.kappa_ab,
+                association.epsilon_k_ab,
+                association.na.unwrap_or(1.0),
+                association.nb.unwrap_or(1.0)
+            )
+            .unwrap();
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for CpaParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CpaParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        let pure = |param: fn(&CpaRecord) -> f64| -> Array1<f64> {
+            self.pure_records
+                .iter()
+                .map(|r| param(&r.model_record))
+                .collect()
+        };
+        write!(f, "\n\ta0={}", pure(|r| r.a0))?;
+        write!(f, "\n\tb={}", self.b)?;
+        write!(f, "\n\tc1={}", pure(|r| r.c1))?;
+        write!(f, "\n\ttc={}", pure(|r| r.tc))?;
+        if !self.association.assoc_comp.is_empty() {
+            write!(f, "\n\tassociating={}", self.association.assoc_comp)?;
+            write!(f, "\n\tkappa_ab={}", self.association.kappa_ab)?;
+            write!(f, "\n\tepsilon_k_ab={}", self.association.epsilon_k_ab)?;
+            write!(f, "\n\tna={}", self.association.na)?;
+            write!(f, "\n\tnb={}", self.association.nb)?;
+        }
+        if !self.k_ij.iter().all(|k| k.is_zero()) {
+            write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+    use std::sync::Arc;
+
+    pub fn water_parameters() -> Arc<CpaParameters> {
+        let water_json = r#"
+            {
+                "identifier": {
+                    "cas": "7732-18-5",
+                    "name": "water",
+                    "iupac_name": "oxidane",
+                    "smiles": "O",
+                    "inchi": "InChI=1/H2O/h1H2",
+                    "formula": "H2O"
+                },
+                "model_record": {
+                    "a0": 0.12277,
+                    "b": 1.4515e-5,
+                    "c1": 0.67359,
+                    "tc": 647.29,
+                    "kappa_ab": 0.0692,
+                    "epsilon_k_ab": 2003.0,
+                    "na": 2.0,
+                    "nb": 2.0
+                },
+                "molarweight": 18.015
+            }"#;
+        let water_record: PureRecord<CpaRecord, JobackRecord> =
+            serde_json::from_str(water_json).expect("Unable to parse json.");
+        Arc::new(CpaParameters::new_pure(water_record))
+    }
+}
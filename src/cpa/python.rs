@@ -0,0 +1,215 @@
+//! Python bindings for the CPA equation of state.
+use super::parameters::{CpaBinaryRecord, CpaParameters, CpaRecord};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{
+    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+};
+use feos_core::python::joback::PyJobackRecord;
+use feos_core::python::parameter::*;
+use feos_core::*;
+use ndarray::Array2;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+/// Pure-component parameters of the CPA equation of state.
+///
+/// Parameters
+/// ----------
+/// a0 : float
+///     Attraction parameter in units of Pa m⁶/mol².
+/// b : float
+///     Covolume in units of m³/mol.
+/// c1 : float
+///     Coefficient of the Soave alpha function.
+/// tc : float
+///     Critical temperature in units of Kelvin.
+/// kappa_ab : float, optional
+///     Association volume parameter.
+/// epsilon_k_ab : float, optional
+///     Association energy parameter in units of Kelvin.
+/// na : float, optional
+///     Number of association sites of type A.
+/// nb : float, optional
+///     Number of association sites of type B.
+/// volume_translation : float, optional
+///     Volume translation in units of m³/mol.
+#[pyclass(name = "CpaRecord")]
+#[pyo3(
+    text_signature = "(a0, b, c1, tc, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None, volume_translation=None)"
+)]
+#[derive(Clone)]
+pub struct PyCpaRecord(CpaRecord);
+
+#[pymethods]
+impl PyCpaRecord {
+    #[new]
+    fn new(
+        a0: f64,
+        b: f64,
+        c1: f64,
+        tc: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+        volume_translation: Option<f64>,
+    ) -> PyResult<Self> {
+        let record = CpaRecord::new(a0, b, c1, tc, kappa_ab, epsilon_k_ab, na, nb)?;
+        Ok(Self(match volume_translation {
+            Some(c) => record.with_volume_translation(c),
+            None => record,
+        }))
+    }
+
+    #[getter]
+    fn get_a0(&self) -> f64 {
+        self.0.a0
+    }
+
+    #[getter]
+    fn get_b(&self) -> f64 {
+        self.0.b
+    }
+
+    #[getter]
+    fn get_c1(&self) -> f64 {
+        self.0.c1
+    }
+
+    #[getter]
+    fn get_tc(&self) -> f64 {
+        self.0.tc
+    }
+
+    #[getter]
+    fn get_volume_translation(&self) -> Option<f64> {
+        self.0.volume_translation
+    }
+
+    #[getter]
+    fn get_kappa_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.kappa_ab)
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.epsilon_k_ab)
+    }
+
+    #[getter]
+    fn get_na(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.na)
+    }
+
+    #[getter]
+    fn get_nb(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.nb)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyCpaRecord);
+
+impl_pure_record!(CpaRecord, PyCpaRecord, JobackRecord, PyJobackRecord);
+
+/// CPA binary interaction parameters.
+///
+/// Parameters
+/// ----------
+/// k_ij : float
+///     Binary interaction parameter.
+/// k_ij_t : float, optional
+///     Temperature dependence of the binary interaction parameter
+///     in units of 1/K, so that k_ij(T) = k_ij + k_ij_t * T.
+#[pyclass(name = "CpaBinaryRecord")]
+#[pyo3(text_signature = "(k_ij, k_ij_t=None)")]
+#[derive(Clone)]
+pub struct PyCpaBinaryRecord(CpaBinaryRecord);
+
+#[pymethods]
+impl PyCpaBinaryRecord {
+    #[new]
+    fn new(k_ij: f64, k_ij_t: Option<f64>) -> Self {
+        Self(CpaBinaryRecord::new(k_ij, k_ij_t))
+    }
+
+    #[getter]
+    fn get_k_ij(&self) -> f64 {
+        self.0.k_ij
+    }
+
+    #[getter]
+    fn get_k_ij_t(&self) -> Option<f64> {
+        self.0.k_ij_t
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_binary_record!(CpaBinaryRecord, PyCpaBinaryRecord);
+
+/// Create a set of CPA parameters from records.
+///
+/// Parameters
+/// ----------
+/// pure_records : List[PureRecord]
+///     pure substance records.
+/// binary_records : List[BinaryRecord], optional
+///     binary parameter records
+/// substances : List[str], optional
+///     The substances to use. Filters substances from `pure_records` according to
+///     `search_option`.
+///     When not provided, all entries of `pure_records` are used.
+/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+///     Identifier that is used to search substance.
+///
+/// Returns
+/// -------
+/// CpaParameters
+#[pyclass(name = "CpaParameters")]
+#[pyo3(
+    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
+)]
+#[derive(Clone)]
+pub struct PyCpaParameters(pub Arc<CpaParameters>);
+
+impl_parameter!(CpaParameters, PyCpaParameters);
+
+#[pymethods]
+impl PyCpaParameters {
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
+    fn _repr_markdown_(&self) -> String {
+        self.0.to_markdown()
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+#[pymodule]
+pub fn cpa(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyChemicalRecord>()?;
+    m.add_class::<PyJobackRecord>()?;
+
+    m.add_class::<PyCpaRecord>()?;
+    m.add_class::<PyCpaBinaryRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyCpaParameters>()?;
+    Ok(())
+}
@@ -1,20 +1,34 @@
+#[cfg(feature = "cpa")]
+use crate::cpa::{Cpa, CpaOptions, CpaParameters};
 #[cfg(feature = "gc_pcsaft")]
-use crate::gc_pcsaft::GcPcSaft;
+use crate::gc_pcsaft::{GcPcSaft, GcPcSaftChemicalRecord, GcPcSaftEosParameters, GcPcSaftOptions};
+#[cfg(feature = "lj")]
+use crate::lj::{LennardJones, LennardJonesParameters};
 #[cfg(feature = "pcsaft")]
-use crate::pcsaft::PcSaft;
+use crate::pcsaft::{PcSaft, PcSaftOptions, PcSaftParameters};
 #[cfg(feature = "pets")]
-use crate::pets::Pets;
+use crate::pets::{Pets, PetsOptions, PetsParameters};
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::{SaftVRMie, SaftVRMieOptions, SaftVRMieParameters};
 #[cfg(feature = "saftvrqmie")]
-use crate::saftvrqmie::SaftVRQMie;
+use crate::saftvrqmie::{SaftVRQMie, SaftVRQMieOptions, SaftVRQMieParameters};
 #[cfg(feature = "uvtheory")]
-use crate::uvtheory::UVTheory;
-use feos_core::cubic::PengRobinson;
+use crate::uvtheory::{UVParameters, UVTheory, UVTheoryOptions};
+use feos_core::cubic::{PengRobinson, PengRobinsonParameters, Srk};
+use feos_core::ideal_gas::{IdealGasModel, WithIdealGas};
+#[cfg(feature = "gc_pcsaft")]
+use feos_core::parameter::{BinaryRecord, ParameterHetero, SegmentRecord};
+use feos_core::parameter::{Parameter, ParameterError, PureRecord};
 #[cfg(feature = "python")]
-use feos_core::python::user_defined::PyEoSObj;
+use feos_core::python::user_defined::{PyCompositeEoS, PyEoSObj};
 use feos_core::*;
 use feos_derive::EquationOfState;
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use quantity::si::*;
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::Arc;
 
 /// Collection of different [EquationOfState] implementations.
 ///
@@ -30,15 +44,267 @@ pub enum EosVariant {
     GcPcSaft(GcPcSaft),
     #[implement(molar_weight)]
     PengRobinson(PengRobinson),
+    #[implement(molar_weight)]
+    Srk(Srk),
+    #[cfg(feature = "cpa")]
+    #[implement(molar_weight)]
+    Cpa(Cpa),
     #[cfg(feature = "python")]
     #[implement(molar_weight)]
     Python(PyEoSObj),
-    #[cfg(feature = "saftvrqmie")]
+    #[cfg(feature = "python")]
     #[implement(molar_weight)]
+    Composite(PyCompositeEoS<EosVariant>),
+    #[cfg(feature = "saftvrmie")]
+    #[implement(molar_weight)]
+    SaftVRMie(SaftVRMie),
+    #[cfg(feature = "saftvrqmie")]
+    #[implement(entropy_scaling, molar_weight)]
     SaftVRQMie(SaftVRQMie),
     #[cfg(feature = "pets")]
-    #[implement(molar_weight)]
+    #[implement(entropy_scaling, molar_weight)]
     Pets(Pets),
     #[cfg(feature = "uvtheory")]
     UVTheory(UVTheory),
+    #[cfg(feature = "lj")]
+    #[implement(molar_weight)]
+    LennardJones(LennardJones),
+    #[implement(molar_weight)]
+    WithIdealGas(WithIdealGas<EosVariant>),
+}
+
+/// Pure and binary records and options of an equation of state.
+#[derive(Serialize, Deserialize)]
+struct ModelRecord<M, I, B, O> {
+    pure_records: Vec<PureRecord<M, I>>,
+    binary_records: Array2<B>,
+    #[serde(default)]
+    options: O,
+}
+
+type Record<P, O> =
+    ModelRecord<<P as Parameter>::Pure, <P as Parameter>::IdealGas, <P as Parameter>::Binary, O>;
+
+impl<M: Clone, I: Clone, B: Clone, O> ModelRecord<M, I, B, O> {
+    fn new<P: Parameter<Pure = M, IdealGas = I, Binary = B>>(parameters: &P, options: O) -> Self {
+        let (pure_records, binary_records) = parameters.records();
+        Self {
+            pure_records: pure_records.to_vec(),
+            binary_records: binary_records.clone(),
+            options,
+        }
+    }
+
+    fn build<P: Parameter<Pure = M, IdealGas = I, Binary = B>>(
+        self,
+    ) -> Result<(Arc<P>, O), ParameterError> {
+        let n = self.pure_records.len();
+        if self.binary_records.dim() != (n, n) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "the binary records have the shape {:?}, but {} pure records are given.",
+                self.binary_records.shape(),
+                n
+            )));
+        }
+        let parameters = P::from_records(self.pure_records, self.binary_records);
+        Ok((Arc::new(parameters), self.options))
+    }
+}
+
+/// Chemical, segment and binary segment records and options of a
+/// group contribution equation of state.
+#[cfg(feature = "gc_pcsaft")]
+#[derive(Serialize, Deserialize)]
+struct HeteroModelRecord<C, M, I, B, O> {
+    chemical_records: Vec<C>,
+    segment_records: Vec<SegmentRecord<M, I>>,
+    binary_segment_records: Option<Vec<BinaryRecord<String, B>>>,
+    #[serde(default)]
+    options: O,
+}
+
+#[cfg(feature = "gc_pcsaft")]
+type HeteroRecord<P, O> = HeteroModelRecord<
+    <P as ParameterHetero>::Chemical,
+    <P as ParameterHetero>::Pure,
+    <P as ParameterHetero>::IdealGas,
+    <P as ParameterHetero>::Binary,
+    O,
+>;
+
+#[cfg(feature = "gc_pcsaft")]
+impl<C: Clone, M: Clone, I: Clone, B: Clone, O> HeteroModelRecord<C, M, I, B, O> {
+    fn new<P: ParameterHetero<Chemical = C, Pure = M, IdealGas = I, Binary = B>>(
+        parameters: &P,
+        options: O,
+    ) -> Self {
+        let (chemical_records, segment_records, binary_segment_records) = parameters.records();
+        Self {
+            chemical_records: chemical_records.to_vec(),
+            segment_records: segment_records.to_vec(),
+            binary_segment_records: binary_segment_records.clone(),
+            options,
+        }
+    }
+
+    fn build<P: ParameterHetero<Chemical = C, Pure = M, IdealGas = I, Binary = B>>(
+        self,
+    ) -> Result<(Arc<P>, O), ParameterError> {
+        let parameters = P::from_segments(
+            self.chemical_records,
+            self.segment_records,
+            self.binary_segment_records,
+        )?;
+        Ok((Arc::new(parameters), self.options))
+    }
+}
+
+/// Serialized representation of an [EosVariant].
+///
+/// The Helmholtz energy contributions can not be serialized directly. Instead,
+/// the records that were used to construct the parameters are stored together
+/// with the options and the equation of state is reconstructed from them.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "model")]
+enum EosVariantRecord {
+    #[cfg(feature = "pcsaft")]
+    PcSaft(Record<PcSaftParameters, PcSaftOptions>),
+    #[cfg(feature = "gc_pcsaft")]
+    GcPcSaft(HeteroRecord<GcPcSaftEosParameters, GcPcSaftOptions>),
+    PengRobinson(Record<PengRobinsonParameters, ()>),
+    Srk(Record<PengRobinsonParameters, ()>),
+    #[cfg(feature = "cpa")]
+    Cpa(Record<CpaParameters, CpaOptions>),
+    #[cfg(feature = "saftvrmie")]
+    SaftVRMie(Record<SaftVRMieParameters, SaftVRMieOptions>),
+    #[cfg(feature = "saftvrqmie")]
+    SaftVRQMie(Record<SaftVRQMieParameters, SaftVRQMieOptions>),
+    #[cfg(feature = "pets")]
+    Pets(Record<PetsParameters, PetsOptions>),
+    #[cfg(feature = "uvtheory")]
+    UVTheory(Record<UVParameters, UVTheoryOptions>),
+    #[cfg(feature = "lj")]
+    LennardJones(Record<LennardJonesParameters, ()>),
+    WithIdealGas {
+        eos: Arc<EosVariant>,
+        ideal_gas: IdealGasModel,
+    },
+}
+
+impl Serialize for EosVariant {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = match self {
+            #[cfg(feature = "pcsaft")]
+            Self::PcSaft(eos) => EosVariantRecord::PcSaft(ModelRecord::new(
+                eos.parameters().as_ref(),
+                *eos.options(),
+            )),
+            #[cfg(feature = "gc_pcsaft")]
+            Self::GcPcSaft(eos) => EosVariantRecord::GcPcSaft(HeteroModelRecord::new(
+                eos.parameters.as_ref(),
+                *eos.options(),
+            )),
+            Self::PengRobinson(eos) => {
+                EosVariantRecord::PengRobinson(ModelRecord::new(eos.parameters().as_ref(), ()))
+            }
+            Self::Srk(eos) => {
+                EosVariantRecord::Srk(ModelRecord::new(eos.parameters().as_ref(), ()))
+            }
+            #[cfg(feature = "cpa")]
+            Self::Cpa(eos) => {
+                EosVariantRecord::Cpa(ModelRecord::new(eos.parameters().as_ref(), *eos.options()))
+            }
+            #[cfg(feature = "python")]
+            Self::Python(_) | Self::Composite(_) => {
+                return Err(S::Error::custom(
+                    "Equations of state implemented in Python can not be serialized.",
+                ))
+            }
+            #[cfg(feature = "saftvrmie")]
+            Self::SaftVRMie(eos) => EosVariantRecord::SaftVRMie(ModelRecord::new(
+                eos.parameters().as_ref(),
+                *eos.options(),
+            )),
+            #[cfg(feature = "saftvrqmie")]
+            Self::SaftVRQMie(eos) => EosVariantRecord::SaftVRQMie(ModelRecord::new(
+                eos.parameters().as_ref(),
+                *eos.options(),
+            )),
+            #[cfg(feature = "pets")]
+            Self::Pets(eos) => {
+                EosVariantRecord::Pets(ModelRecord::new(eos.parameters().as_ref(), *eos.options()))
+            }
+            #[cfg(feature = "uvtheory")]
+            Self::UVTheory(eos) => EosVariantRecord::UVTheory(ModelRecord::new(
+                eos.parameters().as_ref(),
+                eos.options().clone(),
+            )),
+            #[cfg(feature = "lj")]
+            Self::LennardJones(eos) => {
+                EosVariantRecord::LennardJones(ModelRecord::new(eos.parameters().as_ref(), ()))
+            }
+            Self::WithIdealGas(eos) => EosVariantRecord::WithIdealGas {
+                eos: eos.eos().clone(),
+                ideal_gas: eos.ideal_gas_model().clone(),
+            },
+        };
+        record.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EosVariant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match EosVariantRecord::deserialize(deserializer)? {
+            #[cfg(feature = "pcsaft")]
+            EosVariantRecord::PcSaft(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::PcSaft(PcSaft::with_options(parameters, options))
+            }
+            #[cfg(feature = "gc_pcsaft")]
+            EosVariantRecord::GcPcSaft(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::GcPcSaft(GcPcSaft::with_options(parameters, options))
+            }
+            EosVariantRecord::PengRobinson(record) => Self::PengRobinson(PengRobinson::new(
+                record.build().map_err(D::Error::custom)?.0,
+            )),
+            EosVariantRecord::Srk(record) => {
+                Self::Srk(Srk::new(record.build().map_err(D::Error::custom)?.0))
+            }
+            #[cfg(feature = "cpa")]
+            EosVariantRecord::Cpa(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::Cpa(Cpa::with_options(parameters, options))
+            }
+            #[cfg(feature = "saftvrmie")]
+            EosVariantRecord::SaftVRMie(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::SaftVRMie(SaftVRMie::with_options(parameters, options))
+            }
+            #[cfg(feature = "saftvrqmie")]
+            EosVariantRecord::SaftVRQMie(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::SaftVRQMie(SaftVRQMie::with_options(parameters, options))
+            }
+            #[cfg(feature = "pets")]
+            EosVariantRecord::Pets(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::Pets(Pets::with_options(parameters, options))
+            }
+            #[cfg(feature = "uvtheory")]
+            EosVariantRecord::UVTheory(record) => {
+                let (parameters, options) = record.build().map_err(D::Error::custom)?;
+                Self::UVTheory(
+                    UVTheory::with_options(parameters, options).map_err(D::Error::custom)?,
+                )
+            }
+            #[cfg(feature = "lj")]
+            EosVariantRecord::LennardJones(record) => Self::LennardJones(LennardJones::new(
+                record.build().map_err(D::Error::custom)?.0,
+            )),
+            EosVariantRecord::WithIdealGas { eos, ideal_gas } => {
+                Self::WithIdealGas(WithIdealGas::new(eos, ideal_gas).map_err(D::Error::custom)?)
+            }
+        })
+    }
 }
@@ -57,7 +57,12 @@ impl GcPcSaftFunctional {
 
         // Association
         if !parameters.association.assoc_comp.is_empty() {
-            let assoc = Association::new(
+            let association = if saft_options.force_cross_association {
+                Association::new_cross_association
+            } else {
+                Association::new
+            };
+            let assoc = association(
                 &parameters,
                 &parameters.association,
                 saft_options.max_iter_cross_assoc,
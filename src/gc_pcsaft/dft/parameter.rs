@@ -1,4 +1,4 @@
-use crate::association::AssociationParameters;
+use crate::association::{AssociationCombiningRule, AssociationParameters};
 use crate::gc_pcsaft::record::GcPcSaftRecord;
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
@@ -123,8 +123,13 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
         // Association
         let sigma = Array1::from_vec(sigma);
         let component_index = Array1::from_vec(component_index);
-        let association =
-            AssociationParameters::new(&association_records, &sigma, Some(&component_index));
+        let association = AssociationParameters::new(
+            &association_records,
+            &sigma,
+            Some(&component_index),
+            AssociationCombiningRule::default(),
+            None,
+        );
 
         Ok(Self {
             molarweight,
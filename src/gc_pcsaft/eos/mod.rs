@@ -1,10 +1,11 @@
 use crate::association::Association;
-use crate::hard_sphere::HardSphere;
+use crate::hard_sphere::{HardSphere, HardSphereProperties};
 use feos_core::joback::Joback;
 use feos_core::parameter::ParameterHetero;
 use feos_core::{EquationOfState, HelmholtzEnergy, IdealGasContribution, MolarWeight};
 use ndarray::Array1;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -18,7 +19,8 @@ pub use parameter::{GcPcSaftChemicalRecord, GcPcSaftEosParameters};
 use polar::Dipole;
 
 /// Customization options for the gc-PC-SAFT equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GcPcSaftOptions {
     /// maximum packing fraction
     pub max_eta: f64,
@@ -26,6 +28,8 @@ pub struct GcPcSaftOptions {
     pub max_iter_cross_assoc: usize,
     /// tolerance for cross association calculation
     pub tol_cross_assoc: f64,
+    /// use the cross association solver even if an analytic solution exists
+    pub force_cross_association: bool,
 }
 
 impl Default for GcPcSaftOptions {
@@ -34,6 +38,7 @@ impl Default for GcPcSaftOptions {
             max_eta: 0.5,
             max_iter_cross_assoc: 50,
             tol_cross_assoc: 1e-10,
+            force_cross_association: false,
         }
     }
 }
@@ -61,7 +66,12 @@ impl GcPcSaft {
             parameters: parameters.clone(),
         }));
         if !parameters.association.assoc_comp.is_empty() {
-            contributions.push(Box::new(Association::new(
+            let association = if options.force_cross_association {
+                Association::new_cross_association
+            } else {
+                Association::new
+            };
+            contributions.push(Box::new(association(
                 &parameters,
                 &parameters.association,
                 options.max_iter_cross_assoc,
@@ -81,6 +91,11 @@ impl GcPcSaft {
             ),
         }
     }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &GcPcSaftOptions {
+        &self.options
+    }
 }
 
 impl EquationOfState for GcPcSaft {
@@ -106,6 +121,10 @@ impl EquationOfState for GcPcSaft {
         &self.contributions
     }
 
+    fn segment_numbers(&self) -> Array1<f64> {
+        self.parameters.segment_numbers()
+    }
+
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
         &self.joback
     }
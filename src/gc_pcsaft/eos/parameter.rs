@@ -1,4 +1,4 @@
-use crate::association::AssociationParameters;
+use crate::association::{AssociationCombiningRule, AssociationParameters};
 use crate::gc_pcsaft::record::GcPcSaftRecord;
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use feos_core::joback::JobackRecord;
@@ -10,17 +10,37 @@ use indexmap::IndexMap;
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use quantity::si::{JOULE, KB, KELVIN};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GcPcSaftChemicalRecord {
     pub identifier: Identifier,
     pub segments: HashMap<String, f64>,
+    #[serde(serialize_with = "serialize_bonds")]
+    #[serde(deserialize_with = "deserialize_bonds")]
     pub bonds: HashMap<[String; 2], f64>,
 }
 
+/// The bonds are stored as a list of pairs because json
+/// only supports strings as keys of maps.
+fn serialize_bonds<S: Serializer>(
+    bonds: &HashMap<[String; 2], f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(bonds.iter())
+}
+
+fn deserialize_bonds<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<[String; 2], f64>, D::Error> {
+    Ok(Vec::<([String; 2], f64)>::deserialize(deserializer)?
+        .into_iter()
+        .collect())
+}
+
 impl GcPcSaftChemicalRecord {
     pub fn new(
         identifier: Identifier,
@@ -228,8 +248,13 @@ impl ParameterHetero for GcPcSaftEosParameters {
         // Association
         let sigma = Array1::from_vec(sigma);
         let component_index = Array1::from_vec(component_index);
-        let association =
-            AssociationParameters::new(&association_records, &sigma, Some(&component_index));
+        let association = AssociationParameters::new(
+            &association_records,
+            &sigma,
+            Some(&component_index),
+            AssociationCombiningRule::default(),
+            None,
+        );
 
         Ok(Self {
             molarweight,
@@ -426,7 +451,7 @@ pub mod test {
                 2.7702,
                 334.29,
                 None,
-                Some(AssociationRecord::new(0.009583, 2575.9, None, None)),
+                Some(AssociationRecord::new(0.009583, 2575.9, None, None).unwrap()),
                 None,
             ),
             None,
@@ -503,4 +528,23 @@ pub mod test {
             (181.49f64 * 334.29).sqrt() * 1.0087
         );
     }
+
+    #[test]
+    fn test_kij_intramolecular() {
+        // the segment interaction only applies between segments of different molecules
+        let params = ethanol_propanol(true);
+        let segment = |id: &str, component: usize| {
+            (0..params.identifiers.len())
+                .find(|&i| params.identifiers[i] == id && params.component_index[i] == component)
+                .unwrap()
+        };
+        let ch3 = [segment("CH3", 0), segment("CH3", 1)];
+        let oh = [segment("OH", 0), segment("OH", 1)];
+        for i in 0..2 {
+            assert_eq!(params.k_ij[(ch3[i], oh[i])], 0.0);
+            assert_eq!(params.k_ij[(ch3[i], oh[1 - i])], -0.0087);
+        }
+        let params = ethanol_propanol(false);
+        assert!(params.k_ij.iter().all(|&k| k == 0.0));
+    }
 }
@@ -10,7 +10,6 @@ use feos_core::parameter::{
 use feos_core::python::joback::PyJobackRecord;
 use feos_core::python::parameter::{PyBinarySegmentRecord, PyChemicalRecord, PyIdentifier};
 use feos_core::{impl_json_handling, impl_parameter_from_segments, impl_segment_record};
-#[cfg(feature = "dft")]
 use numpy::{PyArray2, ToPyArray};
 use pyo3::prelude::*;
 use std::sync::Arc;
@@ -99,6 +98,11 @@ impl PyGcPcSaftEosParameters {
         self.0.to_markdown()
     }
 
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
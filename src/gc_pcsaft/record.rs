@@ -1,4 +1,4 @@
-use crate::association::AssociationRecord;
+use crate::association::{deserialize_association_record, AssociationRecord};
 use serde::{Deserialize, Serialize};
 
 /// gc-PC-SAFT pure-component parameters.
@@ -16,6 +16,7 @@ pub struct GcPcSaftRecord {
     /// Association parameters
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "deserialize_association_record")]
     pub association_record: Option<AssociationRecord>,
     /// interaction range parameter for the dispersion functional
     #[serde(skip_serializing_if = "Option::is_none")]
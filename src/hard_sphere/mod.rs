@@ -42,6 +42,18 @@ pub trait HardSphereProperties {
         }
     }
 
+    /// The total segment number $m_i$ of every component.
+    fn segment_numbers(&self) -> Array1<f64> {
+        let m = self.geometry_coefficients(1.0)[0].clone();
+        let component_index = self.component_index();
+        let components = component_index.iter().max().map_or(0, |&i| i + 1);
+        let mut segments = Array1::zeros(components);
+        for (&m, &i) in m.iter().zip(component_index.iter()) {
+            segments[i] += m;
+        }
+        segments
+    }
+
     /// The geometry coefficients $C_{k,\alpha}$ for every segment.
     fn geometry_coefficients<D: DualNum<f64>>(&self, temperature: D) -> [Array1<D>; 4] {
         match self.monomer_shape(temperature) {
@@ -48,12 +48,18 @@ pub mod association;
 pub mod hard_sphere;
 
 // models
+#[cfg(feature = "cpa")]
+pub mod cpa;
 #[cfg(feature = "gc_pcsaft")]
 pub mod gc_pcsaft;
+#[cfg(feature = "lj")]
+pub mod lj;
 #[cfg(feature = "pcsaft")]
 pub mod pcsaft;
 #[cfg(feature = "pets")]
 pub mod pets;
+#[cfg(feature = "saftvrmie")]
+pub mod saftvrmie;
 #[cfg(feature = "saftvrqmie")]
 pub mod saftvrqmie;
 #[cfg(feature = "uvtheory")]
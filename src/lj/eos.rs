@@ -0,0 +1,285 @@
+use super::parameters::LennardJonesParameters;
+use feos_core::joback::Joback;
+use feos_core::parameter::Parameter;
+use feos_core::{
+    EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, IdealGasContribution, MolarWeight,
+    StateHD,
+};
+use ndarray::Array1;
+use num_dual::DualNum;
+use quantity::si::*;
+use std::fmt;
+use std::sync::Arc;
+
+/// Maximum reduced density $\rho^*=\rho\sigma^3$ of the fluid.
+const MAX_DENSITY: f64 = 1.2;
+
+/// Nonlinear parameter $\gamma$ of the MBWR equation of state.
+const GAMMA: f64 = 3.0;
+
+/// Coefficients $x_1$ to $x_{32}$ of the MBWR equation of state (Johnson et al., Table 10).
+const X: [f64; 32] = [
+    0.8623085097507421,
+    2.976218765822098,
+    -8.402230115796038,
+    0.1054136629203555,
+    -0.8564583828174598,
+    1.582759470107601,
+    0.7639421948305453,
+    1.753173414312048,
+    2.798291772190376e3,
+    -4.8394220260857657e-2,
+    0.9963265197721935,
+    -3.698000291272493e1,
+    2.084012299434647e1,
+    8.305402124717285e1,
+    -9.574799715203068e2,
+    -1.477746229234994e2,
+    6.398607852471505e1,
+    1.603993673294834e1,
+    6.805916615864377e1,
+    -2.791293578795945e3,
+    -6.245128304568454,
+    -8.116836104958410e3,
+    1.488735559561229e1,
+    -1.059346754655084e4,
+    -1.131607632802822e2,
+    -8.867771540418822e3,
+    -3.986982844450543e1,
+    -4.689270299917261e3,
+    2.593535277438717e2,
+    -2.694523589434903e3,
+    -7.218487631550215e2,
+    1.721802063863269e2,
+];
+
+/// Residual Helmholtz energy per particle $a^*=A^\mathrm{res}/N\varepsilon$ of the
+/// pure Lennard-Jones fluid as function of reduced temperature and density.
+fn reduced_helmholtz_energy<D: DualNum<f64>>(temperature: D, density: D) -> D {
+    let x = &X;
+    let t = temperature;
+    let ti = t.recip();
+    let ti2 = ti * ti;
+    let ti3 = ti2 * ti;
+    let ti4 = ti2 * ti2;
+
+    // coefficients of the polynomial terms
+    let a = [
+        t * x[0] + t.sqrt() * x[1] + x[2] + ti * x[3] + ti2 * x[4],
+        t * x[5] + x[6] + ti * x[7] + ti2 * x[8],
+        t * x[9] + x[10] + ti * x[11],
+        D::from(x[12]),
+        ti * x[13] + ti2 * x[14],
+        ti * x[15],
+        ti * x[16] + ti2 * x[17],
+        ti2 * x[18],
+    ];
+
+    // coefficients of the exponential terms
+    let b = [
+        ti2 * x[19] + ti3 * x[20],
+        ti2 * x[21] + ti4 * x[22],
+        ti2 * x[23] + ti3 * x[24],
+        ti2 * x[25] + ti4 * x[26],
+        ti2 * x[27] + ti3 * x[28],
+        ti2 * x[29] + ti3 * x[30] + ti4 * x[31],
+    ];
+
+    let rho2 = density * density;
+    let f = (-rho2 * GAMMA).exp();
+    let mut g = -(f - 1.0) / (2.0 * GAMMA);
+    let mut a_res = b[0] * g;
+    let mut rho_2k = D::one();
+    for (k, &b) in b.iter().enumerate().skip(1) {
+        rho_2k *= rho2;
+        g = -(f * rho_2k - g * (2 * k) as f64) / (2.0 * GAMMA);
+        a_res += b * g;
+    }
+
+    let mut rho_i = D::one();
+    for (i, &a) in a.iter().enumerate() {
+        rho_i *= density;
+        a_res += a * rho_i / (i + 1) as f64;
+    }
+    a_res
+}
+
+/// Residual Helmholtz energy of the Lennard-Jones fluid.
+struct JohnsonZollwegGubbins {
+    parameters: Arc<LennardJonesParameters>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for JohnsonZollwegGubbins {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let n = p.sigma.len();
+
+        // van der Waals one-fluid mixing rules
+        let mut sigma3 = D::zero();
+        let mut epsilon_k_sigma3 = D::zero();
+        for i in 0..n {
+            for j in 0..n {
+                let xx = state.molefracs[i] * state.molefracs[j];
+                let s3 = p.sigma_ij[[i, j]].powi(3);
+                sigma3 += xx * s3;
+                epsilon_k_sigma3 += xx * (s3 * p.epsilon_k_ij[[i, j]]);
+            }
+        }
+        let epsilon_k = epsilon_k_sigma3 / sigma3;
+
+        let t = state.temperature / epsilon_k;
+        let rho = state.partial_density.sum() * sigma3;
+        state.moles.sum() * reduced_helmholtz_energy(t, rho) / t
+    }
+}
+
+impl fmt::Display for JohnsonZollwegGubbins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Lennard-Jones (Johnson et al.)")
+    }
+}
+
+/// Equation of state for Lennard-Jones fluids and mixtures.
+pub struct LennardJones {
+    parameters: Arc<LennardJonesParameters>,
+    ideal_gas: Joback,
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+}
+
+impl LennardJones {
+    pub fn new(parameters: Arc<LennardJonesParameters>) -> Self {
+        let contributions: Vec<Box<dyn HelmholtzEnergy>> = vec![Box::new(JohnsonZollwegGubbins {
+            parameters: parameters.clone(),
+        })];
+
+        let ideal_gas = parameters.joback_records.as_ref().map_or_else(
+            || Joback::default(parameters.sigma.len()),
+            |j| Joback::new(j.clone()),
+        );
+
+        Self {
+            parameters,
+            ideal_gas,
+            contributions,
+        }
+    }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<LennardJonesParameters> {
+        &self.parameters
+    }
+}
+
+impl EquationOfState for LennardJones {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        MAX_DENSITY * moles.sum() / (moles * &self.parameters.sigma.mapv(|s| s.powi(3))).sum()
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    fn ideal_gas(&self) -> &dyn IdealGasContribution {
+        &self.ideal_gas
+    }
+}
+
+impl MolarWeight for LennardJones {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lj::LennardJonesRecord;
+    use approx::assert_relative_eq;
+    use feos_core::joback::JobackRecord;
+    use feos_core::parameter::{Identifier, PureRecord};
+    use feos_core::{Contributions, EosUnit, State};
+    use ndarray::arr1;
+
+    fn argon() -> PureRecord<LennardJonesRecord, JobackRecord> {
+        PureRecord::new(
+            Identifier::default(),
+            39.948,
+            LennardJonesRecord::new(3.405, 119.8),
+            None,
+        )
+    }
+
+    #[test]
+    fn pressure() {
+        // pressure of the MBWR equation of state in closed form (Johnson et al., Eq. 7)
+        let (t, rho) = (1.5, 0.6);
+        let x = &X;
+        let a = [
+            x[0] * t + x[1] * t.sqrt() + x[2] + x[3] / t + x[4] / t.powi(2),
+            x[5] * t + x[6] + x[7] / t + x[8] / t.powi(2),
+            x[9] * t + x[10] + x[11] / t,
+            x[12],
+            x[13] / t + x[14] / t.powi(2),
+            x[15] / t,
+            x[16] / t + x[17] / t.powi(2),
+            x[18] / t.powi(2),
+        ];
+        let b = [
+            x[19] / t.powi(2) + x[20] / t.powi(3),
+            x[21] / t.powi(2) + x[22] / t.powi(4),
+            x[23] / t.powi(2) + x[24] / t.powi(3),
+            x[25] / t.powi(2) + x[26] / t.powi(4),
+            x[27] / t.powi(2) + x[28] / t.powi(3),
+            x[29] / t.powi(2) + x[30] / t.powi(3) + x[31] / t.powi(4),
+        ];
+        let f = (-GAMMA * rho * rho).exp();
+        let p_res: f64 = (0..8).map(|i| a[i] * rho.powi(i as i32 + 2)).sum::<f64>()
+            + f * (0..6)
+                .map(|i| b[i] * rho.powi(2 * i as i32 + 3))
+                .sum::<f64>();
+
+        let parameters = Arc::new(LennardJonesParameters::new_pure(argon()));
+        let (sigma, epsilon_k) = (3.405, 119.8);
+        let eos = Arc::new(LennardJones::new(parameters));
+        let moles = arr1(&[1.0]) * MOL;
+        let state = State::new_nvt(
+            &eos,
+            t * epsilon_k * KELVIN,
+            MOL / (rho / (sigma * ANGSTROM).powi(3) / NAV),
+            &moles,
+        )
+        .unwrap();
+        let p = state
+            .pressure(Contributions::ResidualNvt)
+            .to_reduced(SIUnit::reference_pressure())
+            .unwrap();
+        assert_relative_eq!(p * sigma.powi(3) / epsilon_k, p_res, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn mixture_of_identical_components() {
+        let pure = Arc::new(LennardJones::new(Arc::new(
+            LennardJonesParameters::new_pure(argon()),
+        )));
+        let mixture = Arc::new(LennardJones::new(Arc::new(
+            LennardJonesParameters::new_binary(vec![argon(), argon()], None),
+        )));
+        let t = 150.0 * KELVIN;
+        let v = 1e-4 * METER.powi(3);
+        let s_pure = State::new_nvt(&pure, t, v, &(arr1(&[1.0]) * MOL)).unwrap();
+        let s_mix = State::new_nvt(&mixture, t, v, &(arr1(&[0.3, 0.7]) * MOL)).unwrap();
+        assert_relative_eq!(
+            s_pure.pressure(Contributions::ResidualNvt),
+            s_mix.pressure(Contributions::ResidualNvt),
+            max_relative = 1e-12
+        );
+    }
+}
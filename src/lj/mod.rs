@@ -0,0 +1,22 @@
+//! Equation of state for the Lennard-Jones fluid.
+//!
+//! The residual Helmholtz energy of the pure Lennard-Jones fluid is given by the
+//! modified Benedict-Webb-Rubin equation of state of Johnson, Zollweg and Gubbins
+//! that was fitted to simulation data in reduced units $T^*=kT/\varepsilon$ and
+//! $\rho^*=\rho\sigma^3$. Mixtures are described with the van der Waals one-fluid
+//! theory, i.e., $\sigma_x^3=\sum_{ij}x_ix_j\sigma_{ij}^3$ and
+//! $\varepsilon_x\sigma_x^3=\sum_{ij}x_ix_j\varepsilon_{ij}\sigma_{ij}^3$ with the
+//! Lorentz-Berthelot combining rules for $\sigma_{ij}$ and $\varepsilon_{ij}$.
+//!
+//! # Literature
+//! - [Johnson et al. (1993)](https://doi.org/10.1080/00268979300100411)
+#![warn(clippy::all)]
+#![allow(clippy::too_many_arguments)]
+mod eos;
+mod parameters;
+
+pub use eos::LennardJones;
+pub use parameters::{LennardJonesBinaryRecord, LennardJonesParameters, LennardJonesRecord};
+
+#[cfg(feature = "python")]
+pub mod python;
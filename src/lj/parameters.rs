@@ -0,0 +1,185 @@
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, PureRecord};
+use ndarray::{Array, Array1, Array2};
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Lennard-Jones parameters for a pure substance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LennardJonesRecord {
+    /// Size parameter in units of Angstrom
+    pub sigma: f64,
+    /// Energy parameter in units of Kelvin
+    pub epsilon_k: f64,
+}
+
+impl std::fmt::Display for LennardJonesRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesRecord(sigma={}", self.sigma)?;
+        write!(f, ", epsilon_k={})", self.epsilon_k)
+    }
+}
+
+impl LennardJonesRecord {
+    /// New Lennard-Jones parameters for a pure substance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use feos::lj::LennardJonesRecord;
+    /// let record = LennardJonesRecord::new(3.405, 119.8);
+    /// ```
+    pub fn new(sigma: f64, epsilon_k: f64) -> LennardJonesRecord {
+        LennardJonesRecord { sigma, epsilon_k }
+    }
+}
+
+/// Parameters that modify binary interactions.
+///
+/// $\varepsilon_{k,ij} = (1 - k_{ij})\sqrt{\varepsilon_{k,i} \varepsilon_{k,j}}$
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct LennardJonesBinaryRecord {
+    k_ij: f64,
+}
+
+impl From<f64> for LennardJonesBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self { k_ij }
+    }
+}
+
+impl From<LennardJonesBinaryRecord> for f64 {
+    fn from(binary_record: LennardJonesBinaryRecord) -> Self {
+        binary_record.k_ij
+    }
+}
+
+impl std::fmt::Display for LennardJonesBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesBinaryRecord(k_ij={})", self.k_ij)
+    }
+}
+
+/// Parameter set required for the Lennard-Jones equation of state.
+pub struct LennardJonesParameters {
+    /// molar weight in gram per mole
+    pub molarweight: Array1<f64>,
+    /// Lennard-Jones diameter in Angstrom
+    pub sigma: Array1<f64>,
+    /// Lennard-Jones energy parameter in Kelvin
+    pub epsilon_k: Array1<f64>,
+    /// binary interaction parameter
+    pub k_ij: Array2<f64>,
+    /// diameter matrix
+    pub sigma_ij: Array2<f64>,
+    /// energy parameter matrix including k_ij
+    pub epsilon_k_ij: Array2<f64>,
+    /// records of all pure substances of the system
+    pub pure_records: Vec<PureRecord<LennardJonesRecord, JobackRecord>>,
+    /// records of all binary interaction parameters
+    pub binary_records: Array2<LennardJonesBinaryRecord>,
+    /// records of parameters for Joback method
+    pub joback_records: Option<Vec<JobackRecord>>,
+}
+
+impl Parameter for LennardJonesParameters {
+    type Pure = LennardJonesRecord;
+    type IdealGas = JobackRecord;
+    type Binary = LennardJonesBinaryRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<LennardJonesBinaryRecord>,
+    ) -> Self {
+        let n = pure_records.len();
+
+        let mut molarweight = Array::zeros(n);
+        let mut sigma = Array::zeros(n);
+        let mut epsilon_k = Array::zeros(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            sigma[i] = r.sigma;
+            epsilon_k[i] = r.epsilon_k;
+            molarweight[i] = record.molarweight;
+        }
+
+        // Lorentz-Berthelot combining rules
+        let k_ij = binary_records.map(|br| br.k_ij);
+        let mut sigma_ij = Array::zeros((n, n));
+        let mut epsilon_k_ij = Array::zeros((n, n));
+        for i in 0..n {
+            for j in 0..n {
+                sigma_ij[[i, j]] = 0.5 * (sigma[i] + sigma[j]);
+                epsilon_k_ij[[i, j]] = (1.0 - k_ij[[i, j]]) * (epsilon_k[i] * epsilon_k[j]).sqrt();
+            }
+        }
+
+        let joback_records = pure_records
+            .iter()
+            .map(|r| r.ideal_gas_record.clone())
+            .collect();
+
+        Self {
+            molarweight,
+            sigma,
+            epsilon_k,
+            k_ij,
+            sigma_ij,
+            epsilon_k_ij,
+            pure_records,
+            binary_records,
+            joback_records,
+        }
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<LennardJonesRecord, JobackRecord>],
+        &Array2<LennardJonesBinaryRecord>,
+    ) {
+        (&self.pure_records, &self.binary_records)
+    }
+}
+
+impl LennardJonesParameters {
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|$\\sigma$|$\\varepsilon$|\n|-|-|-|-|"
+        )
+        .unwrap();
+        for (i, record) in self.pure_records.iter().enumerate() {
+            let component = record.identifier.name.clone();
+            let component = component.unwrap_or(format!("Component {}", i + 1));
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|",
+                component,
+                record.molarweight,
+                record.model_record.sigma,
+                record.model_record.epsilon_k,
+            )
+            .unwrap();
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for LennardJonesParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LennardJonesParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\tsigma={}", self.sigma)?;
+        write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
+        if !self.k_ij.iter().all(|k| k.is_zero()) {
+            write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
+        }
+        write!(f, "\n)")
+    }
+}
@@ -0,0 +1,121 @@
+//! Python bindings for the Lennard-Jones equation of state.
+use super::parameters::{LennardJonesBinaryRecord, LennardJonesParameters, LennardJonesRecord};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{
+    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+};
+use feos_core::python::joback::PyJobackRecord;
+use feos_core::python::parameter::*;
+use feos_core::*;
+use ndarray::Array2;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+/// Pure-component parameters of the Lennard-Jones equation of state.
+///
+/// Parameters
+/// ----------
+/// sigma : float
+///     Size parameter in units of Angstrom.
+/// epsilon_k : float
+///     Energy parameter in units of Kelvin.
+#[pyclass(name = "LennardJonesRecord")]
+#[pyo3(text_signature = "(sigma, epsilon_k)")]
+#[derive(Clone)]
+pub struct PyLennardJonesRecord(LennardJonesRecord);
+
+#[pymethods]
+impl PyLennardJonesRecord {
+    #[new]
+    fn new(sigma: f64, epsilon_k: f64) -> Self {
+        Self(LennardJonesRecord::new(sigma, epsilon_k))
+    }
+
+    #[getter]
+    fn get_sigma(&self) -> f64 {
+        self.0.sigma
+    }
+
+    #[getter]
+    fn get_epsilon_k(&self) -> f64 {
+        self.0.epsilon_k
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyLennardJonesRecord);
+
+impl_pure_record!(
+    LennardJonesRecord,
+    PyLennardJonesRecord,
+    JobackRecord,
+    PyJobackRecord
+);
+
+#[pyclass(name = "LennardJonesBinaryRecord")]
+#[derive(Clone)]
+pub struct PyLennardJonesBinaryRecord(LennardJonesBinaryRecord);
+impl_binary_record!(LennardJonesBinaryRecord, PyLennardJonesBinaryRecord);
+
+/// Create a set of Lennard-Jones parameters from records.
+///
+/// Parameters
+/// ----------
+/// pure_records : List[PureRecord]
+///     pure substance records.
+/// binary_records : List[BinaryRecord], optional
+///     binary parameter records
+/// substances : List[str], optional
+///     The substances to use. Filters substances from `pure_records` according to
+///     `search_option`.
+///     When not provided, all entries of `pure_records` are used.
+/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+///     Identifier that is used to search substance.
+///
+/// Returns
+/// -------
+/// LennardJonesParameters
+#[pyclass(name = "LennardJonesParameters")]
+#[pyo3(
+    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
+)]
+#[derive(Clone)]
+pub struct PyLennardJonesParameters(pub Arc<LennardJonesParameters>);
+
+impl_parameter!(LennardJonesParameters, PyLennardJonesParameters);
+
+#[pymethods]
+impl PyLennardJonesParameters {
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
+    fn _repr_markdown_(&self) -> String {
+        self.0.to_markdown()
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+#[pymodule]
+pub fn lj(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyChemicalRecord>()?;
+    m.add_class::<PyJobackRecord>()?;
+
+    m.add_class::<PyLennardJonesRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyLennardJonesParameters>()?;
+    Ok(())
+}
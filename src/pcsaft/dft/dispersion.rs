@@ -90,7 +90,7 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for Attracti
         let mut rho2mix: Array1<N> = Array::zeros(eta.raw_dim());
         for i in 0..n {
             for j in 0..n {
-                let eps_ij_t = temperature.recip() * p.epsilon_k_ij[(i, j)];
+                let eps_ij_t = p.epsilon_ij_t(i, j, temperature);
                 let sigma_ij_3 = p.sigma_ij[(i, j)].powi(3);
                 rho1mix = rho1mix
                     + (&density.index_axis(Axis(0), i) * &density.index_axis(Axis(0), j))
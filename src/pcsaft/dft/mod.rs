@@ -77,9 +77,14 @@ impl PcSaftFunctional {
 
             // Association
             if !parameters.association.assoc_comp.is_empty() {
-                let assoc = Association::new(
+                let association = if saft_options.force_cross_association {
+                    Association::new_cross_association
+                } else {
+                    Association::new
+                };
+                let assoc = association(
                     &parameters,
-                    &parameters.association,
+                    &parameters.association_parameters(saft_options.association_combining_rule),
                     saft_options.max_iter_cross_assoc,
                     saft_options.tol_cross_assoc,
                 );
@@ -148,12 +153,13 @@ impl FluidParameters for PcSaftFunctional {
 }
 
 impl PairPotential for PcSaftFunctional {
-    fn pair_potential(&self, i: usize, r: &Array1<f64>, _: f64) -> Array2<f64> {
-        let sigma_ij = &self.parameters.sigma_ij;
-        let eps_ij_4 = 4.0 * &self.parameters.epsilon_k_ij;
-        Array2::from_shape_fn((self.parameters.m.len(), r.len()), |(j, k)| {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, temperature: f64) -> Array2<f64> {
+        let p = &self.parameters;
+        let sigma_ij = &p.sigma_ij;
+        Array2::from_shape_fn((p.m.len(), r.len()), |(j, k)| {
+            let eps_ij_4 = 4.0 * p.epsilon_ij_t(i, j, temperature) * temperature;
             let att = (sigma_ij[[i, j]] / r[k]).powi(6);
-            eps_ij_4[[i, j]] * att * (att - 1.0)
+            eps_ij_4 * att * (att - 1.0)
         })
     }
 }
@@ -122,24 +122,21 @@ impl<N: DualNum<f64> + ScalarOperand> FunctionalContributionDual<N> for PureFMTA
             });
 
             let k = &n2 * &n3m1rec * r;
-            let deltarho = (((&k / 18.0 + 0.5) * &k * &xi + 1.0) * n3m1rec)
-                * ((temperature.recip() * a.epsilon_k_aibj[(0, 0)]).exp_m1()
-                    * a.sigma3_kappa_aibj[(0, 0)])
-                * (&n0 / p.m[0] * &xi);
-
-            let f = |x: N| x.ln() - x * 0.5 + 0.5;
-            phi = phi
-                + if a.nb[0] > 0.0 {
-                    let xa = deltarho.mapv(|d| {
-                        Association::<PcSaftParameters>::assoc_site_frac_ab(d, a.na[0], a.nb[0])
-                    });
-                    let xb = (xa.clone() - 1.0) * a.na[0] / a.nb[0] + 1.0;
-                    (n0 / p.m[0] * xi) * (xa.mapv(f) * a.na[0] + xb.mapv(f) * a.nb[0])
-                } else {
-                    let xa = deltarho
-                        .mapv(|d| Association::<PcSaftParameters>::assoc_site_frac_a(d, a.na[0]));
-                    n0 / p.m[0] * xi * (xa.mapv(f) * a.na[0])
-                };
+            let deltarho = |site_pair| {
+                let [sigma3_kappa, epsilon_k] = a.site_pair(site_pair);
+                (((&k / 18.0 + 0.5) * &k * &xi + 1.0) * &n3m1rec)
+                    * ((temperature.recip() * epsilon_k[(0, 0)]).exp_m1() * sigma3_kappa[(0, 0)])
+                    * (&n0 / p.m[0] * &xi)
+            };
+
+            let f_pair = |d: N, na: f64, nb: f64| {
+                Association::<PcSaftParameters>::helmholtz_energy_site_pair(d, na, nb)
+            };
+            let mut f = deltarho(0).mapv(|d| f_pair(d, a.na[0], a.nb[0]));
+            if a.has_cd_sites() {
+                f += &deltarho(1).mapv(|d| f_pair(d, a.nc[0], a.nd[0]));
+            }
+            phi = phi + (n0 / p.m[0] * xi) * f;
         }
 
         Ok(phi)
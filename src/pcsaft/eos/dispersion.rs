@@ -86,7 +86,7 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
         let mut rho2mix = D::zero();
         for i in 0..n {
             for j in 0..n {
-                let eps_ij = state.temperature.recip() * p.epsilon_k_ij[(i, j)];
+                let eps_ij = p.epsilon_ij_t(i, j, state.temperature);
                 let sigma_ij = p.sigma_ij[[i, j]].powi(3);
                 rho1mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * sigma_ij;
                 rho2mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * eps_ij * sigma_ij;
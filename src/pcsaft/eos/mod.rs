@@ -1,6 +1,7 @@
 use super::parameters::PcSaftParameters;
-use crate::association::Association;
-use crate::hard_sphere::HardSphere;
+use crate::association::{Association, AssociationCombiningRule};
+use crate::hard_sphere::{HardSphere, HardSphereProperties};
+use feos_core::ideal_gas::ThermalDeBroglie;
 use feos_core::joback::Joback;
 use feos_core::parameter::Parameter;
 use feos_core::{
@@ -9,6 +10,7 @@ use feos_core::{
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
@@ -26,15 +28,32 @@ use qspr::QSPR;
 enum IdealGasContributions {
     QSPR(QSPR),
     Joback(Joback),
+    ThermalDeBroglie(ThermalDeBroglie),
 }
 
 /// Customization options for the PC-SAFT equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PcSaftOptions {
     pub max_eta: f64,
     pub max_iter_cross_assoc: usize,
     pub tol_cross_assoc: f64,
     pub dq_variant: DQVariants,
+    /// Include the dipolar contribution for components with a dipole moment.
+    pub dipole: bool,
+    /// Include the quadrupolar contribution for components with a quadrupole moment.
+    pub quadrupole: bool,
+    /// Include the dipole-quadrupole cross contribution in mixtures of dipolar
+    /// and quadrupolar components.
+    pub dipole_quadrupole: bool,
+    /// Use the iterative cross association solver even if the site
+    /// fractions can be calculated analytically.
+    pub force_cross_association: bool,
+    /// Combining rule for the cross association parameters.
+    pub association_combining_rule: AssociationCombiningRule,
+    /// Use the thermal de Broglie wavelength as ideal gas contribution of the
+    /// equation of state instead of the Joback or QSPR model.
+    pub thermal_de_broglie: bool,
 }
 
 impl Default for PcSaftOptions {
@@ -44,6 +63,12 @@ impl Default for PcSaftOptions {
             max_iter_cross_assoc: 50,
             tol_cross_assoc: 1e-10,
             dq_variant: DQVariants::DQ35,
+            dipole: true,
+            quadrupole: true,
+            dipole_quadrupole: true,
+            force_cross_association: false,
+            association_combining_rule: AssociationCombiningRule::default(),
+            thermal_de_broglie: false,
         }
     }
 }
@@ -70,43 +95,66 @@ impl PcSaft {
         contributions.push(Box::new(Dispersion {
             parameters: parameters.clone(),
         }));
-        if parameters.ndipole > 0 {
+        if options.dipole && parameters.ndipole > 0 {
             contributions.push(Box::new(Dipole {
                 parameters: parameters.clone(),
             }));
         };
-        if parameters.nquadpole > 0 {
+        if options.quadrupole && parameters.nquadpole > 0 {
             contributions.push(Box::new(Quadrupole {
                 parameters: parameters.clone(),
             }));
         };
-        if parameters.ndipole > 0 && parameters.nquadpole > 0 {
+        if options.dipole_quadrupole && parameters.ndipole > 0 && parameters.nquadpole > 0 {
             contributions.push(Box::new(DipoleQuadrupole {
                 parameters: parameters.clone(),
                 variant: options.dq_variant,
             }));
         };
         if !parameters.association.assoc_comp.is_empty() {
-            contributions.push(Box::new(Association::new(
+            let association = if options.force_cross_association {
+                Association::new_cross_association
+            } else {
+                Association::new
+            };
+            contributions.push(Box::new(association(
                 &parameters,
-                &parameters.association,
+                &parameters.association_parameters(options.association_combining_rule),
                 options.max_iter_cross_assoc,
                 options.tol_cross_assoc,
             )));
         };
 
-        let joback_records = parameters.joback_records.clone();
+        let ideal_gas = if options.thermal_de_broglie {
+            IdealGasContributions::ThermalDeBroglie(ThermalDeBroglie::new(
+                parameters.molarweight.clone(),
+            ))
+        } else {
+            parameters.joback_records.clone().map_or(
+                IdealGasContributions::QSPR(QSPR {
+                    parameters: parameters.clone(),
+                }),
+                |joback_records| IdealGasContributions::Joback(Joback::new(joback_records)),
+            )
+        };
 
         Self {
-            parameters: parameters.clone(),
+            parameters,
             options,
             contributions,
-            ideal_gas: joback_records.map_or(
-                IdealGasContributions::QSPR(QSPR { parameters }),
-                |joback_records| IdealGasContributions::Joback(Joback::new(joback_records)),
-            ),
+            ideal_gas,
         }
     }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<PcSaftParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &PcSaftOptions {
+        &self.options
+    }
 }
 
 impl EquationOfState for PcSaft {
@@ -131,10 +179,15 @@ impl EquationOfState for PcSaft {
         &self.contributions
     }
 
+    fn segment_numbers(&self) -> Array1<f64> {
+        self.parameters.segment_numbers()
+    }
+
     fn ideal_gas(&self) -> &dyn IdealGasContribution {
         match &self.ideal_gas {
             IdealGasContributions::QSPR(qspr) => qspr,
             IdealGasContributions::Joback(joback) => joback,
+            IdealGasContributions::ThermalDeBroglie(ideal_gas) => ideal_gas,
         }
     }
 }
@@ -341,9 +394,12 @@ impl EntropyScaling for PcSaft {
 mod tests {
     use super::*;
     use crate::pcsaft::parameters::utils::{
-        butane_parameters, propane_butane_parameters, propane_parameters, water_parameters,
+        butane_parameters, dme_co2_parameters, propane_butane_parameters, propane_parameters,
+        water_parameters,
     };
+    use crate::pcsaft::parameters::PcSaftBinaryRecord;
     use approx::assert_relative_eq;
+    use feos_core::parameter::IdentifierOption;
     use feos_core::*;
     use ndarray::arr1;
     use quantity::si::{BAR, KELVIN, METER, PASCAL, RGAS, SECOND};
@@ -380,6 +436,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ideal_gas_heat_capacity_de_broglie() {
+        let options = PcSaftOptions {
+            thermal_de_broglie: true,
+            ..Default::default()
+        };
+        let e = Arc::new(PcSaft::with_options(propane_parameters(), options));
+        let t = 200.0 * KELVIN;
+        let v = 1e-3 * METER.powi(3);
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_nvt(&e, t, v, &n).unwrap();
+        assert_relative_eq!(
+            s.c_v(Contributions::IdealGas),
+            1.5 * RGAS,
+            max_relative = 1e-10
+        );
+    }
+
     #[test]
     fn hard_sphere() {
         let hs = HardSphere::new(&propane_parameters());
@@ -410,6 +484,46 @@ mod tests {
         assert_relative_eq!(a2, a2m, epsilon = 1e-14);
     }
 
+    #[test]
+    fn polar_options() {
+        let parameters = Arc::new(dme_co2_parameters());
+        let s = StateHD::new(300.0, 100.0, arr1(&[0.3, 0.7]));
+        let full = PcSaft::new(parameters.clone()).evaluate_residual_contributions(&s);
+        let names: Vec<_> = full.iter().map(|(n, _)| n.as_str()).collect();
+        assert!(names.contains(&"Dipole"));
+        assert!(names.contains(&"Quadrupole"));
+        assert!(names.contains(&"DipoleQuadrupole"));
+
+        let options = PcSaftOptions {
+            dipole_quadrupole: false,
+            ..Default::default()
+        };
+        let eos = PcSaft::with_options(parameters.clone(), options);
+        let a_dq = full
+            .iter()
+            .find(|(n, _)| n == "DipoleQuadrupole")
+            .map(|(_, a)| *a)
+            .unwrap();
+        assert_eq!(eos.residual().len(), full.len() - 1);
+        assert_relative_eq!(
+            eos.evaluate_residual(&s),
+            full.iter().map(|(_, a)| a).sum::<f64>() - a_dq,
+            max_relative = 1e-14
+        );
+
+        let options = PcSaftOptions {
+            dipole: false,
+            quadrupole: false,
+            dipole_quadrupole: false,
+            ..Default::default()
+        };
+        let eos = PcSaft::with_options(parameters, options);
+        assert!(eos
+            .residual()
+            .iter()
+            .all(|c| !c.to_string().contains("pole")));
+    }
+
     #[test]
     fn association() {
         let parameters = Arc::new(water_parameters());
@@ -435,6 +549,152 @@ mod tests {
         assert_relative_eq!(a_rust, -4.229878997054543, epsilon = 1e-10);
     }
 
+    #[test]
+    fn force_cross_association() {
+        let parameters = Arc::new(water_parameters());
+        let options = PcSaftOptions {
+            force_cross_association: true,
+            ..Default::default()
+        };
+        let eos = PcSaft::with_options(parameters.clone(), options);
+        let s = StateHD::new(350.0, 41.248289328513216, arr1(&[1.23]));
+        assert_relative_eq!(
+            eos.evaluate_residual(&s),
+            PcSaft::new(parameters).evaluate_residual(&s),
+            max_relative = 1e-10
+        );
+    }
+
+    #[test]
+    fn association_combining_rule() {
+        let parameters = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["water", "methanol"],
+                "parameters/pcsaft/gross2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let options = PcSaftOptions {
+            association_combining_rule: AssociationCombiningRule::Elliott,
+            ..Default::default()
+        };
+        let eos = PcSaft::with_options(parameters.clone(), options);
+        let s = StateHD::new(350.0, 41.248289328513216, arr1(&[0.6, 0.4]));
+        let a_default = PcSaft::new(parameters).evaluate_residual(&s);
+        assert!((eos.evaluate_residual(&s) - a_default).abs() > 1e-6);
+
+        // the combining rule is kept for a subset of the components
+        let subset = eos.subset(&[1, 0]);
+        assert_eq!(
+            subset.options().association_combining_rule,
+            AssociationCombiningRule::Elliott
+        );
+        let s_subset = StateHD::new(350.0, 41.248289328513216, arr1(&[0.4, 0.6]));
+        assert_relative_eq!(
+            subset.evaluate_residual(&s_subset),
+            eos.evaluate_residual(&s),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn association_binary_correction() {
+        let parameters = PcSaftParameters::from_json(
+            vec!["water", "methanol"],
+            "parameters/pcsaft/gross2002.json",
+            None,
+            IdentifierOption::Name,
+        )
+        .unwrap();
+        let binary_record = PcSaftBinaryRecord::new(0.0, None).with_beta_assoc(0.05);
+        let corrected = Arc::new(PcSaftParameters::new_binary(
+            parameters.pure_records.clone(),
+            Some(binary_record),
+        ));
+        let epsilon_k = &parameters.association.epsilon_k_aibj;
+        let epsilon_k_corrected = &corrected.association.epsilon_k_aibj;
+        assert_eq!(epsilon_k_corrected[(0, 0)], epsilon_k[(0, 0)]);
+        assert_relative_eq!(
+            epsilon_k_corrected[(0, 1)],
+            epsilon_k[(0, 1)] * 0.95,
+            max_relative = 1e-14
+        );
+
+        // the correction is kept for a subset of the components
+        let eos = PcSaft::new(corrected);
+        let s = StateHD::new(350.0, 41.248289328513216, arr1(&[0.6, 0.4]));
+        let a_uncorrected = PcSaft::new(Arc::new(parameters)).evaluate_residual(&s);
+        assert!(eos.evaluate_residual(&s) > a_uncorrected);
+        let s_subset = StateHD::new(350.0, 41.248289328513216, arr1(&[0.4, 0.6]));
+        assert_relative_eq!(
+            eos.subset(&[1, 0]).evaluate_residual(&s_subset),
+            eos.evaluate_residual(&s),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn association_solvation() {
+        let parameters = PcSaftParameters::from_json(
+            vec!["carbon-dioxide", "water_np"],
+            "tests/pcsaft/test_parameters.json",
+            None,
+            IdentifierOption::Name,
+        )
+        .unwrap();
+        let binary_record = PcSaftBinaryRecord::new(0.0, None).with_solvation(0.05, 1500.0);
+        let solvation = Arc::new(PcSaftParameters::new_binary(
+            parameters.pure_records.clone(),
+            Some(binary_record),
+        ));
+        assert_eq!(solvation.association.assoc_comp, arr1(&[1, 0]));
+
+        // the solvation adds an association contribution to the mixture
+        let eos = PcSaft::new(solvation);
+        let s = StateHD::new(300.0, 41.248289328513216, arr1(&[0.3, 0.7]));
+        let a = PcSaft::new(Arc::new(parameters)).evaluate_residual(&s);
+        assert!(eos.evaluate_residual(&s) < a);
+
+        // the solvation is kept for a subset of the components
+        let s_subset = StateHD::new(300.0, 41.248289328513216, arr1(&[0.7, 0.3]));
+        assert_relative_eq!(
+            eos.subset(&[1, 0]).evaluate_residual(&s_subset),
+            eos.evaluate_residual(&s),
+            max_relative = 1e-12
+        );
+    }
+
+    #[test]
+    fn association_not_converged() {
+        let parameters = Arc::new(
+            PcSaftParameters::from_json(
+                vec!["water", "methanol"],
+                "parameters/pcsaft/gross2002.json",
+                None,
+                IdentifierOption::Name,
+            )
+            .unwrap(),
+        );
+        let options = PcSaftOptions {
+            max_iter_cross_assoc: 1,
+            ..Default::default()
+        };
+        let eos = Arc::new(PcSaft::with_options(parameters, options));
+        let t = 350.0 * KELVIN;
+        let n = arr1(&[0.6, 0.4]) * MOL;
+        let state = State::new_nvt(&eos, t, 2.5e-5 * METER.powi(3), &n).unwrap();
+        assert!(matches!(
+            state.try_residual_helmholtz_energy(),
+            Err(EosError::AssociationNotConverged { .. })
+        ));
+        assert!(matches!(
+            State::new_npt(&eos, t, BAR, &n, DensityInitialization::Liquid),
+            Err(EosError::AssociationNotConverged { .. })
+        ));
+    }
+
     #[test]
     fn new_tpn() {
         let e = Arc::new(PcSaft::new(propane_parameters()));
@@ -3,6 +3,7 @@ use crate::hard_sphere::HardSphereProperties;
 use feos_core::{HelmholtzEnergyDual, StateHD};
 use ndarray::prelude::*;
 use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_3, PI};
 use std::fmt;
 use std::sync::Arc;
@@ -312,7 +313,7 @@ impl fmt::Display for Quadrupole {
 }
 
 /// Different combination rules used in the dipole-quadrupole contribution.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum DQVariants {
     DQ35,
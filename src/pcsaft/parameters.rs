@@ -1,4 +1,7 @@
-use crate::association::{AssociationParameters, AssociationRecord};
+use crate::association::{
+    deserialize_association_record, AssociationCombiningRule, AssociationParameters,
+    AssociationRecord, ASSOCIATION_MISMATCH,
+};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use conv::ValueInto;
 use feos_core::joback::JobackRecord;
@@ -11,6 +14,7 @@ use num_traits::Zero;
 use quantity::si::{JOULE, KB, KELVIN};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt::Write;
 
 /// PC-SAFT pure-component parameters.
@@ -31,6 +35,7 @@ pub struct PcSaftRecord {
     /// Association parameters
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "deserialize_association_record")]
     pub association_record: Option<AssociationRecord>,
     /// Entropy scaling coefficients for the viscosity
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,13 +77,30 @@ impl FromSegments<f64> for PcSaftRecord {
                         record.epsilon_k_ab * n,
                         record.na.unwrap_or(1.0) * n,
                         record.nb.unwrap_or(1.0) * n,
+                        record.nc.unwrap_or(0.0) * n,
+                        record.nd.unwrap_or(0.0) * n,
+                        record.kappa_cd() * n,
+                        record.epsilon_k_cd() * n,
                     ]
                 })
             })
-            .reduce(|a, b| [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]])
-            .map(|[kappa_ab, epsilon_k_ab, na, nb]| {
-                AssociationRecord::new(kappa_ab, epsilon_k_ab, Some(na), Some(nb))
-            });
+            .reduce(|a, b| {
+                let mut c = a;
+                c.iter_mut().zip(b).for_each(|(c, b)| *c += b);
+                c
+            })
+            .map(
+                |[kappa_ab, epsilon_k_ab, na, nb, nc, nd, kappa_cd, epsilon_k_cd]| {
+                    let record =
+                        AssociationRecord::new(kappa_ab, epsilon_k_ab, Some(na), Some(nb))?;
+                    if nc > 0.0 || nd > 0.0 {
+                        record.with_cd_sites(kappa_cd, epsilon_k_cd, nc, nd)
+                    } else {
+                        Ok(record)
+                    }
+                },
+            )
+            .transpose()?;
 
         // entropy scaling
         let mut viscosity = if segments
@@ -212,17 +234,19 @@ impl PcSaftRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
-    ) -> PcSaftRecord {
+    ) -> Result<PcSaftRecord, ParameterError> {
         let association_record = match (kappa_ab, epsilon_k_ab) {
             (Some(kappa_ab), Some(epsilon_k_ab)) => {
-                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb))
+                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb)?)
             }
             (None, None) => None,
             _ => {
-                panic!("To model association, both kappa_ab and epsilon_k_ab need to be specified.")
+                return Err(ParameterError::IncompatibleParameters(
+                    ASSOCIATION_MISMATCH.into(),
+                ))
             }
         };
-        PcSaftRecord {
+        Ok(PcSaftRecord {
             m,
             sigma,
             epsilon_k,
@@ -232,40 +256,143 @@ impl PcSaftRecord {
             viscosity,
             diffusion,
             thermal_conductivity,
-        }
+        })
     }
 }
 
+/// PC-SAFT binary interaction parameters.
+///
+/// The binary interaction parameter can depend linearly on temperature:
+/// $k_{ij}(T)=k_{ij}+k_{ij,T}T$
+///
+/// The optional binary correction $\beta_{ij}$ scales the cross association
+/// energy by $(1-\beta_{ij})$. The optional solvation parameters add induced
+/// association between the two components (see [AssociationParameters::with_solvation]).
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct PcSaftBinaryRecord {
-    k_ij: f64,
+    /// Binary interaction parameter
+    pub k_ij: f64,
+    /// Temperature dependence of the binary interaction parameter in units of 1/K
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<f64>,
+    /// Binary correction of the cross association energy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub beta_assoc: Option<f64>,
+    /// Association volume of the solvation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub kappa_ab_solvation: Option<f64>,
+    /// Association energy of the solvation in units of Kelvin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub epsilon_k_ab_solvation: Option<f64>,
+}
+
+impl PcSaftBinaryRecord {
+    pub fn new(k_ij: f64, k_ij_t: Option<f64>) -> Self {
+        Self {
+            k_ij,
+            k_ij_t,
+            beta_assoc: None,
+            kappa_ab_solvation: None,
+            epsilon_k_ab_solvation: None,
+        }
+    }
+
+    /// Use the binary correction `beta_assoc` for the cross association energy.
+    pub fn with_beta_assoc(mut self, beta_assoc: f64) -> Self {
+        self.beta_assoc = Some(beta_assoc);
+        self
+    }
+
+    /// Add solvation (induced association) between the two components with the
+    /// cross association parameters `kappa_ab` and `epsilon_k_ab` (in units of Kelvin).
+    pub fn with_solvation(mut self, kappa_ab: f64, epsilon_k_ab: f64) -> Self {
+        self.kappa_ab_solvation = Some(kappa_ab);
+        self.epsilon_k_ab_solvation = Some(epsilon_k_ab);
+        self
+    }
+
+    /// Cross association parameters of the solvation, if both are given.
+    fn solvation(&self) -> Option<(f64, f64)> {
+        self.kappa_ab_solvation.zip(self.epsilon_k_ab_solvation)
+    }
 }
 
 impl From<f64> for PcSaftBinaryRecord {
     fn from(k_ij: f64) -> Self {
-        Self { k_ij }
+        Self::new(k_ij, None)
     }
 }
 
-impl From<PcSaftBinaryRecord> for f64 {
-    fn from(binary_record: PcSaftBinaryRecord) -> Self {
-        binary_record.k_ij
+impl TryFrom<PcSaftBinaryRecord> for f64 {
+    type Error = ParameterError;
+
+    fn try_from(binary_record: PcSaftBinaryRecord) -> Result<Self, Self::Error> {
+        let association = binary_record.beta_assoc.is_some()
+            || binary_record.kappa_ab_solvation.is_some()
+            || binary_record.epsilon_k_ab_solvation.is_some();
+        match (binary_record.k_ij_t, association) {
+            (None, false) => Ok(binary_record.k_ij),
+            (Some(_), _) => Err(ParameterError::IncompatibleParameters(String::from(
+                "a temperature dependent binary interaction parameter can not be converted to a single number.",
+            ))),
+            (None, true) => Err(ParameterError::IncompatibleParameters(String::from(
+                "a binary record with association parameters can not be converted to a single number.",
+            ))),
+        }
     }
 }
 
 impl<T: Copy + ValueInto<f64>> FromSegmentsBinary<T> for PcSaftBinaryRecord {
     fn from_segments_binary(segments: &[(Self, T, T)]) -> Result<Self, ParameterError> {
-        let (k_ij, n) = segments.iter().fold((0.0, 0.0), |(k_ij, n), (br, n1, n2)| {
-            let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
-            (k_ij + br.k_ij * nab, n + nab)
-        });
-        Ok(Self { k_ij: k_ij / n })
+        let (k_ij, k_ij_t, beta_assoc, kappa_ab_solvation, epsilon_k_ab_solvation, n) =
+            segments.iter().fold(
+                (0.0, None, None, None, None, 0.0),
+                |(k_ij, k_ij_t, beta_assoc, kappa, epsilon_k, n), (br, n1, n2)| {
+                    let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
+                    let add = |sum: Option<f64>, x: Option<f64>| match (sum, x) {
+                        (None, None) => None,
+                        (sum, x) => Some(sum.unwrap_or(0.0) + x.unwrap_or(0.0) * nab),
+                    };
+                    (
+                        k_ij + br.k_ij * nab,
+                        add(k_ij_t, br.k_ij_t),
+                        add(beta_assoc, br.beta_assoc),
+                        add(kappa, br.kappa_ab_solvation),
+                        add(epsilon_k, br.epsilon_k_ab_solvation),
+                        n + nab,
+                    )
+                },
+            );
+        Ok(Self {
+            k_ij: k_ij / n,
+            k_ij_t: k_ij_t.map(|k| k / n),
+            beta_assoc: beta_assoc.map(|b| b / n),
+            kappa_ab_solvation: kappa_ab_solvation.map(|k| k / n),
+            epsilon_k_ab_solvation: epsilon_k_ab_solvation.map(|e| e / n),
+        })
     }
 }
 
 impl std::fmt::Display for PcSaftBinaryRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "PcSaftBinaryRecord(k_ij={})", self.k_ij)
+        write!(f, "PcSaftBinaryRecord(k_ij={}", self.k_ij)?;
+        if let Some(k_ij_t) = self.k_ij_t {
+            write!(f, ", k_ij_t={}", k_ij_t)?;
+        }
+        if let Some(beta_assoc) = self.beta_assoc {
+            write!(f, ", beta_assoc={}", beta_assoc)?;
+        }
+        if let Some(kappa_ab) = self.kappa_ab_solvation {
+            write!(f, ", kappa_ab_solvation={}", kappa_ab)?;
+        }
+        if let Some(epsilon_k_ab) = self.epsilon_k_ab_solvation {
+            write!(f, ", epsilon_k_ab_solvation={}", epsilon_k_ab)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -281,7 +408,10 @@ pub struct PcSaftParameters {
     pub q2: Array1<f64>,
     pub association: AssociationParameters,
     pub k_ij: Array2<f64>,
+    /// Temperature dependence of the binary interaction parameter in units of 1/K
+    pub k_ij_t: Array2<f64>,
     pub sigma_ij: Array2<f64>,
+    /// Cross energy parameter with the temperature independent part of $k_{ij}$
     pub epsilon_k_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
     pub ndipole: usize,
@@ -354,9 +484,15 @@ impl Parameter for PcSaftParameters {
             .collect();
         let nquadpole = quadpole_comp.len();
 
-        let association = AssociationParameters::new(&association_records, &sigma, None);
+        let association = association_parameters(
+            &association_records,
+            &sigma,
+            &binary_records,
+            AssociationCombiningRule::default(),
+        );
 
         let k_ij = binary_records.map(|br| br.k_ij);
+        let k_ij_t = binary_records.map(|br| br.k_ij_t.unwrap_or(0.0));
         let mut epsilon_k_ij = Array::zeros((n, n));
         let mut sigma_ij = Array::zeros((n, n));
         let mut e_k_ij = Array::zeros((n, n));
@@ -415,6 +551,7 @@ impl Parameter for PcSaftParameters {
             q2,
             association,
             k_ij,
+            k_ij_t,
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
@@ -454,7 +591,51 @@ impl HardSphereProperties for PcSaftParameters {
     }
 }
 
+/// Association parameters including the binary corrections and the solvation
+/// of the binary records.
+fn association_parameters(
+    records: &[Option<AssociationRecord>],
+    sigma: &Array1<f64>,
+    binary_records: &Array2<PcSaftBinaryRecord>,
+    combining_rule: AssociationCombiningRule,
+) -> AssociationParameters {
+    let beta_assoc = binary_records
+        .iter()
+        .any(|br| br.beta_assoc.is_some())
+        .then(|| binary_records.map(|br| br.beta_assoc.unwrap_or(0.0)));
+    let solvation: Vec<_> = binary_records
+        .indexed_iter()
+        .filter(|((i, j), _)| i < j)
+        .filter_map(|((i, j), br)| br.solvation().map(|(kappa, eps)| (i, j, kappa, eps)))
+        .collect();
+    AssociationParameters::new(records, sigma, None, combining_rule, beta_assoc.as_ref())
+        .with_solvation(&solvation, sigma)
+}
+
 impl PcSaftParameters {
+    /// Association parameters with the given combining rule for the cross
+    /// association parameters.
+    pub fn association_parameters(
+        &self,
+        combining_rule: AssociationCombiningRule,
+    ) -> AssociationParameters {
+        if combining_rule == self.association.combining_rule {
+            return self.association.clone();
+        }
+        let records: Vec<_> = self
+            .pure_records
+            .iter()
+            .map(|r| r.model_record.association_record)
+            .collect();
+        association_parameters(&records, &self.sigma, &self.binary_records, combining_rule)
+    }
+
+    /// Reduced cross energy parameter $\frac{\varepsilon_{ij}}{kT}$ with the
+    /// temperature dependent binary interaction parameter $k_{ij}(T)$.
+    pub fn epsilon_ij_t<D: DualNum<f64>>(&self, i: usize, j: usize, temperature: D) -> D {
+        temperature.recip() * self.epsilon_k_ij[(i, j)] - self.e_k_ij[(i, j)] * self.k_ij_t[(i, j)]
+    }
+
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
         let o = &mut output;
@@ -466,10 +647,7 @@ impl PcSaftParameters {
         for (i, record) in self.pure_records.iter().enumerate() {
             let component = record.identifier.name.clone();
             let component = component.unwrap_or(format!("Component {}", i + 1));
-            let association = record
-                .model_record
-                .association_record
-                .unwrap_or_else(|| AssociationRecord::new(0.0, 0.0, None, None));
+            let association = record.model_record.association_record.unwrap_or_default();
             write!(
                 o,
                 "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
@@ -511,10 +689,19 @@ impl std::fmt::Display for PcSaftParameters {
             write!(f, "\n\tepsilon_k_ab={}", self.association.epsilon_k_ab)?;
             write!(f, "\n\tna={}", self.association.na)?;
             write!(f, "\n\tnb={}", self.association.nb)?;
+            if self.association.has_cd_sites() {
+                write!(f, "\n\tnc={}", self.association.nc)?;
+                write!(f, "\n\tnd={}", self.association.nd)?;
+                write!(f, "\n\tkappa_cd={}", self.association.kappa_cd)?;
+                write!(f, "\n\tepsilon_k_cd={}", self.association.epsilon_k_cd)?;
+            }
         }
         if !self.k_ij.iter().all(|k| k.is_zero()) {
             write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
         }
+        if !self.k_ij_t.iter().all(|k| k.is_zero()) {
+            write!(f, "\n\tk_ij_t=\n{}", self.k_ij_t)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -750,9 +937,7 @@ pub mod utils {
         let kij = [("CH3", "OH", -0.2), ("CH2", "OH", -0.1)];
         let binary_segment_records = kij
             .iter()
-            .map(|&(id1, id2, k_ij)| {
-                BinaryRecord::new(id1.into(), id2.into(), PcSaftBinaryRecord { k_ij })
-            })
+            .map(|&(id1, id2, k_ij)| BinaryRecord::new(id1.into(), id2.into(), k_ij.into()))
             .collect();
         let params = PcSaftParameters::from_segments(
             vec![propane, ethanol],
@@ -1,5 +1,6 @@
 use super::parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
 use super::DQVariants;
+use crate::association::AssociationCombiningRule;
 use feos_core::joback::JobackRecord;
 use feos_core::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
@@ -10,7 +11,7 @@ use feos_core::python::parameter::*;
 use feos_core::*;
 use ndarray::Array2;
 use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
@@ -39,8 +40,8 @@ impl PyPcSaftRecord {
         viscosity: Option<[f64; 4]>,
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
-    ) -> Self {
-        Self(PcSaftRecord::new(
+    ) -> PyResult<Self> {
+        Ok(Self(PcSaftRecord::new(
             m,
             sigma,
             epsilon_k,
@@ -53,7 +54,7 @@ impl PyPcSaftRecord {
             viscosity,
             diffusion,
             thermal_conductivity,
-        ))
+        )?))
     }
 
     #[getter]
@@ -126,12 +127,87 @@ impl_json_handling!(PyPcSaftRecord);
 impl_pure_record!(PcSaftRecord, PyPcSaftRecord, JobackRecord, PyJobackRecord);
 impl_segment_record!(PcSaftRecord, PyPcSaftRecord, JobackRecord, PyJobackRecord);
 
+/// PC-SAFT binary interaction parameters.
+///
+/// Parameters
+/// ----------
+/// k_ij : float
+///     Binary interaction parameter.
+/// k_ij_t : float, optional
+///     Temperature dependence of the binary interaction parameter
+///     in units of 1/K, so that k_ij(T) = k_ij + k_ij_t * T.
+/// beta_assoc : float, optional
+///     Binary correction of the cross association energy, which
+///     is scaled by (1 - beta_assoc).
+/// kappa_ab_solvation : float, optional
+///     Association volume of the solvation between the two components.
+/// epsilon_k_ab_solvation : float, optional
+///     Association energy of the solvation between the two components in units of Kelvin.
 #[pyclass(name = "PcSaftBinaryRecord")]
 #[pyo3(
-    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
+    text_signature = "(k_ij, k_ij_t=None, beta_assoc=None, kappa_ab_solvation=None, epsilon_k_ab_solvation=None)"
 )]
 #[derive(Clone)]
 pub struct PyPcSaftBinaryRecord(PcSaftBinaryRecord);
+
+#[pymethods]
+impl PyPcSaftBinaryRecord {
+    #[new]
+    fn new(
+        k_ij: f64,
+        k_ij_t: Option<f64>,
+        beta_assoc: Option<f64>,
+        kappa_ab_solvation: Option<f64>,
+        epsilon_k_ab_solvation: Option<f64>,
+    ) -> PyResult<Self> {
+        let mut record = PcSaftBinaryRecord::new(k_ij, k_ij_t);
+        if let Some(beta_assoc) = beta_assoc {
+            record = record.with_beta_assoc(beta_assoc);
+        }
+        match (kappa_ab_solvation, epsilon_k_ab_solvation) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                record = record.with_solvation(kappa_ab, epsilon_k_ab)
+            }
+            (None, None) => (),
+            _ => {
+                return Err(PyValueError::new_err(
+                    "kappa_ab_solvation and epsilon_k_ab_solvation have to be given together.",
+                ))
+            }
+        }
+        Ok(Self(record))
+    }
+
+    #[getter]
+    fn get_k_ij(&self) -> f64 {
+        self.0.k_ij
+    }
+
+    #[getter]
+    fn get_k_ij_t(&self) -> Option<f64> {
+        self.0.k_ij_t
+    }
+
+    #[getter]
+    fn get_beta_assoc(&self) -> Option<f64> {
+        self.0.beta_assoc
+    }
+
+    #[getter]
+    fn get_kappa_ab_solvation(&self) -> Option<f64> {
+        self.0.kappa_ab_solvation
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab_solvation(&self) -> Option<f64> {
+        self.0.epsilon_k_ab_solvation
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
 impl_binary_record!(PcSaftBinaryRecord, PyPcSaftBinaryRecord);
 
 /// Create a set of PC-SAFT parameters from records.
@@ -169,6 +245,11 @@ impl PyPcSaftParameters {
         self.0.k_ij.view().to_pyarray(py)
     }
 
+    #[getter]
+    fn get_k_ij_t<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij_t.view().to_pyarray(py)
+    }
+
     fn _repr_markdown_(&self) -> String {
         self.0.to_markdown()
     }
@@ -186,10 +267,12 @@ pub fn pcsaft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyJobackRecord>()?;
 
     m.add_class::<DQVariants>()?;
+    m.add_class::<AssociationCombiningRule>()?;
     m.add_class::<PyPcSaftRecord>()?;
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PySegmentRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PyPcSaftBinaryRecord>()?;
     m.add_class::<PyBinarySegmentRecord>()?;
     m.add_class::<PyPcSaftParameters>()?;
     Ok(())
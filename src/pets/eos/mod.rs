@@ -8,6 +8,7 @@ use feos_core::{
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
@@ -26,7 +27,8 @@ enum IdealGasContributions {
 ///
 /// The maximum packing fraction is used to infer initial values
 /// for routines that depend on starting values for the system density.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PetsOptions {
     /// maximum packing fraction
     pub max_eta: f64,
@@ -73,6 +75,16 @@ impl Pets {
             ),
         }
     }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<PetsParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &PetsOptions {
+        &self.options
+    }
 }
 
 impl EquationOfState for Pets {
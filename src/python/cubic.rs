@@ -11,6 +11,7 @@ pub fn cubic(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
     m.add_class::<PyPengRobinsonRecord>()?;
     m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyPengRobinsonBinaryRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
     m.add_class::<PyPengRobinsonParameters>()?;
     Ok(())
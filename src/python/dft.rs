@@ -1,6 +1,10 @@
+#[cfg(feature = "pcsaft")]
+use crate::association::AssociationCombiningRule;
 use crate::dft::FunctionalVariant;
 #[cfg(feature = "estimator")]
 use crate::estimator::*;
+#[cfg(feature = "estimator")]
+use crate::estimator::Phase;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::python::PyGcPcSaftFunctionalParameters;
 #[cfg(feature = "gc_pcsaft")]
@@ -28,7 +32,7 @@ use feos_dft::python::*;
 use feos_dft::solvation::*;
 use feos_dft::*;
 use numpy::convert::ToPyArray;
-use numpy::{PyArray1, PyArray2, PyArray4};
+use numpy::{PyArray1, PyArray2, PyArray4, PyReadonlyArray1};
 use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 #[cfg(feature = "estimator")]
@@ -60,6 +64,12 @@ impl PyFunctionalVariant {
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
     /// dq_variant : DQVariants, optional
     ///     Combination rule used in the dipole/quadrupole term. Defaults to 'DQVariants.DQ35'
+    /// force_cross_association : bool, optional
+    ///     Use the iterative cross association solver even for a single
+    ///     associating component. Defaults to False.
+    /// association_combining_rule : AssociationCombiningRule, optional
+    ///     Combining rule for the cross association parameters.
+    ///     Defaults to 'AssociationCombiningRule.Geometric'.
     ///
     /// Returns
     /// -------
@@ -67,8 +77,8 @@ impl PyFunctionalVariant {
     #[cfg(feature = "pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35),
-        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35, force_cross_association=false, association_combining_rule=AssociationCombiningRule::Geometric),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant, force_cross_association=False, association_combining_rule)"
     )]
     fn pcsaft(
         parameters: PyPcSaftParameters,
@@ -77,12 +87,17 @@ impl PyFunctionalVariant {
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
         dq_variant: DQVariants,
+        force_cross_association: bool,
+        association_combining_rule: AssociationCombiningRule,
     ) -> Self {
         let options = PcSaftOptions {
             max_eta,
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            force_cross_association,
+            association_combining_rule,
+            ..Default::default()
         };
         Self(Arc::new(
             PcSaftFunctional::with_options(parameters.0, fmt_version, options).into(),
@@ -103,6 +118,9 @@ impl PyFunctionalVariant {
     ///     Maximum number of iterations for cross association. Defaults to 50.
     /// tol_cross_assoc : float
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// force_cross_association : bool, optional
+    ///     Use the iterative cross association solver even for a single
+    ///     associating component. Defaults to False.
     ///
     /// Returns
     /// -------
@@ -110,8 +128,8 @@ impl PyFunctionalVariant {
     #[cfg(feature = "gc_pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
-        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, force_cross_association=false),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, force_cross_association=False)"
     )]
     fn gc_pcsaft(
         parameters: PyGcPcSaftFunctionalParameters,
@@ -119,11 +137,13 @@ impl PyFunctionalVariant {
         max_eta: f64,
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
+        force_cross_association: bool,
     ) -> Self {
         let options = GcPcSaftOptions {
             max_eta,
             max_iter_cross_assoc,
             tol_cross_assoc,
+            force_cross_association,
         };
         Self(Arc::new(
             GcPcSaftFunctional::with_options(parameters.0, fmt_version, options).into(),
@@ -242,6 +262,8 @@ impl_estimator!(DFT<FunctionalVariant>, PyFunctionalVariant);
 pub fn dft(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Contributions>()?;
     m.add_class::<Verbosity>()?;
+    m.add_class::<AzeotropeType>()?;
+    m.add_class::<PhaseRole>()?;
 
     m.add_class::<PyFunctionalVariant>()?;
     m.add_class::<PyState>()?;
@@ -1,6 +1,14 @@
+#[cfg(feature = "pcsaft")]
+use crate::association::AssociationCombiningRule;
+#[cfg(feature = "cpa")]
+use crate::cpa::python::PyCpaParameters;
+#[cfg(feature = "cpa")]
+use crate::cpa::{Cpa, CpaOptions};
 use crate::eos::EosVariant;
 #[cfg(feature = "estimator")]
 use crate::estimator::*;
+#[cfg(feature = "estimator")]
+use crate::estimator::Phase;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::python::PyGcPcSaftEosParameters;
 #[cfg(feature = "gc_pcsaft")]
@@ -9,6 +17,10 @@ use crate::gc_pcsaft::{GcPcSaft, GcPcSaftOptions};
 use crate::impl_estimator;
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 use crate::impl_estimator_entropy_scaling;
+#[cfg(feature = "lj")]
+use crate::lj::python::PyLennardJonesParameters;
+#[cfg(feature = "lj")]
+use crate::lj::LennardJones;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::PyPcSaftParameters;
 #[cfg(feature = "pcsaft")]
@@ -17,6 +29,10 @@ use crate::pcsaft::{DQVariants, PcSaft, PcSaftOptions};
 use crate::pets::python::PyPetsParameters;
 #[cfg(feature = "pets")]
 use crate::pets::{Pets, PetsOptions};
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::python::PySaftVRMieParameters;
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::{SaftVRMie, SaftVRMieOptions};
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::python::PySaftVRQMieParameters;
 #[cfg(feature = "saftvrqmie")]
@@ -26,12 +42,18 @@ use crate::uvtheory::python::PyUVParameters;
 #[cfg(feature = "uvtheory")]
 use crate::uvtheory::{Perturbation, UVTheory, UVTheoryOptions, VirialOrder};
 
-use feos_core::cubic::PengRobinson;
+use feos_core::cubic::{PengRobinson, Srk};
+use feos_core::ideal_gas::WithIdealGas;
+use feos_core::parameter::ParameterError;
 use feos_core::python::cubic::PyPengRobinsonParameters;
-use feos_core::python::user_defined::PyEoSObj;
+use feos_core::python::ideal_gas::{
+    PyDippr107, PyDippr107Record, PyIdealGasModel, PyNasaPolynomial, PyNasaRecord,
+    PyThermalDeBroglie,
+};
+use feos_core::python::user_defined::{PyCompositeEoS, PyEoSObj};
 use feos_core::*;
 use numpy::convert::ToPyArray;
-use numpy::{PyArray1, PyArray2};
+use numpy::{PyArray1, PyArray2, PyReadonlyArray1};
 use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 #[cfg(feature = "estimator")]
@@ -62,6 +84,21 @@ impl PyEosVariant {
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
     /// dq_variant : DQVariants, optional
     ///     Combination rule used in the dipole/quadrupole term. Defaults to 'DQVariants.DQ35'
+    /// dipole : bool, optional
+    ///     Include the dipolar contribution. Defaults to True.
+    /// quadrupole : bool, optional
+    ///     Include the quadrupolar contribution. Defaults to True.
+    /// dipole_quadrupole : bool, optional
+    ///     Include the dipole-quadrupole cross contribution. Defaults to True.
+    /// force_cross_association : bool, optional
+    ///     Use the iterative cross association solver even for a single
+    ///     associating component. Defaults to False.
+    /// association_combining_rule : AssociationCombiningRule, optional
+    ///     Combining rule for the cross association parameters.
+    ///     Defaults to 'AssociationCombiningRule.Geometric'.
+    /// thermal_de_broglie : bool, optional
+    ///     Use the thermal de Broglie wavelength as ideal gas contribution
+    ///     instead of the Joback or QSPR model. Defaults to False.
     ///
     /// Returns
     /// -------
@@ -71,8 +108,8 @@ impl PyEosVariant {
     #[cfg(feature = "pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35),
-        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant)"
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35, dipole=true, quadrupole=true, dipole_quadrupole=true, force_cross_association=false, association_combining_rule=AssociationCombiningRule::Geometric, thermal_de_broglie=false),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant, dipole=True, quadrupole=True, dipole_quadrupole=True, force_cross_association=False, association_combining_rule, thermal_de_broglie=False)"
     )]
     pub fn pcsaft(
         parameters: PyPcSaftParameters,
@@ -80,12 +117,24 @@ impl PyEosVariant {
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
         dq_variant: DQVariants,
+        dipole: bool,
+        quadrupole: bool,
+        dipole_quadrupole: bool,
+        force_cross_association: bool,
+        association_combining_rule: AssociationCombiningRule,
+        thermal_de_broglie: bool,
     ) -> Self {
         let options = PcSaftOptions {
             max_eta,
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            dipole,
+            quadrupole,
+            dipole_quadrupole,
+            force_cross_association,
+            association_combining_rule,
+            thermal_de_broglie,
         };
         Self(Arc::new(EosVariant::PcSaft(PcSaft::with_options(
             parameters.0,
@@ -105,6 +154,9 @@ impl PyEosVariant {
     ///     Maximum number of iterations for cross association. Defaults to 50.
     /// tol_cross_assoc : float
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// force_cross_association : bool, optional
+    ///     Use the iterative cross association solver even for a single
+    ///     associating component. Defaults to False.
     ///
     /// Returns
     /// -------
@@ -114,19 +166,21 @@ impl PyEosVariant {
     #[cfg(feature = "gc_pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
-        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, force_cross_association=false),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, force_cross_association=False)"
     )]
     pub fn gc_pcsaft(
         parameters: PyGcPcSaftEosParameters,
         max_eta: f64,
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
+        force_cross_association: bool,
     ) -> Self {
         let options = GcPcSaftOptions {
             max_eta,
             max_iter_cross_assoc,
             tol_cross_assoc,
+            force_cross_association,
         };
         Self(Arc::new(EosVariant::GcPcSaft(GcPcSaft::with_options(
             parameters.0,
@@ -153,6 +207,23 @@ impl PyEosVariant {
         ))))
     }
 
+    /// Soave-Redlich-Kwong equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : PengRobinsonParameters
+    ///     The parameters of the SRK equation of state to use.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The SRK equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[staticmethod]
+    pub fn srk(parameters: PyPengRobinsonParameters) -> Self {
+        Self(Arc::new(EosVariant::Srk(Srk::new(parameters.0))))
+    }
+
     /// Equation of state from a Python class.
     ///
     /// Parameters
@@ -169,6 +240,106 @@ impl PyEosVariant {
         Ok(Self(Arc::new(EosVariant::Python(PyEoSObj::new(obj)?))))
     }
 
+    /// Equation of state that combines the residual Helmholtz energy
+    /// contributions of an existing equation of state with contributions
+    /// implemented in Python.
+    ///
+    /// The residual Helmholtz energy is the sum of all contributions. The
+    /// number of components, the maximum density, the molar weights and the
+    /// ideal gas contribution are taken from `eos`.
+    ///
+    /// Every contribution has to implement a method
+    /// `helmholtz_energy(state)` that returns the reduced residual Helmholtz
+    /// energy for a `StateHD` with the same number type as the temperature.
+    /// Floats and first derivatives (`Dual64`) are used for the pressure and
+    /// the chemical potential, second derivatives (`HyperDual64`,
+    /// `Dual2_64`) for heat capacities and density iterations and third
+    /// derivatives (`Dual3_64`) for critical points. Nested dual numbers
+    /// (e.g., `HyperDualDual64`, `Dual3Dual64`) are used for derivatives of
+    /// critical points and phase equilibria. Use
+    /// `EquationOfState.python_self_test` to check a contribution.
+    ///
+    /// Parameters
+    /// ----------
+    /// eos : EquationOfState
+    ///     The equation of state that provides the reference contributions.
+    /// contributions : [Any]
+    ///     Python objects implementing `helmholtz_energy(state)` and
+    ///     `subset(component_list)`, which returns the contribution for a
+    ///     subset of components.
+    /// residual_contributions : [str], optional
+    ///     The names of the contributions of `eos` that are used.
+    ///     Defaults to all contributions.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[staticmethod]
+    #[pyo3(text_signature = "(eos, contributions, residual_contributions=None)")]
+    fn composite(
+        eos: PyEosVariant,
+        contributions: Vec<Py<PyAny>>,
+        residual_contributions: Option<Vec<String>>,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(EosVariant::Composite(PyCompositeEoS::new(
+            eos.0,
+            contributions,
+            residual_contributions,
+        )?))))
+    }
+
+    /// Equation of state that combines the residual Helmholtz energy of
+    /// an existing equation of state with a different ideal gas model.
+    ///
+    /// The residual contributions, the maximum density and the molar
+    /// weights are taken from `eos`.
+    ///
+    /// Parameters
+    /// ----------
+    /// eos : EquationOfState
+    ///     The equation of state that provides the residual contributions.
+    /// ideal_gas : ThermalDeBroglie | NasaPolynomial | Dippr107
+    ///     The ideal gas model.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///
+    /// Raises
+    /// ------
+    /// RuntimeError
+    ///     If the number of components of the ideal gas model and the
+    ///     equation of state differ.
+    #[staticmethod]
+    #[pyo3(text_signature = "(eos, ideal_gas)")]
+    fn with_ideal_gas(eos: PyEosVariant, ideal_gas: PyIdealGasModel) -> PyResult<Self> {
+        Ok(Self(Arc::new(EosVariant::WithIdealGas(WithIdealGas::new(
+            eos.0, ideal_gas,
+        )?))))
+    }
+
+    /// Evaluate the Helmholtz energy of a Python class with all
+    /// dual number types that are used by the equation of state.
+    ///
+    /// Use this function to debug a Python class before using
+    /// it in `EquationOfState.python`.
+    ///
+    /// Parameters
+    /// ----------
+    /// obj : Class
+    ///     A python class implementing the necessary methods
+    ///     to be used as equation of state.
+    ///
+    /// Returns
+    /// -------
+    /// [(str, str)]
+    ///     The name of every dual number type together with an
+    ///     error message, or None if the evaluation succeeded.
+    #[staticmethod]
+    fn python_self_test(obj: Py<PyAny>) -> PyResult<Vec<(String, Option<String>)>> {
+        Ok(PyEoSObj::new(obj)?.self_test())
+    }
+
     /// PeTS equation of state.
     ///
     /// Parameters
@@ -235,6 +406,80 @@ impl PyEosVariant {
         ))))
     }
 
+    /// SAFT-VR Mie equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : SaftVRMieParameters
+    ///     The parameters of the SAFT-VR Mie equation of state to use.
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The SAFT-VR Mie equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[cfg(feature = "saftvrmie")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+    )]
+    fn saftvrmie(
+        parameters: PySaftVRMieParameters,
+        max_eta: f64,
+        max_iter_cross_assoc: usize,
+        tol_cross_assoc: f64,
+    ) -> Self {
+        let options = SaftVRMieOptions {
+            max_eta,
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+        };
+        Self(Arc::new(EosVariant::SaftVRMie(SaftVRMie::with_options(
+            parameters.0,
+            options,
+        ))))
+    }
+
+    /// Cubic-Plus-Association equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : CpaParameters
+    ///     The parameters of the CPA equation of state to use.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The CPA equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[cfg(feature = "cpa")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
+        text_signature = "(parameters, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+    )]
+    fn cpa(parameters: PyCpaParameters, max_iter_cross_assoc: usize, tol_cross_assoc: f64) -> Self {
+        let options = CpaOptions {
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+        };
+        Self(Arc::new(EosVariant::Cpa(Cpa::with_options(
+            parameters.0,
+            options,
+        ))))
+    }
+
     /// SAFT-VRQ Mie equation of state.
     ///
     /// Parameters
@@ -276,6 +521,79 @@ impl PyEosVariant {
             options,
         ))))
     }
+
+    /// Equation of state for Lennard-Jones fluids and mixtures.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : LennardJonesParameters
+    ///     The parameters of the Lennard-Jones equation of state to use.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The Lennard-Jones equation of state that can be used to compute
+    ///     thermodynamic states.
+    #[cfg(feature = "lj")]
+    #[staticmethod]
+    #[pyo3(text_signature = "(parameters)")]
+    fn lennard_jones(parameters: PyLennardJonesParameters) -> Self {
+        Self(Arc::new(EosVariant::LennardJones(LennardJones::new(
+            parameters.0,
+        ))))
+    }
+
+    /// Creates the equation of state from a json string.
+    ///
+    /// Parameters
+    /// ----------
+    /// json : str
+    ///     The json string created by `EquationOfState.to_json_str`.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[staticmethod]
+    #[pyo3(text_signature = "(json)")]
+    fn from_json_str(json: &str) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(serde_json::from_str(json)?)))
+    }
+
+    /// Creates a json string containing the model, the pure and binary
+    /// records of the parameters and the options of the equation of state.
+    ///
+    /// Equations of state implemented in Python can not be serialized.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    fn to_json_str(&self) -> Result<String, ParameterError> {
+        Ok(serde_json::to_string(self.0.as_ref())?)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        let (constructor, args) = match self.0.as_ref() {
+            EosVariant::Python(eos) => ("python", (eos.obj().clone_ref(py),).into_py(py)),
+            EosVariant::Composite(eos) => {
+                let args = (
+                    Self(eos.eos().clone()),
+                    eos.objs().to_vec(),
+                    eos.residual_contributions().map(|c| c.to_vec()),
+                );
+                ("composite", args.into_py(py))
+            }
+            EosVariant::WithIdealGas(eos) => {
+                let args = (
+                    Self(eos.eos().clone()),
+                    PyIdealGasModel::from(eos.ideal_gas_model().clone()),
+                );
+                ("with_ideal_gas", args.into_py(py))
+            }
+            _ => ("from_json_str", (self.to_json_str()?,).into_py(py)),
+        };
+        let constructor = py.get_type::<Self>().getattr(constructor)?;
+        Ok((constructor.into(), args))
+    }
 }
 
 impl_equation_of_state!(PyEosVariant);
@@ -291,16 +609,97 @@ impl_estimator!(EosVariant, PyEosVariant);
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 impl_estimator_entropy_scaling!(EosVariant, PyEosVariant);
 
+fn molar_weight_check(eos: &PyEosVariant) -> PyResult<()> {
+    if eos.0.has_molar_weight() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(
+            "The equation of state does not provide molar weights.",
+        ))
+    }
+}
+
+/// Convert mole fractions into mass fractions.
+///
+/// Parameters
+/// ----------
+/// eos : EquationOfState
+///     The equation of state that provides the molar weights.
+/// molefracs : numpy.ndarray[float]
+///     The mole fractions of all components.
+///
+/// Returns
+/// -------
+/// numpy.ndarray[float]
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the equation of state does not provide molar weights.
+#[pyfunction]
+#[pyo3(text_signature = "(eos, molefracs)")]
+fn mole_to_mass_fractions<'py>(
+    py: Python<'py>,
+    eos: PyEosVariant,
+    molefracs: PyReadonlyArray1<f64>,
+) -> PyResult<&'py PyArray1<f64>> {
+    molar_weight_check(&eos)?;
+    Ok(eos
+        .0
+        .mole_to_mass_fractions(&molefracs.to_owned_array())?
+        .to_pyarray(py))
+}
+
+/// Convert mass fractions into mole fractions.
+///
+/// Parameters
+/// ----------
+/// eos : EquationOfState
+///     The equation of state that provides the molar weights.
+/// massfracs : numpy.ndarray[float]
+///     The mass fractions of all components.
+///
+/// Returns
+/// -------
+/// numpy.ndarray[float]
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the equation of state does not provide molar weights.
+#[pyfunction]
+#[pyo3(text_signature = "(eos, massfracs)")]
+fn mass_to_mole_fractions<'py>(
+    py: Python<'py>,
+    eos: PyEosVariant,
+    massfracs: PyReadonlyArray1<f64>,
+) -> PyResult<&'py PyArray1<f64>> {
+    molar_weight_check(&eos)?;
+    Ok(eos
+        .0
+        .mass_to_mole_fractions(&massfracs.to_owned_array())?
+        .to_pyarray(py))
+}
+
 #[pymodule]
 pub fn eos(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Contributions>()?;
     m.add_class::<Verbosity>()?;
+    m.add_class::<AzeotropeType>()?;
+    m.add_class::<PhaseRole>()?;
+    m.add_class::<PyThermalDeBroglie>()?;
+    m.add_class::<PyNasaRecord>()?;
+    m.add_class::<PyNasaPolynomial>()?;
+    m.add_class::<PyDippr107Record>()?;
+    m.add_class::<PyDippr107>()?;
 
     m.add_class::<PyEosVariant>()?;
     m.add_class::<PyState>()?;
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
+    m.add_function(wrap_pyfunction!(mole_to_mass_fractions, m)?)?;
+    m.add_function(wrap_pyfunction!(mass_to_mole_fractions, m)?)?;
 
     #[cfg(feature = "estimator")]
     m.add_wrapped(wrap_pymodule!(estimator_eos))?;
@@ -1,9 +1,15 @@
+#[cfg(feature = "cpa")]
+use crate::cpa::python::cpa as cpa_module;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::python::gc_pcsaft as gc_pcsaft_module;
+#[cfg(feature = "lj")]
+use crate::lj::python::lj as lj_module;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::pcsaft as pcsaft_module;
 #[cfg(feature = "pets")]
 use crate::pets::python::pets as pets_module;
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::python::saftvrmie as saftvrmie_module;
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::python::saftvrqmie as saftvrqmie_module;
 #[cfg(feature = "uvtheory")]
@@ -40,8 +46,14 @@ pub fn feos(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(pets_module))?;
     #[cfg(feature = "uvtheory")]
     m.add_wrapped(wrap_pymodule!(uvtheory_module))?;
+    #[cfg(feature = "saftvrmie")]
+    m.add_wrapped(wrap_pymodule!(saftvrmie_module))?;
     #[cfg(feature = "saftvrqmie")]
     m.add_wrapped(wrap_pymodule!(saftvrqmie_module))?;
+    #[cfg(feature = "cpa")]
+    m.add_wrapped(wrap_pymodule!(cpa_module))?;
+    #[cfg(feature = "lj")]
+    m.add_wrapped(wrap_pymodule!(lj_module))?;
 
     set_path(py, m, "feos.si", "quantity")?;
     set_path(py, m, "feos.eos", "eos")?;
@@ -60,8 +72,14 @@ pub fn feos(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     set_path(py, m, "feos.pets", "pets")?;
     #[cfg(feature = "uvtheory")]
     set_path(py, m, "feos.uvtheory", "uvtheory")?;
+    #[cfg(feature = "saftvrmie")]
+    set_path(py, m, "feos.saftvrmie", "saftvrmie")?;
     #[cfg(feature = "saftvrqmie")]
     set_path(py, m, "feos.saftvrqmie", "saftvrqmie")?;
+    #[cfg(feature = "cpa")]
+    set_path(py, m, "feos.cpa", "cpa")?;
+    #[cfg(feature = "lj")]
+    set_path(py, m, "feos.lj", "lj")?;
 
     py.run(
         "\
@@ -0,0 +1,89 @@
+use super::dispersion::{a1_a2_ij, a1s_b, k_hs, packing_fractions, PHI};
+use crate::hard_sphere::HardSphereProperties;
+use crate::saftvrmie::parameters::SaftVRMieParameters;
+use feos_core::{HelmholtzEnergyDual, StateHD};
+use ndarray::Array1;
+use num_dual::{Dual, DualNum};
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+pub struct Chain {
+    pub parameters: Arc<SaftVRMieParameters>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Chain {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let n = p.m.len();
+        let d = p.hs_diameter(state.temperature);
+
+        // segment density and segment fractions
+        let rho_s = (&state.partial_density * &p.m).sum();
+        let m = (&state.molefracs * &p.m).sum();
+        let x_s = Array1::from_shape_fn(n, |i| state.molefracs[i] * p.m[i] / m);
+        let [zeta_x, zeta_x_bar] = packing_fractions(p, &x_s, rho_s, &d);
+
+        // contact value of the hard-sphere radial distribution function
+        let frac_1mz = (-zeta_x + 1.0).recip();
+        let k0 = -(-zeta_x + 1.0).ln()
+            + (zeta_x * 42.0 - zeta_x.powi(2) * 39.0 + zeta_x.powi(3) * 9.0 - zeta_x.powi(4) * 2.0)
+                * frac_1mz.powi(3)
+                / 6.0;
+        let k1 = (zeta_x.powi(4) + zeta_x.powi(2) * 6.0 - zeta_x * 12.0) * frac_1mz.powi(3) * 0.5;
+        let k2 = -zeta_x.powi(2) * frac_1mz.powi(2) * 0.375;
+        let k3 = (-zeta_x.powi(4) + zeta_x.powi(2) * 3.0 + zeta_x * 3.0) * frac_1mz.powi(3) / 6.0;
+        let k_hs = k_hs(zeta_x);
+
+        // the perturbation terms are evaluated as functions of zeta_x to
+        // obtain their derivatives with respect to the segment density
+        let zeta_x_dual: Dual<D, f64> = Dual::from_re(zeta_x).derive();
+
+        let mut a = D::zero();
+        for i in 0..n {
+            if p.m[i] == 1.0 {
+                continue;
+            }
+            let eps = p.epsilon_k_ij[[i, i]];
+            let lr = p.lambda_r_ij[[i, i]];
+            let la = p.lambda_a_ij[[i, i]];
+            let c = p.c_ij[[i, i]];
+            let d3 = d[i].powi(3);
+            let x0 = d[i].recip() * p.sigma[i];
+            let a_s = |lambda: f64| x0.powf(lambda) * a1s_b(zeta_x, x0, d3, eps, lambda);
+
+            let [a1, a2] = a1_a2_ij(p, i, i, zeta_x_dual, Dual::from_re(x0), Dual::from_re(d3));
+            let da1 = a1.re + zeta_x * a1.eps;
+            let da2 = a2.re + zeta_x * a2.eps;
+
+            // first and second order terms of the radial distribution function
+            let g1 = (da1 * 3.0 - a_s(la) * (c * la) + a_s(lr) * (c * lr)) / d3
+                * (2.0 * PI * eps).recip();
+            let g2_mca = (da2 * 3.0
+                - k_hs
+                    * (a_s(2.0 * lr) * lr - a_s(la + lr) * (la + lr) + a_s(2.0 * la) * la)
+                    * (eps * c * c))
+                / d3
+                * (2.0 * PI * eps * eps).recip();
+            let tau = state.temperature.recip() * eps;
+            let alpha = p.alpha_ij[[i, i]];
+            let gamma_c = zeta_x_bar
+                * (zeta_x_bar * PHI[3][6] + zeta_x_bar.powi(2) * PHI[4][6]).exp()
+                * tau.exp_m1()
+                * (PHI[0][6] * (1.0 - (PHI[1][6] * (PHI[2][6] - alpha)).tanh()));
+            let g2 = (gamma_c + 1.0) * g2_mca;
+
+            let ln_g_hs = k0 + k1 * x0 + k2 * x0.powi(2) + k3 * x0.powi(3);
+            let g_hs_inv = (-ln_g_hs).exp();
+            let ln_g = ln_g_hs + tau * g1 * g_hs_inv + tau.powi(2) * g2 * g_hs_inv;
+            a -= state.partial_density[i] * (p.m[i] - 1.0) * ln_g;
+        }
+        a * state.volume
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chain")
+    }
+}
@@ -0,0 +1,162 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::saftvrmie::parameters::SaftVRMieParameters;
+use feos_core::{HelmholtzEnergyDual, StateHD};
+use ndarray::Array1;
+use num_dual::DualNum;
+use std::f64::consts::{FRAC_PI_6, PI};
+use std::fmt;
+use std::sync::Arc;
+
+/// Coefficients of the effective packing fraction (Lafitte et al., Eq. 40).
+const C_ETA_EFF: [[f64; 4]; 4] = [
+    [0.81096, 1.7888, -37.578, 92.284],
+    [1.0205, -19.341, 151.26, -463.50],
+    [-1.9057, 22.845, -228.14, 973.92],
+    [1.0885, -6.1962, 106.98, -677.64],
+];
+
+/// Coefficients $\phi_{k,n}$ of the functions $f_1$ to $f_6$ (columns 0 to 5)
+/// and of the correction $\gamma_c$ (column 6) (Lafitte et al., Table 3).
+pub(super) const PHI: [[f64; 7]; 7] = [
+    [7.5365557, -359.44, 1550.9, -1.19932, -1911.28, 9236.9, 10.0],
+    [
+        -37.60463, 1825.6, -5070.1, 9.063632, 21390.175, -129430.0, 10.0,
+    ],
+    [
+        71.745953, -3168.0, 6534.6, -17.9482, -51320.7, 357230.0, 0.57,
+    ],
+    [
+        -46.83552, 1884.2, -3288.7, 11.34027, 37064.54, -315530.0, -6.7,
+    ],
+    [
+        -2.467982, -0.82376, -2.7171, 20.52142, 1103.742, 1390.2, -8.0,
+    ],
+    [-0.50272, -3.1935, 2.0883, -56.6377, -3264.61, -4518.2, 0.0],
+    [8.0956883, 3.7090, 0.0, 40.53683, 2556.181, 4241.6, 0.0],
+];
+
+/// The functions $f_1$ to $f_6$ of the parameter $\alpha$ (Lafitte et al., Eq. 20).
+fn f_k(alpha: f64, k: usize) -> f64 {
+    let num: f64 = (0..4).map(|n| PHI[n][k] * alpha.powi(n as i32)).sum();
+    let den: f64 = (4..7).map(|n| PHI[n][k] * alpha.powi(n as i32 - 3)).sum();
+    num / (den + 1.0)
+}
+
+/// The packing fractions $\zeta_x$ (based on the hard-sphere diameters) and
+/// $\bar\zeta_x$ (based on the segment diameters) of the segment mixture.
+pub(super) fn packing_fractions<D: DualNum<f64>>(
+    parameters: &SaftVRMieParameters,
+    segment_fractions: &Array1<D>,
+    segment_density: D,
+    diameter: &Array1<D>,
+) -> [D; 2] {
+    let p = parameters;
+    let mut zeta_x = D::zero();
+    let mut zeta_x_bar = D::zero();
+    for i in 0..p.m.len() {
+        for j in 0..p.m.len() {
+            let xx = segment_fractions[i] * segment_fractions[j];
+            zeta_x += xx * ((diameter[i] + diameter[j]) * 0.5).powi(3);
+            zeta_x_bar += xx * p.sigma_ij[[i, j]].powi(3);
+        }
+    }
+    [
+        zeta_x * segment_density * FRAC_PI_6,
+        zeta_x_bar * segment_density * FRAC_PI_6,
+    ]
+}
+
+/// Isothermal compressibility $K^\mathrm{HS}$ of the hard-sphere fluid.
+pub(super) fn k_hs<D: DualNum<f64>>(zeta_x: D) -> D {
+    (-zeta_x + 1.0).powi(4)
+        / (zeta_x * 4.0 + zeta_x.powi(2) * 4.0 - zeta_x.powi(3) * 4.0 + zeta_x.powi(4) + 1.0)
+}
+
+/// The sum $a_1^S+B$ of a Sutherland potential with exponent `lambda`
+/// divided by the segment density (Lafitte et al., Eqs. 33 and 39).
+pub(super) fn a1s_b<D: DualNum<f64>>(zeta_x: D, x0: D, d3: D, epsilon_k: f64, lambda: f64) -> D {
+    let c = C_ETA_EFF.map(|c| c[0] + c[1] / lambda + c[2] / lambda.powi(2) + c[3] / lambda.powi(3));
+    let zeta_eff = zeta_x * (zeta_x * (zeta_x * (zeta_x * c[3] + c[2]) + c[1]) + c[0]);
+    let a1s = -(-zeta_eff * 0.5 + 1.0) / (-zeta_eff + 1.0).powi(3) / (lambda - 3.0);
+
+    let i = -(x0.powf(3.0 - lambda) - 1.0) / (lambda - 3.0);
+    let j =
+        -(x0.powf(4.0 - lambda) * (lambda - 3.0) - x0.powf(3.0 - lambda) * (lambda - 4.0) - 1.0)
+            / ((lambda - 3.0) * (lambda - 4.0));
+    let frac_1mz3 = (-zeta_x + 1.0).powi(3).recip();
+    let b = (-zeta_x * 0.5 + 1.0) * frac_1mz3 * i - zeta_x * (zeta_x + 1.0) * frac_1mz3 * j * 4.5;
+
+    (a1s + b) * d3 * (2.0 * PI * epsilon_k)
+}
+
+/// The first and second order perturbation terms $a_{1,ij}$ and
+/// $\frac{a_{2,ij}}{1+\chi_{ij}}$ divided by the segment density.
+pub(super) fn a1_a2_ij<D: DualNum<f64>>(
+    parameters: &SaftVRMieParameters,
+    i: usize,
+    j: usize,
+    zeta_x: D,
+    x0: D,
+    d3: D,
+) -> [D; 2] {
+    let p = parameters;
+    let eps = p.epsilon_k_ij[[i, j]];
+    let lr = p.lambda_r_ij[[i, j]];
+    let la = p.lambda_a_ij[[i, j]];
+    let c = p.c_ij[[i, j]];
+    let a = |lambda: f64| x0.powf(lambda) * a1s_b(zeta_x, x0, d3, eps, lambda);
+
+    let a1 = (a(la) - a(lr)) * c;
+    let a2 = (a(2.0 * la) - a(la + lr) * 2.0 + a(2.0 * lr)) * k_hs(zeta_x) * (0.5 * eps * c * c);
+    [a1, a2]
+}
+
+pub struct Dispersion {
+    pub parameters: Arc<SaftVRMieParameters>,
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for Dispersion {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let n = p.m.len();
+        let t_inv = state.temperature.recip();
+        let d = p.hs_diameter(state.temperature);
+
+        // segment density and segment fractions
+        let rho_s = (&state.partial_density * &p.m).sum();
+        let m = (&state.molefracs * &p.m).sum();
+        let x_s = Array1::from_shape_fn(n, |i| state.molefracs[i] * p.m[i] / m);
+        let [zeta_x, zeta_x_bar] = packing_fractions(p, &x_s, rho_s, &d);
+
+        let mut a1 = D::zero();
+        let mut a2 = D::zero();
+        let mut a3 = D::zero();
+        for i in 0..n {
+            for j in 0..n {
+                let d_ij = (d[i] + d[j]) * 0.5;
+                let x0 = d_ij.recip() * p.sigma_ij[[i, j]];
+                let [a1_ij, a2_ij] = a1_a2_ij(p, i, j, zeta_x, x0, d_ij.powi(3));
+
+                let alpha = p.alpha_ij[[i, j]];
+                let f = [0, 1, 2, 3, 4, 5].map(|k| f_k(alpha, k));
+                let chi = zeta_x_bar * f[0] + zeta_x_bar.powi(5) * f[1] + zeta_x_bar.powi(8) * f[2];
+                let a3_ij = -zeta_x_bar
+                    * (zeta_x_bar * f[4] + zeta_x_bar.powi(2) * f[5]).exp()
+                    * (p.epsilon_k_ij[[i, j]].powi(3) * f[3]);
+
+                let xx = x_s[i] * x_s[j];
+                a1 += xx * a1_ij;
+                a2 += xx * a2_ij * (chi + 1.0);
+                a3 += xx * a3_ij;
+            }
+        }
+
+        state.volume * rho_s * (rho_s * (a1 * t_inv + a2 * t_inv.powi(2)) + a3 * t_inv.powi(3))
+    }
+}
+
+impl fmt::Display for Dispersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dispersion")
+    }
+}
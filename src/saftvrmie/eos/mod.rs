@@ -0,0 +1,150 @@
+use super::parameters::SaftVRMieParameters;
+use crate::association::Association;
+use crate::hard_sphere::{HardSphere, HardSphereProperties};
+use feos_core::parameter::Parameter;
+use feos_core::{EquationOfState, HelmholtzEnergy, MolarWeight};
+use ndarray::Array1;
+use quantity::si::*;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+pub(crate) mod chain;
+pub(crate) mod dispersion;
+use chain::Chain;
+use dispersion::Dispersion;
+
+/// Customization options for the SAFT-VR Mie equation of state.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaftVRMieOptions {
+    pub max_eta: f64,
+    pub max_iter_cross_assoc: usize,
+    pub tol_cross_assoc: f64,
+}
+
+impl Default for SaftVRMieOptions {
+    fn default() -> Self {
+        Self {
+            max_eta: 0.5,
+            max_iter_cross_assoc: 50,
+            tol_cross_assoc: 1e-10,
+        }
+    }
+}
+
+/// SAFT-VR Mie equation of state.
+pub struct SaftVRMie {
+    parameters: Arc<SaftVRMieParameters>,
+    options: SaftVRMieOptions,
+    contributions: Vec<Box<dyn HelmholtzEnergy>>,
+}
+
+impl SaftVRMie {
+    pub fn new(parameters: Arc<SaftVRMieParameters>) -> Self {
+        Self::with_options(parameters, SaftVRMieOptions::default())
+    }
+
+    pub fn with_options(parameters: Arc<SaftVRMieParameters>, options: SaftVRMieOptions) -> Self {
+        let mut contributions: Vec<Box<dyn HelmholtzEnergy>> = Vec::with_capacity(4);
+        contributions.push(Box::new(HardSphere::new(&parameters)));
+        contributions.push(Box::new(Dispersion {
+            parameters: parameters.clone(),
+        }));
+        contributions.push(Box::new(Chain {
+            parameters: parameters.clone(),
+        }));
+        if !parameters.association.assoc_comp.is_empty() {
+            contributions.push(Box::new(Association::new(
+                &parameters,
+                &parameters.association,
+                options.max_iter_cross_assoc,
+                options.tol_cross_assoc,
+            )));
+        };
+
+        Self {
+            parameters,
+            options,
+            contributions,
+        }
+    }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<SaftVRMieParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &SaftVRMieOptions {
+        &self.options
+    }
+}
+
+impl EquationOfState for SaftVRMie {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_eta * moles.sum()
+            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
+                .sum()
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.contributions
+    }
+
+    fn segment_numbers(&self) -> Array1<f64> {
+        self.parameters.segment_numbers()
+    }
+}
+
+impl MolarWeight for SaftVRMie {
+    fn molar_weight(&self) -> SIArray1 {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::saftvrmie::parameters::utils::propane_parameters;
+    use approx::assert_relative_eq;
+    use feos_core::{HelmholtzEnergyDual, StateHD};
+    use ndarray::arr1;
+
+    #[test]
+    fn hs_diameter() {
+        let p = propane_parameters();
+        assert_relative_eq!(
+            p.hs_diameter(250.0)[0],
+            3.802356725189725,
+            max_relative = 1e-8
+        );
+    }
+
+    #[test]
+    fn helmholtz_energy() {
+        // reference values from an independent implementation of Lafitte et al. (2013)
+        let p = propane_parameters();
+        let s = StateHD::new(250.0, 150.0, arr1(&[1.0]));
+        let a_hs = HardSphere::new(&p).helmholtz_energy(&s);
+        let a_disp = Dispersion {
+            parameters: p.clone(),
+        }
+        .helmholtz_energy(&s);
+        let a_chain = Chain { parameters: p }.helmholtz_energy(&s);
+        assert_relative_eq!(a_hs, 3.602706618889872, max_relative = 1e-7);
+        assert_relative_eq!(a_disp, -7.057877047994579, max_relative = 1e-7);
+        assert_relative_eq!(a_chain, -0.16464717867932155, max_relative = 1e-7);
+    }
+}
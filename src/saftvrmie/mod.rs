@@ -0,0 +1,22 @@
+//! SAFT-VR Mie equation of state.
+//!
+//! Statistical associating fluid theory for variable range interactions
+//! of the Mie form, i.e., segments interact via a Mie potential with
+//! individual repulsive and attractive exponents.
+//!
+//! The association contribution uses the association strength of the
+//! generic [association](crate::association) implementation with the
+//! Barker-Henderson diameters of the Mie segments.
+//!
+//! # Literature
+//! - [Lafitte et al. (2013)](https://doi.org/10.1063/1.4819786)
+#![warn(clippy::all)]
+#![allow(clippy::too_many_arguments)]
+mod eos;
+pub(crate) mod parameters;
+
+pub use eos::{SaftVRMie, SaftVRMieOptions};
+pub use parameters::{SaftVRMieBinaryRecord, SaftVRMieParameters, SaftVRMieRecord};
+
+#[cfg(feature = "python")]
+pub mod python;
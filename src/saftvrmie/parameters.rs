@@ -0,0 +1,378 @@
+use crate::association::{
+    deserialize_association_record, AssociationCombiningRule, AssociationParameters,
+    AssociationRecord, ASSOCIATION_MISMATCH,
+};
+use crate::hard_sphere::{HardSphereProperties, MonomerShape};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use ndarray::{Array, Array1, Array2};
+use num_dual::DualNum;
+use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// SAFT-VR Mie pure-component parameters.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct SaftVRMieRecord {
+    /// Segment number
+    pub m: f64,
+    /// Segment diameter in units of Angstrom
+    pub sigma: f64,
+    /// Energetic parameter in units of Kelvin
+    pub epsilon_k: f64,
+    /// Repulsive Mie exponent
+    pub lr: f64,
+    /// Attractive Mie exponent
+    pub la: f64,
+    /// Association parameters
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(deserialize_with = "deserialize_association_record")]
+    pub association_record: Option<AssociationRecord>,
+}
+
+impl std::fmt::Display for SaftVRMieRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftVRMieRecord(m={}", self.m)?;
+        write!(f, ", sigma={}", self.sigma)?;
+        write!(f, ", epsilon_k={}", self.epsilon_k)?;
+        write!(f, ", lr={}", self.lr)?;
+        write!(f, ", la={}", self.la)?;
+        if let Some(n) = &self.association_record {
+            write!(f, ", association_record={}", n)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl SaftVRMieRecord {
+    pub fn new(
+        m: f64,
+        sigma: f64,
+        epsilon_k: f64,
+        lr: f64,
+        la: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+    ) -> Result<SaftVRMieRecord, ParameterError> {
+        let association_record = match (kappa_ab, epsilon_k_ab) {
+            (Some(kappa_ab), Some(epsilon_k_ab)) => {
+                Some(AssociationRecord::new(kappa_ab, epsilon_k_ab, na, nb)?)
+            }
+            (None, None) => None,
+            _ => {
+                return Err(ParameterError::IncompatibleParameters(
+                    ASSOCIATION_MISMATCH.into(),
+                ))
+            }
+        };
+        Ok(SaftVRMieRecord {
+            m,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            association_record,
+        })
+    }
+}
+
+/// SAFT-VR Mie binary mixture parameters.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct SaftVRMieBinaryRecord {
+    /// correction to energy parameters
+    pub k_ij: f64,
+}
+
+impl From<f64> for SaftVRMieBinaryRecord {
+    fn from(k_ij: f64) -> Self {
+        Self { k_ij }
+    }
+}
+
+impl From<SaftVRMieBinaryRecord> for f64 {
+    fn from(binary_record: SaftVRMieBinaryRecord) -> Self {
+        binary_record.k_ij
+    }
+}
+
+impl std::fmt::Display for SaftVRMieBinaryRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftVRMieBinaryRecord(k_ij={})", self.k_ij)
+    }
+}
+
+/// Parameter set required for the SAFT-VR Mie equation of state.
+pub struct SaftVRMieParameters {
+    pub molarweight: Array1<f64>,
+    pub m: Array1<f64>,
+    pub sigma: Array1<f64>,
+    pub epsilon_k: Array1<f64>,
+    pub lr: Array1<f64>,
+    pub la: Array1<f64>,
+    pub association: AssociationParameters,
+    pub k_ij: Array2<f64>,
+    pub sigma_ij: Array2<f64>,
+    pub epsilon_k_ij: Array2<f64>,
+    pub lambda_r_ij: Array2<f64>,
+    pub lambda_a_ij: Array2<f64>,
+    pub c_ij: Array2<f64>,
+    pub alpha_ij: Array2<f64>,
+    pub pure_records: Vec<PureRecord<SaftVRMieRecord, JobackRecord>>,
+    pub binary_records: Array2<SaftVRMieBinaryRecord>,
+    pub joback_records: Option<Vec<JobackRecord>>,
+}
+
+impl Parameter for SaftVRMieParameters {
+    type Pure = SaftVRMieRecord;
+    type IdealGas = JobackRecord;
+    type Binary = SaftVRMieBinaryRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure, Self::IdealGas>>,
+        binary_records: Array2<SaftVRMieBinaryRecord>,
+    ) -> Self {
+        let n = pure_records.len();
+
+        let mut molarweight = Array::zeros(n);
+        let mut m = Array::zeros(n);
+        let mut sigma = Array::zeros(n);
+        let mut epsilon_k = Array::zeros(n);
+        let mut lr = Array::zeros(n);
+        let mut la = Array::zeros(n);
+        let mut association_records = Vec::with_capacity(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            let r = &record.model_record;
+            m[i] = r.m;
+            sigma[i] = r.sigma;
+            epsilon_k[i] = r.epsilon_k;
+            lr[i] = r.lr;
+            la[i] = r.la;
+            association_records.push(r.association_record);
+            molarweight[i] = record.molarweight;
+        }
+
+        let association = AssociationParameters::new(
+            &association_records,
+            &sigma,
+            None,
+            AssociationCombiningRule::default(),
+            None,
+        );
+
+        let k_ij = binary_records.map(|br| br.k_ij);
+        let mut sigma_ij = Array::zeros((n, n));
+        let mut epsilon_k_ij = Array::zeros((n, n));
+        let mut lambda_r_ij = Array::zeros((n, n));
+        let mut lambda_a_ij = Array::zeros((n, n));
+        let mut c_ij = Array::zeros((n, n));
+        let mut alpha_ij = Array::zeros((n, n));
+        for i in 0..n {
+            for j in 0..n {
+                sigma_ij[[i, j]] = 0.5 * (sigma[i] + sigma[j]);
+                epsilon_k_ij[[i, j]] = (1.0 - k_ij[[i, j]])
+                    * (sigma[i].powi(3) * sigma[j].powi(3)).sqrt()
+                    / sigma_ij[[i, j]].powi(3)
+                    * (epsilon_k[i] * epsilon_k[j]).sqrt();
+                let lr_ij = ((lr[i] - 3.0) * (lr[j] - 3.0)).sqrt() + 3.0;
+                let la_ij = ((la[i] - 3.0) * (la[j] - 3.0)).sqrt() + 3.0;
+                lambda_r_ij[[i, j]] = lr_ij;
+                lambda_a_ij[[i, j]] = la_ij;
+                c_ij[[i, j]] =
+                    lr_ij / (lr_ij - la_ij) * (lr_ij / la_ij).powf(la_ij / (lr_ij - la_ij));
+                alpha_ij[[i, j]] = c_ij[[i, j]] * (1.0 / (la_ij - 3.0) - 1.0 / (lr_ij - 3.0));
+            }
+        }
+
+        let joback_records = pure_records
+            .iter()
+            .map(|r| r.ideal_gas_record.clone())
+            .collect();
+
+        Self {
+            molarweight,
+            m,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            association,
+            k_ij,
+            sigma_ij,
+            epsilon_k_ij,
+            lambda_r_ij,
+            lambda_a_ij,
+            c_ij,
+            alpha_ij,
+            pure_records,
+            binary_records,
+            joback_records,
+        }
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<SaftVRMieRecord, JobackRecord>],
+        &Array2<SaftVRMieBinaryRecord>,
+    ) {
+        (&self.pure_records, &self.binary_records)
+    }
+}
+
+/// Nodes of the 10-point Gauss-Legendre quadrature on [-1, 1].
+const X_GL10: [f64; 10] = [
+    -0.973906528517171720077964012084452,
+    -0.865063366688984510732096688423493,
+    -0.679409568299024406234327365114874,
+    -0.433395394129247190799265943165784,
+    -0.148874338981631210884826001129720,
+    0.148874338981631210884826001129720,
+    0.433395394129247190799265943165784,
+    0.679409568299024406234327365114874,
+    0.865063366688984510732096688423493,
+    0.973906528517171720077964012084452,
+];
+
+/// Weights of the 10-point Gauss-Legendre quadrature on [-1, 1].
+const W_GL10: [f64; 10] = [
+    0.066671344308688137593568809893332,
+    0.149451349150580593145776339657697,
+    0.219086362515982043995534934228163,
+    0.269266719309996355091226921569469,
+    0.295524224714752870173892994651028,
+    0.295524224714752870173892994651028,
+    0.269266719309996355091226921569469,
+    0.219086362515982043995534934228163,
+    0.149451349150580593145776339657697,
+    0.066671344308688137593568809893332,
+];
+
+impl HardSphereProperties for SaftVRMieParameters {
+    fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
+        MonomerShape::NonSpherical(self.m.mapv(N::from))
+    }
+
+    /// Barker-Henderson diameter of the Mie potential.
+    ///
+    /// Below the distance $r_0$, at which $\beta u(r_0)=-\ln\epsilon$ with the
+    /// machine precision $\epsilon$, the integrand is 1. The remaining integral
+    /// up to $\sigma$ is evaluated with a Gauss-Legendre quadrature.
+    fn hs_diameter<D: DualNum<f64>>(&self, temperature: D) -> Array1<D> {
+        Array::from_shape_fn(self.sigma.len(), |i| {
+            let sigma = self.sigma[i];
+            let lr = self.lambda_r_ij[[i, i]];
+            let la = self.lambda_a_ij[[i, i]];
+            let c_eps = self.c_ij[[i, i]] * self.epsilon_k[i];
+
+            // estimate r_0 from the repulsive part of the potential
+            let r0 = (temperature * -f64::EPSILON.ln()).recip() * c_eps;
+            let r0 = if r0.re() < 1.0 {
+                r0.powf(lr.recip()) * sigma
+            } else {
+                D::from(sigma)
+            };
+
+            let width = (-r0 + sigma) * 0.5;
+            let mut d = r0;
+            for (&x, &w) in X_GL10.iter().zip(W_GL10.iter()) {
+                let r_inv = (width * x + width + r0).recip() * sigma;
+                let u = (r_inv.powf(lr) - r_inv.powf(la)) * c_eps;
+                d += width * w * (-(-u / temperature).exp() + 1.0);
+            }
+            d
+        })
+    }
+}
+
+impl SaftVRMieParameters {
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+        let o = &mut output;
+        write!(
+            o,
+            "|component|molarweight|$m$|$\\sigma$|$\\varepsilon$|$\\lambda_r$|$\\lambda_a$|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|\n|-|-|-|-|-|-|-|-|-|-|-|"
+        )
+        .unwrap();
+        for (i, record) in self.pure_records.iter().enumerate() {
+            let component = record.identifier.name.clone();
+            let component = component.unwrap_or(format!("Component {}", i + 1));
+            let association = record.model_record.association_record.unwrap_or_default();
+            write!(
+                o,
+                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                component,
+                record.molarweight,
+                record.model_record.m,
+                record.model_record.sigma,
+                record.model_record.epsilon_k,
+                record.model_record.lr,
+                record.model_record.la,
+                association.kappa_ab,
+                association.epsilon_k_ab,
+                association.na.unwrap_or(1.0),
+                association.nb.unwrap_or(1.0)
+            )
+            .unwrap();
+        }
+
+        output
+    }
+}
+
+impl std::fmt::Display for SaftVRMieParameters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SaftVRMieParameters(")?;
+        write!(f, "\n\tmolarweight={}", self.molarweight)?;
+        write!(f, "\n\tm={}", self.m)?;
+        write!(f, "\n\tsigma={}", self.sigma)?;
+        write!(f, "\n\tepsilon_k={}", self.epsilon_k)?;
+        write!(f, "\n\tlr={}", self.lr)?;
+        write!(f, "\n\tla={}", self.la)?;
+        if !self.association.assoc_comp.is_empty() {
+            write!(f, "\n\tassociating={}", self.association.assoc_comp)?;
+            write!(f, "\n\tkappa_ab={}", self.association.kappa_ab)?;
+            write!(f, "\n\tepsilon_k_ab={}", self.association.epsilon_k_ab)?;
+            write!(f, "\n\tna={}", self.association.na)?;
+            write!(f, "\n\tnb={}", self.association.nb)?;
+        }
+        if !self.k_ij.iter().all(|k| k.is_zero()) {
+            write!(f, "\n\tk_ij=\n{}", self.k_ij)?;
+        }
+        write!(f, "\n)")
+    }
+}
+
+#[cfg(test)]
+pub mod utils {
+    use super::*;
+    use std::sync::Arc;
+
+    pub fn propane_parameters() -> Arc<SaftVRMieParameters> {
+        let propane_json = r#"
+            {
+                "identifier": {
+                    "cas": "74-98-6",
+                    "name": "propane",
+                    "iupac_name": "propane",
+                    "smiles": "CCC",
+                    "inchi": "InChI=1/C3H8/c1-3-2/h3H2,1-2H3",
+                    "formula": "C3H8"
+                },
+                "model_record": {
+                    "m": 1.6845,
+                    "sigma": 3.9056,
+                    "epsilon_k": 239.89,
+                    "lr": 13.006,
+                    "la": 6.0
+                },
+                "molarweight": 44.0962
+            }"#;
+        let propane_record: PureRecord<SaftVRMieRecord, JobackRecord> =
+            serde_json::from_str(propane_json).expect("Unable to parse json.");
+        Arc::new(SaftVRMieParameters::new_pure(propane_record))
+    }
+}
@@ -0,0 +1,171 @@
+//! Python bindings for the SAFT-VR Mie equation of state.
+use super::parameters::{SaftVRMieBinaryRecord, SaftVRMieParameters, SaftVRMieRecord};
+use feos_core::joback::JobackRecord;
+use feos_core::parameter::{
+    BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
+};
+use feos_core::python::joback::PyJobackRecord;
+use feos_core::python::parameter::*;
+use feos_core::*;
+use ndarray::Array2;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+/// Create a set of SAFT-VR Mie parameters from records.
+#[pyclass(name = "SaftVRMieRecord")]
+#[pyo3(
+    text_signature = "(m, sigma, epsilon_k, lr, la, kappa_ab=None, epsilon_k_ab=None, na=None, nb=None)"
+)]
+#[derive(Clone)]
+pub struct PySaftVRMieRecord(SaftVRMieRecord);
+
+#[pymethods]
+impl PySaftVRMieRecord {
+    #[new]
+    fn new(
+        m: f64,
+        sigma: f64,
+        epsilon_k: f64,
+        lr: f64,
+        la: f64,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        na: Option<f64>,
+        nb: Option<f64>,
+    ) -> PyResult<Self> {
+        Ok(Self(SaftVRMieRecord::new(
+            m,
+            sigma,
+            epsilon_k,
+            lr,
+            la,
+            kappa_ab,
+            epsilon_k_ab,
+            na,
+            nb,
+        )?))
+    }
+
+    #[getter]
+    fn get_m(&self) -> f64 {
+        self.0.m
+    }
+
+    #[getter]
+    fn get_sigma(&self) -> f64 {
+        self.0.sigma
+    }
+
+    #[getter]
+    fn get_epsilon_k(&self) -> f64 {
+        self.0.epsilon_k
+    }
+
+    #[getter]
+    fn get_lr(&self) -> f64 {
+        self.0.lr
+    }
+
+    #[getter]
+    fn get_la(&self) -> f64 {
+        self.0.la
+    }
+
+    #[getter]
+    fn get_kappa_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.kappa_ab)
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> Option<f64> {
+        self.0.association_record.map(|a| a.epsilon_k_ab)
+    }
+
+    #[getter]
+    fn get_na(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.na)
+    }
+
+    #[getter]
+    fn get_nb(&self) -> Option<f64> {
+        self.0.association_record.and_then(|a| a.nb)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PySaftVRMieRecord);
+
+impl_pure_record!(
+    SaftVRMieRecord,
+    PySaftVRMieRecord,
+    JobackRecord,
+    PyJobackRecord
+);
+
+#[pyclass(name = "SaftVRMieBinaryRecord")]
+#[derive(Clone)]
+pub struct PySaftVRMieBinaryRecord(SaftVRMieBinaryRecord);
+impl_binary_record!(SaftVRMieBinaryRecord, PySaftVRMieBinaryRecord);
+
+/// Create a set of SAFT-VR Mie parameters from records.
+///
+/// Parameters
+/// ----------
+/// pure_records : List[PureRecord]
+///     pure substance records.
+/// binary_records : List[BinaryRecord], optional
+///     binary saft parameter records
+/// substances : List[str], optional
+///     The substances to use. Filters substances from `pure_records` according to
+///     `search_option`.
+///     When not provided, all entries of `pure_records` are used.
+/// search_option : {'Name', 'Cas', 'Inchi', 'IupacName', 'Formula', 'Smiles'}, optional, defaults to 'Name'.
+///     Identifier that is used to search substance.
+///
+/// Returns
+/// -------
+/// SaftVRMieParameters
+#[pyclass(name = "SaftVRMieParameters")]
+#[pyo3(
+    text_signature = "(pure_records, binary_records=None, substances=None, search_option='Name')"
+)]
+#[derive(Clone)]
+pub struct PySaftVRMieParameters(pub Arc<SaftVRMieParameters>);
+
+impl_parameter!(SaftVRMieParameters, PySaftVRMieParameters);
+
+#[pymethods]
+impl PySaftVRMieParameters {
+    #[getter]
+    fn get_k_ij<'py>(&self, py: Python<'py>) -> &'py PyArray2<f64> {
+        self.0.k_ij.view().to_pyarray(py)
+    }
+
+    fn _repr_markdown_(&self) -> String {
+        self.0.to_markdown()
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+#[pymodule]
+pub fn saftvrmie(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyChemicalRecord>()?;
+    m.add_class::<PyJobackRecord>()?;
+
+    m.add_class::<PySaftVRMieRecord>()?;
+    m.add_class::<PyPureRecord>()?;
+    m.add_class::<PyBinaryRecord>()?;
+    m.add_class::<PySaftVRMieParameters>()?;
+    Ok(())
+}
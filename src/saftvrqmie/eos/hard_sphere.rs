@@ -69,6 +69,53 @@ impl SaftVRQMieParameters {
         })
     }
 
+    /// The hard-sphere diameters of every segment at the temperature of `state`.
+    ///
+    /// The diameters are cached (see [Self::hs_diameter_ij_cached]).
+    pub fn hs_diameter_cached<D: DualNum<f64>>(&self, state: &StateHD<D>) -> Array1<D> {
+        self.hs_diameter_ij_cached(state).diag().to_owned()
+    }
+
+    /// The effective diameters $\sigma_{\mathrm{eff},ij}$ of all pairs of segments
+    /// at the temperature of `state`.
+    ///
+    /// During the evaluation of the Helmholtz energy of the state, the diameters
+    /// are cached, so that they are only calculated once, even if they are required
+    /// by multiple Helmholtz energy contributions.
+    pub fn sigma_eff_ij_cached<D: DualNum<f64>>(&self, state: &StateHD<D>) -> Array2<D> {
+        let n = self.m.len();
+        let key = (self as *const Self as usize, "sigma_eff_ij");
+        state
+            .temperature_dependent(key, |t| {
+                Array1::from_shape_fn(n * n, |k| self.calc_sigma_eff_ij(k / n, k % n, t))
+            })
+            .into_shape((n, n))
+            .unwrap()
+    }
+
+    /// The hard-sphere diameters $d_{ij}$ of all pairs of segments at the
+    /// temperature of `state`.
+    ///
+    /// The evaluation requires the numerical integration of the potential.
+    /// During the evaluation of the Helmholtz energy of the state, the diameters
+    /// are therefore cached (see [StateHD::with_temperature_cache]), so that they
+    /// are only calculated once, even if they are required by multiple Helmholtz energy
+    /// contributions.
+    pub fn hs_diameter_ij_cached<D: DualNum<f64>>(&self, state: &StateHD<D>) -> Array2<D> {
+        let n = self.m.len();
+        let s_eff_ij = self.sigma_eff_ij_cached(state);
+        let key = (self as *const Self as usize, "hs_diameter_ij");
+        state
+            .temperature_dependent(key, |t| {
+                Array1::from_shape_fn(n * n, |k| {
+                    let (i, j) = (k / n, k % n);
+                    self.hs_diameter_ij(i, j, t, s_eff_ij[[i, j]])
+                })
+            })
+            .into_shape((n, n))
+            .unwrap()
+    }
+
     #[inline]
     pub fn hs_diameter_ij<D: DualNum<f64>>(
         &self,
@@ -221,7 +268,7 @@ pub struct HardSphere {
 
 impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for HardSphere {
     fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
-        let d = self.parameters.hs_diameter(state.temperature);
+        let d = self.parameters.hs_diameter_cached(state);
         let zeta = zeta(&self.parameters.m, &state.partial_density, &d);
         let frac_1mz3 = -(zeta[3] - 1.0).recip();
         let zeta_23 = zeta_23(&self.parameters.m, &state.molefracs, &d);
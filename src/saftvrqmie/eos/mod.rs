@@ -6,6 +6,7 @@ use feos_core::{
 };
 use ndarray::Array1;
 use quantity::si::*;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::{FRAC_PI_6, PI};
 use std::sync::Arc;
 
@@ -17,7 +18,8 @@ use hard_sphere::HardSphere;
 use non_additive_hs::NonAddHardSphere;
 
 /// Customization options for the SAFT-VRQ Mie equation of state and functional.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SaftVRQMieOptions {
     pub max_eta: f64,
     pub fh_order: FeynmanHibbsOrder,
@@ -35,7 +37,7 @@ impl Default for SaftVRQMieOptions {
 }
 
 /// Order of Feynman-Hibbs potential
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum FeynmanHibbsOrder {
     /// First order correction
@@ -83,6 +85,16 @@ impl SaftVRQMie {
             contributions,
         }
     }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<SaftVRQMieParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &SaftVRQMieOptions {
+        &self.options
+    }
 }
 
 impl EquationOfState for SaftVRQMie {
@@ -16,14 +16,10 @@ impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for NonAddHardSphere {
         let p = &self.parameters;
         let n = p.m.len();
         // temperature dependent segment radius
-        let s_eff_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
-            p.calc_sigma_eff_ij(i, j, state.temperature)
-        });
+        let s_eff_ij = p.sigma_eff_ij_cached(state);
 
         // temperature dependent segment radius
-        let d_hs_ij = Array2::from_shape_fn((n, n), |(i, j)| -> D {
-            p.hs_diameter_ij(i, j, state.temperature, s_eff_ij[[i, j]])
-        });
+        let d_hs_ij = p.hs_diameter_ij_cached(state);
 
         // Additive hard-sphere diameter
         let d_hs_add_ij =
@@ -4,6 +4,7 @@
 use super::parameters::UVParameters;
 use feos_core::{parameter::Parameter, EosError, EosResult, EquationOfState, HelmholtzEnergy};
 use ndarray::Array1;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::FRAC_PI_6;
 use std::sync::Arc;
 
@@ -26,7 +27,7 @@ use reference_perturbation_uvb3::ReferencePerturbationUVB3;
 use reference_perturbation_wca::ReferencePerturbationWCA;
 
 /// Type of perturbation.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum Perturbation {
     BarkerHenderson,
@@ -34,7 +35,7 @@ pub enum Perturbation {
 }
 
 /// Order of the highest virial coefficient included in the model.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum VirialOrder {
     Second,
@@ -42,7 +43,8 @@ pub enum VirialOrder {
 }
 
 /// Configuration options for uv-theory
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UVTheoryOptions {
     pub max_eta: f64,
     pub perturbation: Perturbation,
@@ -142,6 +144,16 @@ impl UVTheory {
             contributions,
         })
     }
+
+    /// Return the parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<UVParameters> {
+        &self.parameters
+    }
+
+    /// Return the options of the equation of state.
+    pub fn options(&self) -> &UVTheoryOptions {
+        &self.options
+    }
 }
 
 impl EquationOfState for UVTheory {
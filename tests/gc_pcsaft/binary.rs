@@ -1,11 +1,13 @@
 use approx::assert_relative_eq;
-use feos::gc_pcsaft::{GcPcSaft, GcPcSaftEosParameters};
+use feos::gc_pcsaft::{GcPcSaft, GcPcSaftEosParameters, GcPcSaftOptions};
 #[cfg(feature = "dft")]
 use feos::gc_pcsaft::{GcPcSaftFunctional, GcPcSaftFunctionalParameters};
+use feos::EosVariant;
 use feos_core::parameter::{IdentifierOption, ParameterHetero};
-use feos_core::{Contributions, EosResult, State};
+use feos_core::{Contributions, EosResult, EquationOfState, State, StateHD};
 use ndarray::arr1;
 use quantity::si::{KELVIN, METER, MOL};
+use std::error::Error;
 use std::sync::Arc;
 
 #[test]
@@ -78,3 +80,37 @@ fn test_polar_term() -> EosResult<()> {
     assert_eq!(p1, p2);
     Ok(())
 }
+
+#[test]
+fn test_serialize_eos_variant() -> Result<(), Box<dyn Error>> {
+    let parameters = GcPcSaftEosParameters::from_json_segments(
+        &["CCCOC(C)=O", "CCCO"],
+        "parameters/pcsaft/gc_substances.json",
+        "parameters/pcsaft/sauer2014_hetero.json",
+        None,
+        IdentifierOption::Smiles,
+    )?;
+    let options = GcPcSaftOptions {
+        max_eta: 0.45,
+        max_iter_cross_assoc: 100,
+        ..Default::default()
+    };
+    let eos = EosVariant::GcPcSaft(GcPcSaft::with_options(Arc::new(parameters), options));
+    let json = serde_json::to_string(&eos)?;
+    let eos_json: EosVariant = serde_json::from_str(&json)?;
+
+    let saft = match &eos_json {
+        EosVariant::GcPcSaft(saft) => saft,
+        _ => panic!("Expected gc-PC-SAFT."),
+    };
+    assert_eq!(saft.options().max_eta, 0.45);
+    assert_eq!(saft.options().max_iter_cross_assoc, 100);
+
+    let state_hd = StateHD::from_partial_densities(300.0, arr1(&[0.001, 0.002]));
+    assert_relative_eq!(
+        eos.evaluate_residual(&state_hd),
+        eos_json.evaluate_residual(&state_hd),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use approx::assert_relative_eq;
+use feos::lj::{LennardJones, LennardJonesParameters, LennardJonesRecord};
+use feos_core::parameter::{Identifier, Parameter, PureRecord};
+use feos_core::{Contributions, EosUnit, State};
+use ndarray::arr1;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+/// Critical point of the Lennard-Jones fluid in reduced units compared
+/// to the values reported by Johnson et al. (1993) for their equation of state.
+#[test]
+fn critical_point_reduced_units() -> Result<(), Box<dyn Error>> {
+    let (sigma, epsilon_k) = (3.405, 119.8);
+    let record = PureRecord::new(
+        Identifier::default(),
+        39.948,
+        LennardJonesRecord::new(sigma, epsilon_k),
+        None,
+    );
+    let eos = Arc::new(LennardJones::new(Arc::new(
+        LennardJonesParameters::new_pure(record),
+    )));
+    let cp = State::critical_point(
+        &eos,
+        None,
+        Some(1.3 * epsilon_k * KELVIN),
+        Default::default(),
+    )?;
+
+    let t = cp.temperature.to_reduced(KELVIN)? / epsilon_k;
+    let rho = cp.density.to_reduced(SIUnit::reference_density())? * sigma.powi(3);
+    let p = cp
+        .pressure(Contributions::Total)
+        .to_reduced(SIUnit::reference_pressure())?
+        * sigma.powi(3)
+        / epsilon_k;
+    assert_relative_eq!(t, 1.313, max_relative = 1e-3);
+    assert_relative_eq!(rho, 0.310, epsilon = 5e-3);
+    assert_relative_eq!(p, 0.13, epsilon = 5e-3);
+    Ok(())
+}
+
+/// Tricritical point of a symmetric mixture of two identical Lennard-Jones fluids
+/// with a weak attraction between unlike molecules. The line of critical points
+/// of the demixing transition (lambda line) is located at the equimolar composition.
+/// Depending on the attraction between unlike molecules, it ends in a critical end
+/// point or in a tricritical point (Wilding et al., Phys. Rev. E 58, 2201, 1998).
+#[test]
+fn tricritical_point_symmetric_mixture() -> Result<(), Box<dyn Error>> {
+    let (sigma, epsilon_k) = (3.405, 119.8);
+    let record = PureRecord::new(
+        Identifier::default(),
+        39.948,
+        LennardJonesRecord::new(sigma, epsilon_k),
+        None,
+    );
+    let parameters =
+        LennardJonesParameters::new_binary(vec![record.clone(), record], Some(0.45.into()));
+    let eos = Arc::new(LennardJones::new(Arc::new(parameters)));
+
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let density = 0.46 / sigma.powi(3) * SIUnit::reference_density();
+    let initial_state = State::new_nvt(&eos, 1.35 * epsilon_k * KELVIN, MOL / density, &moles)?;
+    let tcp = State::tricritical_point_binary(
+        &eos,
+        None,
+        None,
+        Some(&initial_state),
+        Default::default(),
+    )?;
+    assert_relative_eq!(tcp.molefracs[0], 0.5, max_relative = 1e-8);
+
+    // the composition is a critical direction (lambda line)
+    let dmu_dni = tcp.dmu_dni(Contributions::Total);
+    let (mu_11, mu_12) = (dmu_dni.get((0, 0)), dmu_dni.get((0, 1)));
+    assert_relative_eq!(
+        ((mu_11 - mu_12) / (mu_11 + mu_12)).into_value()?,
+        0.0,
+        epsilon = 1e-6
+    );
+
+    // reference values from the tricritical point conditions of the equation of
+    // state of Johnson et al. (1993) evaluated with symbolic derivatives in
+    // extended precision
+    let t = tcp.temperature.to_reduced(KELVIN)? / epsilon_k;
+    let rho = tcp.density.to_reduced(SIUnit::reference_density())? * sigma.powi(3);
+    assert_relative_eq!(t, 1.367085244695989, max_relative = 1e-7);
+    assert_relative_eq!(rho, 0.4698043950345764, max_relative = 1e-7);
+    Ok(())
+}
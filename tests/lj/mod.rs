@@ -0,0 +1 @@
+mod critical_point;
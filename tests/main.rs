@@ -1,4 +1,8 @@
 #[cfg(feature = "gc_pcsaft")]
 mod gc_pcsaft;
+#[cfg(feature = "lj")]
+mod lj;
 #[cfg(feature = "pcsaft")]
 mod pcsaft;
+#[cfg(feature = "saftvrmie")]
+mod saftvrmie;
@@ -1,7 +1,9 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::State;
+use feos_core::{
+    Contributions, PhaseEquilibrium, ResidualScaling, SolverOptions, SolverStats, State,
+};
 use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
@@ -47,3 +49,443 @@ fn test_critical_point_mix() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_critical_point_binary_tp() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let cp = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    let t = cp.temperature;
+    let p = cp.pressure(Contributions::Total);
+
+    let cp_tp = State::critical_point_binary_tp(&saft, t, p, None, Default::default())?;
+    assert_relative_eq!(cp_tp.molefracs, arr1(&[0.3, 0.7]), epsilon = 1e-6);
+    assert_relative_eq!(cp_tp.density, cp.density, max_relative = 1e-6);
+
+    // the critical pressure is already determined by the temperature
+    assert!(State::critical_point_binary_tp(&saft, t, 1.1 * p, None, Default::default()).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn test_par_critical_point() {
+    use feos::pcsaft::PcSaftRecord;
+    use feos_core::parameter::{Identifier, PureRecord};
+
+    let records: Vec<_> = (0..5)
+        .map(|i| {
+            let epsilon_k = 200.0 + 5.0 * i as f64;
+            let record = PcSaftRecord::new(
+                2.0, 3.6, epsilon_k, None, None, None, None, None, None, None, None, None,
+            )
+            .unwrap();
+            PureRecord::new(Identifier::default(), 44.0, record, None)
+        })
+        .collect();
+    let cp = State::par_critical_point(
+        records,
+        |r| Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_pure(r)))),
+        None,
+        Default::default(),
+    );
+    assert_eq!(cp.iter().filter(|s| s.is_some()).count(), 5);
+    let tc: Vec<_> = cp.iter().flatten().map(|s| s.temperature).collect();
+    assert!(tc.windows(2).all(|t| t[0] < t[1]));
+}
+
+#[test]
+fn test_reduced_residual_helmholtz_energy_surface() -> Result<(), Box<dyn Error>> {
+    use feos::pcsaft::PcSaftRecord;
+    use feos_core::parameter::{Identifier, PureRecord};
+
+    // PC-SAFT fluids with the same segment number are conformal
+    let surface = |sigma: f64, epsilon_k: f64| {
+        let record = PcSaftRecord::new(
+            1.5, sigma, epsilon_k, None, None, None, None, None, None, None, None, None,
+        )?;
+        let record = PureRecord::new(Identifier::default(), 30.0, record, None);
+        let saft = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_pure(record))));
+        State::reduced_residual_helmholtz_energy_surface(
+            &saft,
+            &arr1(&[0.8, 1.0, 1.5]),
+            &arr1(&[0.1, 1.0, 2.5]),
+            Default::default(),
+        )
+    };
+    let a1 = surface(3.5, 200.0)?;
+    let a2 = surface(3.0, 300.0)?;
+    assert_relative_eq!(a1, a2, max_relative = 1e-6);
+    assert!(a1[(0, 2)] < 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_krichevskii_parameter() -> Result<(), Box<dyn Error>> {
+    let krichevskii_parameter = |components: Vec<&str>, solute| {
+        let params = PcSaftParameters::from_json(
+            components,
+            "tests/pcsaft/test_parameters.json",
+            None,
+            IdentifierOption::Name,
+        )?;
+        let saft = Arc::new(PcSaft::new(Arc::new(params)));
+        State::krichevskii_parameter(&saft, solute, None, Default::default())
+    };
+
+    // volatile solute (methane) in propane
+    let a_kr = krichevskii_parameter(vec!["methane", "propane"], 0)?.to_reduced(BAR)?;
+    assert!(a_kr.is_finite() && a_kr > 0.0);
+
+    // nonvolatile solute (butane) in propane
+    let a_kr = krichevskii_parameter(vec!["propane", "butane"], 1)?.to_reduced(BAR)?;
+    assert!(a_kr.is_finite() && a_kr < 0.0);
+
+    assert!(krichevskii_parameter(vec!["propane", "butane"], 2).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_state_from_reduced() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    let cp = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+
+    let s = State::from_reduced(&saft, 1.0, 1.0, Some(&moles), Default::default())?;
+    assert_relative_eq!(s.temperature, cp.temperature, max_relative = 1e-10);
+    assert_relative_eq!(s.density, cp.density, max_relative = 1e-10);
+    assert_relative_eq!(s.moles, cp.moles, max_relative = 1e-10);
+
+    let s = State::from_reduced(&saft, 0.9, 2.0, Some(&moles), Default::default())?;
+    assert_relative_eq!(s.temperature, 0.9 * cp.temperature, max_relative = 1e-10);
+    assert_relative_eq!(s.density, 2.0 * cp.density, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_scaling() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[1500.0, 1500.0]) * MOL;
+
+    // smallest number of iterations required to converge
+    let iterations = |options: SolverOptions| {
+        (1..=50).find(|&k| {
+            State::critical_point(&saft, Some(&moles), Some(t), options.max_iter(k)).is_ok()
+        })
+    };
+    let raw = SolverOptions::new();
+    let scaled = SolverOptions::new().scaling(ResidualScaling::Automatic);
+    let iter_raw = iterations(raw).unwrap();
+    let iter_scaled = iterations(scaled).unwrap();
+    assert!(iter_scaled <= iter_raw);
+
+    let cp_raw = State::critical_point(&saft, Some(&moles), Some(t), raw)?;
+    let cp_scaled = State::critical_point(&saft, Some(&moles), Some(t), scaled)?;
+    assert_relative_eq!(
+        cp_scaled.temperature,
+        cp_raw.temperature,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(cp_scaled.density, cp_raw.density, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_mix_t() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 400.0 * KELVIN;
+    let cp_binary = State::critical_point_binary(&saft, t, None, None, Default::default())?;
+    let cp = State::critical_point_mix_t(&saft, t, &[0.5, 0.5], Default::default())?;
+    assert_relative_eq!(cp.density, cp_binary.density, max_relative = 1e-8);
+    assert_relative_eq!(cp.molefracs, cp_binary.molefracs, max_relative = 1e-8);
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 420.0 * KELVIN;
+    let cp = State::critical_point_mix_t(&saft, t, &[0.5, 0.25, 0.25], Default::default())?;
+    assert_relative_eq!(cp.temperature, t, max_relative = 1e-10);
+    assert_relative_eq!(cp.molefracs[1], cp.molefracs[2], max_relative = 1e-10);
+
+    // the critical point of the resulting composition is at the same temperature
+    let cp_n = State::critical_point(&saft, Some(&cp.moles), Some(t), Default::default())?;
+    assert_relative_eq!(cp_n.temperature, t, max_relative = 1e-6);
+    assert_relative_eq!(cp_n.density, cp.density, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_critical_compressibility_acentric_factor() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cp = State::critical_point_pure(&saft, None, Default::default())?;
+    for cp in cp.iter() {
+        let zc = cp.pressure(Contributions::Total) / (cp.density * RGAS * cp.temperature);
+        assert_relative_eq!(
+            cp.critical_compressibility(),
+            zc.into_value()?,
+            max_relative = 1e-12
+        );
+    }
+
+    let omega = State::acentric_factors(&saft, Default::default())?;
+    for (i, cp) in cp.iter().enumerate() {
+        let vle = PhaseEquilibrium::pure(&cp.eos, 0.7 * cp.temperature, None, Default::default())?;
+        let pr = vle.vapor().pressure(Contributions::Total) / cp.pressure(Contributions::Total);
+        assert_relative_eq!(
+            omega[i],
+            -pr.into_value()?.log10() - 1.0,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            State::acentric_factor(&saft, i, Default::default())?,
+            omega[i],
+            max_relative = 1e-8
+        );
+    }
+    // experimental values (Poling et al., The Properties of Gases and
+    // Liquids, 5th ed.): propane 0.152, butane 0.200
+    assert_relative_eq!(omega[0], 0.152, epsilon = 0.03);
+    assert_relative_eq!(omega[1], 0.200, epsilon = 0.03);
+    assert!(State::acentric_factor(&saft, 2, Default::default()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_spinodal_curve() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    // subcritical for all compositions
+    let t = 350.0 * KELVIN;
+    let spinodal = State::spinodal_curve(&saft, t, 9, Default::default())?;
+    assert_eq!(spinodal.len(), 18);
+    for (vapor, liquid) in spinodal[..9].iter().zip(spinodal[9..].iter().rev()) {
+        assert_relative_eq!(vapor.temperature, t, max_relative = 1e-12);
+        assert_relative_eq!(vapor.molefracs, liquid.molefracs, max_relative = 1e-12);
+        assert!(vapor.density < liquid.density);
+    }
+    assert!(spinodal[0].molefracs[0] < spinodal[8].molefracs[0]);
+
+    // supercritical for compositions rich in propane
+    let t = 400.0 * KELVIN;
+    let spinodal = State::spinodal_curve(&saft, t, 9, Default::default())?;
+    assert!(spinodal.len() < 18);
+    assert_eq!(spinodal.len() % 2, 0);
+    Ok(())
+}
+
+#[test]
+fn test_critical_locus_binary() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cp = State::critical_point_pure(&saft, None, Default::default())?;
+    let locus = State::critical_locus_binary(&saft, 20, Default::default())?;
+    assert!(locus.len() >= 19);
+
+    // the critical line connects the pure component critical points
+    let (first, last) = (&locus[0], &locus[locus.len() - 1]);
+    assert_relative_eq!(first.temperature, cp[0].temperature, max_relative = 1e-10);
+    assert_relative_eq!(first.molefracs[0], 1.0);
+    assert_relative_eq!(last.temperature, cp[1].temperature, max_relative = 1e-10);
+    assert_relative_eq!(last.molefracs[1], 1.0);
+    for s in locus.windows(2) {
+        assert!(s[0].molefracs[1] < s[1].molefracs[1]);
+    }
+
+    // every point is a critical point of the respective composition
+    for s in &locus[1..locus.len() - 1] {
+        let cp = State::critical_point(
+            &saft,
+            Some(&s.moles),
+            Some(s.temperature),
+            Default::default(),
+        )?;
+        assert_relative_eq!(cp.temperature, s.temperature, max_relative = 1e-6);
+        assert_relative_eq!(cp.density, s.density, max_relative = 1e-6);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_binary_warm_start() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let options = SolverOptions::default();
+
+    // temperature sweep, every point starts from the previous one
+    let mut cp = State::critical_point_binary(&saft, 380.0 * KELVIN, None, None, options)?;
+    for t in [385.0, 390.0, 395.0, 400.0] {
+        let t = t * KELVIN;
+        let cp_cold = State::critical_point_binary(&saft, t, None, None, options)?;
+        cp = State::critical_point_binary_from(&saft, t, &cp, options)?;
+        assert_relative_eq!(cp.density, cp_cold.density, max_relative = 1e-8);
+        assert_relative_eq!(cp.molefracs, cp_cold.molefracs, max_relative = 1e-8);
+    }
+
+    // pressure specification
+    let p = cp.pressure(Contributions::Total);
+    let cp_p = State::critical_point_binary_from(&saft, p, &cp, options)?;
+    assert_relative_eq!(cp_p.temperature, cp.temperature, max_relative = 1e-8);
+    assert_relative_eq!(cp_p.density, cp.density, max_relative = 1e-8);
+
+    // with a tight iteration budget, only the warm start converges: the cold
+    // start at the pressure of the last critical point begins at 300 K
+    let options = SolverOptions::new().max_iter(3);
+    let p = State::critical_point_binary(&saft, 400.1 * KELVIN, None, None, Default::default())?
+        .pressure(Contributions::Total);
+    assert!(State::critical_point_binary(&saft, p, None, None, options).is_err());
+    let cp_warm = State::critical_point_binary_from(&saft, p, &cp, options)?;
+    assert_relative_eq!(cp_warm.temperature, 400.1 * KELVIN, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_stats() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    let options = SolverOptions::default();
+
+    let (cp, stats) = State::critical_point_with_stats(&saft, Some(&moles), Some(t), options);
+    let cp = cp?;
+    let cp_ref = State::critical_point(&saft, Some(&moles), Some(t), options)?;
+    assert_relative_eq!(cp.temperature, cp_ref.temperature, max_relative = 1e-12);
+    assert!(stats.converged);
+    assert!(stats.iterations > 0);
+    assert!(stats.final_residual < 1e-8);
+
+    let (cp, stats) =
+        State::critical_point_with_stats(&saft, Some(&moles), Some(t), options.max_iter(1));
+    assert!(cp.is_err());
+    assert_eq!(stats.iterations, 1);
+    assert!(!stats.converged);
+
+    let (cp, stats) =
+        State::critical_point_binary_with_stats(&saft, 400.0 * KELVIN, None, None, options);
+    cp?;
+    assert!(stats.converged);
+
+    let (spinodal, stats) = State::spinodal_with_stats(&saft, t, Some(&moles), options);
+    spinodal?;
+    assert!(stats.iter().all(|s| s.converged && s.iterations > 0));
+    let (_, cp_stats) = State::critical_point_with_stats(&saft, Some(&moles), None, options);
+    assert_eq!(stats[0], cp_stats);
+    assert_ne!(stats[1], SolverStats::default());
+    Ok(())
+}
+
+#[test]
+fn test_cached_critical_point_pure() -> Result<(), Box<dyn Error>> {
+    use feos_core::{CachedEquationOfState, EquationOfState};
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cached = CachedEquationOfState::new(&saft, 0);
+    let cp = State::critical_point_pure(&saft, None, Default::default())?;
+
+    // the options of later calls are ignored
+    let cp1 = cached.critical_point_pure(Default::default())?;
+    let cp2 = cached.critical_point_pure(SolverOptions::new().max_iter(0))?;
+    for i in 0..2 {
+        assert_relative_eq!(cp1[i].temperature, cp[i].temperature, max_relative = 1e-12);
+        assert_relative_eq!(cp2[i].density, cp1[i].density, max_relative = 1e-12);
+    }
+
+    // the acentric factors and the critical locus use the cached critical points
+    let omega = State::acentric_factors(&saft, Default::default())?;
+    let omega_cached = cached.acentric_factors(Default::default())?;
+    for i in 0..2 {
+        assert_relative_eq!(omega[i], omega_cached[i], max_relative = 1e-10);
+    }
+    let locus = cached.critical_locus_binary(10, Default::default())?;
+    assert_relative_eq!(
+        locus[0].temperature,
+        cp[0].temperature,
+        max_relative = 1e-12
+    );
+
+    // the cache is cleared together with the states
+    cached.clear();
+    assert!(cached
+        .critical_point_pure(SolverOptions::new().max_iter(0))
+        .is_err());
+
+    // a subset uses its own cache
+    let cached_subset = CachedEquationOfState::new(&Arc::new(saft.subset(&[1])), 0);
+    let cp_subset = cached_subset.critical_point_pure(Default::default())?;
+    assert_eq!(cp_subset.len(), 1);
+    assert_relative_eq!(
+        cp_subset[0].temperature,
+        cp[1].temperature,
+        max_relative = 1e-12
+    );
+    let diagram = cached_subset.phase_diagram_pure(200.0 * KELVIN, 11, Default::default())?;
+    assert_eq!(diagram.states.len(), 11);
+    assert_relative_eq!(
+        diagram.states[10].vapor().temperature,
+        cp[1].temperature,
+        max_relative = 1e-12
+    );
+    Ok(())
+}
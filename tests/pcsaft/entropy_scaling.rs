@@ -0,0 +1,70 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos::EosVariant;
+use feos_core::cubic::{PengRobinson, PengRobinsonParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{DensityInitialization, EosError, EquationOfState, State};
+use ndarray::arr1;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_viscosity_toluene() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["toluene"],
+        "parameters/pcsaft/loetgeringlin2018.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = Arc::new(EosVariant::PcSaft(PcSaft::new(Arc::new(params))));
+    let t = 298.15 * KELVIN;
+    let p = BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let s = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)?;
+    assert_relative_eq!(
+        s.viscosity()?,
+        0.5513695812 * MILLI * PASCAL * SECOND,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_entropy_scaling_not_supported() -> Result<(), Box<dyn Error>> {
+    let params = PengRobinsonParameters::new_simple(&[369.96], &[4250000.0], &[0.153], &[44.0962])?;
+    let eos = Arc::new(EosVariant::PengRobinson(PengRobinson::new(Arc::new(
+        params,
+    ))));
+    let t = 300.0 * KELVIN;
+    let p = BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let s = State::new_npt(&eos, t, p, &moles, DensityInitialization::Vapor)?;
+    assert!(matches!(s.viscosity(), Err(EosError::NotSupported(_))));
+    Ok(())
+}
+
+#[test]
+fn test_segment_numbers_eos_variant() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let saft = Arc::new(PcSaft::new(params.clone()));
+    let eos = Arc::new(EosVariant::PcSaft(PcSaft::new(params.clone())));
+    assert_eq!(eos.segment_numbers(), params.m);
+
+    let t = 300.0 * KELVIN;
+    let p = 20.0 * BAR;
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)?;
+    let s_saft = State::new_npt(&saft, t, p, &moles, DensityInitialization::Liquid)?;
+    assert_relative_eq!(
+        s.reduced_residual_entropy(),
+        s_saft.reduced_residual_entropy(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
@@ -1,8 +1,10 @@
 mod critical_point;
 mod dft;
+mod entropy_scaling;
 mod properties;
 mod stability_analysis;
 mod state_creation_mixture;
 mod state_creation_pure;
 mod tp_flash;
+mod vle_binary;
 mod vle_pure;
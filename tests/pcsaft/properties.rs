@@ -1,10 +1,17 @@
 use approx::assert_relative_eq;
-use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos::pcsaft::{PcSaft, PcSaftBinaryRecord, PcSaftOptions, PcSaftParameters};
+use feos::EosVariant;
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{EquationOfState, StateBuilder};
+use feos_core::{
+    CachedEquationOfState, Contributions, DensityInitialization, Derivative, DerivativeReport,
+    EosUnit, EquationOfState, HelmholtzEnergy, HelmholtzEnergyDual, MolarWeight, Phase, State,
+    StateBuilder, StateHD,
+};
 use ndarray::*;
+use num_dual::{Dual64, DualNum};
 use quantity::si::*;
 use std::error::Error;
+use std::fmt;
 use std::sync::Arc;
 
 #[test]
@@ -53,3 +60,1100 @@ fn test_virial_is_not_nan() -> Result<(), Box<dyn Error>> {
     assert!(!virial_b.is_nan());
     Ok(())
 }
+
+#[test]
+fn test_excess_chemical_potential_gibbs_duhem() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let p = 20.0 * BAR;
+    let x = 0.4;
+    let h = 1e-5;
+    let mu_e = |x: f64| -> Result<Array1<f64>, Box<dyn Error>> {
+        let s = StateBuilder::new(&saft)
+            .temperature(t)
+            .pressure(p)
+            .molefracs(&arr1(&[x, 1.0 - x]))
+            .liquid()
+            .build()?;
+        Ok(s.excess_chemical_potentials()?.to_reduced(JOULE / MOL)?)
+    };
+    let dmu_dx = (mu_e(x + h)? - mu_e(x - h)?) / (2.0 * h);
+    let gibbs_duhem = x * dmu_dx[0] + (1.0 - x) * dmu_dx[1];
+    assert_relative_eq!(gibbs_duhem, 0.0, epsilon = 1e-6 * dmu_dx[0].abs().max(1.0));
+    Ok(())
+}
+
+#[test]
+fn test_z_factor_isotherm() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let p = arr1(&[1e2, 1e5, 5e5, 2e6]) * PASCAL;
+    let z = State::z_factor_isotherm(&saft, t, &p, None, Default::default())?;
+    assert_relative_eq!(z[0], 1.0, epsilon = 1e-4);
+
+    let s = State::new_npt(
+        &saft,
+        t,
+        p.get(2),
+        &(arr1(&[1.0]) * MOL),
+        DensityInitialization::Vapor,
+    )?;
+    assert_relative_eq!(
+        z[2],
+        s.compressibility(Contributions::Total),
+        max_relative = 1e-10
+    );
+
+    // 20 bar is above the vapor pressure of propane at 300 K
+    assert!(z[3].is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_pressure_finite_differences() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let s = StateBuilder::new(&saft)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .molefracs(&arr1(&[0.5, 0.5]))
+        .liquid()
+        .build()?;
+
+    let t = s.temperature.to_reduced(SIUnit::reference_temperature())?;
+    let v = s.volume.to_reduced(SIUnit::reference_volume())?;
+    let n = s.moles.to_reduced(SIUnit::reference_moles())?;
+    let a = |v: f64| saft.evaluate_residual(&StateHD::new(t, v, n.clone())) * t;
+    let h = v * 1e-5;
+    let p = -(a(v + h) - a(v - h)) / (2.0 * h) * SIUnit::reference_pressure();
+    assert_relative_eq!(
+        p,
+        s.pressure(Contributions::ResidualNvt),
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_temperature_dependent_k_ij() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let records = params.pure_records.clone();
+    let linear = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_binary(
+        records.clone(),
+        Some(PcSaftBinaryRecord::new(0.01, Some(1e-4))),
+    ))));
+    let constant = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_binary(
+        records,
+        Some(0.04.into()),
+    ))));
+    let t = 300.0 * KELVIN;
+    let v = 1e-4 * METER.powi(3);
+    let n = arr1(&[0.3, 0.7]) * MOL;
+
+    // k_ij(T) evaluated at a fixed temperature
+    let s = State::new_nvt(&linear, t, v, &n)?;
+    assert_relative_eq!(
+        s.pressure(Contributions::Total),
+        State::new_nvt(&constant, t, v, &n)?.pressure(Contributions::Total),
+        max_relative = 1e-12
+    );
+
+    // the temperature dependence enters the entropy
+    let a = |t| {
+        State::new_nvt(&linear, t, v, &n).map(|s| s.helmholtz_energy(Contributions::ResidualNvt))
+    };
+    let h = 1e-4 * KELVIN;
+    let s_num = -(a(t + h)? - a(t - h)?) / (2.0 * h);
+    assert_relative_eq!(
+        s.entropy(Contributions::ResidualNvt),
+        s_num,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_density_temperature_derivative_isobaric() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let p = 20.0 * BAR;
+    let h = 1e-4 * KELVIN;
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let s = State::new_npt(&saft, t, p, &moles, DensityInitialization::Liquid)?;
+    let s_h = State::new_npt(&saft, t + h, p, &moles, DensityInitialization::Liquid)?;
+    let s_mh = State::new_npt(&saft, t - h, p, &moles, DensityInitialization::Liquid)?;
+    assert_relative_eq!(
+        s.density_temperature_derivative_isobaric(),
+        (s_h.density - s_mh.density) / (2.0 * h),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pressure_density_taylor() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let s = State::new_npt(&saft, t, 20.0 * BAR, &moles, DensityInitialization::Liquid)?;
+    let c = s.pressure_density_taylor(3)?;
+    assert_relative_eq!(c[0], s.pressure(Contributions::Total), max_relative = 1e-12);
+    assert_relative_eq!(c[1], s.dp_drho(Contributions::Total), max_relative = 1e-10);
+
+    let drho = 1e-3 * s.density;
+    let s_h = State::new_nvt(&saft, t, s.total_moles / (s.density + drho), &moles)?;
+    let p_taylor = c[0] + c[1] * drho + c[2] * drho.powi(2) + c[3] * drho.powi(3);
+    assert_relative_eq!(
+        p_taylor,
+        s_h.pressure(Contributions::Total),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_residual_tv_hessian() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let s = State::new_npt(
+        &saft,
+        300.0 * KELVIN,
+        20.0 * BAR,
+        &moles,
+        DensityInitialization::Liquid,
+    )?;
+    let [[a_tt, a_tv], [a_vt, a_vv]] = s.residual_tv_hessian();
+    let c = Contributions::ResidualNvt;
+    assert_relative_eq!(
+        a_tt,
+        -s.c_v(c) * s.total_moles / s.temperature,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(a_tv, -s.dp_dt(c), max_relative = 1e-10);
+    assert_relative_eq!(a_vt, a_tv, max_relative = 1e-14);
+    assert_relative_eq!(a_vv, -s.dp_dv(c), max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_reduced_residual_derivatives() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let s = State::new_npt(
+        &saft,
+        300.0 * KELVIN,
+        20.0 * BAR,
+        &moles,
+        DensityInitialization::Liquid,
+    )?;
+    let (_, a_v, _) = s.reduced_residual_derivatives();
+    let t = s.temperature.to_reduced(SIUnit::reference_temperature())?;
+    let p_res = -t * a_v * SIUnit::reference_pressure();
+    assert_relative_eq!(
+        p_res,
+        s.pressure(Contributions::ResidualNvt),
+        max_relative = 1e-12
+    );
+    let p_ig = s.density * RGAS * s.temperature;
+    assert_relative_eq!(
+        p_res + p_ig,
+        s.pressure(Contributions::Total),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ln_phi_without_state() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    for (p, phase) in [(2.0 * BAR, Phase::Vapor), (20.0 * BAR, Phase::Liquid)] {
+        let state = State::new_npt(&saft, t, p, &moles, phase)?;
+        let ln_phi = saft.ln_phi(t, p, Some(&moles), phase)?;
+        assert_relative_eq!(ln_phi, state.ln_phi(), max_relative = 1e-10);
+        let ln_phi = saft.ln_phi(t, p, Some(&moles), Phase::Stable)?;
+        assert_relative_eq!(ln_phi, state.ln_phi(), max_relative = 1e-10);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_zero_pressure_inversion_temperature() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["argon"],
+        "parameters/pcsaft/gross2001.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = saft
+        .zero_pressure_inversion_temperature(None)
+        .ok_or("no inversion temperature found")?;
+    let b = saft.second_virial_coefficient(t, None)?;
+    let db_dt = saft.second_virial_coefficient_temperature_derivative(t, None)?;
+    assert_relative_eq!(t * db_dt, b, max_relative = 1e-8);
+
+    // experimental value for argon: approx. 723 K
+    assert!(t > 600.0 * KELVIN && t < 900.0 * KELVIN);
+    Ok(())
+}
+
+#[test]
+fn test_boyle_temperature() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["argon"],
+        "parameters/pcsaft/gross2001.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = saft.boyle_temperature(None, None)?;
+    let b = saft.second_virial_coefficient(t, None)?;
+    assert!(b.to_reduced(METER.powi(3) / MOL)?.abs() < 1e-12);
+
+    // the result does not depend on the initial temperature
+    let t2 = saft.boyle_temperature(None, Some(1000.0 * KELVIN))?;
+    assert_relative_eq!(t, t2, max_relative = 1e-8);
+
+    // experimental value for argon: approx. 410 K
+    assert!(t > 300.0 * KELVIN && t < 550.0 * KELVIN);
+    Ok(())
+}
+
+#[test]
+fn test_heat_capacity_ratio() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["methane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.0]) * MOL;
+
+    // ideal gas limit of a polyatomic molecule
+    let s = State::new_npt(&saft, 300.0 * KELVIN, 1e-3 * BAR, &moles, Phase::Vapor)?;
+    let gamma = s.heat_capacity_ratio();
+    let c_p = s.c_p(Contributions::Total);
+    assert_relative_eq!(
+        gamma,
+        (c_p / (c_p - RGAS)).into_value()?,
+        max_relative = 1e-4
+    );
+    assert!(gamma > 1.0 && gamma < 4.0 / 3.0);
+
+    // consistency with the speed of sound
+    let s = State::new_npt(&saft, 150.0 * KELVIN, 50.0 * BAR, &moles, Phase::Liquid)?;
+    let c = (s.heat_capacity_ratio() * s.dp_drho(Contributions::Total) / s.total_molar_weight())
+        .sqrt()?;
+    assert_relative_eq!(c, s.speed_of_sound(), max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_reduced_residual_entropy() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let m = params.m[0];
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.0]) * MOL;
+    let s = State::new_npt(
+        &saft,
+        300.0 * KELVIN,
+        20.0 * BAR,
+        &moles,
+        DensityInitialization::Liquid,
+    )?;
+
+    // s_res/R = -(beta A_res + T d(beta A_res)/dT) / N
+    let (a_t, _, _) = s.reduced_residual_derivatives();
+    let t = s.temperature.to_reduced(SIUnit::reference_temperature())?;
+    let n = s.total_moles.to_reduced(SIUnit::reference_moles())?;
+    let beta_a = (s.helmholtz_energy(Contributions::ResidualNvt) / (RGAS * s.temperature))
+        .to_reduced(SIUnit::reference_moles())?;
+    let s_star = (beta_a + t * a_t) / n / m;
+    let s_star_liquid = s.reduced_residual_entropy();
+    assert_relative_eq!(s_star_liquid, s_star, max_relative = 1e-10);
+    assert!(s_star_liquid > 1.0 && s_star_liquid < 5.0);
+
+    // the residual entropy vanishes in the ideal gas limit
+    let s = State::new_npt(&saft, 300.0 * KELVIN, 1e-5 * BAR, &moles, Phase::Vapor)?;
+    let s_star_vapor = s.reduced_residual_entropy();
+    assert!(s_star_vapor > 0.0 && s_star_vapor < 1e-5);
+    Ok(())
+}
+
+#[test]
+fn test_second_virial_coefficient_matrix_temperature_derivative() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let x = arr1(&[0.3, 0.7]);
+    let db_dt = saft.second_virial_coefficient_matrix_temperature_derivative(t, Some(&moles))?;
+    assert_relative_eq!(db_dt.get((0, 1)), db_dt.get((1, 0)));
+
+    let mut db_dt_mix = 0.0 * db_dt.get((0, 0));
+    for i in 0..2 {
+        for j in 0..2 {
+            db_dt_mix += x[i] * x[j] * db_dt.get((i, j));
+        }
+    }
+    assert_relative_eq!(
+        db_dt_mix,
+        saft.second_virial_coefficient_temperature_derivative(t, Some(&moles))?,
+        max_relative = 1e-12
+    );
+
+    // central differences of the second virial coefficient matrix
+    let h = 1e-3 * KELVIN;
+    let b_plus = saft.second_virial_coefficient_matrix(t + h, Some(&moles))?;
+    let b_minus = saft.second_virial_coefficient_matrix(t - h, Some(&moles))?;
+    for i in 0..2 {
+        for j in 0..2 {
+            assert_relative_eq!(
+                db_dt.get((i, j)),
+                (b_plus.get((i, j)) - b_minus.get((i, j))) / (2.0 * h),
+                max_relative = 1e-6
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_mass_fraction_conversion() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["methane", "propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let x = arr1(&[0.2, 0.3, 0.5]);
+    let w = saft.mole_to_mass_fractions(&x)?;
+    assert_relative_eq!(w.sum(), 1.0, max_relative = 1e-14);
+    assert_relative_eq!(saft.mass_to_mole_fractions(&w)?, x, max_relative = 1e-14);
+
+    let s = State::new_nvt(
+        &saft,
+        300.0 * KELVIN,
+        1.0 * METER.powi(3),
+        &(x.clone() * MOL),
+    )?;
+    assert_relative_eq!(s.massfracs(), w, max_relative = 1e-14);
+
+    assert!(saft.mole_to_mass_fractions(&arr1(&[0.5, 0.5])).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pressure_and_gradient() -> Result<(), Box<dyn Error>> {
+    for components in [vec!["propane"], vec!["methane", "propane", "butane"]] {
+        let params = PcSaftParameters::from_json(
+            components,
+            "tests/pcsaft/test_parameters.json",
+            None,
+            IdentifierOption::Name,
+        )?;
+        let saft = Arc::new(PcSaft::new(Arc::new(params)));
+        let n = saft.components();
+        let t = 300.0;
+        let v = 1e-3;
+        let moles: Vec<f64> = (0..n).map(|i| 1.0 + 0.5 * i as f64).collect();
+        let (p, grad) = saft.pressure_and_gradient(t, v, &moles)?;
+        assert_eq!(grad.len(), n + 2);
+
+        let state = State::new_nvt(&saft, t * KELVIN, v * METER.powi(3), &(arr1(&moles) * MOL))?;
+        assert_relative_eq!(
+            p * PASCAL,
+            state.pressure(Contributions::Total),
+            max_relative = 1e-10
+        );
+
+        // central finite differences in the order (T, V, n_1, ..., n_N)
+        let x: Vec<f64> = [t, v].iter().chain(moles.iter()).copied().collect();
+        for i in 0..n + 2 {
+            let h = 1e-6 * x[i];
+            let mut x_h = x.clone();
+            x_h[i] = x[i] + h;
+            let (p_plus, _) = saft.pressure_and_gradient(x_h[0], x_h[1], &x_h[2..])?;
+            x_h[i] = x[i] - h;
+            let (p_minus, _) = saft.pressure_and_gradient(x_h[0], x_h[1], &x_h[2..])?;
+            assert_relative_eq!(grad[i], (p_plus - p_minus) / (2.0 * h), max_relative = 1e-6);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_grand_potential_density() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let partial_density = arr1(&[3000.0, 5000.0]) * MOL / METER.powi(3);
+    let s = State::from_partial_densities(&saft, 300.0 * KELVIN, &partial_density)?;
+    assert_relative_eq!(s.partial_density, partial_density, max_relative = 1e-12);
+
+    // Omega = -pV
+    for c in [Contributions::Total, Contributions::ResidualNvt] {
+        let omega = s.grand_potential_density(c) * s.volume;
+        assert_relative_eq!(omega, -s.pressure(c) * s.volume, max_relative = 1e-10);
+    }
+
+    let t = s.temperature.to_reduced(SIUnit::reference_temperature())?;
+    let rho = partial_density.to_reduced(SIUnit::reference_density())?;
+    let state_hd = StateHD::from_partial_densities(t, rho.clone());
+    assert_relative_eq!(state_hd.partial_density, rho, max_relative = 1e-12);
+    assert_relative_eq!(
+        saft.evaluate_residual(&state_hd) * t * SIUnit::reference_pressure(),
+        s.helmholtz_energy(Contributions::ResidualNvt) / s.volume,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_contribution_timings() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["ethanol", "water"],
+        "parameters/pcsaft/gross2002.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let state_hd = StateHD::from_partial_densities(300.0, arr1(&[0.01, 0.02]));
+    let a = saft.evaluate_residual(&state_hd);
+
+    // profiling is enabled for the current thread only
+    feos_core::profiling::enable();
+    let a_profiled = saft.evaluate_residual(&state_hd);
+    let timings = saft.last_contribution_timings();
+    feos_core::profiling::disable();
+
+    assert_eq!(a_profiled, a);
+    let names: Vec<_> = saft.residual().iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        timings.iter().map(|(c, _)| c).collect::<Vec<_>>(),
+        names.iter().collect::<Vec<_>>()
+    );
+    assert!(timings.iter().all(|(_, t)| !t.is_zero()));
+    Ok(())
+}
+
+#[test]
+fn test_isochoric_heat_capacity() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 350.0 * KELVIN;
+    let v = 2.0e-3 * METER.powi(3);
+    let n = arr1(&[0.2, 0.3]) * MOL;
+    let s = State::new_nvt(&saft, t, v, &n)?;
+    assert_relative_eq!(
+        s.isochoric_heat_capacity(Contributions::Total),
+        s.c_v(Contributions::Total) * s.total_moles,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        s.c_v(Contributions::Total),
+        s.c_v(Contributions::IdealGas) + s.c_v(Contributions::ResidualNvt),
+        max_relative = 1e-12
+    );
+
+    // cp - cv = -T (dp/dT)^2 / (dp/dV) with derivatives from finite differences
+    let p = |t, v| -> Result<SINumber, Box<dyn Error>> {
+        Ok(State::new_nvt(&saft, t, v, &n)?.pressure(Contributions::Total))
+    };
+    let dt = 1e-4 * KELVIN;
+    let dv = 1e-9 * METER.powi(3);
+    let dp_dt = (p(t + dt, v)? - p(t - dt, v)?) / (2.0 * dt);
+    let dp_dv = (p(t, v + dv)? - p(t, v - dv)?) / (2.0 * dv);
+    assert_relative_eq!(
+        (s.c_p(Contributions::Total) - s.c_v(Contributions::Total)) * s.total_moles,
+        -t * dp_dt * dp_dt / dp_dv,
+        max_relative = 1e-6
+    );
+
+    // ideal gas limit
+    let s = State::new_nvt(&saft, t, 1e3 * METER.powi(3), &n)?;
+    let c_v_res = (s.c_v(Contributions::ResidualNvt) / RGAS).into_value()?;
+    assert!(c_v_res.abs() < 1e-6);
+    assert_relative_eq!(
+        s.c_p(Contributions::Total) - s.c_v(Contributions::Total),
+        RGAS,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+/// Mean field contribution that (incorrectly) discards the
+/// derivatives w.r.t. the density.
+struct BrokenContribution;
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for BrokenContribution {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let density = state.partial_density.sum().re();
+        state.moles.sum() * (D::from(1.0) - state.temperature.recip() * density * 100.0)
+    }
+}
+
+impl fmt::Display for BrokenContribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Broken")
+    }
+}
+
+struct BrokenEos(Vec<Box<dyn HelmholtzEnergy>>);
+
+impl EquationOfState for BrokenEos {
+    fn components(&self) -> usize {
+        1
+    }
+
+    fn subset(&self, _: &[usize]) -> Self {
+        Self(vec![Box::new(BrokenContribution)])
+    }
+
+    fn compute_max_density(&self, _: &Array1<f64>) -> f64 {
+        0.05
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.0
+    }
+}
+
+#[test]
+fn test_derivative_self_check() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = State::new_nvt(&saft, 300.0 * KELVIN, 1e-4 * METER.powi(3), &moles)?;
+    let report = saft.derivative_self_check(&s)?;
+    assert_eq!(report.chemical_potential.len(), 2);
+    assert!(report.max_deviation() < 1e-6);
+    assert!(report.isochoric_heat_capacity < 1e-6);
+    assert!(report.failed(1e-6).is_empty());
+    assert!(s.check_derivative_consistency(1e-6).is_ok());
+
+    let broken = Arc::new(BrokenEos(vec![Box::new(BrokenContribution)]));
+    let moles = arr1(&[1.0]) * MOL;
+    let s = State::new_nvt(&broken, 300.0 * KELVIN, 1e-4 * METER.powi(3), &moles)?;
+    let report = broken.derivative_self_check(&s)?;
+    assert!(report.entropy < 1e-6);
+    assert!(report.pressure > 1e-2);
+    assert!(report.chemical_potential[0] > 1e-2);
+    assert!(report.dp_dv > 1e-2);
+    let failed: Vec<_> = report.failed(1e-6).into_iter().map(|(d, _)| d).collect();
+    assert!(failed.contains(&"dA/dV".to_string()));
+    assert!(failed.contains(&"d2A/dV2".to_string()));
+    assert!(!failed.contains(&"dA/dT".to_string()));
+    let error = s.check_derivative_consistency(1e-6).unwrap_err();
+    assert!(error.to_string().contains("dA/dV"));
+
+    // NaN deviations are not hidden by the maximum
+    let report = DerivativeReport {
+        dp_dt: f64::NAN,
+        ..report
+    };
+    assert!(report.max_deviation().is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_chemical_potential_contributions() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["methane", "propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.2, 0.3, 0.5]) * MOL;
+    let s = State::new_npt(
+        &saft,
+        300.0 * KELVIN,
+        20.0 * BAR,
+        &moles,
+        DensityInitialization::None,
+    )?;
+    let mu = s.chemical_potential(Contributions::Total);
+    let mu_ig = s.chemical_potential(Contributions::IdealGas);
+    let mu_res = s.chemical_potential(Contributions::ResidualNvt);
+    for i in 0..3 {
+        assert_relative_eq!(
+            mu.get(i),
+            mu_ig.get(i) + mu_res.get(i),
+            max_relative = 1e-10
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_helmholtz_closure() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let closure = saft.as_helmholtz_closure();
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    assert_send_sync(&closure);
+
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = State::new_npt(
+        &saft,
+        300.0 * KELVIN,
+        10.0 * BAR,
+        &moles,
+        DensityInitialization::Liquid,
+    )?;
+    let t = s.temperature.to_reduced(SIUnit::reference_temperature())?;
+    let v = s.volume.to_reduced(SIUnit::reference_volume())?;
+    let n = s.moles.to_reduced(SIUnit::reference_moles())?;
+    let a = s
+        .helmholtz_energy(Contributions::Total)
+        .to_reduced(SIUnit::reference_energy())?;
+    let beta_a = closure(t, v, &n);
+    assert_relative_eq!(beta_a * t, a, max_relative = 1e-12);
+
+    // evaluate the closure on a different thread
+    let beta_a_thread = std::thread::spawn(move || closure(t, v, &n))
+        .join()
+        .unwrap();
+    assert_eq!(beta_a_thread, beta_a);
+    Ok(())
+}
+
+#[test]
+fn test_second_virial_coefficient_matrix() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let b = saft.second_virial_coefficient_matrix(t, Some(&moles))?;
+    assert_relative_eq!(b.get((0, 1)), b.get((1, 0)));
+    assert!(b.get((0, 1)).to_reduced(METER.powi(3) / MOL)? < 0.0);
+
+    // the composition-weighted sum yields the second virial coefficient of the mixture
+    let x = arr1(&[0.3, 0.7]);
+    let mut b_mix = 0.0 * b.get((0, 0));
+    for i in 0..2 {
+        for j in 0..2 {
+            b_mix += x[i] * x[j] * b.get((i, j));
+        }
+    }
+    assert_relative_eq!(
+        b_mix,
+        saft.second_virial_coefficient(t, Some(&moles))?,
+        max_relative = 1e-12
+    );
+
+    // for pure compositions, the diagonal yields the pure component values
+    for i in 0..2 {
+        let mut x = arr1(&[0.0, 0.0]);
+        x[i] = 1.0;
+        let b = saft.second_virial_coefficient_matrix(t, Some(&(x * MOL)))?;
+        let pure = saft.subset(&[i]);
+        assert_relative_eq!(
+            b.get((i, i)),
+            pure.second_virial_coefficient(t, None)?,
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_state_hd_derivative_seeds() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    // second derivative of the residual Helmholtz energy w.r.t. the mole numbers
+    let (t, v) = (300.0, 1000.0);
+    let s = StateHD::new(t, v, arr1(&[1.0, 2.0]));
+    let a_n0n1 = saft
+        .evaluate_residual(&s.derive2_mixed(Derivative::DN(0), Derivative::DN(1)))
+        .eps1eps2[(0, 0)];
+    let h = 1e-5;
+    let a_n0 = |n1: f64| {
+        let s = StateHD::new(t, v, arr1(&[1.0, n1]));
+        saft.evaluate_residual(&s.derive1(Derivative::DN(0))).eps[0]
+    };
+    assert_relative_eq!(
+        a_n0n1,
+        (a_n0(2.0 + h) - a_n0(2.0 - h)) / (2.0 * h),
+        max_relative = 1e-6
+    );
+
+    // the same derivative in the limit of zero density yields the
+    // cross second virial coefficient (as in `second_virial_coefficient_matrix`)
+    let moles = arr1(&[0.3, 0.7]);
+    let s = StateHD::new_virial(t, 0.0, moles.clone());
+    let b_01 = saft
+        .evaluate_residual(&s.derive2_mixed(Derivative::DN(0), Derivative::DN(1)))
+        .eps1eps2[(0, 0)]
+        * 0.5;
+    let b = saft.second_virial_coefficient_matrix(t * KELVIN, Some(&(moles * MOL)))?;
+    assert_relative_eq!(
+        b_01,
+        b.get((0, 1))
+            .to_reduced(SIUnit::reference_volume() / SIUnit::reference_moles())?,
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+/// Truncated virial expansion of a square-well fluid (sigma = 3 A, lambda = 1.5,
+/// epsilon/k = 150 K) with the analytic second and third virial coefficients
+/// (Hirschfelder, Curtiss and Bird, 1954, valid for lambda <= 2).
+///
+/// The fourth and fifth virial coefficients of the square-well fluid are not
+/// known analytically and are replaced by the values of hard spheres. The
+/// fourth virial coefficient of hard spheres is exact (Boltzmann), the fifth
+/// is a numerical value.
+struct SquareWellVirial;
+
+impl SquareWellVirial {
+    fn coefficients<D: DualNum<f64>>(temperature: D) -> [D; 4] {
+        let pi = std::f64::consts::PI;
+        let b0 = 2.0 / 3.0 * pi * 27.0;
+        let l: f64 = 1.5;
+        let x = (temperature.recip() * 150.0).exp_m1();
+        let b = -x * (l.powi(3) - 1.0) * b0 + b0;
+        let c1 = l.powi(6) - 18.0 * l.powi(4) + 32.0 * l.powi(3) - 15.0;
+        let c2 = 2.0 * l.powi(6) - 36.0 * l.powi(4) + 32.0 * l.powi(3) + 18.0 * l.powi(2) - 16.0;
+        let c3 = 6.0 * l.powi(6) - 18.0 * l.powi(4) + 18.0 * l.powi(2) - 6.0;
+        let c = -(x * (x * (x * c3 + c2) + c1) - 5.0) * b0.powi(2) / 8.0;
+        let d = 2707.0 / 4480.0 + 219.0 * 2f64.sqrt() / (2240.0 * pi)
+            - 4131.0 / (4480.0 * pi) * (1.0f64 / 3.0).acos();
+        [b, c, D::from(d * b0.powi(3)), D::from(0.1103 * b0.powi(4))]
+    }
+}
+
+impl<D: DualNum<f64>> HelmholtzEnergyDual<D> for SquareWellVirial {
+    fn helmholtz_energy(&self, state: &StateHD<D>) -> D {
+        let rho = state.partial_density.sum();
+        let [b, c, d, e] = Self::coefficients(state.temperature);
+        state.volume
+            * rho
+            * (b * rho + c * rho.powi(2) / 2.0 + d * rho.powi(3) / 3.0 + e * rho.powi(4) / 4.0)
+    }
+}
+
+impl fmt::Display for SquareWellVirial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Square-well virial expansion")
+    }
+}
+
+struct SquareWellVirialEos(Vec<Box<dyn HelmholtzEnergy>>);
+
+impl EquationOfState for SquareWellVirialEos {
+    fn components(&self) -> usize {
+        1
+    }
+
+    fn subset(&self, _: &[usize]) -> Self {
+        Self(vec![Box::new(SquareWellVirial)])
+    }
+
+    fn compute_max_density(&self, _: &Array1<f64>) -> f64 {
+        0.01
+    }
+
+    fn residual(&self) -> &[Box<dyn HelmholtzEnergy>] {
+        &self.0
+    }
+}
+
+#[test]
+fn test_fourth_virial_coefficient() -> Result<(), Box<dyn Error>> {
+    let eos = Arc::new(SquareWellVirialEos(vec![Box::new(SquareWellVirial)]));
+    let unit = ANGSTROM.powi(3) * NAV;
+    for t in [100.0, 300.0, 1000.0] {
+        let [b, c, d, _] = SquareWellVirial::coefficients(t);
+        let temperature = t * KELVIN;
+        assert_relative_eq!(
+            eos.second_virial_coefficient(temperature, None)?,
+            b * unit,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            eos.third_virial_coefficient(temperature, None)?,
+            c * unit.powi(2),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            eos.fourth_virial_coefficient(temperature, None)?,
+            d * unit.powi(3),
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parameter_records_json_roundtrip() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let json = serde_json::to_string(&params.records())?;
+    let (pure_records, binary_records) = serde_json::from_str(&json)?;
+    let params_json = PcSaftParameters::from_records(pure_records, binary_records);
+
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let saft_json = Arc::new(PcSaft::new(Arc::new(params_json)));
+    let t = 350.0 * KELVIN;
+    let v = 1e-3 * METER.powi(3);
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let p = State::new_nvt(&saft, t, v, &moles)?.pressure(Contributions::Total);
+    let p_json = State::new_nvt(&saft_json, t, v, &moles)?.pressure(Contributions::Total);
+    assert_eq!(p.to_reduced(PASCAL)?, p_json.to_reduced(PASCAL)?);
+    Ok(())
+}
+
+#[test]
+fn test_residual_helmholtz_energy_contributions() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = State::new_nvt(&saft, 350.0 * KELVIN, 1e-4 * METER.powi(3), &moles)?;
+    let contributions = s.residual_helmholtz_energy_contributions();
+    assert!(contributions.iter().any(|(name, _)| name == "Association"));
+    assert!(contributions.iter().any(|(name, _)| name == "Dispersion"));
+
+    let mut a_res = 0.0 * JOULE;
+    for (_, a) in contributions {
+        a_res += a;
+    }
+    assert_relative_eq!(
+        a_res,
+        s.helmholtz_energy(Contributions::ResidualNvt),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_residual_contributions_order() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["ethanol", "water"],
+        "parameters/pcsaft/gross2002.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let names: Vec<_> = saft.residual().iter().map(|c| c.to_string()).collect();
+
+    let state_hd = StateHD::from_partial_densities(300.0, arr1(&[0.01, 0.02]));
+    let contributions = saft.evaluate_residual_contributions(&state_hd);
+    assert_eq!(
+        contributions.iter().map(|(c, _)| c).collect::<Vec<_>>(),
+        names.iter().collect::<Vec<_>>()
+    );
+    let a: f64 = contributions.iter().map(|(_, a)| a).sum();
+    assert_eq!(saft.evaluate_residual(&state_hd), a);
+
+    let t = Dual64::from(300.0).derive();
+    let state_hd = StateHD::new(t, Dual64::from(100.0), arr1(&[1.0, 2.0]).mapv(Dual64::from));
+    let contributions = saft.evaluate_residual_contributions(&state_hd);
+    let a: Dual64 = contributions.iter().map(|(_, a)| *a).sum();
+    let a_res = saft.evaluate_residual(&state_hd);
+    assert_eq!((a_res.re, a_res.eps[0]), (a.re, a.eps[0]));
+
+    // the contributions are summed up in their original order, also if they
+    // are evaluated in parallel
+    let a_seq: Dual64 = saft
+        .residual()
+        .iter()
+        .map(|c| c.helmholtz_energy(&state_hd))
+        .sum();
+    assert_eq!((a_res.re, a_res.eps[0]), (a_seq.re, a_seq.eps[0]));
+    Ok(())
+}
+
+#[test]
+fn test_serialize_eos_variant() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["ethanol", "water"],
+        "parameters/pcsaft/gross2002.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let options = PcSaftOptions {
+        max_eta: 0.45,
+        max_iter_cross_assoc: 100,
+        ..Default::default()
+    };
+    let eos = EosVariant::PcSaft(PcSaft::with_options(Arc::new(params), options));
+    let json = serde_json::to_string(&eos)?;
+    let eos_json: EosVariant = serde_json::from_str(&json)?;
+    assert_eq!(serde_json::to_string(&eos_json)?, json);
+
+    let saft = match &eos_json {
+        EosVariant::PcSaft(saft) => saft,
+        _ => panic!("Expected PC-SAFT."),
+    };
+    assert_eq!(saft.options().max_eta, 0.45);
+    assert_eq!(saft.options().max_iter_cross_assoc, 100);
+
+    let state_hd = StateHD::from_partial_densities(300.0, arr1(&[0.01, 0.02]));
+    assert_eq!(
+        eos.evaluate_residual(&state_hd),
+        eos_json.evaluate_residual(&state_hd)
+    );
+
+    // the binary records have to match the number of pure records
+    let mut value: serde_json::Value = serde_json::from_str(&json)?;
+    value["pure_records"].as_array_mut().unwrap().pop();
+    let err = serde_json::from_value::<EosVariant>(value).err().unwrap();
+    assert!(err.to_string().contains("binary records"));
+    Ok(())
+}
+
+#[test]
+fn test_cached_equation_of_state() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cached = CachedEquationOfState::new(&saft, 2);
+    let t = 300.0 * KELVIN;
+    let n = arr1(&[1.0]) * MOL;
+    let v1 = 1e-3 * METER.powi(3);
+    let v2 = 2.0 * v1;
+    let v3 = 3.0 * v1;
+
+    let s1 = cached.state(t, v1, &n)?;
+    assert!(Arc::ptr_eq(&s1, &cached.state(t, v1, &n)?));
+    assert_eq!(cached.statistics(), (1, 1));
+    let direct = State::new_nvt(&saft, t, v1, &n)?;
+    assert_relative_eq!(
+        s1.pressure(Contributions::Total),
+        direct.pressure(Contributions::Total),
+        max_relative = 1e-14
+    );
+
+    // the least recently used state (v2) is discarded
+    cached.state(t, v2, &n)?;
+    cached.state(t, v1, &n)?;
+    cached.state(t, v3, &n)?;
+    assert_eq!(cached.statistics(), (2, 3));
+    assert!(Arc::ptr_eq(&s1, &cached.state(t, v1, &n)?));
+    cached.state(t, v2, &n)?;
+    assert_eq!(cached.statistics(), (3, 4));
+
+    // after clearing the cache, the state is created again
+    cached.clear();
+    assert!(!Arc::ptr_eq(&s1, &cached.state(t, v1, &n)?));
+    assert_eq!(cached.statistics(), (3, 5));
+
+    // without capacity, nothing is stored
+    let uncached = CachedEquationOfState::new(&saft, 0);
+    uncached.state(t, v1, &n)?;
+    uncached.state(t, v1, &n)?;
+    assert_eq!(uncached.statistics(), (0, 2));
+    Ok(())
+}
@@ -1,6 +1,7 @@
+use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{DensityInitialization, PhaseEquilibrium, State};
+use feos_core::{Contributions, DensityInitialization, PhaseEquilibrium, State};
 use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
@@ -46,3 +47,132 @@ fn test_stability_analysis() -> Result<(), Box<dyn Error>> {
     assert!(liquid_check.is_empty());
     Ok(())
 }
+
+#[test]
+fn test_is_two_phase() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let mix = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let bubble = PhaseEquilibrium::bubble_point(
+        &mix,
+        t,
+        &arr1(&[0.5, 0.5]),
+        Some(6.0 * BAR),
+        None,
+        Default::default(),
+    )?;
+    let dew = PhaseEquilibrium::dew_point(
+        &mix,
+        t,
+        &arr1(&[0.5, 0.5]),
+        Some(5.0 * BAR),
+        None,
+        Default::default(),
+    )?;
+    let p_bubble = bubble.liquid().pressure(Contributions::Total);
+    let p_dew = dew.vapor().pressure(Contributions::Total);
+
+    // inside the two-phase region
+    let p = 0.5 * (p_bubble + p_dew);
+    assert!(State::is_two_phase(&mix, t, p, &moles, Default::default())?);
+    let state = State::new_npt(&mix, t, p, &moles, DensityInitialization::Liquid)?;
+    let x = state
+        .incipient_phase(Default::default())?
+        .ok_or("no incipient phase")?;
+    assert!((x[0] - 0.5).abs() > 1e-3);
+
+    // compressed liquid
+    let p = 2.0 * p_bubble;
+    assert!(!State::is_two_phase(
+        &mix,
+        t,
+        p,
+        &moles,
+        Default::default()
+    )?);
+    let state = State::new_npt(&mix, t, p, &moles, DensityInitialization::Liquid)?;
+    assert!(state.incipient_phase(Default::default())?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_stability_test() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let mix = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    let bubble = PhaseEquilibrium::bubble_point(
+        &mix,
+        t,
+        &arr1(&[0.5, 0.5]),
+        Some(6.0 * BAR),
+        None,
+        Default::default(),
+    )?;
+    let p_bubble = bubble.liquid().pressure(Contributions::Total);
+
+    // a vapor slightly below the bubble point forms a liquid-like phase
+    let p = 0.95 * p_bubble;
+    let vapor = State::new_npt(&mix, t, p, &moles, DensityInitialization::Vapor)?;
+    let result = vapor.stability_test(Default::default())?;
+    assert!(!result.stable);
+    assert!(result.tangent_plane_distance.ok_or("no tpd")? < 0.0);
+    let incipient = result.incipient_phase.ok_or("no incipient phase")?;
+    assert!(incipient.density > vapor.density);
+    assert!(incipient.molefracs[1] > 0.5);
+
+    // compressed liquid
+    let liquid = State::new_npt(
+        &mix,
+        t,
+        2.0 * p_bubble,
+        &moles,
+        DensityInitialization::Liquid,
+    )?;
+    let result = liquid.stability_test(Default::default())?;
+    assert!(result.stable);
+    assert!(result.incipient_phase.is_none());
+    assert!(result.tangent_plane_distance.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_stable_states_grid() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.0]) * MOL;
+    let temperatures = SIArray1::linspace(250.0 * KELVIN, 300.0 * KELVIN, 3)?;
+    let pressures = SIArray1::linspace(BAR, 50.0 * BAR, 4)?;
+    let states =
+        State::stable_states_grid(&saft, &temperatures, &pressures, &moles, Default::default())?;
+    assert_eq!(states.len(), 12);
+
+    // the pressure changes fastest
+    let (t, p, state) = &states[5];
+    assert_eq!(*t, temperatures.get(1));
+    assert_eq!(*p, pressures.get(1));
+    let state = state.as_ref().ok_or("state is not stable")?;
+    let direct = State::new_npt(&saft, *t, *p, &moles, DensityInitialization::None)?;
+    assert_relative_eq!(
+        state.molar_enthalpy(Contributions::Total),
+        direct.molar_enthalpy(Contributions::Total),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
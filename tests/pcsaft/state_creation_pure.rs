@@ -2,8 +2,10 @@ use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
 use feos_core::{
-    Contributions, DensityInitialization, EquationOfState, PhaseEquilibrium, State, StateBuilder,
+    Contributions, DensityInitialization, EquationOfState, Phase, PhaseEquilibrium, State,
+    StateBuilder,
 };
+use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -422,3 +424,76 @@ fn test_consistency() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn pressure_temperature_explicit_phase() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?));
+    let temperature = 300.0 * KELVIN;
+    let pressure = 8.0 * BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let vapor = State::new_npt(&saft, temperature, pressure, &moles, Phase::Vapor)?;
+    let liquid = State::new_npt(&saft, temperature, pressure, &moles, Phase::Liquid)?;
+    let stable = State::new_npt(&saft, temperature, pressure, &moles, Phase::Stable)?;
+    assert!(vapor.density < 0.5 * liquid.density);
+
+    // propane is a vapor at 300 K and 8 bar
+    assert!(
+        vapor.molar_gibbs_energy(Contributions::Total)
+            < liquid.molar_gibbs_energy(Contributions::Total)
+    );
+    assert_relative_eq!(stable.density, vapor.density, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn stable_gibbs_energy() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?));
+    let temperature = 300.0 * KELVIN;
+    let moles = arr1(&[1.0]) * MOL;
+    let vle = PhaseEquilibrium::pure(&saft, temperature, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+
+    for (pressure, phase) in [(0.9 * p_sat, Phase::Vapor), (1.1 * p_sat, Phase::Liquid)] {
+        let g_vapor = State::new_npt(&saft, temperature, pressure, &moles, Phase::Vapor)?
+            .molar_gibbs_energy(Contributions::Total);
+        let g_liquid = State::new_npt(&saft, temperature, pressure, &moles, Phase::Liquid)?
+            .molar_gibbs_energy(Contributions::Total);
+        let (g, stable_phase) = State::stable_gibbs_energy(&saft, temperature, pressure, &moles)?;
+        assert_eq!(stable_phase, phase);
+        assert_relative_eq!(
+            g,
+            if g_vapor < g_liquid {
+                g_vapor
+            } else {
+                g_liquid
+            },
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn isentropic_state_ideal_gas_limit() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?));
+    let moles = arr1(&[1.0]) * MOL;
+    let state = State::new_npt(&saft, 400.0 * KELVIN, 0.01 * BAR, &moles, Phase::Vapor)?;
+    let expanded = state.isentropic_state(0.001 * BAR)?;
+    assert_relative_eq!(
+        expanded.molar_entropy(Contributions::Total),
+        state.molar_entropy(Contributions::Total),
+        max_relative = 1e-8
+    );
+
+    // in the ideal gas limit, the isentropic enthalpy change equals the work
+    // int v dp = c_p (T_2 - T_1) for a constant heat capacity
+    let c_p = 0.5 * (state.c_p(Contributions::Total) + expanded.c_p(Contributions::Total));
+    let dh =
+        expanded.molar_enthalpy(Contributions::Total) - state.molar_enthalpy(Contributions::Total);
+    assert_relative_eq!(
+        dh,
+        c_p * (expanded.temperature - state.temperature),
+        max_relative = 2e-3
+    );
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
-use feos_core::{Contributions, PhaseEquilibrium, SolverOptions};
+use feos_core::{
+    Contributions, DensityInitialization, EosError, FlashResult, PhaseEquilibrium, PhaseRole,
+    SolverOptions, State,
+};
 use ndarray::*;
 use quantity::si::*;
 use std::error::Error;
@@ -60,3 +63,204 @@ fn test_tp_flash() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_component_phase_distribution() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 250.0 * KELVIN;
+    let p = BAR;
+    let z = arr1(&[0.5, 0.5]);
+    let vle = PhaseEquilibrium::tp_flash(&mix, t, p, &(&z * MOL), None, Default::default(), None)?;
+    let distribution = vle.component_phase_distribution();
+    assert_relative_eq!(
+        distribution.sum_axis(Axis(1)),
+        arr1(&[1.0, 1.0]),
+        max_relative = 1e-12
+    );
+
+    let total_moles = vle.vapor().total_moles + vle.liquid().total_moles;
+    let beta_v = (vle.vapor().total_moles / total_moles).into_value()?;
+    let feed = &vle.vapor().molefracs * beta_v + &vle.liquid().molefracs * (1.0 - beta_v);
+    assert_relative_eq!(feed, z, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_tp_flash_relaxation() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 390.0 * KELVIN;
+    let z = arr1(&[0.5, 0.5]);
+    let p_bubble = PhaseEquilibrium::bubble_point(&mix, t, &z, None, None, Default::default())?
+        .vapor()
+        .pressure(Contributions::Total);
+    let p_dew = PhaseEquilibrium::dew_point(&mix, t, &z, None, None, Default::default())?
+        .vapor()
+        .pressure(Contributions::Total);
+    let p = 0.5 * (p_bubble + p_dew);
+
+    let options = SolverOptions::new()
+        .max_iter(100)
+        .tol(1e-10)
+        .relaxation(0.5);
+    let vle = PhaseEquilibrium::tp_flash(&mix, t, p, &(&z * MOL), None, options, None)?;
+    assert_relative_eq!(
+        &vle.vapor().molefracs * &vle.vapor().ln_phi().mapv(f64::exp),
+        &vle.liquid().molefracs * &vle.liquid().ln_phi().mapv(f64::exp),
+        max_relative = 1e-8
+    );
+
+    let options = SolverOptions::new().relaxation(1.5);
+    assert!(PhaseEquilibrium::tp_flash(&mix, t, p, &(&z * MOL), None, options, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_tv_flash() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 250.0 * KELVIN;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let p = 1.0 * BAR;
+    let vle_tp = PhaseEquilibrium::tp_flash(&mix, t, p, &feed, None, Default::default(), None)?;
+    let volume = vle_tp.vapor().volume + vle_tp.liquid().volume;
+
+    let vle = PhaseEquilibrium::tv_flash(&mix, t, volume, &feed, Default::default())?;
+    assert_relative_eq!(
+        vle.vapor().volume + vle.liquid().volume,
+        volume,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        vle.liquid().pressure(Contributions::Total),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        p,
+        max_relative = 1e-6
+    );
+
+    // vapor fraction is consistent with the feed and the phase volumes
+    let n = feed.sum();
+    let beta = (vle.vapor().total_moles / n).into_value()?;
+    assert_relative_eq!(
+        beta,
+        (vle_tp.vapor().total_moles / n).into_value()?,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        beta * n / vle.vapor().density + (1.0 - beta) * n / vle.liquid().density,
+        volume,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        &(beta * &vle.vapor().molefracs + (1.0 - beta) * &vle.liquid().molefracs),
+        &arr1(&[0.5, 0.5]),
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lle_tie_line() -> Result<(), Box<dyn Error>> {
+    let params = read_params(vec!["water_np", "hexane"])?;
+    let saft = Arc::new(PcSaft::new(params));
+    let t = 300.0 * KELVIN;
+    let p = BAR;
+    let z = arr1(&[0.3, 0.7]);
+    let (lle, beta) = State::lle_tie_line(&saft, t, p, &z, SolverOptions::default())?;
+
+    // the feed lies on the tie line (lever rule)
+    let x1 = &lle.vapor().molefracs;
+    let x2 = &lle.liquid().molefracs;
+    assert_relative_eq!(beta[0] + beta[1], 1.0, epsilon = 1e-10);
+    assert_relative_eq!(x1 * beta[0] + x2 * beta[1], z, epsilon = 1e-8);
+    assert!((x1[0] - x2[0]).abs() > 0.5);
+
+    // both phases are liquid-like
+    for phase in [lle.vapor(), lle.liquid()] {
+        assert!(phase.phase_identification_parameter() > 1.0);
+    }
+    let roles = lle.phase_roles();
+    let (light, heavy) = if lle.vapor().density < lle.liquid().density {
+        (roles[0], roles[1])
+    } else {
+        (roles[1], roles[0])
+    };
+    assert_eq!((light, heavy), (PhaseRole::Liquid1, PhaseRole::Liquid2));
+
+    // single-phase feed
+    let saft = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let z = arr1(&[0.5, 0.5]);
+    assert!(matches!(
+        State::lle_tie_line(&saft, t, 20.0 * BAR, &z, SolverOptions::default()),
+        Err(EosError::NoPhaseSplit)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_ph_ps_flash() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 250.0 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let n = feed.sum();
+    let options = (SolverOptions::default(), SolverOptions::default());
+
+    // two-phase feed
+    let vle_tp = PhaseEquilibrium::tp_flash(&mix, t, p, &feed, None, Default::default(), None)?;
+    let h = (vle_tp.vapor().enthalpy(Contributions::Total)
+        + vle_tp.liquid().enthalpy(Contributions::Total))
+        / n;
+    let s = (vle_tp.vapor().entropy(Contributions::Total)
+        + vle_tp.liquid().entropy(Contributions::Total))
+        / n;
+    let beta = (vle_tp.vapor().total_moles / n).into_value()?;
+    for result in [
+        State::new_ph(&mix, p, h, &feed, Some(270.0 * KELVIN), options)?,
+        State::new_ps(&mix, p, s, &feed, Some(270.0 * KELVIN), options)?,
+    ] {
+        assert_relative_eq!(result.temperature(), t, max_relative = 1e-8);
+        match result {
+            FlashResult::TwoPhase(vle) => assert_relative_eq!(
+                (vle.vapor().total_moles / n).into_value()?,
+                beta,
+                max_relative = 1e-6
+            ),
+            FlashResult::SinglePhase(_) => panic!("The feed should split into two phases."),
+        }
+    }
+
+    // single-phase (vapor) feed
+    let t = 350.0 * KELVIN;
+    let state = State::new_npt(&mix, t, p, &feed, DensityInitialization::None)?;
+    let h = state.molar_enthalpy(Contributions::Total);
+    match State::new_ph(&mix, p, h, &feed, None, options)? {
+        FlashResult::SinglePhase(s) => assert_relative_eq!(s.temperature, t, max_relative = 1e-8),
+        FlashResult::TwoPhase(_) => panic!("The feed should be stable."),
+    }
+
+    // pure component in the two-phase region
+    let propane = Arc::new(PcSaft::new(read_params(vec!["propane"])?));
+    let vle = PhaseEquilibrium::pure(&propane, p, None, Default::default())?;
+    let h = 0.3 * vle.vapor().molar_enthalpy(Contributions::Total)
+        + 0.7 * vle.liquid().molar_enthalpy(Contributions::Total);
+    let moles = arr1(&[1.0]) * MOL;
+    match State::new_ph(&propane, p, h, &moles, None, options)? {
+        FlashResult::TwoPhase(vle_ph) => {
+            assert_relative_eq!(
+                vle_ph.vapor().temperature,
+                vle.vapor().temperature,
+                max_relative = 1e-10
+            );
+            assert_relative_eq!(
+                (vle_ph.vapor().total_moles / MOL).into_value()?,
+                0.3,
+                max_relative = 1e-8
+            );
+        }
+        FlashResult::SinglePhase(_) => panic!("The feed should split into two phases."),
+    }
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{AzeotropeType, Contributions, PhaseDiagram, PhaseEquilibrium, State};
+use ndarray::arr1;
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn azeotrope_ethanol_water() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["ethanol", "water"],
+        "parameters/pcsaft/gross2002.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let vle = PhaseDiagram::binary_vle(&saft, 350.0 * KELVIN, None, None, Default::default())?;
+    let azeotrope = vle.azeotrope()?.ok_or("no azeotrope found")?;
+    assert_eq!(azeotrope.azeotrope_type, AzeotropeType::MinimumBoiling);
+    assert!(azeotrope.molefrac > 0.5);
+    Ok(())
+}
+
+#[test]
+fn bancroft_point_benzene_cyclohexane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["benzene", "cyclohexane"],
+        "parameters/pcsaft/gross2001.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let (t, p) = PhaseEquilibrium::bancroft_point(&saft, 350.0 * KELVIN, Default::default())?;
+    let p_sat = PhaseEquilibrium::vapor_pressure(&saft, t);
+    let p1 = p_sat[0].ok_or("no vapor pressure")?;
+    let p2 = p_sat[1].ok_or("no vapor pressure")?;
+    assert_relative_eq!(p1, p2, max_relative = 1e-8);
+    assert_relative_eq!(p, p1, max_relative = 1e-8);
+    Ok(())
+}
+
+#[test]
+fn isopleth_propane_butane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    let cp = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    let p_c = cp.pressure(Contributions::Total);
+    let temperatures = SIArray1::linspace(350.0 * KELVIN, 0.997 * cp.temperature, 30)?;
+    let (bubble, dew) = PhaseDiagram::isopleth(&saft, &moles, &temperatures, Default::default())?;
+    assert_eq!(bubble.states.len(), 30);
+    assert_eq!(dew.states.len(), 30);
+
+    let p_bubble = bubble.liquid().pressure();
+    let p_dew = dew.vapor().pressure();
+    let gap: Vec<f64> = (0..30)
+        .map(|i| (p_bubble.get(i) - p_dew.get(i)).to_reduced(p_bubble.get(i)))
+        .collect::<Result<_, _>>()?;
+    assert!(gap.iter().all(|&g| g > 0.0));
+
+    // bubble and dew curves approach each other at the critical point
+    assert!(gap[29] < 0.2 * gap[0]);
+    assert_relative_eq!(p_bubble.get(29), p_c, max_relative = 5e-2);
+    Ok(())
+}
+
+#[test]
+fn vlle_water_hexane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["water_np", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let vlle = PhaseEquilibrium::vlle_binary(&saft, 300.0 * KELVIN, Default::default())?;
+    let p_v = vlle.vapor().pressure(Contributions::Total);
+    let mu_v = vlle.vapor().chemical_potential(Contributions::Total);
+    for liquid in [vlle.liquid1(), vlle.liquid2()] {
+        assert!(liquid.density > vlle.vapor().density);
+        assert_relative_eq!(
+            liquid.pressure(Contributions::Total),
+            p_v,
+            max_relative = 1e-8
+        );
+        let mu_l = liquid.chemical_potential(Contributions::Total);
+        for i in 0..2 {
+            assert_relative_eq!(mu_l.get(i), mu_v.get(i), max_relative = 1e-6);
+        }
+    }
+    assert!((vlle.liquid1().molefracs[0] - vlle.liquid2().molefracs[0]).abs() > 0.5);
+    Ok(())
+}
+
+#[test]
+fn pxy_propane_butane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    // both components subcritical
+    let dia = PhaseDiagram::binary_vle(&saft, 300.0 * KELVIN, Some(21), None, Default::default())?;
+    assert!(dia.states.len() <= 21);
+    let p_sat = PhaseEquilibrium::vapor_pressure(&saft, 300.0 * KELVIN);
+    let p = dia.liquid().pressure();
+    assert_relative_eq!(
+        p.get(0),
+        p_sat[1].ok_or("no vapor pressure")?,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        p.get(dia.states.len() - 1),
+        p_sat[0].ok_or("no vapor pressure")?,
+        max_relative = 1e-8
+    );
+
+    // propane is supercritical: the diagram ends at the critical point
+    let dia = PhaseDiagram::binary_vle(&saft, 380.0 * KELVIN, Some(21), None, Default::default())?;
+    let cp = dia.states.last().ok_or("empty phase diagram")?;
+    assert_relative_eq!(
+        cp.vapor().molefracs[0],
+        cp.liquid().molefracs[0],
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_pxy_propane_butane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(4).build()?;
+    let par_dia = PhaseDiagram::par_binary_pxy(&saft, t, 21, 5, thread_pool, Default::default())?;
+    let dia = PhaseDiagram::binary_vle(&saft, t, Some(21), None, Default::default())?;
+    assert_eq!(par_dia.states.len(), dia.states.len());
+    let (p_par, p) = (par_dia.liquid().pressure(), dia.liquid().pressure());
+    let (y_par, y) = (par_dia.vapor().molefracs(), dia.vapor().molefracs());
+    for i in 0..dia.states.len() {
+        assert_relative_eq!(p_par.get(i), p.get(i), max_relative = 1e-6);
+        assert_relative_eq!(y_par[[i, 0]], y[[i, 0]], max_relative = 1e-6);
+    }
+
+    // invalid number of points or chunksize
+    for (npoints, chunksize) in [(1, 5), (21, 0)] {
+        let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(4).build()?;
+        let dia = PhaseDiagram::par_binary_pxy(
+            &saft,
+            t,
+            npoints,
+            chunksize,
+            thread_pool,
+            Default::default(),
+        );
+        assert!(matches!(dia, Err(feos_core::EosError::Error(_))));
+    }
+    Ok(())
+}
+
+#[test]
+fn phase_diagram_to_arrays() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let dia = PhaseDiagram::binary_vle(&saft, 300.0 * KELVIN, Some(11), None, Default::default())?;
+    let columns = dia.to_arrays();
+    let names: Vec<_> = columns.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "temperature",
+            "pressure",
+            "density liquid",
+            "density vapor",
+            "x0",
+            "x1",
+            "y0",
+            "y1"
+        ]
+    );
+    let x0 = columns[4].1.clone().into_value()?;
+    let x1 = columns[5].1.clone().into_value()?;
+    assert_relative_eq!(&x0 + &x1, arr1(&vec![1.0; x0.len()]), epsilon = 1e-12);
+
+    let path = std::env::temp_dir().join("feos_phase_diagram_to_arrays.csv");
+    dia.to_csv(&path)?;
+    let csv = std::fs::read_to_string(&path)?;
+    std::fs::remove_file(&path)?;
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some(names.join(",").as_str()));
+    assert_eq!(lines.count(), dia.states.len());
+
+    // pure component diagrams do not contain mole fractions
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let propane = Arc::new(PcSaft::new(Arc::new(params)));
+    let dia = PhaseDiagram::pure(&propane, 250.0 * KELVIN, 11, None, Default::default())?;
+    assert_eq!(dia.to_arrays().len(), 4);
+    Ok(())
+}
+
+#[test]
+fn phase_envelope_propane_butane() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    let envelope = PhaseDiagram::phase_envelope(&saft, &moles, 200, Default::default())?;
+
+    // the feed composition is found in the liquid (vapor) phase of the bubble (dew) points
+    let bubble_points = envelope.bubble_points();
+    let dew_points = envelope.dew_points();
+    assert!(bubble_points.0.len() > 10);
+    assert!(dew_points.0.len() > 10);
+    assert!(bubble_points
+        .0
+        .iter()
+        .all(|s| (s.molefracs[0] - 0.5).abs() < 1e-10));
+    assert!(dew_points
+        .0
+        .iter()
+        .all(|s| (s.molefracs[0] - 0.5).abs() < 1e-10));
+
+    // both branches start at 1 bar and end at the critical point
+    assert_relative_eq!(
+        bubble_points.0[0].pressure(Contributions::Total),
+        BAR,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        dew_points.0[0].pressure(Contributions::Total),
+        BAR,
+        max_relative = 1e-6
+    );
+    let cp = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    let n = bubble_points.0.len();
+    assert_relative_eq!(
+        bubble_points.0[n - 1].temperature,
+        cp.temperature,
+        max_relative = 1e-6
+    );
+    let n = dew_points.0.len();
+    assert_relative_eq!(
+        dew_points.0[n - 1].temperature,
+        cp.temperature,
+        max_relative = 1e-6
+    );
+
+    // cricondentherm and cricondenbar bound the envelope
+    let t_max = envelope.cricondentherm.vapor().temperature;
+    let p_max = envelope.cricondenbar.vapor().pressure(Contributions::Total);
+    for s in bubble_points.0.iter().chain(dew_points.0.iter()) {
+        assert!(s.temperature <= t_max * (1.0 + 1e-10));
+        assert!(s.pressure(Contributions::Total) <= p_max * (1.0 + 1e-10));
+    }
+    // for the 50/50 mixture, both extrema lie on the retrograde part of the dew point line
+    assert!(t_max > cp.temperature);
+    assert!(p_max > cp.pressure(Contributions::Total));
+    assert!(dew_points.0.iter().any(|s| s.temperature > cp.temperature));
+    Ok(())
+}
@@ -1,7 +1,8 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Contributions, PhaseEquilibrium};
+use feos_core::{Contributions, PhaseEquilibrium, PhaseRole, State};
+use ndarray::arr1;
 use quantity::si::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -75,3 +76,88 @@ fn vle_pure_pressure() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn vle_pure_phase_roles() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let vle = PhaseEquilibrium::pure(&saft, 300.0 * KELVIN, None, Default::default())?;
+    assert_eq!(vle.phase_roles(), [PhaseRole::Vapor, PhaseRole::Liquid]);
+    assert!(vle.vapor().density < vle.liquid().density);
+    assert!(vle.vapor().phase_identification_parameter() < 1.0);
+    assert!(vle.liquid().phase_identification_parameter() > 1.0);
+    Ok(())
+}
+
+#[test]
+fn enthalpy_temperature_isobar() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let p = 1.0 * BAR;
+    let vle = PhaseEquilibrium::pure(&saft, p, None, Default::default())?;
+    let t_sat = vle.liquid().temperature;
+    let temperatures = SIArray1::linspace(200.0 * KELVIN, 260.0 * KELVIN, 13)?;
+    let moles = arr1(&[1.0]) * MOL;
+    let (t, h) =
+        State::enthalpy_temperature_isobar(&saft, p, &temperatures, &moles, Default::default())?;
+    assert_eq!(t.len(), 15);
+
+    // the saturation temperature is inserted twice
+    let i = (0..t.len())
+        .find(|&i| t.get(i) == t_sat)
+        .ok_or("saturation temperature not found")?;
+    assert_eq!(t.get(i + 1), t_sat);
+
+    // latent heat of vaporization
+    let dh_vap = vle.vapor().molar_enthalpy(Contributions::Total)
+        - vle.liquid().molar_enthalpy(Contributions::Total);
+    assert_relative_eq!(h.get(i + 1) - h.get(i), dh_vap, max_relative = 1e-10);
+    assert!(h.get(i + 1) - h.get(i) > 10.0 * (h.get(i) - h.get(i - 1)));
+    assert!(h.get(i + 1) - h.get(i) > 10.0 * (h.get(i + 2) - h.get(i + 1)));
+
+    // the enthalpy increases monotonically along the isobar
+    assert!((1..t.len()).all(|i| h.get(i) >= h.get(i - 1)));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_pure_phase_diagram() -> Result<(), Box<dyn Error>> {
+    use feos_core::PhaseDiagram;
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let thread_pool = rayon::ThreadPoolBuilder::new().num_threads(4).build()?;
+    let par_dia = PhaseDiagram::par_pure(
+        &saft,
+        200.0 * KELVIN,
+        31,
+        5,
+        thread_pool,
+        None,
+        Default::default(),
+    )?;
+    let dia = PhaseDiagram::pure(&saft, 200.0 * KELVIN, 31, None, Default::default())?;
+    assert_eq!(par_dia.states.len(), 31);
+    assert_eq!(par_dia.states.len(), dia.states.len());
+    let (p_par, p) = (par_dia.vapor().pressure(), dia.vapor().pressure());
+    for i in 0..31 {
+        assert_relative_eq!(p_par.get(i), p.get(i), max_relative = 1e-8);
+    }
+    Ok(())
+}
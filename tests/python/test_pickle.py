@@ -0,0 +1,58 @@
+import pickle
+from pathlib import Path
+
+import numpy as np
+import pytest
+from feos.eos import EquationOfState, State, ThermalDeBroglie
+from feos.gc_pcsaft import GcPcSaftEosParameters, IdentifierOption
+from feos.pcsaft import PcSaftParameters
+from feos.si import KELVIN, METER, MOL, PASCAL
+
+PARAMETERS = Path(__file__).parents[2] / "parameters" / "pcsaft"
+
+
+def pcsaft():
+    parameters = PcSaftParameters.from_json(
+        ["ethanol", "water"], str(PARAMETERS / "gross2002.json")
+    )
+    return EquationOfState.pcsaft(parameters, max_eta=0.45, max_iter_cross_assoc=100)
+
+
+def gc_pcsaft():
+    parameters = GcPcSaftEosParameters.from_json_segments(
+        ["CCCOC(C)=O", "CCCO"],
+        str(PARAMETERS / "gc_substances.json"),
+        str(PARAMETERS / "sauer2014_hetero.json"),
+        search_option=IdentifierOption.Smiles,
+    )
+    return EquationOfState.gc_pcsaft(parameters, max_eta=0.45)
+
+
+def pressure(eos):
+    state = State(
+        eos,
+        temperature=350 * KELVIN,
+        volume=1e-3 * METER**3,
+        moles=np.array([0.3, 0.7]) * MOL,
+    )
+    return state.pressure() / PASCAL
+
+
+@pytest.mark.parametrize("model", [pcsaft, gc_pcsaft])
+def test_pickle_equation_of_state(model):
+    eos = model()
+    eos_pickle = pickle.loads(pickle.dumps(eos))
+    assert pressure(eos_pickle) == pytest.approx(pressure(eos), rel=1e-14)
+
+
+def test_pickle_composite():
+    eos = EquationOfState.composite(pcsaft(), [])
+    eos_pickle = pickle.loads(pickle.dumps(eos))
+    assert pressure(eos_pickle) == pytest.approx(pressure(eos), rel=1e-14)
+
+
+def test_pickle_with_ideal_gas():
+    ideal_gas = ThermalDeBroglie(np.array([46.069, 18.015]))
+    eos = EquationOfState.with_ideal_gas(pcsaft(), ideal_gas)
+    eos_pickle = pickle.loads(pickle.dumps(eos))
+    assert pressure(eos_pickle) == pytest.approx(pressure(eos), rel=1e-14)
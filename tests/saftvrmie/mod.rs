@@ -0,0 +1 @@
+mod vle_pure;
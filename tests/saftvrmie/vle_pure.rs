@@ -0,0 +1,47 @@
+use approx::assert_relative_eq;
+use feos::saftvrmie::{SaftVRMie, SaftVRMieParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::{Contributions, PhaseEquilibrium};
+use quantity::si::*;
+use std::error::Error;
+use std::sync::Arc;
+
+/// Vapor pressures and saturated liquid densities of propane
+/// compared to experimental data (NIST Chemistry WebBook) using
+/// the parameters of Lafitte et al. (2013).
+#[test]
+fn vle_pure_propane() -> Result<(), Box<dyn Error>> {
+    let params = SaftVRMieParameters::from_json(
+        vec!["propane"],
+        "tests/saftvrmie/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(SaftVRMie::new(Arc::new(params)));
+    let data = [
+        (
+            231.04 * KELVIN,
+            1.01325 * BAR,
+            13.173 * KILO * MOL / METER.powi(3),
+        ),
+        (
+            300.0 * KELVIN,
+            9.9742 * BAR,
+            11.104 * KILO * MOL / METER.powi(3),
+        ),
+    ];
+    for (t, p, rho_l) in data {
+        let vle = PhaseEquilibrium::pure(&saft, t, None, Default::default())?;
+        assert_relative_eq!(
+            vle.vapor().pressure(Contributions::Total).to_reduced(p)?,
+            1.0,
+            max_relative = 1e-2
+        );
+        assert_relative_eq!(
+            vle.liquid().density.to_reduced(rho_l)?,
+            1.0,
+            max_relative = 1e-2
+        );
+    }
+    Ok(())
+}